@@ -7,6 +7,7 @@ use serde::Deserialize;
 use tracing::info;
 
 use ghc_core::browser::Browser;
+use ghc_core::clipboard::{Clipboard, SystemClipboard};
 use ghc_core::instance;
 
 /// OAuth device code response.
@@ -20,10 +21,19 @@ pub struct DeviceCodeResponse {
     pub verification_uri: String,
     /// Expiration in seconds.
     pub expires_in: u64,
-    /// Polling interval in seconds.
+    /// Minimum polling interval in seconds.
+    #[serde(default = "default_interval")]
     pub interval: u64,
 }
 
+/// Polling interval to use when the server does not send one (RFC 8628).
+fn default_interval() -> u64 {
+    MIN_POLL_INTERVAL
+}
+
+/// Shortest polling interval honoured, even if the server asks for less.
+const MIN_POLL_INTERVAL: u64 = 5;
+
 /// OAuth access token response.
 #[derive(Debug, Deserialize)]
 pub struct AccessTokenResponse {
@@ -37,6 +47,38 @@ pub struct AccessTokenResponse {
     pub error: Option<String>,
     /// Error description.
     pub error_description: Option<String>,
+    /// New polling interval, sent with `slow_down`.
+    pub interval: Option<u64>,
+}
+
+/// A device flow that ended without a token.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum DeviceFlowError {
+    /// The one-time code expired before it was authorized.
+    #[error(
+        "the one-time code expired before it was authorized; run `ghc auth login` to try again"
+    )]
+    Expired,
+    /// The user declined the authorization request.
+    #[error("authorization was denied in the browser")]
+    AccessDenied,
+    /// Any other error reported by the token endpoint.
+    #[error("OAuth error: {code} - {description}")]
+    OAuth {
+        /// The `error` code.
+        code: String,
+        /// The `error_description` text.
+        description: String,
+    },
+}
+
+/// What to do after one poll of the token endpoint.
+#[derive(Debug, PartialEq, Eq)]
+enum PollStep {
+    /// Authorization finished with this access token.
+    Token(String),
+    /// Not authorized yet; poll again after this many seconds.
+    Wait(u64),
 }
 
 /// Result of a completed OAuth device flow.
@@ -92,7 +134,7 @@ pub async fn auth_flow(
     )?;
 
     if copy_to_clipboard {
-        if let Err(e) = copy_to_system_clipboard(&device_code.user_code) {
+        if let Err(e) = SystemClipboard.copy(&device_code.user_code) {
             writeln!(write_status, "! Failed to copy to clipboard: {e}",)?;
         } else {
             writeln!(write_status, "! Copied to clipboard.")?;
@@ -101,7 +143,7 @@ pub async fn auth_flow(
 
     writeln!(
         write_status,
-        "- Press Enter to open {} in your browser...",
+        "- Opening {} in your browser...",
         device_code.verification_uri,
     )?;
 
@@ -133,13 +175,7 @@ pub async fn request_device_code(
     hostname: &str,
     scopes: &[&str],
 ) -> anyhow::Result<DeviceCodeResponse> {
-    let normalized = instance::normalize_hostname(hostname);
-    let url = if instance::is_github_com(&normalized) {
-        "https://github.com/login/device/code".to_string()
-    } else {
-        format!("https://{normalized}/login/device/code")
-    };
-
+    let url = login_url(hostname, "device/code");
     let scope = scopes.join(" ");
 
     let resp = client
@@ -163,36 +199,58 @@ pub async fn request_device_code(
     Ok(code)
 }
 
+/// The `/login/...` endpoint URL on `hostname`.
+fn login_url(hostname: &str, path: &str) -> String {
+    let normalized = instance::normalize_hostname(hostname);
+    if instance::is_github_com(&normalized) {
+        format!("https://github.com/login/{path}")
+    } else {
+        format!("https://{normalized}/login/{path}")
+    }
+}
+
 /// Poll for the access token after user completes verification.
 ///
+/// Waits `interval` seconds (at least five) between polls, backing off
+/// further whenever the server answers `slow_down`.
+///
 /// # Errors
 ///
-/// Returns an error if polling fails or the code expires.
+/// Returns a [`DeviceFlowError`] if the code expires, the user denies
+/// access, or the server reports another OAuth error, and an error if a
+/// request fails.
 pub async fn poll_access_token(
     client: &reqwest::Client,
     hostname: &str,
     device_code: &DeviceCodeResponse,
 ) -> anyhow::Result<String> {
-    let normalized = instance::normalize_hostname(hostname);
-    let url = if instance::is_github_com(&normalized) {
-        "https://github.com/login/oauth/access_token".to_string()
-    } else {
-        format!("https://{normalized}/login/oauth/access_token")
-    };
+    poll_token_endpoint(
+        client,
+        &login_url(hostname, "oauth/access_token"),
+        device_code,
+        device_code.interval.max(MIN_POLL_INTERVAL),
+    )
+    .await
+}
 
-    let interval = std::time::Duration::from_secs(device_code.interval.max(5));
+async fn poll_token_endpoint(
+    client: &reqwest::Client,
+    url: &str,
+    device_code: &DeviceCodeResponse,
+    mut interval: u64,
+) -> anyhow::Result<String> {
     let deadline =
         tokio::time::Instant::now() + std::time::Duration::from_secs(device_code.expires_in);
 
     loop {
-        tokio::time::sleep(interval).await;
+        tokio::time::sleep(std::time::Duration::from_secs(interval)).await;
 
         if tokio::time::Instant::now() > deadline {
-            anyhow::bail!("device code expired");
+            return Err(DeviceFlowError::Expired.into());
         }
 
         let resp = client
-            .post(&url)
+            .post(url)
             .header("Accept", "application/json")
             .form(&[
                 ("client_id", CLIENT_ID),
@@ -203,21 +261,30 @@ pub async fn poll_access_token(
             .await?;
 
         let token_resp: AccessTokenResponse = resp.json().await?;
-
-        if let Some(token) = token_resp.access_token {
-            return Ok(token);
+        match next_poll_step(token_resp, interval)? {
+            PollStep::Token(token) => return Ok(token),
+            PollStep::Wait(next) => interval = next,
         }
+    }
+}
 
-        match token_resp.error.as_deref() {
-            Some("authorization_pending") | None => {}
-            Some("slow_down") => {
-                tokio::time::sleep(std::time::Duration::from_secs(5)).await;
-            }
-            Some(err) => {
-                let desc = token_resp.error_description.unwrap_or_default();
-                anyhow::bail!("OAuth error: {err} - {desc}");
-            }
-        }
+/// Interpret a token endpoint response.
+///
+/// `slow_down` raises the interval to the server's new value, or by five
+/// seconds when it sends none, for all later polls.
+fn next_poll_step(resp: AccessTokenResponse, interval: u64) -> Result<PollStep, DeviceFlowError> {
+    if let Some(token) = resp.access_token.filter(|t| !t.is_empty()) {
+        return Ok(PollStep::Token(token));
+    }
+    match resp.error.as_deref() {
+        Some("authorization_pending") | None => Ok(PollStep::Wait(interval)),
+        Some("slow_down") => Ok(PollStep::Wait(resp.interval.unwrap_or(interval + 5))),
+        Some("expired_token") => Err(DeviceFlowError::Expired),
+        Some("access_denied") => Err(DeviceFlowError::AccessDenied),
+        Some(code) => Err(DeviceFlowError::OAuth {
+            code: code.to_string(),
+            description: resp.error_description.unwrap_or_default(),
+        }),
     }
 }
 
@@ -262,62 +329,137 @@ async fn get_username(
     Ok(wrapper.data.viewer.login)
 }
 
-/// Copy text to the system clipboard.
-fn copy_to_system_clipboard(text: &str) -> anyhow::Result<()> {
-    #[cfg(target_os = "macos")]
-    {
-        use std::io::Write;
-        let mut child = std::process::Command::new("pbcopy")
-            .stdin(std::process::Stdio::piped())
-            .spawn()?;
-        if let Some(mut stdin) = child.stdin.take() {
-            stdin.write_all(text.as_bytes())?;
-        }
-        child.wait()?;
-        return Ok(());
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wiremock::matchers::{body_string_contains, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn token_response(value: serde_json::Value) -> AccessTokenResponse {
+        serde_json::from_value(value).unwrap()
     }
 
-    #[cfg(target_os = "linux")]
-    {
-        use std::io::Write;
-        // Try xclip first, then xsel
-        let result = std::process::Command::new("xclip")
-            .args(["-selection", "clipboard"])
-            .stdin(std::process::Stdio::piped())
-            .spawn();
-        if let Ok(mut child) = result {
-            if let Some(mut stdin) = child.stdin.take() {
-                stdin.write_all(text.as_bytes())?;
-            }
-            child.wait()?;
-            return Ok(());
-        }
-        let mut child = std::process::Command::new("xsel")
-            .args(["--clipboard", "--input"])
-            .stdin(std::process::Stdio::piped())
-            .spawn()?;
-        if let Some(mut stdin) = child.stdin.take() {
-            stdin.write_all(text.as_bytes())?;
+    fn device_code(interval: u64) -> DeviceCodeResponse {
+        DeviceCodeResponse {
+            device_code: "dc-123".into(),
+            user_code: "ABCD-1234".into(),
+            verification_uri: "https://github.com/login/device".into(),
+            expires_in: 900,
+            interval,
         }
-        child.wait()?;
-        return Ok(());
     }
 
-    #[cfg(target_os = "windows")]
-    {
-        use std::io::Write;
-        let mut child = std::process::Command::new("clip")
-            .stdin(std::process::Stdio::piped())
-            .spawn()?;
-        if let Some(mut stdin) = child.stdin.take() {
-            stdin.write_all(text.as_bytes())?;
-        }
-        child.wait()?;
-        return Ok(());
+    #[test]
+    fn test_should_keep_waiting_while_authorization_is_pending() {
+        let resp = token_response(serde_json::json!({ "error": "authorization_pending" }));
+        assert_eq!(next_poll_step(resp, 5), Ok(PollStep::Wait(5)));
+    }
+
+    #[test]
+    fn test_should_back_off_on_slow_down() {
+        let resp = token_response(serde_json::json!({ "error": "slow_down" }));
+        assert_eq!(next_poll_step(resp, 5), Ok(PollStep::Wait(10)));
+
+        let resp = token_response(serde_json::json!({ "error": "slow_down", "interval": 15 }));
+        assert_eq!(next_poll_step(resp, 10), Ok(PollStep::Wait(15)));
+    }
+
+    #[test]
+    fn test_should_distinguish_terminal_errors() {
+        let resp = token_response(serde_json::json!({ "error": "expired_token" }));
+        assert_eq!(next_poll_step(resp, 5), Err(DeviceFlowError::Expired));
+
+        let resp = token_response(serde_json::json!({ "error": "access_denied" }));
+        assert_eq!(next_poll_step(resp, 5), Err(DeviceFlowError::AccessDenied));
+
+        let resp = token_response(serde_json::json!({
+            "error": "unsupported_grant_type",
+            "error_description": "bad grant"
+        }));
+        assert_eq!(
+            next_poll_step(resp, 5),
+            Err(DeviceFlowError::OAuth {
+                code: "unsupported_grant_type".into(),
+                description: "bad grant".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_should_default_interval_when_missing() {
+        let code: DeviceCodeResponse = serde_json::from_value(serde_json::json!({
+            "device_code": "dc",
+            "user_code": "UC",
+            "verification_uri": "https://github.com/login/device",
+            "expires_in": 900
+        }))
+        .unwrap();
+        assert_eq!(code.interval, 5);
+    }
+
+    #[tokio::test]
+    async fn test_should_poll_until_token_is_issued() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/login/oauth/access_token"))
+            .and(body_string_contains("device_code=dc-123"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "error": "authorization_pending" })),
+            )
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/login/oauth/access_token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(
+                serde_json::json!({ "access_token": "gho_abc", "token_type": "bearer" }),
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let url = format!("{}/login/oauth/access_token", server.uri());
+        let token = poll_token_endpoint(&reqwest::Client::new(), &url, &device_code(0), 0)
+            .await
+            .unwrap();
+        assert_eq!(token, "gho_abc");
+    }
+
+    #[tokio::test]
+    async fn test_should_stop_polling_when_access_is_denied() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/login/oauth/access_token"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "error": "access_denied" })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let url = format!("{}/login/oauth/access_token", server.uri());
+        let err = poll_token_endpoint(&reqwest::Client::new(), &url, &device_code(0), 0)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<DeviceFlowError>(),
+            Some(&DeviceFlowError::AccessDenied)
+        );
     }
 
-    #[allow(unreachable_code)]
-    {
-        anyhow::bail!("clipboard not supported on this platform")
+    #[test]
+    fn test_should_build_login_urls_per_host() {
+        assert_eq!(
+            login_url("github.com", "device/code"),
+            "https://github.com/login/device/code"
+        );
+        assert_eq!(
+            login_url("GHE.corp.example", "oauth/access_token"),
+            "https://ghe.corp.example/login/oauth/access_token"
+        );
     }
 }
@@ -0,0 +1,220 @@
+//! On-disk HTTP response cache keyed by `ETag`.
+//!
+//! Stores successful `GET` responses together with their `ETag` so that
+//! repeated reads can be served locally while fresh, and revalidated with
+//! `If-None-Match` once stale. GraphQL queries are cached the same way,
+//! keyed on their query text and variables. Entries are keyed on the request
+//! method, URL, `Accept` header, request body, and a fingerprint of the
+//! credentials, so responses are never shared between differently
+//! authenticated clients. Entry files are only readable by their owner.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Environment variable that disables the response cache when set.
+pub const NO_CACHE_ENV: &str = "GH_NO_CACHE";
+
+/// A directory of cached HTTP responses with a freshness window.
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    dir: PathBuf,
+    ttl: Duration,
+}
+
+/// A cached response body and the validator needed to revalidate it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CacheEntry {
+    /// `ETag` returned with the response, if any.
+    pub(crate) etag: Option<String>,
+    /// Raw response body.
+    pub(crate) body: String,
+    /// Unix timestamp of when the entry was stored or last revalidated.
+    pub(crate) stored_at: i64,
+}
+
+impl ResponseCache {
+    /// Create a cache rooted at `dir` whose entries stay fresh for `ttl`.
+    pub fn new(dir: impl Into<PathBuf>, ttl: Duration) -> Self {
+        Self {
+            dir: dir.into(),
+            ttl,
+        }
+    }
+
+    /// Directory holding the cache entries.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Whether caching is disabled through [`NO_CACHE_ENV`].
+    pub fn disabled_by_env() -> bool {
+        std::env::var_os(NO_CACHE_ENV).is_some_and(|v| !v.is_empty())
+    }
+
+    /// Compute the cache key for a request.
+    pub(crate) fn key(
+        method: &reqwest::Method,
+        url: &str,
+        accept: &str,
+        body: &str,
+        token: Option<&str>,
+    ) -> String {
+        let mut hasher = Sha256::new();
+        for part in [method.as_str(), url, accept, body, token.unwrap_or("")] {
+            hasher.update(part.as_bytes());
+            hasher.update([0]);
+        }
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// Load an entry, treating unreadable or corrupt files as a miss.
+    pub(crate) fn load(&self, key: &str) -> Option<CacheEntry> {
+        let content = std::fs::read_to_string(self.entry_path(key)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Store an entry. Failures are ignored; the cache is only an optimization.
+    pub(crate) fn store(&self, key: &str, entry: &CacheEntry) {
+        let Ok(content) = serde_json::to_string(entry) else {
+            return;
+        };
+        if std::fs::create_dir_all(&self.dir).is_ok() {
+            let _ = write_private(&self.entry_path(key), &content);
+        }
+    }
+
+    /// Whether an entry stored at `stored_at` is still fresh at `now`.
+    pub(crate) fn is_fresh(&self, entry: &CacheEntry, now: i64) -> bool {
+        let age = now - entry.stored_at;
+        age >= 0 && u64::try_from(age).is_ok_and(|age| age < self.ttl.as_secs())
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.json"))
+    }
+}
+
+/// Write `content` to `path`, creating it with mode `0600` on Unix.
+fn write_private(path: &Path, content: &str) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)?.write_all(content.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(stored_at: i64) -> CacheEntry {
+        CacheEntry {
+            etag: Some("\"abc\"".to_string()),
+            body: "{\"name\":\"cli\"}".to_string(),
+            stored_at,
+        }
+    }
+
+    #[test]
+    fn test_should_key_on_method_url_accept_and_token() {
+        let get = reqwest::Method::GET;
+        let base = ResponseCache::key(&get, "https://api.github.com/x", "", "", Some("t1"));
+        assert_eq!(
+            base,
+            ResponseCache::key(&get, "https://api.github.com/x", "", "", Some("t1"))
+        );
+        assert_ne!(
+            base,
+            ResponseCache::key(
+                &reqwest::Method::HEAD,
+                "https://api.github.com/x",
+                "",
+                "",
+                Some("t1")
+            )
+        );
+        assert_ne!(
+            base,
+            ResponseCache::key(&get, "https://api.github.com/y", "", "", Some("t1"))
+        );
+        assert_ne!(
+            base,
+            ResponseCache::key(
+                &get,
+                "https://api.github.com/x",
+                "text/plain",
+                "",
+                Some("t1")
+            )
+        );
+        assert_ne!(
+            base,
+            ResponseCache::key(&get, "https://api.github.com/x", "", "", Some("t2"))
+        );
+        assert_ne!(
+            base,
+            ResponseCache::key(&get, "https://api.github.com/x", "", "", None)
+        );
+    }
+
+    #[test]
+    fn test_should_key_on_request_body() {
+        let post = reqwest::Method::POST;
+        let url = "https://api.github.com/graphql";
+        assert_ne!(
+            ResponseCache::key(&post, url, "", "{\"query\":\"a\"}", Some("t1")),
+            ResponseCache::key(&post, url, "", "{\"query\":\"b\"}", Some("t1"))
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_should_store_entries_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ResponseCache::new(dir.path(), Duration::from_secs(60));
+        cache.store("k", &entry(100));
+        let mode = std::fs::metadata(dir.path().join("k.json"))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn test_should_round_trip_entry() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ResponseCache::new(dir.path().join("http"), Duration::from_secs(60));
+        cache.store("k", &entry(100));
+        let loaded = cache.load("k").unwrap();
+        assert_eq!(loaded.etag.as_deref(), Some("\"abc\""));
+        assert_eq!(loaded.body, "{\"name\":\"cli\"}");
+        assert!(cache.load("missing").is_none());
+    }
+
+    #[test]
+    fn test_should_treat_corrupt_entry_as_miss() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ResponseCache::new(dir.path(), Duration::from_secs(60));
+        std::fs::write(dir.path().join("k.json"), "not json").unwrap();
+        assert!(cache.load("k").is_none());
+    }
+
+    #[test]
+    fn test_should_check_freshness() {
+        let cache = ResponseCache::new("/unused", Duration::from_secs(60));
+        assert!(cache.is_fresh(&entry(100), 100));
+        assert!(cache.is_fresh(&entry(100), 159));
+        assert!(!cache.is_fresh(&entry(100), 160));
+        assert!(!cache.is_fresh(&entry(100), 99));
+    }
+}
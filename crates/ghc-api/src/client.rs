@@ -5,7 +5,9 @@
 //! suggestions.
 
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::LazyLock;
+use std::time::Duration;
 
 use regex::Regex;
 use reqwest::header::HeaderMap;
@@ -14,8 +16,10 @@ use serde::de::DeserializeOwned;
 use serde_json::Value;
 use tracing::warn;
 
+use crate::cache::{CacheEntry, ResponseCache};
 use crate::errors::{ApiError, GraphQLErrorEntry};
 use ghc_core::instance;
+use ghc_core::iostreams::StderrHandle;
 
 /// Maximum number of retries for transient failures.
 const MAX_RETRIES: u32 = 3;
@@ -23,6 +27,12 @@ const MAX_RETRIES: u32 = 3;
 /// Initial back-off delay for retries.
 const RETRY_BASE_DELAY_MS: u64 = 1000;
 
+/// Environment variable overriding how many times a REST request is retried.
+pub const MAX_RETRIES_ENV: &str = "GH_MAX_RETRIES";
+
+/// Longest server-requested wait worth retrying after; longer waits fail.
+const MAX_RETRY_WAIT: Duration = Duration::from_secs(60);
+
 /// GitHub API client wrapping reqwest with auth and error handling.
 ///
 /// Tokens are stored as [`SecretString`] to prevent accidental logging or
@@ -35,6 +45,12 @@ pub struct Client {
     /// Optional base URL override for testing (e.g., `"http://127.0.0.1:PORT/"`).
     /// When set, REST and GraphQL requests use this instead of the real GitHub URLs.
     api_url_override: Option<String>,
+    /// Optional response cache for REST `GET` requests and GraphQL queries.
+    cache: Option<ResponseCache>,
+    /// Whether requests travel over a Unix domain socket as plain HTTP.
+    unix_socket: bool,
+    /// Where to announce retries; falls back to tracing when unset.
+    stderr: Option<StderrHandle>,
 }
 
 impl std::fmt::Debug for Client {
@@ -43,6 +59,8 @@ impl std::fmt::Debug for Client {
             .field("hostname", &self.hostname)
             .field("token", &self.token.as_ref().map(|_| "[REDACTED]"))
             .field("api_url_override", &self.api_url_override)
+            .field("cache", &self.cache)
+            .field("unix_socket", &self.unix_socket)
             .finish_non_exhaustive()
     }
 }
@@ -55,6 +73,71 @@ pub struct RestPage<T> {
     pub data: T,
     /// URL of the next page, if any.
     pub next_url: Option<String>,
+    /// HTTP status of the response.
+    pub status: reqwest::StatusCode,
+    /// HTTP version the response was served over.
+    pub version: reqwest::Version,
+    /// Response headers.
+    pub headers: HeaderMap,
+}
+
+/// JSON payloads of a server-sent event stream, read as they arrive.
+#[derive(Debug)]
+pub struct EventStream {
+    resp: reqwest::Response,
+    buf: Vec<u8>,
+    done: bool,
+}
+
+impl EventStream {
+    /// Wait for the next event's `data` payload.
+    ///
+    /// Returns `None` once the stream ends or sends `[DONE]`. Events without
+    /// data, such as keep-alive comments, are skipped.
+    pub async fn next(&mut self) -> Option<Result<Value, ApiError>> {
+        while !self.done {
+            if let Some(event) = self.take_event() {
+                let data = sse_data(&event);
+                if data.is_empty() {
+                    continue;
+                }
+                if data == "[DONE]" {
+                    self.done = true;
+                    return None;
+                }
+                return Some(serde_json::from_str(&data).map_err(ApiError::from));
+            }
+            match self.resp.chunk().await {
+                // Normalize CRLF line endings so events split on "\n\n".
+                Ok(Some(chunk)) => self.buf.extend(chunk.iter().filter(|&&b| b != b'\r')),
+                Ok(None) if self.buf.is_empty() => self.done = true,
+                // Treat a final event without a trailing blank line as complete.
+                Ok(None) => self.buf.extend_from_slice(b"\n\n"),
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e.into()));
+                }
+            }
+        }
+        None
+    }
+
+    /// Split the first complete event (terminated by a blank line) off the buffer.
+    fn take_event(&mut self) -> Option<String> {
+        let end = self.buf.windows(2).position(|w| w == b"\n\n")?;
+        let event: Vec<u8> = self.buf.drain(..end + 2).collect();
+        Some(String::from_utf8_lossy(&event).into_owned())
+    }
+}
+
+/// Join the `data:` lines of a server-sent event.
+fn sse_data(event: &str) -> String {
+    event
+        .lines()
+        .filter_map(|line| line.strip_prefix("data:"))
+        .map(|data| data.strip_prefix(' ').unwrap_or(data))
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 /// GraphQL page info for cursor-based pagination.
@@ -79,6 +162,9 @@ impl Client {
             hostname: instance::normalize_hostname(hostname),
             token,
             api_url_override: None,
+            cache: None,
+            unix_socket: false,
+            stderr: None,
         }
     }
 
@@ -93,6 +179,45 @@ impl Client {
         self
     }
 
+    /// Cache REST `GET` responses and GraphQL queries under the GHC cache
+    /// directory.
+    ///
+    /// Responses younger than `ttl` are served without a request; older REST
+    /// responses are revalidated with `If-None-Match`, and `304 Not Modified`
+    /// reuses the stored body. GraphQL mutations are never cached. Setting
+    /// `GH_NO_CACHE` disables the cache at request time.
+    #[must_use]
+    pub fn with_cache(self, ttl: Duration) -> Self {
+        self.with_cache_dir(ghc_core::config::cache_dir().join("http"), ttl)
+    }
+
+    /// Cache REST `GET` responses and GraphQL queries in a specific directory.
+    ///
+    /// See [`with_cache`](Self::with_cache).
+    #[must_use]
+    pub fn with_cache_dir(mut self, dir: impl Into<PathBuf>, ttl: Duration) -> Self {
+        self.cache = Some(ResponseCache::new(dir, ttl));
+        self
+    }
+
+    /// Send requests as plain HTTP for a client connected to a Unix socket.
+    ///
+    /// The process on the other end of an `http_unix_socket` is a local
+    /// proxy that handles TLS itself, so `https://` URLs are rewritten to
+    /// `http://` while the `Host` header keeps naming the API host.
+    #[must_use]
+    pub fn with_unix_socket(mut self) -> Self {
+        self.unix_socket = true;
+        self
+    }
+
+    /// Announce rate-limit and transient-failure retries on stderr.
+    #[must_use]
+    pub fn with_stderr(mut self, stderr: StderrHandle) -> Self {
+        self.stderr = Some(stderr);
+        self
+    }
+
     /// Get the hostname this client is configured for.
     pub fn hostname(&self) -> &str {
         &self.hostname
@@ -107,7 +232,10 @@ impl Client {
 
     /// Build a request with authentication headers applied.
     fn authed_request(&self, method: reqwest::Method, url: &str) -> reqwest::RequestBuilder {
-        let mut req = self.http.request(method, url);
+        let mut req = match url.strip_prefix("https://") {
+            Some(rest) if self.unix_socket => self.http.request(method, format!("http://{rest}")),
+            _ => self.http.request(method, url),
+        };
         if let Some(ref token) = self.token {
             req = req.header("Authorization", format!("token {}", token.expose_secret()));
         }
@@ -134,27 +262,13 @@ impl Client {
             "variables": variables,
         });
 
-        let resp = self
-            .authed_request(reqwest::Method::POST, &url)
-            .header("GraphQL-Features", "merge_queue")
-            .json(&body)
-            .send()
-            .await?;
-
-        let status = resp.status();
-        let headers = resp.headers().clone();
-
-        if !status.is_success() {
-            let text = resp.text().await.unwrap_or_default();
-            return Err(ApiError::Http {
-                status: status.as_u16(),
-                message: text,
-                scopes_suggestion: None,
-                headers: extract_header_map(&headers),
-            });
-        }
-
-        let body: Value = resp.json().await?;
+        let cache = self
+            .active_cache()
+            .filter(|_| !query.trim_start().starts_with("mutation"));
+        let body = match cache {
+            Some(cache) => self.cached_graphql(cache, &url, &body).await?,
+            None => self.post_graphql(&url, &body).await?,
+        };
 
         // Check for GraphQL errors
         if let Some(errors) = body.get("errors") {
@@ -193,8 +307,14 @@ impl Client {
         body: Option<&Value>,
     ) -> Result<T, ApiError> {
         let url = self.resolve_rest_url(path);
-        let resp = self.send_rest_request(method, &url, body).await?;
-        let resp = Self::check_response(resp, true).await?;
+        if method == reqwest::Method::GET
+            && body.is_none()
+            && let Some(cache) = self.active_cache()
+        {
+            let text = self.cached_get(cache, &url, None).await?;
+            return Ok(serde_json::from_str(&text)?);
+        }
+        let resp = self.send_checked(method, &url, body).await?;
         Ok(resp.json().await?)
     }
 
@@ -210,14 +330,39 @@ impl Client {
         body: Option<&Value>,
     ) -> Result<String, ApiError> {
         let url = self.resolve_rest_url(path);
-        let resp = self.send_rest_request(method, &url, body).await?;
-        let resp = Self::check_response(resp, true).await?;
+        let resp = self.send_checked(method, &url, body).await?;
+        Ok(resp.text().await?)
+    }
+
+    /// Execute a REST API GET with a custom Accept header and return the
+    /// raw response body, such as a pull request in diff media type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error on network failure or non-success status.
+    pub async fn rest_text_with_accept(
+        &self,
+        path: &str,
+        accept: &str,
+    ) -> Result<String, ApiError> {
+        let url = self.resolve_rest_url(path);
+        let resp = self
+            .send_with_retries(&reqwest::Method::GET, || async {
+                let resp = self
+                    .authed_request(reqwest::Method::GET, &url)
+                    .header("Accept", accept)
+                    .send()
+                    .await?;
+                Self::check_response(resp, true).await
+            })
+            .await?;
         Ok(resp.text().await?)
     }
 
     /// Execute a REST API request with Link-header based pagination.
     ///
-    /// Returns the deserialized data and the URL of the next page (if any).
+    /// Returns the deserialized data, the URL of the next page (if any), and
+    /// the response status and headers.
     ///
     /// # Errors
     ///
@@ -229,23 +374,70 @@ impl Client {
         body: Option<&Value>,
     ) -> Result<RestPage<T>, ApiError> {
         let url = self.resolve_rest_url(path);
-        let resp = self.send_rest_request(method, &url, body).await?;
-        let resp = Self::check_response(resp, true).await?;
+        let resp = self.send_checked(method, &url, body).await?;
+        let status = resp.status();
+        let version = resp.version();
+        let headers = resp.headers().clone();
 
-        if resp.status() == reqwest::StatusCode::NO_CONTENT {
+        if status == reqwest::StatusCode::NO_CONTENT {
             // For 204 responses, try to return default-ish data
             let text = resp.text().await.unwrap_or_default();
             let data: T = serde_json::from_str(&text)?;
             return Ok(RestPage {
                 data,
                 next_url: None,
+                status,
+                version,
+                headers,
             });
         }
 
-        let next_url = parse_link_next(resp.headers());
+        let next_url = parse_link_next(&headers);
         let data: T = resp.json().await?;
 
-        Ok(RestPage { data, next_url })
+        Ok(RestPage {
+            data,
+            next_url,
+            status,
+            version,
+            headers,
+        })
+    }
+
+    /// Execute a REST API request and return the response without reading
+    /// its body, so large downloads can be streamed with
+    /// [`reqwest::Response::chunk`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error on network failure or non-success status.
+    pub async fn rest_response(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+    ) -> Result<reqwest::Response, ApiError> {
+        let url = self.resolve_rest_url(path);
+        self.send_checked(method, &url, None).await
+    }
+
+    /// Execute a REST request whose response is a server-sent event stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error on network failure or non-success status.
+    pub async fn rest_stream(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<&Value>,
+    ) -> Result<EventStream, ApiError> {
+        let url = self.resolve_rest_url(path);
+        let resp = self.send_checked(method, &url, body).await?;
+        Ok(EventStream {
+            resp,
+            buf: Vec::new(),
+            done: false,
+        })
     }
 
     /// Collect all pages from a paginated REST endpoint.
@@ -279,44 +471,46 @@ impl Client {
         Ok(all_items)
     }
 
-    /// Execute a REST request with automatic retry for transient failures.
+    /// Collect items from a paginated REST `GET` endpoint, stopping once
+    /// `limit` items have been gathered.
     ///
-    /// Retries on 429 (rate limit), 502, 503, and 504 status codes.
+    /// `items` turns each page body into the items it contributes, usually
+    /// through [`page_items`]; only those count toward `limit`.
     ///
     /// # Errors
     ///
-    /// Returns the last error if all retries are exhausted.
-    pub async fn rest_with_retry<T: DeserializeOwned>(
+    /// Returns an error on network failure or non-success status.
+    pub async fn rest_paginate_limit<F>(
         &self,
-        method: reqwest::Method,
         path: &str,
-        body: Option<&Value>,
-    ) -> Result<T, ApiError> {
-        let mut last_err = None;
-        for attempt in 0..MAX_RETRIES {
-            match self.rest::<T>(method.clone(), path, body).await {
-                Ok(result) => return Ok(result),
-                Err(e) if is_retryable(&e) => {
-                    let delay = RETRY_BASE_DELAY_MS * 2u64.pow(attempt);
-                    warn!(
-                        attempt = attempt + 1,
-                        max = MAX_RETRIES,
-                        delay_ms = delay,
-                        error = %e,
-                        "Retrying request"
-                    );
-                    tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
-                    last_err = Some(e);
-                }
-                Err(e) => return Err(e),
+        limit: Option<usize>,
+        mut items: F,
+    ) -> Result<Vec<Value>, ApiError>
+    where
+        F: FnMut(Value) -> Vec<Value>,
+    {
+        let mut all_items = Vec::new();
+        let mut current_url = self.resolve_rest_url(path);
+
+        loop {
+            let page: RestPage<Value> = self
+                .rest_with_next(reqwest::Method::GET, &current_url, None)
+                .await?;
+            all_items.extend(items(page.data));
+            if let Some(limit) = limit
+                && all_items.len() >= limit
+            {
+                all_items.truncate(limit);
+                break;
+            }
+
+            match page.next_url {
+                Some(next) => current_url = next,
+                None => break,
             }
         }
-        Err(last_err.unwrap_or(ApiError::Http {
-            status: 0,
-            message: "all retries exhausted".to_string(),
-            scopes_suggestion: None,
-            headers: HashMap::new(),
-        }))
+
+        Ok(all_items)
     }
 
     /// Get the OAuth scopes header for a token by making a lightweight request.
@@ -325,7 +519,7 @@ impl Client {
     ///
     /// Returns an error on network failure or non-success status.
     pub async fn get_scopes(&self, token: &str) -> Result<String, ApiError> {
-        let url = instance::rest_url(&self.hostname);
+        let url = self.resolve_rest_url("");
         let resp = self
             .http
             .get(&url)
@@ -391,6 +585,9 @@ impl Client {
             hostname: self.hostname.clone(),
             token: Some(token.into()),
             api_url_override: self.api_url_override.clone(),
+            cache: None,
+            unix_socket: self.unix_socket,
+            stderr: self.stderr.clone(),
         };
         temp_client.current_login().await
     }
@@ -432,8 +629,7 @@ impl Client {
         path: &str,
     ) -> Result<Vec<u8>, ApiError> {
         let url = self.resolve_rest_url(path);
-        let resp = self.send_rest_request(method, &url, None).await?;
-        let resp = Self::check_response(resp, true).await?;
+        let resp = self.send_checked(method, &url, None).await?;
         Ok(resp.bytes().await?.to_vec())
     }
 
@@ -453,16 +649,148 @@ impl Client {
         accept: &str,
     ) -> Result<T, ApiError> {
         let url = self.resolve_rest_url(path);
-        let mut req = self.authed_request(method, &url);
-        req = req.header("Accept", accept);
-        if let Some(body) = body {
-            req = req.json(body);
+        if method == reqwest::Method::GET
+            && body.is_none()
+            && let Some(cache) = self.active_cache()
+        {
+            let text = self.cached_get(cache, &url, Some(accept)).await?;
+            return Ok(serde_json::from_str(&text)?);
         }
-        let resp = req.send().await?;
-        let resp = Self::check_response(resp, true).await?;
+        let resp = self
+            .send_with_retries(&method, || async {
+                let mut req = self.authed_request(method.clone(), &url);
+                req = req.header("Accept", accept);
+                if let Some(body) = body {
+                    req = req.json(body);
+                }
+                Self::check_response(req.send().await?, true).await
+            })
+            .await?;
         Ok(resp.json().await?)
     }
 
+    /// Send a GraphQL request body and return the decoded response.
+    async fn post_graphql(&self, url: &str, body: &Value) -> Result<Value, ApiError> {
+        let resp = self
+            .send_with_retries(&reqwest::Method::POST, || async {
+                let resp = self
+                    .authed_request(reqwest::Method::POST, url)
+                    .header("GraphQL-Features", "merge_queue")
+                    .json(body)
+                    .send()
+                    .await?;
+                Self::check_response(resp, false).await
+            })
+            .await?;
+        Ok(resp.json().await?)
+    }
+
+    /// Send a GraphQL query through the response cache, keyed on its query
+    /// text and variables. Responses carrying errors are not stored.
+    async fn cached_graphql(
+        &self,
+        cache: &ResponseCache,
+        url: &str,
+        body: &Value,
+    ) -> Result<Value, ApiError> {
+        let key = ResponseCache::key(
+            &reqwest::Method::POST,
+            url,
+            "",
+            &body.to_string(),
+            self.token(),
+        );
+        let now = chrono::Utc::now().timestamp();
+        if let Some(entry) = cache.load(&key).filter(|e| cache.is_fresh(e, now))
+            && let Ok(cached) = serde_json::from_str(&entry.body)
+        {
+            return Ok(cached);
+        }
+
+        let resp = self.post_graphql(url, body).await?;
+        if resp.get("errors").is_none() {
+            cache.store(
+                &key,
+                &CacheEntry {
+                    etag: None,
+                    body: resp.to_string(),
+                    stored_at: now,
+                },
+            );
+        }
+        Ok(resp)
+    }
+
+    /// The response cache, unless disabled through `GH_NO_CACHE`.
+    fn active_cache(&self) -> Option<&ResponseCache> {
+        self.cache
+            .as_ref()
+            .filter(|_| !ResponseCache::disabled_by_env())
+    }
+
+    /// Perform a `GET` through the response cache and return the body.
+    async fn cached_get(
+        &self,
+        cache: &ResponseCache,
+        url: &str,
+        accept: Option<&str>,
+    ) -> Result<String, ApiError> {
+        let key = ResponseCache::key(
+            &reqwest::Method::GET,
+            url,
+            accept.unwrap_or(""),
+            "",
+            self.token(),
+        );
+        let now = chrono::Utc::now().timestamp();
+        let cached = cache.load(&key);
+        if let Some(entry) = cached.as_ref().filter(|e| cache.is_fresh(e, now)) {
+            return Ok(entry.body.clone());
+        }
+
+        let etag = cached.as_ref().and_then(|e| e.etag.as_deref());
+        let resp = self
+            .send_with_retries(&reqwest::Method::GET, || async {
+                let mut req = self.authed_request(reqwest::Method::GET, url);
+                if let Some(accept) = accept {
+                    req = req.header(reqwest::header::ACCEPT, accept);
+                }
+                if let Some(etag) = etag {
+                    req = req.header(reqwest::header::IF_NONE_MATCH, etag);
+                }
+                let resp = req.send().await?;
+                if resp.status() == reqwest::StatusCode::NOT_MODIFIED && cached.is_some() {
+                    return Ok(resp);
+                }
+                Self::check_response(resp, true).await
+            })
+            .await?;
+
+        if resp.status() == reqwest::StatusCode::NOT_MODIFIED
+            && let Some(mut entry) = cached
+        {
+            entry.stored_at = now;
+            cache.store(&key, &entry);
+            return Ok(entry.body);
+        }
+
+        let etag = resp
+            .headers()
+            .get(reqwest::header::ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(String::from);
+        let body = resp.text().await?;
+        cache.store(
+            &key,
+            &CacheEntry {
+                etag,
+                body: body.clone(),
+                stored_at: now,
+            },
+        );
+        Ok(body)
+    }
+
     /// Check a response for errors and return an `ApiError::Http` if the
     /// status is not successful. The `include_scopes` flag controls whether
     /// OAuth scope suggestion headers are inspected.
@@ -508,6 +836,66 @@ impl Client {
         }
     }
 
+    /// Send a REST request, retrying rate limits and transient failures.
+    async fn send_checked(
+        &self,
+        method: reqwest::Method,
+        url: &str,
+        body: Option<&Value>,
+    ) -> Result<reqwest::Response, ApiError> {
+        self.send_with_retries(&method, || async {
+            let resp = self.send_rest_request(method.clone(), url, body).await?;
+            Self::check_response(resp, true).await
+        })
+        .await
+    }
+
+    /// Run `send` until it succeeds or fails in a way that is not retried.
+    ///
+    /// This is the single retry policy for every request: up to
+    /// [`MAX_RETRIES_ENV`] retries (default [`MAX_RETRIES`]), with
+    /// [`retry_delay`] deciding which failures qualify.
+    async fn send_with_retries<F, Fut>(
+        &self,
+        method: &reqwest::Method,
+        send: F,
+    ) -> Result<reqwest::Response, ApiError>
+    where
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = Result<reqwest::Response, ApiError>>,
+    {
+        let max_retries = max_retries();
+        let mut attempt = 0;
+        loop {
+            let err = match send().await {
+                Ok(resp) => return Ok(resp),
+                Err(e) => e,
+            };
+            let now = chrono::Utc::now().timestamp();
+            let delay = match retry_delay(method, &err, attempt, now) {
+                Some(delay) if attempt < max_retries => delay,
+                _ => return Err(err),
+            };
+            self.announce_retry(&err, delay);
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    fn announce_retry(&self, err: &ApiError, delay: Duration) {
+        let reason = if is_rate_limit(err) {
+            "hit rate limit"
+        } else {
+            "request failed"
+        };
+        let secs = delay.as_secs_f64().ceil();
+        if let Some(ref stderr) = self.stderr {
+            stderr.println(&format!("{reason}, retrying in {secs}s"));
+        } else {
+            warn!(error = %err, delay_secs = secs, "{reason}, retrying");
+        }
+    }
+
     async fn send_rest_request(
         &self,
         method: reqwest::Method,
@@ -539,6 +927,23 @@ fn parse_link_next(headers: &HeaderMap) -> Option<String> {
     None
 }
 
+/// The items on a REST list page: the body itself for array endpoints, or
+/// the array under `key` for endpoints that wrap it, such as
+/// `{"variables": [...]}`. Any other body yields no items.
+pub fn page_items(page: Value, key: Option<&str>) -> Vec<Value> {
+    let items = match key {
+        Some(key) => match page {
+            Value::Object(mut map) => map.remove(key).unwrap_or(Value::Null),
+            _ => Value::Null,
+        },
+        None => page,
+    };
+    match items {
+        Value::Array(items) => items,
+        _ => Vec::new(),
+    }
+}
+
 /// Extract response headers into a `HashMap<String, String>`.
 fn extract_header_map(headers: &HeaderMap) -> HashMap<String, String> {
     let mut map = HashMap::new();
@@ -550,15 +955,90 @@ fn extract_header_map(headers: &HeaderMap) -> HashMap<String, String> {
     map
 }
 
-/// Check if an API error is retryable (transient).
-fn is_retryable(err: &ApiError) -> bool {
-    match err {
-        ApiError::Http { status, .. } => matches!(status, 429 | 502 | 503 | 504),
-        ApiError::Request(_) => true,
+/// Number of retries allowed per request, from [`MAX_RETRIES_ENV`].
+fn max_retries() -> u32 {
+    std::env::var(MAX_RETRIES_ENV)
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(MAX_RETRIES)
+}
+
+/// Whether an error is a primary or secondary rate-limit response.
+fn is_rate_limit(err: &ApiError) -> bool {
+    let ApiError::Http {
+        status,
+        message,
+        headers,
+        ..
+    } = err
+    else {
+        return false;
+    };
+    match status {
+        429 => true,
+        403 => {
+            headers.contains_key("retry-after")
+                || headers
+                    .get("x-ratelimit-remaining")
+                    .is_some_and(|v| v == "0")
+                || message.to_lowercase().contains("rate limit")
+        }
         _ => false,
     }
 }
 
+/// How long to wait before retrying a failed request, if it should be.
+///
+/// Server errors are retried for every method. Rate limits and timeouts
+/// are only retried for idempotent `GET`/`HEAD` requests, waiting for
+/// `Retry-After` or `x-ratelimit-reset` when the server provides them.
+fn retry_delay(
+    method: &reqwest::Method,
+    err: &ApiError,
+    attempt: u32,
+    now: i64,
+) -> Option<Duration> {
+    let idempotent = *method == reqwest::Method::GET || *method == reqwest::Method::HEAD;
+    match err {
+        ApiError::Http { status, .. } if (500..=599).contains(status) => Some(backoff(attempt)),
+        ApiError::Http { headers, .. } if idempotent && is_rate_limit(err) => {
+            let wait = rate_limit_wait(headers, now).unwrap_or_else(|| backoff(attempt));
+            (wait <= MAX_RETRY_WAIT).then_some(wait)
+        }
+        ApiError::Request(e) if idempotent && e.is_timeout() => Some(backoff(attempt)),
+        _ => None,
+    }
+}
+
+/// Wait requested by `Retry-After`, or until `x-ratelimit-reset` once exhausted.
+fn rate_limit_wait(headers: &HashMap<String, String>, now: i64) -> Option<Duration> {
+    if let Some(secs) = headers
+        .get("retry-after")
+        .and_then(|v| v.trim().parse::<u64>().ok())
+    {
+        return Some(Duration::from_secs(secs));
+    }
+    if headers
+        .get("x-ratelimit-remaining")
+        .is_some_and(|v| v == "0")
+    {
+        let reset: i64 = headers.get("x-ratelimit-reset")?.trim().parse().ok()?;
+        return Some(Duration::from_secs(
+            u64::try_from(reset - now).unwrap_or_default(),
+        ));
+    }
+    None
+}
+
+/// Exponential back-off with up to 50% jitter.
+fn backoff(attempt: u32) -> Duration {
+    let base = RETRY_BASE_DELAY_MS.saturating_mul(2u64.saturating_pow(attempt));
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| u64::from(d.subsec_nanos()));
+    Duration::from_millis(base + nanos % (base / 2 + 1))
+}
+
 /// Generate an OAuth scopes suggestion when a request fails with a 4xx error.
 ///
 /// Compares the scopes the endpoint needs (`X-Accepted-OAuth-Scopes`) against
@@ -800,47 +1280,77 @@ mod tests {
 
     #[test]
     fn test_should_handle_retryable_errors() {
-        assert!(is_retryable(&ApiError::Http {
-            status: 429,
-            message: "rate limit".to_string(),
-            scopes_suggestion: None,
-            headers: HashMap::new(),
-        }));
-        assert!(is_retryable(&ApiError::Http {
-            status: 502,
-            message: "bad gateway".to_string(),
-            scopes_suggestion: None,
-            headers: HashMap::new(),
-        }));
-        assert!(is_retryable(&ApiError::Http {
-            status: 503,
-            message: "service unavailable".to_string(),
-            scopes_suggestion: None,
-            headers: HashMap::new(),
-        }));
-        assert!(is_retryable(&ApiError::Http {
-            status: 504,
-            message: "gateway timeout".to_string(),
+        for status in [429, 502, 503, 504] {
+            let err = http_err(status, "transient", &[]);
+            assert!(
+                retry_delay(&reqwest::Method::GET, &err, 0, 0).is_some(),
+                "{status} should be retried"
+            );
+        }
+    }
+
+    fn http_err(status: u16, message: &str, headers: &[(&str, &str)]) -> ApiError {
+        ApiError::Http {
+            status,
+            message: message.to_string(),
             scopes_suggestion: None,
-            headers: HashMap::new(),
-        }));
+            headers: headers
+                .iter()
+                .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_should_honor_retry_after_for_rate_limited_gets() {
+        let err = http_err(403, "secondary rate limit", &[("retry-after", "7")]);
+        assert_eq!(
+            retry_delay(&reqwest::Method::GET, &err, 0, 0),
+            Some(Duration::from_secs(7))
+        );
+    }
+
+    #[test]
+    fn test_should_wait_for_rate_limit_reset() {
+        let err = http_err(
+            403,
+            "API rate limit exceeded",
+            &[("x-ratelimit-remaining", "0"), ("x-ratelimit-reset", "130")],
+        );
+        assert_eq!(
+            retry_delay(&reqwest::Method::GET, &err, 0, 100),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(retry_delay(&reqwest::Method::GET, &err, 0, -3600), None);
+    }
+
+    #[test]
+    fn test_should_back_off_on_secondary_rate_limit_message() {
+        let err = http_err(403, "You have exceeded a secondary rate limit", &[]);
+        let delay = retry_delay(&reqwest::Method::GET, &err, 1, 0).unwrap();
+        assert!(delay >= Duration::from_millis(2000) && delay <= Duration::from_millis(3000));
+    }
+
+    #[test]
+    fn test_should_retry_mutations_only_on_server_errors() {
+        let limited = http_err(429, "slow down", &[("retry-after", "1")]);
+        assert_eq!(retry_delay(&reqwest::Method::POST, &limited, 0, 0), None);
+        let unavailable = http_err(503, "service unavailable", &[]);
+        assert!(retry_delay(&reqwest::Method::POST, &unavailable, 0, 0).is_some());
+        let forbidden = http_err(403, "Resource not accessible", &[]);
+        assert_eq!(retry_delay(&reqwest::Method::GET, &forbidden, 0, 0), None);
     }
 
     #[test]
     fn test_should_not_retry_client_errors() {
-        assert!(!is_retryable(&ApiError::Http {
-            status: 400,
-            message: "bad request".to_string(),
-            scopes_suggestion: None,
-            headers: HashMap::new(),
-        }));
-        assert!(!is_retryable(&ApiError::Http {
-            status: 404,
-            message: "not found".to_string(),
-            scopes_suggestion: None,
-            headers: HashMap::new(),
-        }));
-        assert!(!is_retryable(&ApiError::AuthRequired));
+        for status in [400, 404] {
+            let err = http_err(status, "client error", &[]);
+            assert_eq!(retry_delay(&reqwest::Method::GET, &err, 0, 0), None);
+        }
+        assert_eq!(
+            retry_delay(&reqwest::Method::GET, &ApiError::AuthRequired, 0, 0),
+            None
+        );
     }
 
     #[test]
@@ -902,6 +1412,9 @@ mod wiremock_tests {
             hostname: "github.com".to_string(),
             token: Some("test-token".into()),
             api_url_override: None,
+            cache: None,
+            unix_socket: false,
+            stderr: None,
         }
     }
 
@@ -1043,6 +1556,64 @@ mod wiremock_tests {
 
         assert_eq!(page.data.len(), 2);
         assert!(page.next_url.is_some());
+        assert_eq!(page.status, reqwest::StatusCode::OK);
+        assert!(page.headers.contains_key("link"));
+    }
+
+    #[tokio::test]
+    async fn test_should_stop_paginating_at_limit() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(wiremock::matchers::query_param_is_missing("page"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"items": [{"id": 1}, {"id": 2}]}))
+                    .append_header(
+                        "link",
+                        format!("<{}/items?page=2>; rel=\"next\"", server.uri()),
+                    ),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(wiremock::matchers::query_param("page", "2"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"items": [{"id": 3}, {"id": 4}]}))
+                    .append_header(
+                        "link",
+                        format!("<{}/items?page=3>; rel=\"next\"", server.uri()),
+                    ),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_client(&server);
+        let items = client
+            .rest_paginate_limit(&format!("{}/items", server.uri()), Some(3), |page| {
+                page_items(page, Some("items"))
+            })
+            .await
+            .unwrap();
+
+        let ids: Vec<&Value> = items.iter().map(|i| &i["id"]).collect();
+        assert_eq!(ids, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_should_take_page_items_from_array_or_wrapper() {
+        let items = serde_json::json!([{"id": 1}]);
+        assert_eq!(page_items(items.clone(), None).len(), 1);
+        assert_eq!(
+            page_items(serde_json::json!({"variables": items}), Some("variables")).len(),
+            1
+        );
+        assert!(page_items(serde_json::json!({"message": "x"}), None).is_empty());
+        assert!(page_items(serde_json::json!({"total_count": 0}), Some("variables")).is_empty());
     }
 
     #[tokio::test]
@@ -1065,6 +1636,9 @@ mod wiremock_tests {
             hostname: "github.com".to_string(),
             token: Some("test-token".into()),
             api_url_override: None,
+            cache: None,
+            unix_socket: false,
+            stderr: None,
         };
 
         // Override the GraphQL URL by using the server directly
@@ -1086,6 +1660,94 @@ mod wiremock_tests {
         assert_eq!(json["data"]["viewer"]["login"], "testuser");
     }
 
+    #[tokio::test]
+    async fn test_should_retry_rate_limited_request_with_custom_accept() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/search/code"))
+            .respond_with(ResponseTemplate::new(429).append_header("retry-after", "0"))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/search/code"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"total_count": 1})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_client(&server);
+        let result: Value = client
+            .rest_with_accept(
+                reqwest::Method::GET,
+                &format!("{}/search/code", server.uri()),
+                None,
+                "application/vnd.github.text-match+json",
+            )
+            .await
+            .unwrap();
+        assert_eq!(result["total_count"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_should_retry_graphql_on_server_error() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .respond_with(ResponseTemplate::new(502))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"data": {"viewer": {"login": "monalisa"}}})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client {
+            api_url_override: Some(format!("{}/", server.uri())),
+            ..setup_client(&server)
+        };
+        let data: Value = client
+            .graphql("query { viewer { login } }", &HashMap::new())
+            .await
+            .unwrap();
+        assert_eq!(data["viewer"]["login"], "monalisa");
+    }
+
+    #[tokio::test]
+    async fn test_should_not_retry_rate_limited_mutation() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/repos/cli/cli/issues"))
+            .respond_with(ResponseTemplate::new(429).append_header("retry-after", "0"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_client(&server);
+        let err = client
+            .rest::<Value>(
+                reqwest::Method::POST,
+                &format!("{}/repos/cli/cli/issues", server.uri()),
+                Some(&serde_json::json!({"title": "x"})),
+            )
+            .await
+            .unwrap_err();
+        assert!(err.is_rate_limited());
+    }
+
     #[tokio::test]
     async fn test_should_include_scopes_suggestion_on_403() {
         let server = MockServer::start().await;
@@ -1122,4 +1784,65 @@ mod wiremock_tests {
             panic!("expected Http error");
         }
     }
+
+    #[tokio::test]
+    async fn test_should_stream_server_sent_events() {
+        let server = MockServer::start().await;
+
+        let body = concat!(
+            ": keep-alive\r\n\r\n",
+            "data: {\"n\":1}\r\n\r\n",
+            "data: {\"n\":\n",
+            "data: 2}\n\n",
+            "data: [DONE]\n\n",
+            "data: {\"n\":3}\n\n",
+        );
+        Mock::given(method("POST"))
+            .and(path("/copilot/chat/completions"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+            .mount(&server)
+            .await;
+
+        let client = setup_client(&server);
+        let mut events = client
+            .rest_stream(
+                reqwest::Method::POST,
+                &format!("{}/copilot/chat/completions", server.uri()),
+                Some(&serde_json::json!({"stream": true})),
+            )
+            .await
+            .unwrap();
+
+        let mut seen = Vec::new();
+        while let Some(event) = events.next().await {
+            seen.push(event.unwrap()["n"].as_i64().unwrap());
+        }
+        assert_eq!(seen, [1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_should_emit_final_event_without_trailing_blank_line() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/events"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_raw("data: {\"n\":1}", "text/event-stream"),
+            )
+            .mount(&server)
+            .await;
+
+        let client = setup_client(&server);
+        let mut events = client
+            .rest_stream(
+                reqwest::Method::GET,
+                &format!("{}/events", server.uri()),
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(events.next().await.unwrap().unwrap()["n"], 1);
+        assert!(events.next().await.is_none());
+    }
 }
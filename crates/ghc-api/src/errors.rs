@@ -79,6 +79,58 @@ impl ApiError {
         }
     }
 
+    /// Describe the error with a hint on how to resolve it.
+    ///
+    /// A 401 points at `ghc auth login`, a 404 mentions missing access, and
+    /// a 422 lists the validation messages from the response body. GraphQL
+    /// errors show their messages verbatim. Other errors fall back to their
+    /// `Display` output.
+    pub fn friendly_message(&self) -> String {
+        if let Self::GraphQL(entries) = self {
+            let messages: Vec<&str> = entries.iter().map(|e| e.message.as_str()).collect();
+            return format!("GraphQL: {}", messages.join(", "));
+        }
+        let Self::Http {
+            status,
+            message,
+            scopes_suggestion,
+            ..
+        } = self
+        else {
+            return self.to_string();
+        };
+
+        let body: Option<serde_json::Value> = serde_json::from_str(message).ok();
+        let api_message = body
+            .as_ref()
+            .and_then(|b| b.get("message"))
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or(message.as_str());
+
+        let mut out = match status {
+            401 => format!("HTTP 401: {api_message}\nTry authenticating with: ghc auth login"),
+            404 => format!(
+                "HTTP 404: {api_message}\nThe resource was not found, or you do not have access to it."
+            ),
+            422 => {
+                let details = body.as_ref().map(validation_messages).unwrap_or_default();
+                let mut out = format!("HTTP 422: {api_message}");
+                for detail in details {
+                    out.push_str("\n- ");
+                    out.push_str(&detail);
+                }
+                out
+            }
+            _ => self.to_string(),
+        };
+
+        if let Some(suggestion) = scopes_suggestion {
+            out.push('\n');
+            out.push_str(suggestion);
+        }
+        out
+    }
+
     /// Get the missing scopes if this is a `MissingScopes` error.
     pub fn missing_scopes(&self) -> Option<&[String]> {
         match self {
@@ -88,6 +140,46 @@ impl ApiError {
     }
 }
 
+/// Collect the `errors[]` entries of a validation failure body.
+///
+/// Entries carry either a `message`, or a `resource`/`field`/`code` triple.
+fn validation_messages(body: &serde_json::Value) -> Vec<String> {
+    let Some(errors) = body.get("errors").and_then(serde_json::Value::as_array) else {
+        return Vec::new();
+    };
+    errors
+        .iter()
+        .filter_map(|entry| {
+            if let Some(msg) = entry.as_str() {
+                return Some(msg.to_string());
+            }
+            if let Some(msg) = entry.get("message").and_then(serde_json::Value::as_str) {
+                return Some(msg.to_string());
+            }
+            let field = entry.get("field").and_then(serde_json::Value::as_str)?;
+            let code = entry
+                .get("code")
+                .and_then(serde_json::Value::as_str)
+                .unwrap_or("invalid");
+            Some(format!("{field} is {code}"))
+        })
+        .collect()
+}
+
+/// Render an error chain for display, using friendly messages for API errors.
+///
+/// Context layers are joined with `: ` like anyhow's alternate format,
+/// with any `ApiError` replaced by [`ApiError::friendly_message`].
+pub fn describe(err: &anyhow::Error) -> String {
+    err.chain()
+        .map(|cause| match cause.downcast_ref::<ApiError>() {
+            Some(api_err) => api_err.friendly_message(),
+            None => cause.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(": ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -139,6 +231,23 @@ mod tests {
         assert!(msg.contains("field not found"));
     }
 
+    #[test]
+    fn test_should_show_graphql_messages_verbatim() {
+        let entry = |message: &str| GraphQLErrorEntry {
+            message: message.to_string(),
+            error_type: None,
+            path: None,
+        };
+        let err = ApiError::GraphQL(vec![
+            entry("Pull request Auto merge is not allowed for this repository"),
+            entry("second problem"),
+        ]);
+        assert_eq!(
+            err.friendly_message(),
+            "GraphQL: Pull request Auto merge is not allowed for this repository, second problem"
+        );
+    }
+
     #[test]
     fn test_should_display_auth_required() {
         let err = ApiError::AuthRequired;
@@ -208,4 +317,67 @@ mod tests {
         assert!(entry.error_type.is_none());
         assert!(entry.path.is_none());
     }
+
+    #[test]
+    fn test_should_suggest_login_for_unauthorized() {
+        let err = http_error(401, r#"{"message": "Bad credentials"}"#);
+        assert_eq!(
+            err.friendly_message(),
+            "HTTP 401: Bad credentials\nTry authenticating with: ghc auth login"
+        );
+    }
+
+    #[test]
+    fn test_should_mention_access_for_not_found() {
+        let err = http_error(404, r#"{"message": "Not Found"}"#);
+        let msg = err.friendly_message();
+        assert!(msg.starts_with("HTTP 404: Not Found\n"));
+        assert!(msg.contains("not found, or you do not have access"));
+    }
+
+    #[test]
+    fn test_should_list_validation_errors() {
+        let body = r#"{
+            "message": "Validation Failed",
+            "errors": [
+                {"resource": "Label", "code": "already_exists", "field": "name"},
+                {"message": "title is too long"}
+            ]
+        }"#;
+        let err = http_error(422, body);
+        assert_eq!(
+            err.friendly_message(),
+            "HTTP 422: Validation Failed\n- name is already_exists\n- title is too long"
+        );
+    }
+
+    #[test]
+    fn test_should_append_scopes_suggestion_to_friendly_message() {
+        let err = ApiError::Http {
+            status: 404,
+            message: "Not Found".to_string(),
+            scopes_suggestion: Some("This API operation needs the \"repo\" scope.".to_string()),
+            headers: HashMap::new(),
+        };
+        assert!(
+            err.friendly_message()
+                .ends_with("needs the \"repo\" scope.")
+        );
+    }
+
+    #[test]
+    fn test_should_keep_display_for_other_errors() {
+        let err = http_error(500, "boom");
+        assert_eq!(err.friendly_message(), "HTTP 500: boom");
+    }
+
+    #[test]
+    fn test_should_describe_error_chain() {
+        let err = anyhow::Error::new(http_error(401, r#"{"message": "Bad credentials"}"#))
+            .context("failed to fetch issue");
+        assert_eq!(
+            describe(&err),
+            "failed to fetch issue: HTTP 401: Bad credentials\nTry authenticating with: ghc auth login"
+        );
+    }
 }
@@ -3,6 +3,8 @@
 //! Maps from Go's `api/http_client.go`. Provides default headers,
 //! User-Agent, Accept, and verbose-logging configuration.
 
+use std::path::PathBuf;
+
 use reqwest::header::{self, HeaderMap, HeaderValue};
 use tracing::debug;
 
@@ -15,6 +17,8 @@ pub struct HttpClientOptions {
     pub skip_default_headers: bool,
     /// Enable verbose HTTP logging.
     pub log_verbose: bool,
+    /// Connect through this Unix domain socket instead of TCP.
+    pub unix_socket: Option<PathBuf>,
 }
 
 /// Build a reqwest client with default configuration.
@@ -39,11 +43,33 @@ pub fn build_client(opts: &HttpClientOptions) -> anyhow::Result<reqwest::Client>
         debug!("Building HTTP client with verbose logging");
     }
 
-    let client = reqwest::Client::builder()
-        .default_headers(headers)
-        .build()?;
+    let mut builder = reqwest::Client::builder().default_headers(headers);
+    if let Some(ref path) = opts.unix_socket {
+        builder = with_unix_socket(builder, path)?;
+    }
+
+    Ok(builder.build()?)
+}
+
+#[cfg(unix)]
+#[allow(clippy::unnecessary_wraps)]
+fn with_unix_socket(
+    builder: reqwest::ClientBuilder,
+    path: &std::path::Path,
+) -> anyhow::Result<reqwest::ClientBuilder> {
+    debug!("Routing HTTP requests through socket {}", path.display());
+    Ok(builder.unix_socket(path))
+}
 
-    Ok(client)
+#[cfg(not(unix))]
+fn with_unix_socket(
+    _builder: reqwest::ClientBuilder,
+    path: &std::path::Path,
+) -> anyhow::Result<reqwest::ClientBuilder> {
+    anyhow::bail!(
+        "http_unix_socket is not supported on this platform: {}",
+        path.display()
+    )
 }
 
 /// Format an authorization header value from a token.
@@ -87,6 +113,7 @@ mod tests {
             app_version: "1.0.0".to_string(),
             skip_default_headers: false,
             log_verbose: false,
+            unix_socket: None,
         };
         let client = build_client(&opts);
         assert!(client.is_ok());
@@ -98,6 +125,7 @@ mod tests {
             app_version: "1.0.0".to_string(),
             skip_default_headers: true,
             log_verbose: false,
+            unix_socket: None,
         };
         let client = build_client(&opts);
         assert!(client.is_ok());
@@ -109,6 +137,7 @@ mod tests {
             app_version: "1.0.0".to_string(),
             skip_default_headers: false,
             log_verbose: true,
+            unix_socket: None,
         };
         let client = build_client(&opts);
         assert!(client.is_ok());
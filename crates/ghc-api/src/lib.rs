@@ -1,6 +1,7 @@
 //! HTTP client, GraphQL/REST API, and authentication for GHC.
 
 pub mod auth_flow;
+pub mod cache;
 pub mod client;
 pub mod errors;
 pub mod features;
@@ -111,9 +111,10 @@ pub struct CommentCount {
 
 /// GraphQL query for listing issues.
 pub const ISSUE_LIST_QUERY: &str = r"
-query IssueList($owner: String!, $name: String!, $first: Int!, $after: String, $states: [IssueState!], $labels: [String!], $assignee: String) {
+query IssueList($owner: String!, $name: String!, $first: Int!, $after: String, $states: [IssueState!], $labels: [String!], $assignee: String, $author: String, $mention: String) {
   repository(owner: $owner, name: $name) {
-    issues(first: $first, after: $after, states: $states, labels: $labels, filterBy: {assignee: $assignee}, orderBy: {field: CREATED_AT, direction: DESC}) {
+    issues(first: $first, after: $after, states: $states, labels: $labels, filterBy: {assignee: $assignee, createdBy: $author, mentioned: $mention}, orderBy: {field: CREATED_AT, direction: DESC}) {
+      totalCount
       pageInfo {
         hasNextPage
         endCursor
@@ -135,6 +136,36 @@ query IssueList($owner: String!, $name: String!, $first: Int!, $after: String, $
 }
 ";
 
+/// GraphQL query for listing issues through the search API.
+///
+/// Used when filters such as a milestone title or raw search terms cannot be
+/// expressed with `filterBy`.
+pub const ISSUE_SEARCH_QUERY: &str = r"
+query IssueSearch($query: String!, $first: Int!, $after: String) {
+  search(query: $query, type: ISSUE, first: $first, after: $after) {
+    issueCount
+    pageInfo {
+      hasNextPage
+      endCursor
+    }
+    nodes {
+      ... on Issue {
+        number
+        title
+        state
+        author { login ... on User { id name } ... on Bot { id } __typename }
+        labels(first: 10) { nodes { name color } }
+        assignees(first: 5) { nodes { login } }
+        url
+        createdAt
+        updatedAt
+        comments { totalCount }
+      }
+    }
+  }
+}
+";
+
 /// GraphQL query for viewing a single issue.
 pub const ISSUE_VIEW_QUERY: &str = r"
 query IssueView($owner: String!, $name: String!, $number: Int!) {
@@ -211,4 +242,11 @@ mod tests {
         assert!(ISSUE_LIST_QUERY.contains("title"));
         assert!(ISSUE_LIST_QUERY.contains("pageInfo"));
     }
+
+    #[test]
+    fn test_should_select_issue_fields_in_search_query() {
+        assert!(ISSUE_SEARCH_QUERY.contains("type: ISSUE"));
+        assert!(ISSUE_SEARCH_QUERY.contains("... on Issue"));
+        assert!(ISSUE_SEARCH_QUERY.contains("pageInfo"));
+    }
 }
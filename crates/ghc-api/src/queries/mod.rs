@@ -2,5 +2,69 @@
 
 pub mod issue;
 pub mod pr;
+pub mod release;
 pub mod repo;
 pub mod user;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::errors::ApiError;
+
+/// Decode the value at a JSON pointer into a typed model.
+///
+/// Returns `Ok(None)` when the pointer is missing or null, which is how
+/// GraphQL reports an unresolved node.
+///
+/// # Errors
+///
+/// Returns `ApiError::JsonParse` if the value does not match the model.
+pub fn decode<T: DeserializeOwned>(data: &Value, pointer: &str) -> Result<Option<T>, ApiError> {
+    match data.pointer(pointer) {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => Ok(Some(T::deserialize(value)?)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_decode_repository_at_pointer() {
+        let data = serde_json::json!({
+            "repository": {
+                "name": "cli",
+                "owner": {"login": "cli"},
+                "url": "https://github.com/cli/cli",
+                "isPrivate": true,
+                "parent": {
+                    "name": "upstream",
+                    "owner": {"login": "org"},
+                    "url": "https://github.com/org/upstream"
+                }
+            }
+        });
+        let repo: repo::Repository = decode(&data, "/repository").unwrap().unwrap();
+        assert_eq!(repo.name, "cli");
+        assert!(repo.is_private);
+        assert!(!repo.is_fork);
+        assert_eq!(repo.parent.unwrap().owner.login, "org");
+    }
+
+    #[test]
+    fn test_should_decode_null_as_none() {
+        let data = serde_json::json!({"repository": null});
+        let repo: Option<repo::Repository> = decode(&data, "/repository").unwrap();
+        assert!(repo.is_none());
+        let missing: Option<repo::Repository> = decode(&data, "/other").unwrap();
+        assert!(missing.is_none());
+    }
+
+    #[test]
+    fn test_should_fail_decoding_mismatched_shape() {
+        let data = serde_json::json!({"repository": {"name": 42}});
+        let result: Result<Option<repo::Repository>, _> = decode(&data, "/repository");
+        assert!(matches!(result, Err(ApiError::JsonParse(_))));
+    }
+}
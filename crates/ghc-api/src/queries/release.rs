@@ -0,0 +1,97 @@
+//! Release-related API types.
+
+use serde::{Deserialize, Serialize};
+
+/// Release metadata from the REST API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Release {
+    /// Release ID.
+    pub id: i64,
+    /// Tag name.
+    pub tag_name: String,
+    /// Release title.
+    pub name: Option<String>,
+    /// Release notes.
+    pub body: Option<String>,
+    /// Whether this is a draft release.
+    #[serde(default)]
+    pub draft: bool,
+    /// Whether this is a prerelease.
+    #[serde(default)]
+    pub prerelease: bool,
+    /// Web URL.
+    pub html_url: String,
+    /// Created at.
+    pub created_at: Option<String>,
+    /// Published at.
+    pub published_at: Option<String>,
+    /// Author.
+    pub author: Option<ReleaseAuthor>,
+    /// Uploaded assets.
+    #[serde(default)]
+    pub assets: Vec<ReleaseAsset>,
+}
+
+/// Release author.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseAuthor {
+    /// Login name.
+    pub login: String,
+}
+
+/// A file attached to a release.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseAsset {
+    /// File name.
+    pub name: String,
+    /// Size in bytes.
+    #[serde(default)]
+    pub size: u64,
+    /// Number of downloads.
+    #[serde(default)]
+    pub download_count: u64,
+    /// Download URL.
+    pub browser_download_url: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_deserialize_release() {
+        let json = r#"{
+            "id": 1,
+            "tag_name": "v1.0.0",
+            "name": "First",
+            "body": "notes",
+            "draft": false,
+            "prerelease": true,
+            "html_url": "https://github.com/o/r/releases/tag/v1.0.0",
+            "published_at": "2024-01-15T10:00:00Z",
+            "author": {"login": "octocat"},
+            "assets": [{
+                "name": "app.tar.gz",
+                "size": 2048,
+                "download_count": 3,
+                "browser_download_url": "https://example.com/app.tar.gz"
+            }]
+        }"#;
+        let release: Release = serde_json::from_str(json).unwrap();
+        assert_eq!(release.tag_name, "v1.0.0");
+        assert!(release.prerelease);
+        assert_eq!(release.author.unwrap().login, "octocat");
+        assert_eq!(release.assets.len(), 1);
+        assert_eq!(release.assets[0].size, 2048);
+    }
+
+    #[test]
+    fn test_should_deserialize_release_without_assets() {
+        let json = r#"{"id": 2, "tag_name": "v2", "html_url": "https://x"}"#;
+        let release: Release = serde_json::from_str(json).unwrap();
+        assert!(release.name.is_none());
+        assert!(!release.draft);
+        assert!(release.assets.is_empty());
+    }
+}
@@ -17,10 +17,13 @@ pub struct Repository {
     /// SSH URL.
     pub ssh_url: Option<String>,
     /// Whether the repo is a fork.
+    #[serde(default)]
     pub is_fork: bool,
     /// Whether the repo is archived.
+    #[serde(default)]
     pub is_archived: bool,
     /// Whether the repo is private.
+    #[serde(default)]
     pub is_private: bool,
     /// Default branch name.
     pub default_branch_ref: Option<BranchRef>,
@@ -73,6 +76,7 @@ query RepositoryInfo($owner: String!, $name: String!) {
     isArchived
     isPrivate
     isTemplate
+    hasWikiEnabled
     defaultBranchRef { name }
     parent {
       name
@@ -91,6 +95,71 @@ query RepositoryInfo($owner: String!, $name: String!) {
 }
 ";
 
+/// GraphQL query for `repo view --json`, selecting the gh-compatible field set.
+pub const REPO_JSON_QUERY: &str = r"
+query RepositoryJson($owner: String!, $name: String!) {
+  repository(owner: $owner, name: $name) {
+    id
+    name
+    nameWithOwner
+    owner { id login }
+    description
+    homepageUrl
+    url
+    sshUrl
+    mirrorUrl
+    openGraphImageUrl
+    securityPolicyUrl
+    visibility
+    isFork
+    isArchived
+    isPrivate
+    isTemplate
+    isEmpty
+    isMirror
+    isInOrganization
+    isBlankIssuesEnabled
+    isSecurityPolicyEnabled
+    isUserConfigurationRepository
+    usesCustomOpengraphImage
+    hasDiscussionsEnabled
+    hasIssuesEnabled
+    hasProjectsEnabled
+    hasWikiEnabled
+    deleteBranchOnMerge
+    mergeCommitAllowed
+    rebaseMergeAllowed
+    squashMergeAllowed
+    diskUsage
+    defaultBranchRef { name }
+    parent { id name owner { id login } }
+    templateRepository { id name owner { id login } }
+    stargazerCount
+    forkCount
+    watchers { totalCount }
+    issues(states: OPEN) { totalCount }
+    pullRequests(states: OPEN) { totalCount }
+    primaryLanguage { name }
+    languages(first: 100, orderBy: {field: SIZE, direction: DESC}) { edges { size node { name } } }
+    licenseInfo { key name nickname }
+    codeOfConduct { key name url }
+    fundingLinks { platform url }
+    repositoryTopics(first: 100) { nodes { topic { name } } }
+    latestRelease { name tagName url publishedAt }
+    viewerCanAdminister
+    viewerDefaultCommitEmail
+    viewerDefaultMergeMethod
+    viewerHasStarred
+    viewerPermission
+    viewerSubscription
+    archivedAt
+    pushedAt
+    createdAt
+    updatedAt
+  }
+}
+";
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -147,4 +216,16 @@ mod tests {
         assert!(REPO_QUERY.contains("owner"));
         assert!(REPO_QUERY.contains("defaultBranchRef"));
     }
+
+    #[test]
+    fn test_should_select_json_fields_in_repo_json_query() {
+        for field in [
+            "licenseInfo",
+            "repositoryTopics",
+            "stargazerCount",
+            "visibility",
+        ] {
+            assert!(REPO_JSON_QUERY.contains(field), "missing {field}");
+        }
+    }
 }
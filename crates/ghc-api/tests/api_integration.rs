@@ -161,3 +161,235 @@ async fn test_should_handle_unauthorized_response() {
 
     assert!(err.is_unauthorized());
 }
+
+fn cached_client(server: &MockServer, cache_dir: &std::path::Path, ttl_secs: u64) -> Client {
+    Client::new(
+        reqwest::Client::new(),
+        "github.com",
+        Some("test-token".into()),
+    )
+    .with_url_override(format!("{}/", server.uri()))
+    .with_cache_dir(cache_dir, std::time::Duration::from_secs(ttl_secs))
+}
+
+#[tokio::test]
+async fn test_should_serve_fresh_get_from_cache() {
+    let server = MockServer::start().await;
+    let cache = tempfile::tempdir().unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/repos/cli/cli"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("ETag", "\"v1\"")
+                .set_body_json(serde_json::json!({"name": "cli"})),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = cached_client(&server, cache.path(), 60);
+    for _ in 0..2 {
+        let repo: Value = client
+            .rest(reqwest::Method::GET, "repos/cli/cli", None)
+            .await
+            .unwrap();
+        assert_eq!(repo["name"], "cli");
+    }
+}
+
+#[tokio::test]
+async fn test_should_revalidate_stale_entry_with_etag() {
+    use wiremock::matchers::header;
+
+    let server = MockServer::start().await;
+    let cache = tempfile::tempdir().unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/repos/cli/cli"))
+        .and(header("If-None-Match", "\"v1\""))
+        .respond_with(ResponseTemplate::new(304))
+        .expect(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/cli/cli"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("ETag", "\"v1\"")
+                .set_body_json(serde_json::json!({"name": "cli"})),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = cached_client(&server, cache.path(), 0);
+    let first: Value = client
+        .rest(reqwest::Method::GET, "repos/cli/cli", None)
+        .await
+        .unwrap();
+    let second: Value = client
+        .rest(reqwest::Method::GET, "repos/cli/cli", None)
+        .await
+        .unwrap();
+    assert_eq!(first, second);
+}
+
+#[tokio::test]
+async fn test_should_not_cache_non_get_requests() {
+    let server = MockServer::start().await;
+    let cache = tempfile::tempdir().unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/repos/cli/cli/issues"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({"number": 1})))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let client = cached_client(&server, cache.path(), 60);
+    let body = serde_json::json!({"title": "x"});
+    for _ in 0..2 {
+        let _: Value = client
+            .rest(reqwest::Method::POST, "repos/cli/cli/issues", Some(&body))
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_should_not_share_cache_between_tokens() {
+    let server = MockServer::start().await;
+    let cache = tempfile::tempdir().unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/user"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"login": "a"})))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let ttl = std::time::Duration::from_secs(60);
+    for token in ["token-a", "token-b"] {
+        let client = Client::new(reqwest::Client::new(), "github.com", Some(token.into()))
+            .with_url_override(format!("{}/", server.uri()))
+            .with_cache_dir(cache.path(), ttl);
+        let _: Value = client
+            .rest(reqwest::Method::GET, "user", None)
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_should_cache_graphql_queries_by_variables() {
+    use wiremock::matchers::body_partial_json;
+
+    let server = MockServer::start().await;
+    let cache = tempfile::tempdir().unwrap();
+
+    for name in ["cli", "ghc"] {
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_partial_json(
+                serde_json::json!({"variables": {"name": name}}),
+            ))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"data": {"repository": {"name": name}}})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let client = cached_client(&server, cache.path(), 60);
+    for name in ["cli", "ghc", "cli", "ghc"] {
+        let variables = std::collections::HashMap::from([("name".to_string(), Value::from(name))]);
+        let data: Value = client
+            .graphql(
+                "query($name: String!) { repository(name: $name) { name } }",
+                &variables,
+            )
+            .await
+            .unwrap();
+        assert_eq!(data["repository"]["name"], name);
+    }
+}
+
+#[tokio::test]
+async fn test_should_not_cache_graphql_mutations() {
+    let server = MockServer::start().await;
+    let cache = tempfile::tempdir().unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({"data": {"ok": true}})),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let client = cached_client(&server, cache.path(), 60);
+    for _ in 0..2 {
+        let _: Value = client
+            .graphql("mutation { ok }", &std::collections::HashMap::new())
+            .await
+            .unwrap();
+    }
+}
+
+/// Serve a single HTTP/1.1 request on a Unix socket and return its head.
+#[cfg(unix)]
+async fn serve_once_on_socket(listener: tokio::net::UnixListener, body: &'static str) -> String {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let (mut stream, _) = listener.accept().await.unwrap();
+    let mut head = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+        let n = stream.read(&mut buf).await.unwrap();
+        assert!(n > 0, "connection closed before request head");
+        head.extend_from_slice(&buf[..n]);
+    }
+    let response = format!(
+        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await.unwrap();
+    String::from_utf8(head).unwrap()
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_should_send_requests_over_unix_socket() {
+    let dir = tempfile::tempdir().unwrap();
+    let socket = dir.path().join("gh.sock");
+    let listener = tokio::net::UnixListener::bind(&socket).unwrap();
+    let server = tokio::spawn(serve_once_on_socket(listener, r#"{"login":"octocat"}"#));
+
+    let http = ghc_api::http::build_client(&ghc_api::http::HttpClientOptions {
+        app_version: "1.0.0".to_string(),
+        skip_default_headers: false,
+        log_verbose: false,
+        unix_socket: Some(socket),
+    })
+    .unwrap();
+    let client = Client::new(http, "github.com", Some("test-token".into())).with_unix_socket();
+
+    let user: Value = client
+        .rest(reqwest::Method::GET, "user", None)
+        .await
+        .unwrap();
+    assert_eq!(user["login"], "octocat");
+
+    let head = server.await.unwrap().to_lowercase();
+    assert!(head.starts_with("get /user http/1.1\r\n"), "{head}");
+    assert!(head.contains("\r\nhost: api.github.com\r\n"), "{head}");
+    assert!(
+        head.contains("\r\nauthorization: token test-token\r\n"),
+        "{head}"
+    );
+}
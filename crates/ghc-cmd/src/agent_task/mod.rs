@@ -2,6 +2,8 @@
 //!
 //! Manage AI agent tasks on GitHub.
 
+use std::path::PathBuf;
+
 use anyhow::{Context, Result};
 use clap::Subcommand;
 use ghc_core::{ios_eprintln, ios_println};
@@ -38,11 +40,15 @@ impl AgentTaskCommand {
 pub struct CreateArgs {
     /// Repository (OWNER/REPO).
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Task description.
-    #[arg(short, long)]
-    body: String,
+    #[arg(short, long, conflicts_with = "body_file")]
+    body: Option<String>,
+
+    /// Read task description from file (use "-" to read from standard input).
+    #[arg(short = 'F', long, conflicts_with = "body")]
+    body_file: Option<PathBuf>,
 
     /// Task title.
     #[arg(short, long)]
@@ -51,14 +57,19 @@ pub struct CreateArgs {
 
 impl CreateArgs {
     async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
 
+        let Some(description) =
+            ghc_core::cmdutil::read_body(self.body.clone(), self.body_file.as_deref())?
+        else {
+            anyhow::bail!("a task description is required; use `--body` or `--body-file`");
+        };
+
         let mut body = serde_json::json!({
-            "body": self.body,
+            "body": description,
         });
         if let Some(ref title) = self.title {
             body["title"] = Value::String(title.clone());
@@ -83,10 +94,10 @@ impl CreateArgs {
 pub struct ListArgs {
     /// Repository (OWNER/REPO).
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Maximum number of tasks to list.
-    #[arg(short = 'L', long, default_value = "30")]
+    #[arg(short = 'L', long, default_value = "30", value_parser = clap::value_parser!(u32).range(1..))]
     limit: u32,
 
     /// Output JSON with specified fields.
@@ -100,12 +111,15 @@ pub struct ListArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl ListArgs {
     async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -114,23 +128,25 @@ impl ListArgs {
             "repos/{}/{}/agent-tasks?per_page={}",
             repo.owner(),
             repo.name(),
-            self.limit
+            self.limit.min(100)
         );
-        let tasks: Vec<Value> = client
-            .rest(reqwest::Method::GET, &path, None::<&Value>)
+        let tasks = client
+            .rest_paginate_limit(&path, Some(self.limit as usize), |page| {
+                ghc_api::client::page_items(page, None)
+            })
             .await
             .context("failed to list agent tasks")?;
 
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let arr = Value::Array(tasks.clone());
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &arr,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -151,12 +167,18 @@ impl ListArgs {
                 .and_then(Value::as_str)
                 .unwrap_or("unknown");
             let created = task.get("created_at").and_then(Value::as_str).unwrap_or("");
+            let created = match chrono::DateTime::parse_from_rfc3339(created) {
+                Ok(dt) if ios.is_stdout_tty() => {
+                    ghc_core::text::fuzzy_ago_abbr(chrono::Utc::now().signed_duration_since(dt))
+                }
+                _ => created.to_string(),
+            };
 
             tp.add_row(vec![
                 cs.bold(&format!("#{id}")),
                 title.to_string(),
                 status.to_string(),
-                created.to_string(),
+                created,
             ]);
         }
         ios_println!(ios, "{}", tp.render());
@@ -170,7 +192,7 @@ impl ListArgs {
 pub struct ViewArgs {
     /// Repository (OWNER/REPO).
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Task ID.
     #[arg(value_name = "ID")]
@@ -183,12 +205,23 @@ pub struct ViewArgs {
     /// Output JSON with specified fields.
     #[arg(long, value_delimiter = ',')]
     json: Vec<String>,
+
+    /// Filter JSON output using a jq expression.
+    #[arg(short = 'q', long)]
+    jq: Option<String>,
+
+    /// Format JSON output using a Go template.
+    #[arg(short = 't', long)]
+    template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl ViewArgs {
     async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -216,8 +249,15 @@ impl ViewArgs {
             .await
             .context("failed to view agent task")?;
 
-        if !self.json.is_empty() {
-            ios_println!(ios, "{}", serde_json::to_string_pretty(&task)?);
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
+            ghc_core::json::output(
+                &task,
+                &self.json,
+                self.jq.as_deref(),
+                self.template.as_deref(),
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -240,3 +280,77 @@ impl ViewArgs {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test_helpers::TestHarness;
+
+    fn list_args(limit: u32) -> ListArgs {
+        ListArgs {
+            repo: Some("owner/repo".into()),
+            limit,
+            json: vec!["id".into()],
+            jq: None,
+            template: None,
+            yaml: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_should_paginate_agent_tasks_up_to_limit() {
+        use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let h = TestHarness::new().await;
+        let next = format!(
+            "<{}/repos/owner/repo/agent-tasks?per_page=3&page=2>; rel=\"next\"",
+            h.server.uri()
+        );
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/agent-tasks"))
+            .and(query_param_is_missing("page"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("link", next.as_str())
+                    .set_body_json(serde_json::json!([{"id": 1}, {"id": 2}])),
+            )
+            .expect(1)
+            .mount(&h.server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/agent-tasks"))
+            .and(query_param("page", "2"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!([{"id": 3}, {"id": 4}])),
+            )
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        list_args(3).run(&h.factory).await.unwrap();
+
+        let out: Value = serde_json::from_str(&h.stdout()).unwrap();
+        let ids: Vec<i64> = out
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|t| t.get("id").and_then(Value::as_i64))
+            .collect();
+        assert_eq!(ids, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_should_reject_zero_limit() {
+        #[derive(clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: ListArgs,
+        }
+        use clap::Parser;
+
+        assert!(Cli::try_parse_from(["list", "--limit", "0"]).is_err());
+        assert!(Cli::try_parse_from(["list", "--limit", "1"]).is_ok());
+    }
+}
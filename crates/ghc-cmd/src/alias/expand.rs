@@ -0,0 +1,201 @@
+//! Alias expansion for the top-level dispatcher.
+//!
+//! Maps from Go's `pkg/cmd/root/alias.go`. Plain aliases expand into new
+//! `ghc` arguments; aliases starting with `!` run through `sh -c`.
+
+use std::sync::LazyLock;
+
+use anyhow::Result;
+use regex::Regex;
+
+/// Prefix marking an alias that is run by the system shell.
+pub const SHELL_PREFIX: char = '!';
+
+/// Environment variable counting how many shell aliases enclose this process.
+pub const ALIAS_DEPTH_ENV: &str = "GHC_ALIAS_DEPTH";
+
+/// Deepest nesting of shell aliases before expansion is refused, so an
+/// alias that runs itself cannot spawn processes without limit.
+pub const MAX_ALIAS_DEPTH: u32 = 8;
+
+/// Matches a `$N` placeholder left without an argument.
+static PLACEHOLDER_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\$\d").unwrap_or_else(|_| unreachable!()));
+
+/// The result of expanding an alias invocation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expansion {
+    /// Arguments to run `ghc` with, not including the program name.
+    Command(Vec<String>),
+    /// A program and its arguments to run in place of `ghc`.
+    Shell(Vec<String>),
+}
+
+/// Whether `expansion` is a shell alias.
+pub fn is_shell_alias(expansion: &str) -> bool {
+    expansion.starts_with(SHELL_PREFIX)
+}
+
+/// Expand `expansion` with the arguments given after the alias name.
+///
+/// Shell aliases become `sh -c SCRIPT -- ARGS...`, so the script sees the
+/// arguments as `$1`, `$2`, ... and `$@`. Plain aliases substitute `$1`,
+/// `$2`, ... and append the arguments when there are no placeholders.
+///
+/// # Errors
+///
+/// Returns an error if a placeholder has no matching argument or the
+/// expansion has unbalanced quotes.
+pub fn expand(expansion: &str, args: &[String]) -> Result<Expansion> {
+    if let Some(script) = expansion.strip_prefix(SHELL_PREFIX) {
+        let mut command = vec!["sh".to_string(), "-c".to_string(), script.to_string()];
+        if !args.is_empty() {
+            command.push("--".to_string());
+            command.extend(args.iter().cloned());
+        }
+        return Ok(Expansion::Shell(command));
+    }
+
+    let mut expanded = expansion.to_string();
+    let mut extra = Vec::new();
+    for (i, arg) in args.iter().enumerate() {
+        if expanded.contains('$') {
+            expanded = expanded.replace(&format!("${}", i + 1), arg);
+        } else {
+            extra.push(arg.clone());
+        }
+    }
+    if PLACEHOLDER_RE.is_match(&expanded) {
+        anyhow::bail!("not enough arguments for alias: {expanded}");
+    }
+    let mut expanded_args = shlex::split(&expanded)
+        .ok_or_else(|| anyhow::anyhow!("invalid quoting in alias: {expansion}"))?;
+    expanded_args.extend(extra);
+    Ok(Expansion::Command(expanded_args))
+}
+
+/// The shell alias nesting depth of this process, from [`ALIAS_DEPTH_ENV`].
+pub fn alias_depth() -> u32 {
+    parse_depth(std::env::var(ALIAS_DEPTH_ENV).ok().as_deref())
+}
+
+fn parse_depth(value: Option<&str>) -> u32 {
+    value.and_then(|v| v.trim().parse().ok()).unwrap_or(0)
+}
+
+/// Refuse to expand another alias once `depth` reaches [`MAX_ALIAS_DEPTH`].
+///
+/// # Errors
+///
+/// Returns an error when the limit is reached.
+pub fn check_alias_depth(depth: u32) -> Result<()> {
+    if depth >= MAX_ALIAS_DEPTH {
+        anyhow::bail!(
+            "alias recursion limit reached: shell aliases nested {depth} deep; does an alias run itself?"
+        );
+    }
+    Ok(())
+}
+
+/// Run a shell alias with inherited stdio and return its exit code.
+///
+/// The child sees [`ALIAS_DEPTH_ENV`] set to `depth + 1`, so aliases it
+/// runs in turn can be cut off by [`check_alias_depth`].
+///
+/// # Errors
+///
+/// Returns an error if the shell cannot be started.
+pub fn run_shell(command: &[String], depth: u32) -> Result<i32> {
+    let (program, args) = command
+        .split_first()
+        .ok_or_else(|| anyhow::anyhow!("empty shell alias"))?;
+    let status = std::process::Command::new(program)
+        .args(args)
+        .env(ALIAS_DEPTH_ENV, (depth + 1).to_string())
+        .status()
+        .map_err(|e| anyhow::anyhow!("failed to run {program}: {e}"))?;
+    Ok(status.code().unwrap_or(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(args: &[&str]) -> Vec<String> {
+        args.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_should_append_args_to_plain_alias() {
+        let expansion = expand("issue list --label bug", &strings(&["--limit", "5"])).unwrap();
+        assert_eq!(
+            expansion,
+            Expansion::Command(strings(&[
+                "issue", "list", "--label", "bug", "--limit", "5"
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_should_substitute_positional_placeholders() {
+        let expansion = expand("pr view $1 --repo '$2'", &strings(&["12", "a/b"])).unwrap();
+        assert_eq!(
+            expansion,
+            Expansion::Command(strings(&["pr", "view", "12", "--repo", "a/b"]))
+        );
+
+        let err = expand("pr view $1", &[]).unwrap_err();
+        assert!(err.to_string().contains("not enough arguments"));
+    }
+
+    #[test]
+    fn test_should_pass_shell_alias_args_after_separator() {
+        let expansion = expand("!ghc pr view $1 --web", &strings(&["7"])).unwrap();
+        assert_eq!(
+            expansion,
+            Expansion::Shell(strings(&["sh", "-c", "ghc pr view $1 --web", "--", "7"]))
+        );
+        assert_eq!(
+            expand("!ghc issue list --label bug", &[]).unwrap(),
+            Expansion::Shell(strings(&["sh", "-c", "ghc issue list --label bug"]))
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_should_run_shell_alias_with_positional_args() {
+        let Expansion::Shell(argv) = expand(
+            "!test \"$1\" = one && test \"$#\" = 2",
+            &strings(&["one", "two"]),
+        )
+        .unwrap() else {
+            panic!("expected a shell alias");
+        };
+        assert_eq!(run_shell(&argv, 0).unwrap(), 0);
+        let Expansion::Shell(argv) = expand("!exit 3", &[]).unwrap() else {
+            panic!("expected a shell alias");
+        };
+        assert_eq!(run_shell(&argv, 0).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_should_stop_nested_shell_aliases_at_depth_limit() {
+        assert_eq!(parse_depth(None), 0);
+        assert_eq!(parse_depth(Some("3")), 3);
+        assert_eq!(parse_depth(Some("junk")), 0);
+        assert!(check_alias_depth(MAX_ALIAS_DEPTH - 1).is_ok());
+        let err = check_alias_depth(MAX_ALIAS_DEPTH).unwrap_err();
+        assert!(err.to_string().contains("alias recursion limit"), "{err}");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_should_pass_incremented_depth_to_shell_alias() {
+        let Expansion::Shell(command) =
+            expand(&format!("!test \"${ALIAS_DEPTH_ENV}\" = 3"), &[]).unwrap()
+        else {
+            panic!("expected a shell alias");
+        };
+        assert_eq!(run_shell(&command, 2).unwrap(), 0);
+    }
+}
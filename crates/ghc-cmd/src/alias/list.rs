@@ -5,11 +5,17 @@ use std::collections::BTreeMap;
 use anyhow::Result;
 use clap::Args;
 
-use ghc_core::ios_print;
+use ghc_core::table::TablePrinter;
+use ghc_core::{ios_print, ios_println};
+
+use super::expand;
 
 use crate::factory::Factory;
 
 /// List configured aliases.
+///
+/// On a terminal, aliases are shown as a table with shell aliases labeled;
+/// otherwise they are printed as YAML that `alias import` accepts.
 #[derive(Debug, Args)]
 pub struct ListArgs;
 
@@ -34,6 +40,21 @@ impl ListArgs {
 
         // Sort for deterministic output
         let sorted: BTreeMap<_, _> = aliases.iter().collect();
+        if ios.is_stdout_tty() {
+            let cs = ios.color_scheme();
+            let mut tp = TablePrinter::new(ios);
+            for (name, expansion) in &sorted {
+                let kind = if expand::is_shell_alias(expansion) {
+                    cs.gray("shell")
+                } else {
+                    String::new()
+                };
+                tp.add_row(vec![cs.bold(name), (*expansion).clone(), kind]);
+            }
+            ios_println!(ios, "{}", tp.render());
+            return Ok(());
+        }
+
         let yaml = serde_yaml::to_string(&sorted)
             .map_err(|e| anyhow::anyhow!("failed to serialize aliases: {e}"))?;
         ios_print!(ios, "{yaml}");
@@ -68,6 +89,27 @@ mod tests {
         assert!(stdout.contains("issue view"));
     }
 
+    #[tokio::test]
+    async fn test_should_label_shell_aliases_on_tty() {
+        let mut h = TestHarness::new().await;
+        h.factory.io.set_stdout_tty(true);
+        {
+            let cfg_lock = h.factory.config().unwrap();
+            let mut cfg = cfg_lock.lock().unwrap();
+            cfg.set_alias("bugs", "!ghc issue list --label bug");
+            cfg.set_alias("co", "pr checkout");
+        }
+
+        ListArgs.run(&h.factory).unwrap();
+
+        let stdout = h.stdout();
+        let bugs = stdout.lines().find(|l| l.starts_with("bugs")).unwrap();
+        assert!(bugs.contains("!ghc issue list --label bug"), "{stdout}");
+        assert!(bugs.trim_end().ends_with("shell"), "{stdout}");
+        let co = stdout.lines().find(|l| l.starts_with("co")).unwrap();
+        assert!(!co.contains("shell"), "{stdout}");
+    }
+
     #[tokio::test]
     async fn test_should_error_when_no_aliases() {
         let h = TestHarness::new().await;
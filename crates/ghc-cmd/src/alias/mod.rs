@@ -1,9 +1,11 @@
 //! Alias commands (`ghc alias`).
 //!
 //! Maps from Go's `pkg/cmd/alias/` package. Provides set, list, delete,
-//! and import subcommands for managing command shortcuts.
+//! and import subcommands for managing command shortcuts, plus the
+//! expansion used by the top-level dispatcher.
 
 pub mod delete;
+pub mod expand;
 pub mod imports;
 pub mod list;
 pub mod set;
@@ -17,6 +17,9 @@ pub struct SetArgs {
     /// The expansion (command the alias maps to).
     expansion: String,
     /// Declare an alias to be passed through a shell interpreter.
+    ///
+    /// The expansion is stored with a `!` prefix and run with `sh -c`, with
+    /// the alias arguments available as `$1`, `$2`, ... and `$@`.
     #[arg(short, long)]
     shell: bool,
     /// Overwrite existing aliases of the same name.
@@ -164,4 +167,23 @@ mod tests {
         let cfg = cfg_lock.lock().unwrap();
         assert_eq!(cfg.aliases().get("greet"), Some(&"!echo hello".to_string()));
     }
+
+    #[tokio::test]
+    async fn test_should_not_double_shell_prefix() {
+        let h = TestHarness::new().await;
+        let args = SetArgs {
+            name: "bugs".to_string(),
+            expansion: "!ghc issue list --label bug".to_string(),
+            shell: true,
+            clobber: false,
+        };
+        args.run(&h.factory).unwrap();
+
+        let cfg_lock = h.factory.config().unwrap();
+        let cfg = cfg_lock.lock().unwrap();
+        assert_eq!(
+            cfg.aliases().get("bugs"),
+            Some(&"!ghc issue list --label bug".to_string())
+        );
+    }
 }
@@ -2,12 +2,24 @@
 //!
 //! Make an authenticated GitHub API request.
 
+use std::path::PathBuf;
+use std::sync::LazyLock;
+
 use anyhow::Context;
 use clap::Args;
+use regex::Regex;
 use serde_json::Value;
 
+use ghc_core::repo::Repo;
 use ghc_core::{ios_eprintln, ios_println};
 
+/// Endpoint shorthand that renders `/rate_limit` as a quota table.
+const RATE_LIMIT_ENDPOINT: &str = "rate-limit";
+
+/// Matches `$owner` and `$repo` placeholders in `--template` files.
+static PLACEHOLDER_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\$(owner|repo)\b").unwrap_or_else(|_| unreachable!()));
+
 /// Make an authenticated GitHub API request.
 ///
 /// Provides a generic interface for making REST or GraphQL requests
@@ -73,6 +85,33 @@ pub struct ApiArgs {
     /// Wrap paginated results in a JSON array.
     #[arg(long)]
     slurp: bool,
+
+    /// Stop paginating after this many items in total.
+    #[arg(long, value_name = "N")]
+    limit: Option<usize>,
+
+    /// Read a GraphQL query or REST JSON body from a file, filling in `$owner`/`$repo`.
+    #[arg(long, value_name = "FILE")]
+    template: Option<PathBuf>,
+
+    /// Repository used to fill in `--template` placeholders, in OWNER/REPO format.
+    #[arg(short = 'R', long)]
+    repo: Option<String>,
+
+    /// Print the raw payload instead of a table (only with `rate-limit`).
+    #[arg(long)]
+    json: bool,
+}
+
+/// Request content read from a `--template` file.
+enum Template {
+    /// A GraphQL query document and the placeholder variables it references.
+    Query {
+        query: String,
+        variables: Vec<(String, String)>,
+    },
+    /// A REST request body.
+    Body(Value),
 }
 
 impl ApiArgs {
@@ -84,15 +123,22 @@ impl ApiArgs {
     pub async fn run(&self, factory: &crate::factory::Factory) -> anyhow::Result<()> {
         self.validate_flags()?;
 
-        let hostname = self.hostname.as_deref().unwrap_or("github.com");
+        let hostname = &self
+            .hostname
+            .clone()
+            .unwrap_or_else(|| factory.default_host());
         let client = factory.api_client(hostname)?;
         let ios = &factory.io;
 
+        if self.endpoint == RATE_LIMIT_ENDPOINT {
+            return self.run_rate_limit(&client, factory).await;
+        }
+
         // Determine effective method first
         let has_params = !self.field.is_empty() || !self.raw_field.is_empty();
         let effective_method = if let Some(ref m) = self.method {
             m.to_uppercase()
-        } else if has_params || self.input.is_some() {
+        } else if has_params || self.input.is_some() || self.template.is_some() {
             "POST".to_string()
         } else {
             "GET".to_string()
@@ -109,7 +155,10 @@ impl ApiArgs {
             let endpoint = self.append_query_params(&self.endpoint);
             (endpoint, self.build_body_from_input()?)
         } else {
-            let mut body = self.build_body()?;
+            let mut body = match self.load_template(factory).await? {
+                Some(template) => Some(self.template_body(template)?),
+                None => self.build_body()?,
+            };
             // For GraphQL endpoint, restructure body to nest non-query fields
             // under "variables" to match gh CLI behavior
             if self.endpoint == "graphql"
@@ -138,7 +187,10 @@ impl ApiArgs {
             ios_eprintln!(ios, "");
         }
 
-        if self.paginate {
+        if self.paginate && endpoint == "graphql" {
+            self.run_paginated_graphql(&client, body.as_ref(), factory)
+                .await
+        } else if self.paginate {
             self.run_paginated_with_endpoint(&client, &method, &endpoint, body.as_ref(), factory)
                 .await
         } else {
@@ -147,6 +199,27 @@ impl ApiArgs {
         }
     }
 
+    /// Show the remaining API quota for each rate-limit resource.
+    async fn run_rate_limit(
+        &self,
+        client: &ghc_api::client::Client,
+        factory: &crate::factory::Factory,
+    ) -> anyhow::Result<()> {
+        let ios = &factory.io;
+        let payload: Value = client
+            .rest(reqwest::Method::GET, "rate_limit", None)
+            .await
+            .context("failed to fetch rate limit status")?;
+
+        if self.json {
+            ios_println!(ios, "{}", serde_json::to_string_pretty(&payload)?);
+            return Ok(());
+        }
+
+        crate::status::print_rate_limit(ios, &payload);
+        Ok(())
+    }
+
     /// Run a single (non-paginated) API request.
     async fn run_single_with_endpoint(
         &self,
@@ -158,10 +231,15 @@ impl ApiArgs {
     ) -> anyhow::Result<()> {
         let ios = &factory.io;
 
-        let result: Value = client
-            .rest(method.clone(), endpoint, body)
-            .await
-            .map_err(|e| anyhow::anyhow!("{e}"))?;
+        if self.include {
+            let page: ghc_api::client::RestPage<Value> = client
+                .rest_with_next(method.clone(), endpoint, body)
+                .await?;
+            self.output_headers(&page, ios);
+            return self.output_result(&page.data, ios);
+        }
+
+        let result: Value = client.rest(method.clone(), endpoint, body).await?;
 
         self.output_result(&result, ios)
     }
@@ -186,12 +264,17 @@ impl ApiArgs {
 
         let mut all_results: Vec<Value> = Vec::new();
         let mut current_endpoint = endpoint;
+        let mut remaining = self.limit;
 
         loop {
-            let page: ghc_api::client::RestPage<Value> = client
+            let mut page: ghc_api::client::RestPage<Value> = client
                 .rest_with_next(method.clone(), &current_endpoint, body)
-                .await
-                .map_err(|e| anyhow::anyhow!("{e}"))?;
+                .await?;
+
+            self.output_headers(&page, ios);
+            if let Some(ref mut left) = remaining {
+                *left = take_items(&mut page.data, *left);
+            }
 
             if self.slurp {
                 // Collect for slurp mode
@@ -201,6 +284,10 @@ impl ApiArgs {
                 self.output_result(&page.data, ios)?;
             }
 
+            if remaining == Some(0) {
+                break;
+            }
+
             match page.next_url {
                 Some(next) => current_endpoint = next,
                 None => break,
@@ -215,6 +302,76 @@ impl ApiArgs {
         Ok(())
     }
 
+    /// Run paginated GraphQL requests, feeding each page's `pageInfo.endCursor`
+    /// back in as the `endCursor` variable until `hasNextPage` is false.
+    async fn run_paginated_graphql(
+        &self,
+        client: &ghc_api::client::Client,
+        body: Option<&Value>,
+        factory: &crate::factory::Factory,
+    ) -> anyhow::Result<()> {
+        let ios = &factory.io;
+
+        let mut body = body.cloned().unwrap_or_else(|| serde_json::json!({}));
+        let mut all_results: Vec<Value> = Vec::new();
+        let mut remaining = self.limit;
+
+        loop {
+            let mut page: ghc_api::client::RestPage<Value> = client
+                .rest_with_next(reqwest::Method::POST, "graphql", Some(&body))
+                .await?;
+            let cursor = next_page_cursor(&page.data);
+
+            self.output_headers(&page, ios);
+            if let Some(ref mut left) = remaining {
+                *left = take_items(&mut page.data, *left);
+            }
+
+            if self.slurp {
+                all_results.push(page.data);
+            } else {
+                self.output_result(&page.data, ios)?;
+            }
+
+            if remaining == Some(0) {
+                break;
+            }
+
+            match (cursor, body.as_object_mut()) {
+                (Some(cursor), Some(map)) => {
+                    let variables = map
+                        .entry("variables")
+                        .or_insert_with(|| Value::Object(serde_json::Map::new()));
+                    if let Some(variables) = variables.as_object_mut() {
+                        variables.insert("endCursor".to_string(), Value::String(cursor));
+                    }
+                }
+                _ => break,
+            }
+        }
+
+        if self.slurp {
+            self.output_result(&Value::Array(all_results), ios)?;
+        }
+
+        Ok(())
+    }
+
+    /// Print the status line and response headers when `--include` is set.
+    fn output_headers(
+        &self,
+        page: &ghc_api::client::RestPage<Value>,
+        ios: &ghc_core::iostreams::IOStreams,
+    ) {
+        if self.include && !self.silent {
+            ios_println!(
+                ios,
+                "{}",
+                format_headers(page.version, page.status, &page.headers)
+            );
+        }
+    }
+
     /// Output the API result, applying jq filter if specified.
     fn output_result(
         &self,
@@ -247,6 +404,12 @@ impl ApiArgs {
             ));
         }
 
+        if self.template.is_some() && self.input.is_some() {
+            return Err(anyhow::anyhow!(
+                "only one of `--template` or `--input` may be used"
+            ));
+        }
+
         if self.paginate && self.input.is_some() {
             return Err(anyhow::anyhow!(
                 "the `--paginate` option is not supported with `--input`"
@@ -259,12 +422,24 @@ impl ApiArgs {
             ));
         }
 
+        if self.limit.is_some() && !self.paginate {
+            return Err(anyhow::anyhow!(
+                "`--paginate` required when passing `--limit`"
+            ));
+        }
+
         if self.slurp && self.jq.is_some() {
             return Err(anyhow::anyhow!(
                 "the `--slurp` option is not supported with `--jq`"
             ));
         }
 
+        if self.json && self.endpoint != RATE_LIMIT_ENDPOINT {
+            return Err(anyhow::anyhow!(
+                "the `--json` option is only supported with `{RATE_LIMIT_ENDPOINT}`"
+            ));
+        }
+
         let exclusive_count =
             u8::from(self.verbose) + u8::from(self.silent) + u8::from(self.jq.is_some());
         if exclusive_count > 1 {
@@ -276,6 +451,82 @@ impl ApiArgs {
         Ok(())
     }
 
+    /// Read the `--template` file, filling in `$owner`/`$repo` placeholders.
+    ///
+    /// GraphQL templates keep `$owner`/`$repo` as query variables and receive
+    /// their values in the variables map; REST templates have the placeholders
+    /// replaced in the JSON text before it is parsed.
+    async fn load_template(
+        &self,
+        factory: &crate::factory::Factory,
+    ) -> anyhow::Result<Option<Template>> {
+        let Some(ref path) = self.template else {
+            return Ok(None);
+        };
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read template file: {}", path.display()))?;
+
+        if !PLACEHOLDER_RE.is_match(&content) {
+            return Ok(Some(if self.endpoint == "graphql" {
+                Template::Query {
+                    query: content,
+                    variables: Vec::new(),
+                }
+            } else {
+                Template::Body(parse_template_body(&content, path)?)
+            }));
+        }
+
+        let repo = self.template_repo(factory).await?;
+        if self.endpoint == "graphql" {
+            let variables = placeholder_variables(&content, &repo);
+            return Ok(Some(Template::Query {
+                query: content,
+                variables,
+            }));
+        }
+        let content = substitute_placeholders(&content, &repo);
+        Ok(Some(Template::Body(parse_template_body(&content, path)?)))
+    }
+
+    /// Build the request body from a template, merging in `-f`/`-F` fields.
+    fn template_body(&self, template: Template) -> anyhow::Result<Value> {
+        let mut fields = match self.build_body()? {
+            Some(Value::Object(map)) => map,
+            _ => serde_json::Map::new(),
+        };
+
+        match template {
+            Template::Query { query, variables } => {
+                for (key, value) in variables {
+                    fields.entry(key).or_insert(Value::String(value));
+                }
+                fields.insert("query".to_string(), Value::String(query));
+                Ok(Value::Object(fields))
+            }
+            Template::Body(Value::Object(mut body)) => {
+                body.extend(fields);
+                Ok(Value::Object(body))
+            }
+            Template::Body(body) if fields.is_empty() => Ok(body),
+            Template::Body(_) => Err(anyhow::anyhow!(
+                "fields can only be added to a template whose body is a JSON object"
+            )),
+        }
+    }
+
+    /// Resolve the repository for template placeholders, falling back to
+    /// the base repository (`GH_REPO` or git remotes) when `--repo` is not given.
+    async fn template_repo(&self, factory: &crate::factory::Factory) -> anyhow::Result<Repo> {
+        if self.repo.is_some() {
+            return factory.resolve_repo(self.repo.as_deref()).await;
+        }
+        factory
+            .base_repo()
+            .await
+            .context("`--repo` is required to fill in `$owner`/`$repo` template placeholders")
+    }
+
     /// Append -f and -F parameters as query string for GET requests.
     fn append_query_params(&self, endpoint: &str) -> String {
         let mut params: Vec<String> = Vec::new();
@@ -379,6 +630,127 @@ impl ApiArgs {
     }
 }
 
+/// Truncate a page to at most `remaining` items and return how many are left.
+///
+/// Only emitted list items count toward the limit; a page without a list
+/// leaves `remaining` unchanged.
+fn take_items(page: &mut Value, remaining: usize) -> usize {
+    match page_list(page) {
+        Some(items) => {
+            items.truncate(remaining);
+            remaining - items.len()
+        }
+        None => remaining,
+    }
+}
+
+/// The list of items on a page: the page itself when it is an array, the
+/// `nodes` or `edges` of a GraphQL connection, or the first array field of a
+/// wrapper object such as `{"total_count": 2, "items": [...]}`.
+fn page_list(page: &mut Value) -> Option<&mut Vec<Value>> {
+    fn find_connection(value: &mut Value) -> Option<&mut serde_json::Map<String, Value>> {
+        match value {
+            Value::Object(map) => {
+                if map.contains_key("pageInfo") {
+                    Some(map)
+                } else {
+                    map.values_mut().find_map(find_connection)
+                }
+            }
+            Value::Array(items) => items.iter_mut().find_map(find_connection),
+            _ => None,
+        }
+    }
+
+    if page.get("data").is_some() {
+        let connection = find_connection(page)?;
+        return ["nodes", "edges"]
+            .into_iter()
+            .find(|key| connection.get(*key).is_some_and(Value::is_array))
+            .and_then(|key| connection.get_mut(key)?.as_array_mut());
+    }
+    match page {
+        Value::Array(items) => Some(items),
+        Value::Object(map) => map.values_mut().find_map(Value::as_array_mut),
+        _ => None,
+    }
+}
+
+/// Replace `$owner`/`$repo` placeholders in template text.
+fn substitute_placeholders(text: &str, repo: &Repo) -> String {
+    PLACEHOLDER_RE
+        .replace_all(text, |caps: &regex::Captures<'_>| {
+            if &caps[1] == "owner" {
+                repo.owner().to_string()
+            } else {
+                repo.name().to_string()
+            }
+        })
+        .into_owned()
+}
+
+/// Values for the `$owner`/`$repo` variables referenced by a GraphQL query.
+fn placeholder_variables(query: &str, repo: &Repo) -> Vec<(String, String)> {
+    let mut variables: Vec<(String, String)> = Vec::new();
+    for caps in PLACEHOLDER_RE.captures_iter(query) {
+        let name = &caps[1];
+        if variables.iter().any(|(key, _)| key == name) {
+            continue;
+        }
+        let value = if name == "owner" {
+            repo.owner()
+        } else {
+            repo.name()
+        };
+        variables.push((name.to_string(), value.to_string()));
+    }
+    variables
+}
+
+fn parse_template_body(content: &str, path: &std::path::Path) -> anyhow::Result<Value> {
+    serde_json::from_str(content)
+        .with_context(|| format!("failed to parse JSON from {}", path.display()))
+}
+
+/// Return the `endCursor` of the first `pageInfo` in a GraphQL response,
+/// if that connection has another page.
+fn next_page_cursor(page: &Value) -> Option<String> {
+    fn find_page_info(value: &Value) -> Option<&Value> {
+        match value {
+            Value::Object(map) => map
+                .get("pageInfo")
+                .or_else(|| map.values().find_map(find_page_info)),
+            Value::Array(items) => items.iter().find_map(find_page_info),
+            _ => None,
+        }
+    }
+
+    let page_info = find_page_info(page.get("data")?)?;
+    if !page_info.get("hasNextPage")?.as_bool()? {
+        return None;
+    }
+    page_info
+        .get("endCursor")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+}
+
+/// Format the status line and headers of a response, sorted by header name.
+fn format_headers(
+    version: reqwest::Version,
+    status: reqwest::StatusCode,
+    headers: &reqwest::header::HeaderMap,
+) -> String {
+    let mut lines: Vec<String> = headers
+        .iter()
+        .map(|(name, value)| format!("{name}: {}", String::from_utf8_lossy(value.as_bytes())))
+        .collect();
+    lines.sort();
+    lines.insert(0, format!("{version:?} {status}"));
+    lines.push(String::new());
+    lines.join("\n")
+}
+
 /// Format a JSON value for output.
 fn format_output(value: &Value, pretty: bool) -> String {
     match value {
@@ -434,6 +806,10 @@ mod tests {
             verbose: false,
             silent: false,
             slurp: false,
+            limit: None,
+            template: None,
+            repo: None,
+            json: false,
         };
         assert!(args.validate_flags().is_err());
     }
@@ -456,6 +832,10 @@ mod tests {
             verbose: false,
             silent: false,
             slurp: true,
+            limit: None,
+            template: None,
+            repo: None,
+            json: false,
         };
         assert!(args.validate_flags().is_err());
     }
@@ -478,6 +858,10 @@ mod tests {
             verbose: true,
             silent: true,
             slurp: false,
+            limit: None,
+            template: None,
+            repo: None,
+            json: false,
         };
         assert!(args.validate_flags().is_err());
     }
@@ -500,6 +884,10 @@ mod tests {
             verbose: false,
             silent: false,
             slurp: false,
+            limit: None,
+            template: None,
+            repo: None,
+            json: false,
         };
         let body = args.build_body().unwrap();
         assert!(body.is_some(), "should have a body");
@@ -535,6 +923,10 @@ mod tests {
             verbose: false,
             silent: false,
             slurp: false,
+            limit: None,
+            template: None,
+            repo: None,
+            json: false,
         };
         let body = args.build_body().unwrap();
         assert!(body.is_none(), "should have no body");
@@ -547,4 +939,510 @@ mod tests {
         };
         assert_eq!(effective, "GET", "should default to GET when no fields");
     }
+
+    fn request_args(endpoint: &str) -> ApiArgs {
+        ApiArgs {
+            endpoint: endpoint.into(),
+            method: None,
+            field: vec![],
+            raw_field: vec![],
+            header: vec![],
+            include: false,
+            paginate: false,
+            jq: None,
+            hostname: None,
+            input: None,
+            preview: vec![],
+            cache: None,
+            verbose: false,
+            silent: false,
+            slurp: false,
+            limit: None,
+            template: None,
+            repo: None,
+            json: false,
+        }
+    }
+
+    fn paginate_args(limit: usize, slurp: bool) -> ApiArgs {
+        ApiArgs {
+            paginate: true,
+            slurp,
+            limit: Some(limit),
+            ..request_args("repos/owner/repo/issues")
+        }
+    }
+
+    /// Mount three pages of two items each, linked with `Link` headers.
+    async fn mount_pages(server: &wiremock::MockServer) {
+        use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let link = |page: u32| {
+            format!(
+                "<{}/repos/owner/repo/issues?per_page=100&page={page}>; rel=\"next\"",
+                server.uri()
+            )
+        };
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/issues"))
+            .and(query_param_is_missing("page"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("link", link(2).as_str())
+                    .set_body_json(serde_json::json!([{ "n": 1 }, { "n": 2 }])),
+            )
+            .mount(server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/issues"))
+            .and(query_param("page", "2"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("link", link(3).as_str())
+                    .set_body_json(serde_json::json!([{ "n": 3 }, { "n": 4 }])),
+            )
+            .mount(server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/issues"))
+            .and(query_param("page", "3"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{ "n": 5 }])))
+            .expect(0)
+            .mount(server)
+            .await;
+    }
+
+    #[test]
+    fn test_should_validate_limit_without_paginate() {
+        let mut args = paginate_args(5, false);
+        args.paginate = false;
+        let err = args.validate_flags().unwrap_err();
+        assert!(err.to_string().contains("`--paginate` required"));
+    }
+
+    #[test]
+    fn test_should_take_items_up_to_remaining() {
+        let mut page = serde_json::json!([1, 2, 3]);
+        assert_eq!(take_items(&mut page, 2), 0);
+        assert_eq!(page, serde_json::json!([1, 2]));
+
+        let mut page = serde_json::json!([1]);
+        assert_eq!(take_items(&mut page, 4), 3);
+
+        let mut page = serde_json::json!({ "total_count": 1 });
+        assert_eq!(take_items(&mut page, 4), 4);
+    }
+
+    #[test]
+    fn test_should_take_items_from_wrapper_and_graphql_pages() {
+        let mut page = serde_json::json!({ "total_count": 3, "items": [1, 2, 3] });
+        assert_eq!(take_items(&mut page, 2), 0);
+        assert_eq!(
+            page,
+            serde_json::json!({ "total_count": 3, "items": [1, 2] })
+        );
+
+        let mut page = serde_json::json!({
+            "data": { "repository": { "issues": {
+                "nodes": [1, 2, 3],
+                "pageInfo": { "hasNextPage": true, "endCursor": "c1" }
+            } } }
+        });
+        assert_eq!(take_items(&mut page, 5), 2);
+        assert_eq!(take_items(&mut page, 1), 0);
+        assert_eq!(
+            page["data"]["repository"]["issues"]["nodes"],
+            serde_json::json!([1])
+        );
+
+        let mut page = serde_json::json!({ "data": { "viewer": { "login": "octocat" } } });
+        assert_eq!(take_items(&mut page, 5), 5);
+    }
+
+    #[tokio::test]
+    async fn test_should_stop_paginating_at_limit_when_slurping() {
+        let h = crate::test_helpers::TestHarness::new().await;
+        mount_pages(&h.server).await;
+
+        paginate_args(3, true).run(&h.factory).await.unwrap();
+
+        let out: Value = serde_json::from_str(&h.stdout()).unwrap();
+        assert_eq!(
+            out,
+            serde_json::json!([[{ "n": 1 }, { "n": 2 }], [{ "n": 3 }]])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_stop_paginating_at_limit_when_streaming() {
+        let h = crate::test_helpers::TestHarness::new().await;
+        mount_pages(&h.server).await;
+
+        paginate_args(2, false).run(&h.factory).await.unwrap();
+
+        assert_eq!(h.stdout().trim(), r#"[{"n":1},{"n":2}]"#);
+    }
+
+    async fn mount_rate_limit(server: &wiremock::MockServer) {
+        let reset = chrono::Utc::now().timestamp() + 30 * 60 + 30;
+        crate::test_helpers::mock_rest_get(
+            server,
+            "/rate_limit",
+            serde_json::json!({
+                "resources": {
+                    "search": { "limit": 30, "used": 30, "remaining": 0, "reset": reset },
+                    "core": { "limit": 5000, "used": 1, "remaining": 4999, "reset": reset }
+                },
+                "rate": { "limit": 5000, "used": 1, "remaining": 4999, "reset": reset }
+            }),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_should_render_rate_limit_table() {
+        let h = crate::test_helpers::TestHarness::new().await;
+        mount_rate_limit(&h.server).await;
+
+        request_args("rate-limit").run(&h.factory).await.unwrap();
+
+        let out = h.stdout();
+        let rows: Vec<Vec<&str>> = out.lines().map(|l| l.split('\t').collect()).collect();
+        assert_eq!(rows.len(), 2, "{out}");
+        assert_eq!(rows[0][..3], ["core", "4999", "5000"]);
+        assert_eq!(rows[1][..3], ["search", "0", "30"]);
+        assert!(
+            chrono::DateTime::parse_from_rfc3339(rows[0][3]).is_ok(),
+            "{out}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_print_raw_rate_limit_payload_with_json() {
+        let h = crate::test_helpers::TestHarness::new().await;
+        mount_rate_limit(&h.server).await;
+
+        let args = ApiArgs {
+            json: true,
+            ..request_args("rate-limit")
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let out: Value = serde_json::from_str(&h.stdout()).unwrap();
+        assert_eq!(out["resources"]["core"]["remaining"], 4999);
+    }
+
+    #[test]
+    fn test_should_reject_json_for_other_endpoints() {
+        let args = ApiArgs {
+            json: true,
+            ..request_args("user")
+        };
+        let err = args.validate_flags().unwrap_err();
+        assert!(err.to_string().contains("only supported with `rate-limit`"));
+    }
+
+    #[tokio::test]
+    async fn test_should_retry_rate_limited_page_when_paginating() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let h = crate::test_helpers::TestHarness::new().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/issues"))
+            .and(query_param("page", "2"))
+            .respond_with(
+                ResponseTemplate::new(403)
+                    .insert_header("retry-after", "0")
+                    .set_body_json(serde_json::json!({
+                        "message": "You have exceeded a secondary rate limit."
+                    })),
+            )
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&h.server)
+            .await;
+        mount_pages(&h.server).await;
+
+        paginate_args(4, true).run(&h.factory).await.unwrap();
+
+        let out: Value = serde_json::from_str(&h.stdout()).unwrap();
+        assert_eq!(out.as_array().unwrap().len(), 2);
+        assert!(h.stderr().contains("hit rate limit, retrying in 0s"));
+    }
+
+    fn template_args(endpoint: &str, template: &std::path::Path) -> ApiArgs {
+        ApiArgs {
+            template: Some(template.to_path_buf()),
+            repo: Some("octo/hello".into()),
+            ..request_args(endpoint)
+        }
+    }
+
+    #[test]
+    fn test_should_substitute_template_placeholders() {
+        let repo = Repo::new("octo", "hello");
+        assert_eq!(
+            substitute_placeholders(r#"{"q": "$owner/$repo $repository"}"#, &repo),
+            r#"{"q": "octo/hello $repository"}"#,
+        );
+    }
+
+    #[test]
+    fn test_should_collect_placeholder_variables_once() {
+        let repo = Repo::new("octo", "hello");
+        let vars = placeholder_variables("query($repo: String!, $owner: String!) { $repo }", &repo);
+        assert_eq!(
+            vars,
+            vec![
+                ("repo".to_string(), "hello".to_string()),
+                ("owner".to_string(), "octo".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_should_find_next_page_cursor() {
+        let page = serde_json::json!({
+            "data": { "repository": { "issues": {
+                "nodes": [],
+                "pageInfo": { "hasNextPage": true, "endCursor": "c1" }
+            } } }
+        });
+        assert_eq!(next_page_cursor(&page), Some("c1".to_string()));
+
+        let last = serde_json::json!({
+            "data": { "viewer": { "pageInfo": { "hasNextPage": false, "endCursor": "c2" } } }
+        });
+        assert_eq!(next_page_cursor(&last), None);
+    }
+
+    #[test]
+    fn test_should_reject_template_with_input() {
+        let mut args = template_args("graphql", std::path::Path::new("q.graphql"));
+        args.input = Some("body.json".into());
+        let err = args.validate_flags().unwrap_err();
+        assert!(err.to_string().contains("`--template` or `--input`"));
+    }
+
+    #[tokio::test]
+    async fn test_should_error_when_template_file_is_missing() {
+        let h = crate::test_helpers::TestHarness::new().await;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("missing.graphql");
+
+        let err = template_args("graphql", &path)
+            .run(&h.factory)
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("failed to read template file"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_keep_api_error_in_chain() {
+        let h = crate::test_helpers::TestHarness::new().await;
+        crate::test_helpers::mock_rest_get_status(
+            &h.server,
+            "/repos/owner/missing",
+            404,
+            serde_json::json!({ "message": "Not Found" }),
+        )
+        .await;
+
+        for args in [
+            request_args("repos/owner/missing"),
+            ApiArgs {
+                paginate: true,
+                ..request_args("repos/owner/missing")
+            },
+        ] {
+            let err = args.run(&h.factory).await.unwrap_err();
+            let api_err = err
+                .downcast_ref::<ghc_api::errors::ApiError>()
+                .expect("ApiError should stay in the chain");
+            assert!(api_err.is_not_found(), "unexpected error: {api_err}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_should_send_rest_template_body_with_placeholders() {
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let h = crate::test_helpers::TestHarness::new().await;
+        Mock::given(method("POST"))
+            .and(path("/repos/octo/hello/issues"))
+            .and(body_json(serde_json::json!({
+                "title": "Report for octo/hello",
+                "labels": ["bug"]
+            })))
+            .respond_with(
+                ResponseTemplate::new(201).set_body_json(serde_json::json!({ "number": 1 })),
+            )
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("issue.json");
+        std::fs::write(&file, r#"{"title": "Report for $owner/$repo"}"#).unwrap();
+
+        let mut args = template_args("repos/octo/hello/issues", &file);
+        args.field = vec![r#"labels=["bug"]"#.into()];
+        args.run(&h.factory).await.unwrap();
+
+        assert!(h.stdout().contains(r#""number":1"#));
+    }
+
+    #[tokio::test]
+    async fn test_should_paginate_graphql_template_with_end_cursor() {
+        use wiremock::matchers::{body_partial_json, method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let h = crate::test_helpers::TestHarness::new().await;
+        let page = |number: u32, has_next: bool, cursor: &str| {
+            serde_json::json!({
+                "data": { "repository": { "issues": {
+                    "nodes": [{ "number": number }],
+                    "pageInfo": { "hasNextPage": has_next, "endCursor": cursor }
+                } } }
+            })
+        };
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_partial_json(serde_json::json!({
+                "variables": { "owner": "octo", "repo": "hello", "endCursor": "c1" }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page(2, false, "c2")))
+            .with_priority(1)
+            .expect(1)
+            .mount(&h.server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_partial_json(serde_json::json!({
+                "variables": { "owner": "octo", "repo": "hello" }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page(1, true, "c1")))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("issues.graphql");
+        std::fs::write(
+            &file,
+            "query($owner: String!, $repo: String!, $endCursor: String) {\n  \
+             repository(owner: $owner, name: $repo) {\n    \
+             issues(first: 1, after: $endCursor) {\n      \
+             nodes { number }\n      pageInfo { hasNextPage endCursor }\n    }\n  }\n}\n",
+        )
+        .unwrap();
+
+        let mut args = template_args("graphql", &file);
+        args.paginate = true;
+        args.slurp = true;
+        args.run(&h.factory).await.unwrap();
+
+        let out: Value = serde_json::from_str(&h.stdout()).unwrap();
+        assert_eq!(
+            out,
+            serde_json::json!([page(1, true, "c1"), page(2, false, "c2")])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_filter_response_with_jq_pipeline() {
+        let h = crate::test_helpers::TestHarness::new().await;
+        crate::test_helpers::mock_rest_get(
+            &h.server,
+            "/repos/o/r/issues",
+            serde_json::json!([
+                { "state": "open", "title": "Bug" },
+                { "state": "closed", "title": "Done" },
+                { "state": "open", "title": "Feature" }
+            ]),
+        )
+        .await;
+
+        let mut args = request_args("repos/o/r/issues");
+        args.jq = Some(r#".[] | select(.state=="open") | .title"#.into());
+        args.run(&h.factory).await.unwrap();
+
+        assert_eq!(h.stdout(), "Bug\nFeature\n");
+    }
+
+    #[test]
+    fn test_should_format_status_line_and_sorted_headers() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("x-ratelimit-remaining", "4999".parse().unwrap());
+        headers.insert("content-type", "application/json".parse().unwrap());
+
+        let out = format_headers(reqwest::Version::HTTP_2, reqwest::StatusCode::OK, &headers);
+        assert_eq!(
+            out,
+            "HTTP/2.0 200 OK\ncontent-type: application/json\nx-ratelimit-remaining: 4999\n"
+        );
+    }
+
+    async fn mount_user(server: &wiremock::MockServer) {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-ratelimit-remaining", "4999")
+                    .set_body_json(serde_json::json!({ "login": "octocat" })),
+            )
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_should_print_headers_with_include() {
+        let h = crate::test_helpers::TestHarness::new().await;
+        mount_user(&h.server).await;
+
+        let mut args = request_args("user");
+        args.include = true;
+        args.run(&h.factory).await.unwrap();
+
+        let out = h.stdout();
+        assert!(out.starts_with("HTTP/1.1 200 OK\n"), "{out}");
+        assert!(out.contains("\nx-ratelimit-remaining: 4999\n"), "{out}");
+        assert!(out.ends_with("\n\n{\"login\":\"octocat\"}\n"), "{out}");
+    }
+
+    #[tokio::test]
+    async fn test_should_not_print_headers_when_silent() {
+        let h = crate::test_helpers::TestHarness::new().await;
+        mount_user(&h.server).await;
+
+        let mut args = request_args("user");
+        args.include = true;
+        args.silent = true;
+        args.run(&h.factory).await.unwrap();
+
+        assert_eq!(h.stdout(), "");
+    }
+
+    #[tokio::test]
+    async fn test_should_print_headers_for_each_page() {
+        let h = crate::test_helpers::TestHarness::new().await;
+        mount_pages(&h.server).await;
+
+        let mut args = paginate_args(3, false);
+        args.include = true;
+        args.run(&h.factory).await.unwrap();
+
+        let out = h.stdout();
+        assert_eq!(out.matches("HTTP/1.1 200 OK").count(), 2, "{out}");
+        assert_eq!(out.matches("link: <").count(), 2, "{out}");
+    }
 }
@@ -73,7 +73,10 @@ impl DownloadArgs {
             ));
         }
 
-        let hostname = self.hostname.as_deref().unwrap_or("github.com");
+        let hostname = &self
+            .hostname
+            .clone()
+            .unwrap_or_else(|| factory.default_host());
         let client = factory.api_client(hostname)?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -54,7 +54,10 @@ impl TrustedRootArgs {
             _ => {}
         }
 
-        let hostname = self.hostname.as_deref().unwrap_or("github.com");
+        let hostname = &self
+            .hostname
+            .clone()
+            .unwrap_or_else(|| factory.default_host());
         let ios = &factory.io;
 
         if let (Some(tuf_url), Some(tuf_root_path)) = (&self.tuf_url, &self.tuf_root) {
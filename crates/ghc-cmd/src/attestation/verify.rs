@@ -2,7 +2,7 @@
 
 use anyhow::{Context, Result};
 use clap::Args;
-use ghc_core::{ios_eprintln, ios_println};
+use ghc_core::ios_eprintln;
 use serde_json::Value;
 
 /// Verify an artifact attestation.
@@ -43,6 +43,10 @@ pub struct VerifyArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl VerifyArgs {
@@ -52,7 +56,7 @@ impl VerifyArgs {
     ///
     /// Returns an error if the attestation cannot be verified.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
 
@@ -80,16 +84,16 @@ impl VerifyArgs {
             ));
         }
 
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let arr = Value::Array(verified.clone());
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &arr,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
         ios_eprintln!(
@@ -10,8 +10,10 @@ use tracing::info;
 use ghc_api::auth_flow;
 use ghc_api::http;
 use ghc_core::instance;
-use ghc_core::ios_eprintln;
+use ghc_core::iostreams::IOStreams;
+use ghc_core::{ios_eprintln, keyring_store};
 
+use crate::auth::setup_git;
 use crate::factory::Factory;
 
 /// Log in to a GitHub account.
@@ -139,20 +141,8 @@ impl LoginArgs {
             .await
             .map_err(|e| anyhow::anyhow!("error retrieving current user: {e}"))?;
 
-        // Store credentials
-        let cfg_lock = factory.config()?;
-        let mut cfg = cfg_lock
-            .lock()
-            .map_err(|e| anyhow::anyhow!("config lock: {e}"))?;
         let git_protocol = self.git_protocol.as_deref().unwrap_or("");
-        let secure_storage = !self.insecure_storage;
-        cfg.authentication_mut().login(
-            hostname,
-            &username,
-            &token,
-            git_protocol,
-            secure_storage,
-        )?;
+        self.store_credentials(factory, hostname, &username, &token, git_protocol)?;
 
         info!(hostname, username, "Logged in with token");
         ios_eprintln!(ios, "Logged in as {username}");
@@ -167,23 +157,7 @@ impl LoginArgs {
         interactive: bool,
     ) -> anyhow::Result<()> {
         let ios = &factory.io;
-        let git_protocol = match &self.git_protocol {
-            Some(p) => p.to_lowercase(),
-            None => {
-                if interactive {
-                    let options = vec!["HTTPS".to_string(), "SSH".to_string()];
-                    let prompter = factory.prompter();
-                    let idx = prompter.select(
-                        "What is your preferred protocol for Git operations on this host?",
-                        Some(0),
-                        &options,
-                    )?;
-                    options[idx].to_lowercase()
-                } else {
-                    "https".to_string()
-                }
-            }
-        };
+        let git_protocol = self.select_git_protocol(factory, interactive)?;
 
         // Choose auth mode
         let use_web = if self.web {
@@ -215,6 +189,7 @@ impl LoginArgs {
                 app_version: factory.app_version.clone(),
                 skip_default_headers: false,
                 log_verbose: false,
+                unix_socket: None,
             })?;
 
             let result = auth_flow::auth_flow(
@@ -231,27 +206,7 @@ impl LoginArgs {
             (result.token, result.username)
         } else {
             // Token auth mode
-            let minimum_scopes = ["repo", "read:org"];
-            ios_eprintln!(
-                ios,
-                "Tip: you can generate a Personal Access Token here https://{hostname}/settings/tokens"
-            );
-            ios_eprintln!(
-                ios,
-                "The minimum required scopes are {}.",
-                minimum_scopes
-                    .iter()
-                    .map(|s| format!("'{s}'"))
-                    .collect::<Vec<_>>()
-                    .join(", ")
-            );
-
-            let prompter = factory.prompter();
-            let token = prompter.password("Paste your authentication token:")?;
-
-            if token.is_empty() {
-                anyhow::bail!("token cannot be empty");
-            }
+            let token = prompt_for_token(factory, hostname)?;
 
             // Validate token
             let api_client = factory.api_client(hostname)?;
@@ -268,33 +223,69 @@ impl LoginArgs {
             (token, username)
         };
 
-        // Store git protocol
-        if !git_protocol.is_empty() {
-            ios_eprintln!(
-                ios,
-                "- ghc config set -h {hostname} git_protocol {git_protocol}"
-            );
-            ios_eprintln!(ios, "Configured git protocol");
-        }
+        self.store_credentials(factory, hostname, &username, &token, "")?;
+
+        save_git_protocol(factory, hostname, &git_protocol)?;
+        offer_setup_git(factory, hostname, &git_protocol, interactive)?;
+
+        ios_eprintln!(ios, "Logged in as {username}");
+        Ok(())
+    }
+
+    /// Store credentials, in the keyring unless it is opted out of or
+    /// unreachable, in which case the token goes to the config file with a
+    /// warning.
+    fn store_credentials(
+        &self,
+        factory: &Factory,
+        hostname: &str,
+        username: &str,
+        token: &str,
+        git_protocol: &str,
+    ) -> anyhow::Result<()> {
+        let storage = token_storage(
+            self.insecure_storage,
+            keyring_store::enabled_by_env(),
+            keyring_store::is_available,
+        );
 
-        // Store credentials
         let cfg_lock = factory.config()?;
         let mut cfg = cfg_lock
             .lock()
             .map_err(|e| anyhow::anyhow!("config lock: {e}"))?;
-        let secure_storage = !self.insecure_storage;
-        cfg.authentication_mut().login(
+        let in_keyring = cfg.authentication_mut().login(
             hostname,
-            &username,
-            &token,
-            &git_protocol,
-            secure_storage,
+            username,
+            token,
+            git_protocol,
+            storage == TokenStorage::Keyring,
         )?;
+        drop(cfg);
 
-        ios_eprintln!(ios, "Logged in as {username}");
+        if let TokenStorage::Fallback(reason) = storage.stored(in_keyring) {
+            warn_plain_text_token(&factory.io, reason);
+        }
         Ok(())
     }
 
+    /// Pick the git protocol from `--git-protocol`, prompting when interactive.
+    fn select_git_protocol(&self, factory: &Factory, interactive: bool) -> anyhow::Result<String> {
+        if let Some(p) = &self.git_protocol {
+            return Ok(p.to_lowercase());
+        }
+        if !interactive {
+            return Ok("https".to_string());
+        }
+
+        let options = vec!["HTTPS".to_string(), "SSH".to_string()];
+        let idx = factory.prompter().select(
+            "What is your preferred protocol for Git operations on this host?",
+            Some(0),
+            &options,
+        )?;
+        Ok(options[idx].to_lowercase())
+    }
+
     #[allow(clippy::unused_self)]
     fn prompt_hostname(&self, factory: &Factory) -> anyhow::Result<String> {
         let options = vec!["GitHub.com".to_string(), "Other".to_string()];
@@ -308,3 +299,279 @@ impl LoginArgs {
         }
     }
 }
+
+/// Persist the chosen git protocol for `hostname` in the config file.
+fn save_git_protocol(factory: &Factory, hostname: &str, protocol: &str) -> anyhow::Result<()> {
+    let ios = &factory.io;
+    let cfg_lock = factory.config()?;
+    let mut cfg = cfg_lock
+        .lock()
+        .map_err(|e| anyhow::anyhow!("config lock: {e}"))?;
+    cfg.set(hostname, "git_protocol", protocol)?;
+    cfg.write()?;
+
+    ios_eprintln!(
+        ios,
+        "- ghc config set -h {hostname} git_protocol {protocol}"
+    );
+    ios_eprintln!(ios, "Configured git protocol");
+    Ok(())
+}
+
+/// Where a newly obtained token is stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenStorage {
+    /// The OS keyring.
+    Keyring,
+    /// The config file, as requested with `--insecure-storage`.
+    ConfigFile,
+    /// The config file, because the keyring cannot be used for the given reason.
+    Fallback(&'static str),
+}
+
+impl TokenStorage {
+    /// Where the token actually went, given whether the keyring accepted it.
+    fn stored(self, in_keyring: bool) -> Self {
+        if self == Self::Keyring && !in_keyring {
+            Self::Fallback(KEYRING_REJECTED)
+        } else {
+            self
+        }
+    }
+}
+
+/// Fallback reason when the keyring probe passed but storing the token failed.
+pub(super) const KEYRING_REJECTED: &str = "the system keyring rejected the token";
+
+/// Warn that the token fell back to the plain-text config file.
+pub(super) fn warn_plain_text_token(ios: &IOStreams, reason: &str) {
+    ios_eprintln!(
+        ios,
+        "{} {reason}; the token was stored in plain text in {}",
+        ios.color_scheme().warning_icon(),
+        ghc_core::config::config_dir().join("hosts.yml").display()
+    );
+}
+
+/// Choose token storage, probing the keyring only when it would be used.
+fn token_storage(
+    insecure: bool,
+    keyring_enabled: bool,
+    keyring_available: impl FnOnce() -> bool,
+) -> TokenStorage {
+    if insecure {
+        TokenStorage::ConfigFile
+    } else if !keyring_enabled {
+        TokenStorage::Fallback("GH_USE_KEYRING disables the system keyring")
+    } else if keyring_available() {
+        TokenStorage::Keyring
+    } else {
+        TokenStorage::Fallback("the system keyring is unavailable")
+    }
+}
+
+/// Prompt for a personal access token without echoing it to the terminal.
+fn prompt_for_token(factory: &Factory, hostname: &str) -> anyhow::Result<String> {
+    let ios = &factory.io;
+    let minimum_scopes = ["repo", "read:org"];
+    ios_eprintln!(
+        ios,
+        "Tip: you can generate a Personal Access Token here https://{hostname}/settings/tokens"
+    );
+    ios_eprintln!(
+        ios,
+        "The minimum required scopes are {}.",
+        minimum_scopes
+            .iter()
+            .map(|s| format!("'{s}'"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    let token = factory
+        .prompter()
+        .password("Paste your authentication token:")?;
+    let token = token.trim();
+    if token.is_empty() {
+        anyhow::bail!("token cannot be empty");
+    }
+    Ok(token.to_string())
+}
+
+/// Offer to configure ghc as the git credential helper for HTTPS hosts.
+fn offer_setup_git(
+    factory: &Factory,
+    hostname: &str,
+    protocol: &str,
+    interactive: bool,
+) -> anyhow::Result<()> {
+    if !interactive || protocol != "https" {
+        return Ok(());
+    }
+
+    let confirmed = factory
+        .prompter()
+        .confirm("Authenticate Git with your GitHub credentials?", true)?;
+    if confirmed {
+        setup_git::configure_credential_helper(&factory.io, hostname)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::TestHarness;
+
+    fn default_args() -> LoginArgs {
+        LoginArgs {
+            hostname: Some("github.com".to_string()),
+            scopes: vec![],
+            with_token: false,
+            web: false,
+            clipboard: false,
+            git_protocol: None,
+            insecure_storage: false,
+            skip_ssh_key: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_should_prompt_for_git_protocol_when_interactive() {
+        let h = TestHarness::new().await;
+        h.prompter.select_answers.lock().unwrap().push(1);
+
+        let protocol = default_args()
+            .select_git_protocol(&h.factory, true)
+            .unwrap();
+        assert_eq!(protocol, "ssh");
+        assert!(h.prompter.select_answers.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_should_skip_git_protocol_prompt_with_flag() {
+        let h = TestHarness::new().await;
+        h.prompter.select_answers.lock().unwrap().push(0);
+
+        let mut args = default_args();
+        args.git_protocol = Some("ssh".to_string());
+        let protocol = args.select_git_protocol(&h.factory, true).unwrap();
+        assert_eq!(protocol, "ssh");
+        assert_eq!(*h.prompter.select_answers.lock().unwrap(), vec![0]);
+    }
+
+    #[tokio::test]
+    async fn test_should_default_to_https_when_not_interactive() {
+        let h = TestHarness::new().await;
+        let protocol = default_args()
+            .select_git_protocol(&h.factory, false)
+            .unwrap();
+        assert_eq!(protocol, "https");
+    }
+
+    #[tokio::test]
+    async fn test_should_save_selected_git_protocol() {
+        let h = TestHarness::new().await;
+        h.prompter.select_answers.lock().unwrap().push(1);
+
+        let protocol = default_args()
+            .select_git_protocol(&h.factory, true)
+            .unwrap();
+        save_git_protocol(&h.factory, "github.com", &protocol).unwrap();
+
+        let cfg_lock = h.factory.config().unwrap();
+        let cfg = cfg_lock.lock().unwrap();
+        assert_eq!(cfg.get("github.com", "git_protocol"), Some("ssh".into()));
+        assert!(
+            h.stderr()
+                .contains("ghc config set -h github.com git_protocol ssh")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_offer_setup_git_only_for_https() {
+        let h = TestHarness::new().await;
+        h.prompter.confirm_answers.lock().unwrap().push(false);
+
+        offer_setup_git(&h.factory, "github.com", "ssh", true).unwrap();
+        assert_eq!(*h.prompter.confirm_answers.lock().unwrap(), vec![false]);
+
+        offer_setup_git(&h.factory, "github.com", "https", true).unwrap();
+        assert!(h.prompter.confirm_answers.lock().unwrap().is_empty());
+        assert!(!h.stderr().contains("Configured git credential helper"));
+    }
+
+    #[tokio::test]
+    async fn test_should_not_offer_setup_git_when_not_interactive() {
+        let h = TestHarness::new().await;
+        h.prompter.confirm_answers.lock().unwrap().push(true);
+
+        offer_setup_git(&h.factory, "github.com", "https", false).unwrap();
+        assert_eq!(*h.prompter.confirm_answers.lock().unwrap(), vec![true]);
+    }
+
+    #[tokio::test]
+    async fn test_should_read_token_from_password_prompt() {
+        let h = TestHarness::new().await;
+        h.prompter
+            .password_answers
+            .lock()
+            .unwrap()
+            .push("  ghp_secret123\n".to_string());
+        h.prompter
+            .input_answers
+            .lock()
+            .unwrap()
+            .push("ghp_visible".to_string());
+
+        let token = prompt_for_token(&h.factory, "github.com").unwrap();
+        assert_eq!(token, "ghp_secret123");
+        assert!(h.prompter.password_answers.lock().unwrap().is_empty());
+        assert_eq!(h.prompter.input_answers.lock().unwrap().len(), 1);
+        assert!(!h.stderr().contains("ghp_secret123"));
+        assert!(h.stderr().contains("https://github.com/settings/tokens"));
+    }
+
+    #[tokio::test]
+    async fn test_should_reject_empty_token() {
+        let h = TestHarness::new().await;
+        h.prompter
+            .password_answers
+            .lock()
+            .unwrap()
+            .push("   ".to_string());
+
+        let err = prompt_for_token(&h.factory, "github.com").unwrap_err();
+        assert!(err.to_string().contains("token cannot be empty"));
+    }
+
+    #[test]
+    fn test_should_fall_back_to_config_file_when_keyring_unusable() {
+        assert_eq!(token_storage(false, true, || true), TokenStorage::Keyring);
+        assert_eq!(
+            token_storage(false, true, || false),
+            TokenStorage::Fallback("the system keyring is unavailable")
+        );
+        assert_eq!(
+            token_storage(false, false, || unreachable!("probe must be skipped")),
+            TokenStorage::Fallback("GH_USE_KEYRING disables the system keyring")
+        );
+        assert_eq!(
+            token_storage(true, true, || unreachable!("probe must be skipped")),
+            TokenStorage::ConfigFile
+        );
+    }
+
+    #[test]
+    fn test_should_fall_back_to_config_file_when_keyring_store_fails() {
+        assert_eq!(TokenStorage::Keyring.stored(true), TokenStorage::Keyring);
+        assert_eq!(
+            TokenStorage::Keyring.stored(false),
+            TokenStorage::Fallback(KEYRING_REJECTED)
+        );
+        assert_eq!(
+            TokenStorage::ConfigFile.stored(false),
+            TokenStorage::ConfigFile
+        );
+    }
+}
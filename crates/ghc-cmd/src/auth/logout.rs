@@ -5,6 +5,7 @@
 use clap::Args;
 
 use ghc_api::http;
+use ghc_core::config::AuthConfig;
 use ghc_core::ios_eprintln;
 
 use crate::factory::Factory;
@@ -48,7 +49,8 @@ impl LogoutArgs {
             .lock()
             .map_err(|e| anyhow::anyhow!("config lock: {e}"))?;
 
-        let known_hosts = cfg.hosts();
+        let mut known_hosts = cfg.hosts();
+        known_hosts.sort();
         if known_hosts.is_empty() {
             anyhow::bail!("not logged in to any hosts");
         }
@@ -70,26 +72,7 @@ impl LogoutArgs {
             }
         }
 
-        let mut candidates = Vec::new();
-        for host in &known_hosts {
-            if let Some(ref h) = self.hostname
-                && host != h
-            {
-                continue;
-            }
-            let known_users = cfg.authentication().users_for_host(host);
-            for user in known_users {
-                if let Some(ref u) = self.user
-                    && &user != u
-                {
-                    continue;
-                }
-                candidates.push(HostUser {
-                    host: host.clone(),
-                    user,
-                });
-            }
-        }
+        let candidates = self.candidates(cfg.authentication(), &known_hosts);
 
         let (hostname, username) = if candidates.is_empty() {
             anyhow::bail!("no accounts matched that criteria");
@@ -158,13 +141,38 @@ impl LogoutArgs {
 
         Ok(())
     }
+    /// Collect the accounts matching `--hostname`/`--user`, sorted by host and user.
+    fn candidates(&self, auth: &dyn AuthConfig, known_hosts: &[String]) -> Vec<HostUser> {
+        let mut candidates = Vec::new();
+        for host in known_hosts {
+            if let Some(ref h) = self.hostname
+                && host != h
+            {
+                continue;
+            }
+            let mut known_users = auth.users_for_host(host);
+            known_users.sort();
+            for user in known_users {
+                if let Some(ref u) = self.user
+                    && &user != u
+                {
+                    continue;
+                }
+                candidates.push(HostUser {
+                    host: host.clone(),
+                    user,
+                });
+            }
+        }
+        candidates
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use ghc_core::config::{AuthConfig, MemoryConfig};
+    use ghc_core::config::MemoryConfig;
 
     use crate::test_helpers::TestHarness;
 
@@ -244,6 +252,79 @@ mod tests {
         let stderr = h.stderr();
         assert!(stderr.contains("Logged out of github.com account user2"));
         assert!(stderr.contains("Switched active account for github.com to user1"));
+
+        let cfg_lock = h.factory.config().unwrap();
+        let cfg = cfg_lock.lock().unwrap();
+        assert_eq!(
+            cfg.authentication().active_user("github.com"),
+            Some("user1".to_string())
+        );
+        assert_eq!(
+            cfg.authentication().users_for_host("github.com"),
+            vec!["user1".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_prompt_for_account_when_multiple_exist() {
+        let mut config = MemoryConfig::new();
+        config
+            .login("github.com", "user1", "token1", "https", false)
+            .unwrap();
+        config
+            .login("github.com", "user2", "token2", "https", false)
+            .unwrap();
+        config
+            .login("github.com", "user3", "token3", "https", false)
+            .unwrap();
+        let mut h = TestHarness::with_config(config).await;
+        h.factory.io.set_stdin_tty(true);
+        h.factory.io.set_stdout_tty(true);
+        h.factory.io.set_never_prompt(false);
+        // Candidates are sorted: user1, user2, user3
+        h.prompter.select_answers.lock().unwrap().push(2);
+
+        let args = LogoutArgs {
+            hostname: None,
+            user: None,
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert!(
+            h.stderr()
+                .contains("Logged out of github.com account user3")
+        );
+        let cfg_lock = h.factory.config().unwrap();
+        let cfg = cfg_lock.lock().unwrap();
+        let mut remaining = cfg.authentication().users_for_host("github.com");
+        remaining.sort();
+        assert_eq!(remaining, vec!["user1".to_string(), "user2".to_string()]);
+        assert_eq!(
+            cfg.authentication().active_user("github.com"),
+            Some("user1".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_require_flags_for_multiple_accounts_without_prompt() {
+        let mut config = MemoryConfig::new();
+        config
+            .login("github.com", "user1", "token1", "https", false)
+            .unwrap();
+        config
+            .login("github.com", "user2", "token2", "https", false)
+            .unwrap();
+        let h = TestHarness::with_config(config).await;
+
+        let args = LogoutArgs {
+            hostname: None,
+            user: None,
+        };
+        let err = args.run(&h.factory).await.unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("unable to determine which account to log out of")
+        );
     }
 
     #[tokio::test]
@@ -11,7 +11,9 @@ use clap::Args;
 use ghc_api::auth_flow;
 use ghc_api::http;
 use ghc_core::ios_eprintln;
+use ghc_core::iostreams::IOStreams;
 
+use crate::auth::login::{KEYRING_REJECTED, warn_plain_text_token};
 use crate::factory::Factory;
 
 /// Refresh stored authentication credentials.
@@ -71,19 +73,24 @@ impl RefreshArgs {
 
         check_token_writeable(factory, &hostname)?;
 
-        let additional_scopes = self
-            .build_scopes(factory, &hostname, old_token.as_deref())
-            .await?;
+        let old_scopes = match old_token {
+            Some(ref token) => token_scopes(factory, &hostname, token).await,
+            None => BTreeSet::new(),
+        };
+        let requested = self.build_scopes(&old_scopes);
 
-        let result = self
-            .run_oauth_flow(factory, &hostname, &additional_scopes)
-            .await?;
+        let result = self.run_oauth_flow(factory, &hostname, &requested).await?;
 
         self.verify_and_store(factory, &hostname, &result)?;
 
         let cs = ios.color_scheme();
         ios_eprintln!(ios, "{} Authentication complete.", cs.success_icon());
 
+        if old_token.is_some() {
+            let new_scopes = token_scopes(factory, &hostname, &result.token).await;
+            print_scope_diff(ios, &old_scopes, &new_scopes);
+        }
+
         Ok(())
     }
 
@@ -123,44 +130,28 @@ impl RefreshArgs {
             candidates[selected].clone()
         };
 
-        let old_token = if self.reset_scopes {
-            None
-        } else {
-            cfg.authentication().active_token(&hostname).map(|(t, _)| t)
-        };
+        let old_token = cfg.authentication().active_token(&hostname).map(|(t, _)| t);
 
         Ok((hostname, old_token))
     }
 
-    /// Build the set of scopes for the OAuth flow.
-    async fn build_scopes(
-        &self,
-        factory: &Factory,
-        hostname: &str,
-        old_token: Option<&str>,
-    ) -> anyhow::Result<BTreeSet<String>> {
-        let mut additional_scopes: BTreeSet<String> = BTreeSet::new();
-
-        if let Some(old_token) = old_token {
-            let api_client = factory.api_client(hostname)?;
-            if let Ok(old_scopes) = api_client.get_scopes(old_token).await {
-                for s in old_scopes.split(',') {
-                    let s = s.trim();
-                    if !s.is_empty() {
-                        additional_scopes.insert(s.to_string());
-                    }
-                }
-            }
-        }
-
-        for s in &self.scopes {
-            additional_scopes.insert(s.clone());
-        }
+    /// Build the set of scopes to request in the OAuth flow.
+    ///
+    /// Starts from the token's current scopes (unless `--reset-scopes`), adds
+    /// `--scopes`, drops `--remove-scopes`, and always keeps the minimum set.
+    fn build_scopes(&self, old_scopes: &BTreeSet<String>) -> BTreeSet<String> {
+        let mut scopes = if self.reset_scopes {
+            BTreeSet::new()
+        } else {
+            old_scopes.clone()
+        };
+        scopes.extend(self.scopes.iter().map(|s| s.trim().to_string()));
         for s in &self.remove_scopes {
-            additional_scopes.remove(s);
+            scopes.remove(s.trim());
         }
-
-        Ok(additional_scopes)
+        scopes.extend(auth_flow::DEFAULT_SCOPES.iter().map(ToString::to_string));
+        scopes.retain(|s| !s.is_empty());
+        scopes
     }
 
     /// Run the OAuth flow with the given scopes.
@@ -176,6 +167,7 @@ impl RefreshArgs {
             app_version: factory.app_version.clone(),
             skip_default_headers: false,
             log_verbose: false,
+            unix_socket: None,
         })?;
 
         auth_flow::auth_flow(
@@ -213,17 +205,59 @@ impl RefreshArgs {
         }
 
         let secure_storage = !self.insecure_storage;
-        cfg.authentication_mut().login(
+        let in_keyring = cfg.authentication_mut().login(
             hostname,
             &result.username,
             &result.token,
             "",
             secure_storage,
         )?;
+        drop(cfg);
+
+        if secure_storage && !in_keyring {
+            warn_plain_text_token(&factory.io, KEYRING_REJECTED);
+        }
         Ok(())
     }
 }
 
+/// The OAuth scopes granted to `token`, or none if they cannot be read.
+async fn token_scopes(factory: &Factory, hostname: &str, token: &str) -> BTreeSet<String> {
+    let Ok(api_client) = factory.api_client(hostname) else {
+        return BTreeSet::new();
+    };
+    api_client
+        .get_scopes(token)
+        .await
+        .map(|header| {
+            header
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(String::from)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Print the scopes gained and lost by refreshing the token.
+fn print_scope_diff(ios: &IOStreams, before: &BTreeSet<String>, after: &BTreeSet<String>) {
+    let cs = ios.color_scheme();
+    let added: Vec<&String> = after.difference(before).collect();
+    let removed: Vec<&String> = before.difference(after).collect();
+    if added.is_empty() && removed.is_empty() {
+        ios_eprintln!(ios, "Token scopes unchanged");
+        return;
+    }
+    ios_eprintln!(ios, "Token scopes changed:");
+    for scope in added {
+        ios_eprintln!(ios, "  {} {scope}", cs.success("+"));
+    }
+    for scope in removed {
+        ios_eprintln!(ios, "  {} {scope}", cs.error("-"));
+    }
+}
+
 /// Check if the token for this host is writeable (not from an env var).
 fn check_token_writeable(factory: &Factory, hostname: &str) -> anyhow::Result<()> {
     let cfg_lock = factory.config()?;
@@ -257,6 +291,72 @@ mod tests {
 
     use crate::test_helpers::TestHarness;
 
+    fn refresh_args(scopes: &[&str], remove_scopes: &[&str]) -> RefreshArgs {
+        RefreshArgs {
+            hostname: Some("github.com".to_string()),
+            scopes: scopes.iter().map(ToString::to_string).collect(),
+            remove_scopes: remove_scopes.iter().map(ToString::to_string).collect(),
+            reset_scopes: false,
+            clipboard: false,
+            insecure_storage: false,
+        }
+    }
+
+    fn scope_set(scopes: &[&str]) -> BTreeSet<String> {
+        scopes.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_should_add_requested_scopes_to_existing_ones() {
+        let old = scope_set(&["gist", "read:org", "repo", "admin:public_key"]);
+        let scopes = refresh_args(&["delete_repo", "workflow"], &[]).build_scopes(&old);
+        assert_eq!(
+            scopes,
+            scope_set(&[
+                "admin:public_key",
+                "delete_repo",
+                "gist",
+                "read:org",
+                "repo",
+                "workflow"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_should_remove_scopes_but_keep_minimum_set() {
+        let old = scope_set(&["gist", "read:org", "repo", "workflow"]);
+        let scopes = refresh_args(&[], &["workflow", "repo"]).build_scopes(&old);
+        assert_eq!(scopes, scope_set(&["gist", "read:org", "repo"]));
+    }
+
+    #[test]
+    fn test_should_reset_to_minimum_scopes() {
+        let old = scope_set(&["gist", "read:org", "repo", "workflow"]);
+        let args = RefreshArgs {
+            reset_scopes: true,
+            ..refresh_args(&["codespace"], &[])
+        };
+        assert_eq!(
+            args.build_scopes(&old),
+            scope_set(&["codespace", "gist", "read:org", "repo"])
+        );
+    }
+
+    #[test]
+    fn test_should_print_scope_diff() {
+        let (factory, output) = crate::factory::Factory::test();
+        print_scope_diff(
+            &factory.io,
+            &scope_set(&["repo", "workflow"]),
+            &scope_set(&["delete_repo", "repo"]),
+        );
+        assert_eq!(
+            output.stderr(),
+            "Token scopes changed:\n  + delete_repo\n  - workflow\n"
+        );
+    }
+
     #[tokio::test]
     async fn test_should_error_when_not_logged_in() {
         let config = MemoryConfig::new();
@@ -353,6 +453,9 @@ mod tests {
         fn set(&mut self, _hostname: &str, _key: &str, _value: &str) -> anyhow::Result<()> {
             Ok(())
         }
+        fn unset(&mut self, _hostname: &str, _key: &str) -> anyhow::Result<()> {
+            Ok(())
+        }
         fn aliases(&self) -> &std::collections::HashMap<String, String> {
             static EMPTY: std::sync::LazyLock<std::collections::HashMap<String, String>> =
                 std::sync::LazyLock::new(std::collections::HashMap::new);
@@ -393,8 +496,8 @@ mod tests {
             _token: &str,
             _git_protocol: &str,
             _secure_storage: bool,
-        ) -> anyhow::Result<()> {
-            Ok(())
+        ) -> anyhow::Result<bool> {
+            Ok(false)
         }
         fn logout(&mut self, _hostname: &str, _username: &str) -> anyhow::Result<()> {
             Ok(())
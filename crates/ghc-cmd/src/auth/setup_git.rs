@@ -74,7 +74,7 @@ impl SetupGitArgs {
 ///
 /// Uses synchronous `std::process::Command` since git config does not require
 /// async I/O.
-fn configure_credential_helper(ios: &IOStreams, hostname: &str) -> anyhow::Result<()> {
+pub(crate) fn configure_credential_helper(ios: &IOStreams, hostname: &str) -> anyhow::Result<()> {
     let helper_pattern = format!("https://{hostname}");
     let helper_cmd = "!ghc auth git-credential";
 
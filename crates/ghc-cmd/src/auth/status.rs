@@ -8,6 +8,7 @@ use clap::Args;
 use serde::Serialize;
 
 use ghc_api::client;
+use ghc_core::iostreams::IOStreams;
 use ghc_core::{ios_eprintln, ios_println};
 
 use crate::factory::Factory;
@@ -35,9 +36,21 @@ pub struct StatusArgs {
     #[arg(short, long)]
     active: bool,
 
-    /// Output in JSON format. Supported fields: hosts.
+    /// Output JSON with specified fields. Supported fields: hosts.
+    #[arg(long, value_delimiter = ',')]
+    json: Vec<String>,
+
+    /// Filter JSON output using a jq expression.
+    #[arg(short = 'q', long)]
+    jq: Option<String>,
+
+    /// Format JSON output using a Go template.
+    #[arg(long)]
+    template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
     #[arg(long)]
-    json: bool,
+    yaml: bool,
 }
 
 /// JSON output structure for auth status.
@@ -66,162 +79,76 @@ struct AuthEntryJson {
 }
 
 impl StatusArgs {
+    /// Whether any of `--json`, `--jq`, `--template` or `--yaml` was given.
+    fn wants_json(&self) -> bool {
+        !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml
+    }
+
+    /// Print `output` as requested by the JSON flags.
+    fn print_json(&self, output: &AuthStatusJson, ios: &IOStreams) -> anyhow::Result<()> {
+        ghc_core::json::output(
+            &serde_json::to_value(output)?,
+            &self.json,
+            self.jq.as_deref(),
+            self.template.as_deref(),
+            self.yaml,
+            ios,
+        )
+    }
+
     /// Run the status command.
     ///
     /// # Errors
     ///
     /// Returns an error if the status check fails.
-    #[allow(clippy::too_many_lines, clippy::await_holding_lock)]
+    #[allow(clippy::too_many_lines)]
     pub async fn run(&self, factory: &Factory) -> anyhow::Result<()> {
         let ios = &factory.io;
         let cfg_lock = factory.config()?;
-        let cfg = cfg_lock
-            .lock()
-            .map_err(|e| anyhow::anyhow!("config lock: {e}"))?;
-
-        let hostnames = cfg.hosts();
-        if hostnames.is_empty() {
-            ios_eprintln!(
-                ios,
-                "You are not logged into any GitHub hosts. To log in, run: ghc auth login"
-            );
-            if self.json {
-                let empty = AuthStatusJson {
-                    hosts: BTreeMap::new(),
-                };
-                ios_println!(ios, "{}", serde_json::to_string_pretty(&empty)?);
-                return Ok(());
-            }
-            anyhow::bail!("");
-        }
-
-        if let Some(ref h) = self.hostname
-            && !hostnames.contains(h)
-        {
-            ios_eprintln!(ios, "You are not logged into any accounts on {h}");
-            if self.json {
-                let empty = AuthStatusJson {
-                    hosts: BTreeMap::new(),
-                };
-                ios_println!(ios, "{}", serde_json::to_string_pretty(&empty)?);
-                return Ok(());
+        let (mut statuses, mut has_error) = {
+            let cfg = cfg_lock
+                .lock()
+                .map_err(|e| anyhow::anyhow!("config lock: {e}"))?;
+
+            let hostnames = cfg.hosts();
+            if hostnames.is_empty() {
+                ios_eprintln!(
+                    ios,
+                    "You are not logged into any GitHub hosts. To log in, run: ghc auth login"
+                );
+                if self.wants_json() {
+                    let empty = AuthStatusJson {
+                        hosts: BTreeMap::new(),
+                    };
+                    self.print_json(&empty, ios)?;
+                    return Ok(());
+                }
+                anyhow::bail!("");
             }
-            anyhow::bail!("");
-        }
-
-        // Build status entries for all hosts/users
-        let mut statuses: BTreeMap<String, Vec<AuthEntryJson>> = BTreeMap::new();
-        let mut has_error = false;
 
-        for hostname in &hostnames {
             if let Some(ref h) = self.hostname
-                && hostname != h
+                && !hostnames.contains(h)
             {
-                continue;
-            }
-
-            let auth = cfg.authentication();
-            let git_protocol = cfg.git_protocol(hostname);
-
-            // Active user entry
-            if let Some((token, source)) = auth.active_token(hostname) {
-                let username = auth.active_user(hostname).unwrap_or_default();
-                let display_username = if username.is_empty() {
-                    "unknown".to_string()
-                } else {
-                    username.clone()
-                };
-
-                let mut entry = AuthEntryJson {
-                    state: "success".to_string(),
-                    error: String::new(),
-                    active: true,
-                    host: hostname.clone(),
-                    login: display_username,
-                    token_source: source.clone(),
-                    token: token.clone(),
-                    scopes: String::new(),
-                    git_protocol: git_protocol.clone(),
-                };
-
-                if client::expect_scopes(&token) {
-                    drop(cfg);
-                    let api_client = factory.api_client(hostname)?;
-                    match api_client.get_scopes(&token).await {
-                        Ok(scopes) => {
-                            entry.scopes.clone_from(&scopes);
-                            if let Err(_e) = client::check_minimum_scopes(&scopes) {
-                                has_error = true;
-                            }
-                        }
-                        Err(e) => {
-                            entry.state = "error".to_string();
-                            entry.error = e.to_string();
-                            has_error = true;
-                        }
-                    }
-                    statuses.entry(hostname.clone()).or_default().push(entry);
-                    // For now, only handle single host when scopes need API call
-                    break;
-                }
-
-                statuses.entry(hostname.clone()).or_default().push(entry);
-
-                // Non-active users (if not --active only)
-                if !self.active {
-                    let users = auth.users_for_host(hostname);
-                    for user in &users {
-                        if Some(user.as_str()) == auth.active_user(hostname).as_deref() {
-                            continue;
-                        }
-                        if let Some((tok, tok_src)) = auth.token_for_user(hostname, user) {
-                            let mut inactive_entry = AuthEntryJson {
-                                state: "success".to_string(),
-                                error: String::new(),
-                                active: false,
-                                host: hostname.clone(),
-                                login: user.clone(),
-                                token_source: tok_src,
-                                token: tok,
-                                scopes: String::new(),
-                                git_protocol: git_protocol.clone(),
-                            };
-                            // We skip scope checking for inactive users in non-JSON mode
-                            // to avoid multiple API calls
-                            if self.json {
-                                inactive_entry.state = "success".to_string();
-                            }
-                            statuses
-                                .entry(hostname.clone())
-                                .or_default()
-                                .push(inactive_entry);
-                        }
-                    }
+                ios_eprintln!(ios, "You are not logged into any accounts on {h}");
+                if self.wants_json() {
+                    let empty = AuthStatusJson {
+                        hosts: BTreeMap::new(),
+                    };
+                    self.print_json(&empty, ios)?;
+                    return Ok(());
                 }
-            } else {
-                statuses
-                    .entry(hostname.clone())
-                    .or_default()
-                    .push(AuthEntryJson {
-                        state: "error".to_string(),
-                        error: format!("no token found for {hostname}"),
-                        active: true,
-                        host: hostname.clone(),
-                        login: String::new(),
-                        token_source: String::new(),
-                        token: String::new(),
-                        scopes: String::new(),
-                        git_protocol: git_protocol.clone(),
-                    });
-                has_error = true;
+                anyhow::bail!("");
             }
-        }
+
+            self.collect_entries(&**cfg)
+        };
+        has_error |= fetch_scopes(factory, &mut statuses).await?;
 
         // Mask tokens unless --show-token
         if !self.show_token {
             for entries in statuses.values_mut() {
                 for entry in entries.iter_mut() {
-                    if self.json {
+                    if self.wants_json() {
                         entry.token.clear();
                     } else {
                         entry.token = client::mask_token(&entry.token);
@@ -231,9 +158,9 @@ impl StatusArgs {
         }
 
         // JSON output
-        if self.json {
+        if self.wants_json() {
             let output = AuthStatusJson { hosts: statuses };
-            ios_println!(ios, "{}", serde_json::to_string_pretty(&output)?);
+            self.print_json(&output, ios)?;
             return Ok(());
         }
 
@@ -341,6 +268,130 @@ impl StatusArgs {
         }
         Ok(())
     }
+    /// Build status entries for every host and account in the config.
+    ///
+    /// Returns the entries by host and whether any host has no token.
+    fn collect_entries(
+        &self,
+        cfg: &dyn ghc_core::config::Config,
+    ) -> (BTreeMap<String, Vec<AuthEntryJson>>, bool) {
+        let hostnames = cfg.hosts();
+        let mut statuses: BTreeMap<String, Vec<AuthEntryJson>> = BTreeMap::new();
+        let mut has_error = false;
+
+        for hostname in &hostnames {
+            if let Some(ref h) = self.hostname
+                && hostname != h
+            {
+                continue;
+            }
+
+            let auth = cfg.authentication();
+            let git_protocol = cfg.git_protocol(hostname);
+
+            // Active user entry
+            if let Some((token, source)) = auth.active_token(hostname) {
+                let username = auth.active_user(hostname).unwrap_or_default();
+                let display_username = if username.is_empty() {
+                    "unknown".to_string()
+                } else {
+                    username.clone()
+                };
+
+                let entry = AuthEntryJson {
+                    state: "success".to_string(),
+                    error: String::new(),
+                    active: true,
+                    host: hostname.clone(),
+                    login: display_username,
+                    token_source: source.clone(),
+                    token: token.clone(),
+                    scopes: String::new(),
+                    git_protocol: git_protocol.clone(),
+                };
+
+                statuses.entry(hostname.clone()).or_default().push(entry);
+
+                // Non-active users (if not --active only)
+                if !self.active {
+                    let users = auth.users_for_host(hostname);
+                    for user in &users {
+                        if Some(user.as_str()) == auth.active_user(hostname).as_deref() {
+                            continue;
+                        }
+                        if let Some((tok, tok_src)) = auth.token_for_user(hostname, user) {
+                            let inactive_entry = AuthEntryJson {
+                                state: "success".to_string(),
+                                error: String::new(),
+                                active: false,
+                                host: hostname.clone(),
+                                login: user.clone(),
+                                token_source: tok_src,
+                                token: tok,
+                                scopes: String::new(),
+                                git_protocol: git_protocol.clone(),
+                            };
+                            statuses
+                                .entry(hostname.clone())
+                                .or_default()
+                                .push(inactive_entry);
+                        }
+                    }
+                }
+            } else {
+                statuses
+                    .entry(hostname.clone())
+                    .or_default()
+                    .push(AuthEntryJson {
+                        state: "error".to_string(),
+                        error: format!("no token found for {hostname}"),
+                        active: true,
+                        host: hostname.clone(),
+                        login: String::new(),
+                        token_source: String::new(),
+                        token: String::new(),
+                        scopes: String::new(),
+                        git_protocol: git_protocol.clone(),
+                    });
+                has_error = true;
+            }
+        }
+
+        (statuses, has_error)
+    }
+}
+
+/// Fetch OAuth scopes for each classic token from the `x-oauth-scopes`
+/// response header.
+///
+/// Returns whether any token was rejected or lacks the minimum scopes.
+async fn fetch_scopes(
+    factory: &Factory,
+    statuses: &mut BTreeMap<String, Vec<AuthEntryJson>>,
+) -> anyhow::Result<bool> {
+    let mut has_error = false;
+    for (hostname, entries) in statuses.iter_mut() {
+        for entry in entries
+            .iter_mut()
+            .filter(|e| e.state == "success" && client::expect_scopes(&e.token))
+        {
+            let api_client = factory.api_client(hostname)?;
+            match api_client.get_scopes(&entry.token).await {
+                Ok(scopes) => {
+                    if client::check_minimum_scopes(&scopes).is_err() {
+                        has_error = true;
+                    }
+                    entry.scopes = scopes;
+                }
+                Err(e) => {
+                    entry.state = "error".to_string();
+                    entry.error = e.to_string();
+                    has_error = true;
+                }
+            }
+        }
+    }
+    Ok(has_error)
 }
 
 fn display_scopes(scopes: &str) -> String {
@@ -375,7 +426,10 @@ mod tests {
             hostname: None,
             show_token: false,
             active: false,
-            json: false,
+            json: vec![],
+            jq: None,
+            template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -394,7 +448,10 @@ mod tests {
             hostname: None,
             show_token: true,
             active: false,
-            json: false,
+            json: vec![],
+            jq: None,
+            template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -411,7 +468,10 @@ mod tests {
             hostname: None,
             show_token: false,
             active: false,
-            json: false,
+            json: vec![],
+            jq: None,
+            template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -433,7 +493,10 @@ mod tests {
             hostname: None,
             show_token: false,
             active: false,
-            json: false,
+            json: vec![],
+            jq: None,
+            template: None,
+            yaml: false,
         };
         let result = args.run(&factory).await;
         assert!(result.is_err());
@@ -452,7 +515,10 @@ mod tests {
             hostname: Some("unknown.host".to_string()),
             show_token: false,
             active: false,
-            json: false,
+            json: vec![],
+            jq: None,
+            template: None,
+            yaml: false,
         };
         let result = args.run(&factory).await;
         assert!(result.is_err());
@@ -472,7 +538,10 @@ mod tests {
             hostname: None,
             show_token: false,
             active: false,
-            json: true,
+            json: vec!["hosts".into()],
+            jq: None,
+            template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -497,7 +566,10 @@ mod tests {
             hostname: None,
             show_token: true,
             active: false,
-            json: true,
+            json: vec!["hosts".into()],
+            jq: None,
+            template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -519,7 +591,10 @@ mod tests {
             hostname: None,
             show_token: false,
             active: false,
-            json: true,
+            json: vec!["hosts".into()],
+            jq: None,
+            template: None,
+            yaml: false,
         };
         // JSON mode should not error
         args.run(&factory).await.unwrap();
@@ -537,4 +612,68 @@ mod tests {
             "'repo', 'read:org', 'gist'"
         );
     }
+
+    async fn mount_scopes(h: &TestHarness, scopes: &str) {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(ResponseTemplate::new(200).insert_header("X-OAuth-Scopes", scopes))
+            .mount(&h.server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_should_list_token_scopes_for_every_host() {
+        let config = MemoryConfig::new()
+            .with_host("github.com", "monalisa", "ghp_first")
+            .with_host("ghe.example.com", "hubot", "ghp_second");
+        let h = TestHarness::with_config(config).await;
+        mount_scopes(&h, "repo, read:org, workflow").await;
+
+        let args = StatusArgs {
+            hostname: None,
+            show_token: false,
+            active: false,
+            json: vec![],
+            jq: None,
+            template: None,
+            yaml: false,
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let stdout = h.stdout();
+        assert_eq!(
+            stdout
+                .matches("- Token scopes: 'repo', 'read:org', 'workflow'")
+                .count(),
+            2,
+            "{stdout}"
+        );
+        assert!(stdout.contains("- Token: ghp_*****"), "{stdout}");
+        assert!(stdout.contains("ghe.example.com"), "{stdout}");
+    }
+
+    #[tokio::test]
+    async fn test_should_flag_missing_minimum_scopes() {
+        let config = MemoryConfig::new().with_host("github.com", "monalisa", "ghp_first");
+        let h = TestHarness::with_config(config).await;
+        mount_scopes(&h, "gist").await;
+
+        let args = StatusArgs {
+            hostname: None,
+            show_token: true,
+            active: false,
+            json: vec![],
+            jq: None,
+            template: None,
+            yaml: false,
+        };
+        assert!(args.run(&h.factory).await.is_err());
+
+        let stdout = h.stdout();
+        assert!(stdout.contains("- Token: ghp_first"), "{stdout}");
+        assert!(stdout.contains("Missing required token scopes"), "{stdout}");
+    }
 }
@@ -11,14 +11,14 @@ use crate::factory::Factory;
 
 /// Switch the active GitHub account for a host.
 ///
-/// If the specified host has two accounts, the active account will be
-/// switched automatically. If there are more than two accounts,
-/// disambiguation will be required either through `--user` or an
-/// interactive prompt.
+/// When prompting is possible and more than one account matches, pick the
+/// account from a list of `user@host` entries; `--hostname` narrows the
+/// list to one host. Without a prompt, a host with exactly two accounts
+/// switches to the inactive one, and anything else needs `--user`.
 #[derive(Debug, Args)]
 pub struct SwitchArgs {
     /// The hostname of the GitHub instance to switch account for.
-    #[arg(short = 'h', long)]
+    #[arg(short = 'h', long, visible_alias = "host")]
     hostname: Option<String>,
 
     /// The account to switch to.
@@ -45,7 +45,8 @@ impl SwitchArgs {
             .lock()
             .map_err(|e| anyhow::anyhow!("config lock: {e}"))?;
 
-        let known_hosts = cfg.hosts();
+        let mut known_hosts = cfg.hosts();
+        known_hosts.sort();
         if known_hosts.is_empty() {
             anyhow::bail!("not logged in to any hosts");
         }
@@ -75,7 +76,8 @@ impl SwitchArgs {
                 continue;
             }
             let active_user = cfg.authentication().active_user(host);
-            let known_users = cfg.authentication().users_for_host(host);
+            let mut known_users = cfg.authentication().users_for_host(host);
+            known_users.sort();
             for user in known_users {
                 if let Some(ref u) = self.user
                     && &user != u
@@ -134,6 +136,23 @@ impl SwitchArgs {
     }
 }
 
+/// Prompt labels (`user@host`, marking the active account) and the default
+/// choice, which is the first inactive account.
+fn account_options(candidates: &[HostUser]) -> (Vec<String>, Option<usize>) {
+    let prompts = candidates
+        .iter()
+        .map(|c| {
+            let mut prompt = format!("{}@{}", c.user, c.host);
+            if c.active {
+                prompt += " (active)";
+            }
+            prompt
+        })
+        .collect();
+    let default = candidates.iter().position(|c| !c.active);
+    (prompts, default)
+}
+
 /// Select which candidate to switch to based on the available options.
 fn select_candidate(
     candidates: &[HostUser],
@@ -146,6 +165,18 @@ fn select_candidate(
     if candidates.len() == 1 {
         return Ok((candidates[0].host.clone(), candidates[0].user.clone()));
     }
+    if can_prompt {
+        let (prompts, default) = account_options(candidates);
+        let selected = factory.prompter().select(
+            "What account do you want to switch to?",
+            default,
+            &prompts,
+        )?;
+        return Ok((
+            candidates[selected].host.clone(),
+            candidates[selected].user.clone(),
+        ));
+    }
     if candidates.len() == 2 && candidates[0].host == candidates[1].host {
         let host = candidates[0].host.clone();
         let user = if candidates[0].active {
@@ -155,27 +186,9 @@ fn select_candidate(
         };
         return Ok((host, user));
     }
-    if !can_prompt {
-        anyhow::bail!(
-            "unable to determine which account to switch to, please specify `--hostname` and `--user`"
-        );
-    }
-    let prompts: Vec<String> = candidates
-        .iter()
-        .map(|c| {
-            let mut prompt = format!("{} ({})", c.user, c.host);
-            if c.active {
-                prompt += " - active";
-            }
-            prompt
-        })
-        .collect();
-    let prompter = factory.prompter();
-    let selected = prompter.select("What account do you want to switch to?", None, &prompts)?;
-    Ok((
-        candidates[selected].host.clone(),
-        candidates[selected].user.clone(),
-    ))
+    anyhow::bail!(
+        "unable to determine which account to switch to, please specify `--hostname` and `--user`"
+    )
 }
 
 #[cfg(test)]
@@ -229,6 +242,109 @@ mod tests {
         );
     }
 
+    fn multi_host_config() -> MemoryConfig {
+        let mut config = two_user_config();
+        config
+            .login("ghe.example.com", "monalisa", "token3", "https", false)
+            .unwrap();
+        config
+    }
+
+    async fn prompting_harness(config: MemoryConfig) -> TestHarness {
+        let mut h = TestHarness::with_config(config).await;
+        h.factory.io.set_stdin_tty(true);
+        h.factory.io.set_stdout_tty(true);
+        h.factory.io.set_never_prompt(false);
+        h
+    }
+
+    #[tokio::test]
+    async fn test_should_pick_account_across_hosts() {
+        let h = prompting_harness(multi_host_config()).await;
+        h.prompter.select_answers.lock().unwrap().push(0);
+
+        let args = SwitchArgs {
+            hostname: None,
+            user: None,
+        };
+        args.run(&h.factory).unwrap();
+
+        assert!(
+            h.stderr()
+                .contains("Switched active account for ghe.example.com to monalisa")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_prompt_within_host_when_interactive() {
+        let h = prompting_harness(multi_host_config()).await;
+        // Candidates are user1@github.com and user2@github.com (active)
+        h.prompter.select_answers.lock().unwrap().push(0);
+
+        let args = SwitchArgs {
+            hostname: Some("github.com".to_string()),
+            user: None,
+        };
+        args.run(&h.factory).unwrap();
+
+        assert!(
+            h.stderr()
+                .contains("Switched active account for github.com to user1")
+        );
+    }
+
+    #[test]
+    fn test_should_label_active_account_and_default_to_inactive() {
+        let candidates = [
+            HostUser {
+                host: "github.com".into(),
+                user: "user1".into(),
+                active: true,
+            },
+            HostUser {
+                host: "github.com".into(),
+                user: "user2".into(),
+                active: false,
+            },
+        ];
+        let (prompts, default) = account_options(&candidates);
+        assert_eq!(prompts, ["user1@github.com (active)", "user2@github.com"]);
+        assert_eq!(default, Some(1));
+    }
+
+    #[test]
+    fn test_should_error_without_prompt_for_accounts_on_different_hosts() {
+        let (factory, _output) = Factory::test();
+        let candidates = [
+            HostUser {
+                host: "github.com".into(),
+                user: "user1".into(),
+                active: true,
+            },
+            HostUser {
+                host: "ghe.example.com".into(),
+                user: "monalisa".into(),
+                active: false,
+            },
+        ];
+        assert!(select_candidate(&candidates, false, &factory).is_err());
+    }
+
+    #[test]
+    fn test_should_accept_host_alias() {
+        use clap::Parser;
+
+        #[derive(clap::Parser)]
+        #[command(disable_help_flag = true)]
+        struct Cli {
+            #[command(flatten)]
+            args: SwitchArgs,
+        }
+
+        let cli = Cli::parse_from(["switch", "--host", "ghe.example.com"]);
+        assert_eq!(cli.args.hostname.as_deref(), Some("ghe.example.com"));
+    }
+
     #[tokio::test]
     async fn test_should_error_when_not_logged_in() {
         let config = MemoryConfig::new();
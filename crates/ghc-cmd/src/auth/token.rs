@@ -18,7 +18,7 @@ use crate::factory::Factory;
 #[derive(Debug, Args)]
 pub struct TokenArgs {
     /// The hostname of the GitHub instance.
-    #[arg(short = 'h', long)]
+    #[arg(short = 'h', long, visible_alias = "host")]
     hostname: Option<String>,
 
     /// The account to output the token for.
@@ -164,4 +164,20 @@ mod tests {
                 .contains("no oauth token found for github.com account ghost")
         );
     }
+
+    #[test]
+    fn test_should_parse_host_alias_and_user() {
+        #[derive(clap::Parser)]
+        #[command(disable_help_flag = true)]
+        struct Cli {
+            #[command(flatten)]
+            args: TokenArgs,
+        }
+        use clap::Parser;
+
+        let cli = Cli::try_parse_from(["token", "--host", "github.com", "--user", "bot-account"])
+            .unwrap();
+        assert_eq!(cli.args.hostname.as_deref(), Some("github.com"));
+        assert_eq!(cli.args.user.as_deref(), Some("bot-account"));
+    }
 }
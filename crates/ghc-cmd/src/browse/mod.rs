@@ -5,13 +5,19 @@
 //! and commits by SHA.
 
 use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use clap::Args;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use ghc_core::{ios_eprintln, ios_println};
 
+/// How long a cached default branch is trusted before refetching.
+const DEFAULT_BRANCH_TTL_SECS: i64 = 60 * 60;
+
 /// Open a GitHub repository in the web browser.
 ///
 /// A browser location can be specified using arguments in the following format:
@@ -19,6 +25,7 @@ use ghc_core::{ios_eprintln, ios_println};
 /// - by path for opening folders and files, e.g. "cmd/gh/main.go"
 /// - by path with line range, e.g. "main.go:10" or "main.go:10-20"
 /// - by commit SHA
+/// - by pull request number with `--file`, e.g. "123 --file main.go:10"
 #[derive(Debug, Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct BrowseArgs {
@@ -26,7 +33,7 @@ pub struct BrowseArgs {
     #[arg(value_name = "LOCATION")]
     location: Option<String>,
 
-    /// Repository to browse (OWNER/REPO).
+    /// Repository to browse (OWNER/REPO). Defaults to the current repository.
     #[arg(short = 'R', long)]
     repo: Option<String>,
 
@@ -69,6 +76,21 @@ pub struct BrowseArgs {
     /// Select a specific commit.
     #[arg(short, long)]
     commit: Option<String>,
+
+    /// Open a file in the pull request's diff, optionally at a line or range (e.g. "main.go:10-20").
+    #[arg(long, value_name = "PATH")]
+    file: Option<String>,
+
+    /// Look up the default branch without using the local cache.
+    #[arg(long)]
+    no_cache: bool,
+}
+
+/// A default branch lookup stored under the cache directory.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedBranch {
+    branch: String,
+    fetched_at: i64,
 }
 
 impl BrowseArgs {
@@ -78,13 +100,7 @@ impl BrowseArgs {
     ///
     /// Returns an error if the URL cannot be determined or browser cannot be opened.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo_str = self
-            .repo
-            .as_deref()
-            .ok_or_else(|| anyhow::anyhow!("repository required (use -R OWNER/REPO)"))?;
-
-        let repo =
-            ghc_core::repo::Repo::from_full_name(repo_str).context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
 
         let base_url = format!("https://{}/{}/{}", repo.host(), repo.owner(), repo.name());
 
@@ -116,10 +132,32 @@ impl BrowseArgs {
     }
 
     /// Fetch the repository's default branch name.
+    ///
+    /// Lookups are cached per repository for [`DEFAULT_BRANCH_TTL_SECS`]
+    /// unless `--no-cache` is given; a fresh lookup always refreshes the cache.
     async fn fetch_default_branch(
         &self,
         factory: &crate::factory::Factory,
         repo: &ghc_core::repo::Repo,
+    ) -> Result<String> {
+        let cache_path = default_branch_cache_path(&factory.cache_dir(), repo);
+        let now = chrono::Utc::now().timestamp();
+        if !self.no_cache
+            && let Some(branch) = read_cached_branch(&cache_path, now)
+        {
+            return Ok(branch);
+        }
+
+        let branch = self.query_default_branch(factory, repo).await?;
+        write_cached_branch(&cache_path, &branch, now);
+        Ok(branch)
+    }
+
+    /// Query the repository's default branch name from the API.
+    async fn query_default_branch(
+        &self,
+        factory: &crate::factory::Factory,
+        repo: &ghc_core::repo::Repo,
     ) -> Result<String> {
         let client = factory.api_client(repo.host())?;
         let mut variables = HashMap::new();
@@ -165,6 +203,15 @@ impl BrowseArgs {
             return Ok("actions".to_string());
         }
 
+        if let Some(ref file) = self.file {
+            let number = self
+                .location
+                .as_deref()
+                .and_then(|loc| loc.trim_start_matches('#').parse::<u64>().ok())
+                .ok_or_else(|| anyhow::anyhow!("`--file` requires a pull request number"))?;
+            return pr_file_section(number, file);
+        }
+
         // Determine ref (branch or commit)
         let git_ref = if let Some(ref commit) = self.commit {
             Some(commit.clone())
@@ -193,7 +240,7 @@ impl BrowseArgs {
 
         // Check if location is a commit SHA (7-64 hex characters)
         if is_commit_sha(&location) && git_ref.is_none() {
-            return Ok(format!("commit/{location}"));
+            return Ok(format!("commit/{}", location.to_ascii_lowercase()));
         }
 
         // Parse file path with optional line range (file.go:10 or file.go:10-20)
@@ -218,11 +265,38 @@ impl BrowseArgs {
 }
 
 /// Check if a string looks like a commit SHA (7-64 hex characters).
+///
+/// Abbreviated SHAs must be lowercase; full SHA-1 and SHA-256 hashes are
+/// accepted in either case since they are usually pasted.
 fn is_commit_sha(s: &str) -> bool {
     let len = s.len();
+    let full = len == 40 || len == 64;
     (7..=64).contains(&len)
         && s.chars()
-            .all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase())
+            .all(|c| c.is_ascii_hexdigit() && (full || !c.is_ascii_uppercase()))
+}
+
+/// Build the `pull/<n>/files` section anchored at a file, and optionally at a
+/// line or line range on the new side of the diff.
+///
+/// GitHub anchors each file in a pull request diff as `diff-<sha256 of path>`.
+fn pr_file_section(number: u64, file: &str) -> Result<String> {
+    use sha2::{Digest, Sha256};
+
+    let (file_path, range_start, range_end) = parse_file_location(file)?;
+    let digest = Sha256::digest(file_path.trim_start_matches("./").as_bytes());
+    let mut anchor = String::from("diff-");
+    for byte in digest {
+        let _ = write!(anchor, "{byte:02x}");
+    }
+
+    if range_start > 0 {
+        let _ = write!(anchor, "R{range_start}");
+        if range_end > 0 && range_start != range_end {
+            let _ = write!(anchor, "-R{range_end}");
+        }
+    }
+    Ok(format!("pull/{number}/files#{anchor}"))
 }
 
 /// Parse a file location with optional line number or line range.
@@ -261,9 +335,45 @@ fn parse_file_location(location: &str) -> Result<(String, u32, u32)> {
     }
 }
 
+/// Location of the cached default branch for a repository.
+fn default_branch_cache_path(cache_dir: &Path, repo: &ghc_core::repo::Repo) -> PathBuf {
+    cache_dir
+        .join("default-branch")
+        .join(repo.host())
+        .join(repo.owner())
+        .join(format!("{}.json", repo.name()))
+}
+
+/// Read a cached default branch, treating stale, unreadable, or corrupt
+/// entries as a miss.
+fn read_cached_branch(path: &Path, now: i64) -> Option<String> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let cached: CachedBranch = serde_json::from_str(&content).ok()?;
+    let age = now - cached.fetched_at;
+    ((0..DEFAULT_BRANCH_TTL_SECS).contains(&age) && !cached.branch.is_empty())
+        .then_some(cached.branch)
+}
+
+/// Store a default branch lookup. Failures are ignored; the cache is only an
+/// optimization.
+fn write_cached_branch(path: &Path, branch: &str, now: i64) {
+    let cached = CachedBranch {
+        branch: branch.to_string(),
+        fetched_at: now,
+    };
+    let Ok(content) = serde_json::to_string(&cached) else {
+        return;
+    };
+    if let Some(dir) = path.parent() {
+        let _ = std::fs::create_dir_all(dir);
+    }
+    let _ = std::fs::write(path, content);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     use crate::test_helpers::TestHarness;
 
@@ -281,6 +391,8 @@ mod tests {
             branch: None,
             no_browser: false,
             commit: None,
+            file: None,
+            no_cache: true,
         }
     }
 
@@ -417,6 +529,88 @@ mod tests {
         assert!(urls[0].contains("/blob/HEAD/main.go?plain=1#L10-L20"));
     }
 
+    fn default_branch_response(branch: &str) -> Value {
+        serde_json::json!({
+            "data": { "repository": { "defaultBranchRef": { "name": branch } } }
+        })
+    }
+
+    async fn cached_harness(cache_dir: &Path, expected_lookups: u64) -> TestHarness {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let mut h = TestHarness::new().await;
+        h.factory = h.factory.with_cache_dir(cache_dir);
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(default_branch_response("trunk")),
+            )
+            .expect(expected_lookups)
+            .mount(&h.server)
+            .await;
+        h
+    }
+
+    #[tokio::test]
+    async fn test_should_cache_default_branch_lookup() {
+        let cache = tempfile::tempdir().unwrap();
+        let h = cached_harness(cache.path(), 1).await;
+
+        let mut args = browse_args("owner/repo");
+        args.location = Some("src/x.rs".to_string());
+        args.no_cache = false;
+        args.run(&h.factory).await.unwrap();
+        args.run(&h.factory).await.unwrap();
+
+        let urls = h.opened_urls();
+        assert_eq!(urls.len(), 2);
+        assert!(urls.iter().all(|u| u.contains("/tree/trunk/src/x.rs")));
+    }
+
+    #[tokio::test]
+    async fn test_should_bypass_cache_with_no_cache() {
+        let cache = tempfile::tempdir().unwrap();
+        let h = cached_harness(cache.path(), 2).await;
+
+        let mut args = browse_args("owner/repo");
+        args.location = Some("src/x.rs".to_string());
+        args.run(&h.factory).await.unwrap();
+        args.run(&h.factory).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_should_treat_corrupt_cache_as_miss() {
+        let cache = tempfile::tempdir().unwrap();
+        let repo = ghc_core::repo::Repo::from_full_name("owner/repo").unwrap();
+        let cache_path = default_branch_cache_path(cache.path(), &repo);
+        std::fs::create_dir_all(cache_path.parent().unwrap()).unwrap();
+        std::fs::write(&cache_path, "{not json").unwrap();
+        let h = cached_harness(cache.path(), 1).await;
+
+        let mut args = browse_args("owner/repo");
+        args.location = Some("src/x.rs".to_string());
+        args.no_cache = false;
+        args.run(&h.factory).await.unwrap();
+
+        assert!(h.opened_urls()[0].contains("/tree/trunk/src/x.rs"));
+        assert_eq!(read_cached_branch(&cache_path, 0).as_deref(), None);
+    }
+
+    #[test]
+    fn test_should_expire_cached_branch() {
+        let cache = tempfile::tempdir().unwrap();
+        let path = cache.path().join("repo.json");
+        write_cached_branch(&path, "main", 1_000);
+        assert_eq!(read_cached_branch(&path, 1_000).as_deref(), Some("main"));
+        assert_eq!(
+            read_cached_branch(&path, 1_000 + DEFAULT_BRANCH_TTL_SECS - 1).as_deref(),
+            Some("main")
+        );
+        assert!(read_cached_branch(&path, 1_000 + DEFAULT_BRANCH_TTL_SECS).is_none());
+        assert!(read_cached_branch(&cache.path().join("missing.json"), 1_000).is_none());
+    }
+
     #[tokio::test]
     async fn test_should_open_file_with_line_and_branch() {
         let h = TestHarness::new().await;
@@ -428,6 +622,50 @@ mod tests {
         assert!(urls[0].contains("/blob/develop/main.go?plain=1#L10"));
     }
 
+    #[tokio::test]
+    async fn test_should_open_uppercase_full_commit_sha() {
+        let h = TestHarness::new().await;
+        let mut args = browse_args("owner/repo");
+        args.location = Some("77507CD94CCAFCF568F8560CFECDE965FCFA63A1".to_string());
+        args.run(&h.factory).await.unwrap();
+        let urls = h.opened_urls();
+        assert!(urls[0].ends_with("/commit/77507cd94ccafcf568f8560cfecde965fcfa63a1"));
+    }
+
+    #[rstest]
+    #[case("src/main.rs", "")]
+    #[case("src/main.rs:42", "R42")]
+    #[case("src/main.rs:10-20", "R10-R20")]
+    #[tokio::test]
+    async fn test_should_open_pr_file_diff(#[case] file: &str, #[case] suffix: &str) {
+        let h = TestHarness::new().await;
+        let mut args = browse_args("owner/repo");
+        args.location = Some("123".to_string());
+        args.file = Some(file.to_string());
+        args.run(&h.factory).await.unwrap();
+        let urls = h.opened_urls();
+        assert_eq!(
+            urls[0],
+            format!(
+                "https://github.com/owner/repo/pull/123/files#diff-\
+                 42cb6807ad74b3e201c5a7ca98b911c5fa08380e942be6e4ac5807f8377f87fc{suffix}"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_require_pr_number_with_file() {
+        let h = TestHarness::new().await;
+        let mut args = browse_args("owner/repo");
+        args.location = Some("src/main.rs".to_string());
+        args.file = Some("src/main.rs".to_string());
+        let err = args.run(&h.factory).await.unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("`--file` requires a pull request number")
+        );
+    }
+
     #[tokio::test]
     async fn test_should_error_without_repo() {
         let h = TestHarness::new().await;
@@ -444,6 +682,8 @@ mod tests {
             branch: None,
             no_browser: false,
             commit: None,
+            file: None,
+            no_cache: true,
         };
         let result = args.run(&h.factory).await;
         assert!(result.is_err());
@@ -457,6 +697,8 @@ mod tests {
         assert!(is_commit_sha("77507cd94ccafcf568f8560cfecde965fcfa63"));
         assert!(!is_commit_sha("abc12")); // Too short
         assert!(!is_commit_sha("ABC1234")); // Uppercase
+        assert!(is_commit_sha("77507cd94ccafcf568f8560cfecde965fcfa63a1")); // Full SHA
+        assert!(is_commit_sha("77507CD94CCAFCF568F8560CFECDE965FCFA63A1")); // Pasted full SHA
         assert!(!is_commit_sha("xyz1234")); // Not hex
         assert!(!is_commit_sha("123")); // Too short
     }
@@ -3,19 +3,43 @@
 use anyhow::{Context, Result};
 use clap::Args;
 
+use ghc_api::client::{Client, page_items};
+use serde_json::Value;
+
 use ghc_core::ios_eprintln;
 use ghc_core::repo::Repo;
 
-/// Delete a cache entry.
+use ghc_core::cmdutil::glob_match;
+
+/// Delete cache entries.
+///
+/// Delete a single cache by key or ID, every cache whose key matches
+/// `--pattern`, or all caches in the repository with `--all`.
 #[derive(Debug, Args)]
 pub struct DeleteArgs {
     /// Cache key or ID to delete.
-    #[arg(value_name = "CACHE")]
-    cache: String,
+    #[arg(
+        value_name = "CACHE",
+        required_unless_present_any = ["all", "pattern"],
+        conflicts_with_all = ["all", "pattern"]
+    )]
+    cache: Option<String>,
 
     /// Repository (OWNER/REPO).
     #[arg(short = 'R', long)]
     repo: Option<String>,
+
+    /// Delete all caches in the repository.
+    #[arg(short, long, conflicts_with = "pattern")]
+    all: bool,
+
+    /// Delete caches whose key matches a glob pattern (e.g. `Linux-*`).
+    #[arg(long, value_name = "GLOB")]
+    pattern: Option<String>,
+
+    /// Skip confirmation prompt.
+    #[arg(short, long)]
+    yes: bool,
 }
 
 impl DeleteArgs {
@@ -25,23 +49,22 @@ impl DeleteArgs {
     ///
     /// Returns an error if the cache entry cannot be deleted.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = self
-            .repo
-            .as_deref()
-            .ok_or_else(|| anyhow::anyhow!("repository argument required (use -R OWNER/REPO)"))?;
-        let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
 
+        let Some(ref cache) = self.cache else {
+            return self.delete_matching(factory, &client, &repo).await;
+        };
+
         // Try as numeric ID first, otherwise use key-based deletion
-        let path = if self.cache.chars().all(|c| c.is_ascii_digit()) {
+        let path = if cache.chars().all(|c| c.is_ascii_digit()) {
             format!(
-                "repos/{}/{}/actions/caches/{}",
+                "repos/{}/{}/actions/caches/{cache}",
                 repo.owner(),
                 repo.name(),
-                self.cache,
             )
         } else {
-            let encoded = ghc_core::text::percent_encode(&self.cache);
+            let encoded = ghc_core::text::percent_encode(cache);
             format!(
                 "repos/{}/{}/actions/caches?key={encoded}",
                 repo.owner(),
@@ -60,32 +83,257 @@ impl DeleteArgs {
             ios,
             "{} Deleted cache {}",
             cs.success_icon(),
-            cs.bold(&self.cache),
+            cs.bold(cache),
         );
 
         Ok(())
     }
+
+    /// Delete every cache selected by `--all` or `--pattern`.
+    async fn delete_matching(
+        &self,
+        factory: &crate::factory::Factory,
+        client: &Client,
+        repo: &Repo,
+    ) -> Result<()> {
+        let ios = &factory.io;
+        let caches = fetch_caches(client, repo).await?;
+        let ids: Vec<u64> = caches
+            .iter()
+            .filter(|cache| {
+                self.pattern.as_deref().is_none_or(|pattern| {
+                    cache
+                        .get("key")
+                        .and_then(Value::as_str)
+                        .is_some_and(|key| glob_match(pattern, key))
+                })
+            })
+            .filter_map(|cache| cache.get("id").and_then(Value::as_u64))
+            .collect();
+
+        if ids.is_empty() {
+            ios_eprintln!(ios, "no caches matched");
+            return Ok(());
+        }
+
+        if !self.yes {
+            if !ios.can_prompt() {
+                anyhow::bail!("--yes required to delete caches in non-interactive mode");
+            }
+            let prompt = match self.pattern {
+                Some(ref pattern) => format!(
+                    "Delete {} matching {pattern} from {}?",
+                    plural_caches(ids.len()),
+                    repo.full_name(),
+                ),
+                None => format!(
+                    "Delete all {} from {}?",
+                    plural_caches(ids.len()),
+                    repo.full_name(),
+                ),
+            };
+            let confirmed = factory
+                .prompter()
+                .confirm(&prompt, false)
+                .context("failed to read confirmation")?;
+            if !confirmed {
+                anyhow::bail!("delete cancelled");
+            }
+        }
+
+        for id in &ids {
+            let path = format!("repos/{}/{}/actions/caches/{id}", repo.owner(), repo.name(),);
+            client
+                .rest_text(reqwest::Method::DELETE, &path, None)
+                .await
+                .with_context(|| format!("failed to delete cache {id}"))?;
+        }
+
+        let cs = ios.color_scheme();
+        ios_eprintln!(
+            ios,
+            "{} Deleted {} from {}",
+            cs.success_icon(),
+            plural_caches(ids.len()),
+            cs.bold(&repo.full_name()),
+        );
+        Ok(())
+    }
+}
+
+/// Fetch every cache entry in the repository, following `Link` pages.
+async fn fetch_caches(client: &Client, repo: &Repo) -> Result<Vec<Value>> {
+    let path = format!(
+        "repos/{}/{}/actions/caches?per_page=100",
+        repo.owner(),
+        repo.name(),
+    );
+    client
+        .rest_paginate_limit(&path, None, |page| page_items(page, Some("actions_caches")))
+        .await
+        .context("failed to list caches")
+}
+
+fn plural_caches(count: usize) -> String {
+    if count == 1 {
+        "1 cache".to_string()
+    } else {
+        format!("{count} caches")
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use crate::test_helpers::{TestHarness, mock_rest_delete};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    use crate::test_helpers::{TestHarness, mock_rest_delete, mock_rest_get};
+
+    fn delete_args(cache: Option<&str>) -> DeleteArgs {
+        DeleteArgs {
+            cache: cache.map(str::to_string),
+            repo: Some("owner/repo".to_string()),
+            all: false,
+            pattern: None,
+            yes: true,
+        }
+    }
+
+    async fn mount_caches(h: &TestHarness) {
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/actions/caches",
+            serde_json::json!({
+                "total_count": 3,
+                "actions_caches": [
+                    { "id": 1, "key": "Linux-cargo-abc" },
+                    { "id": 2, "key": "Linux-npm-def" },
+                    { "id": 3, "key": "macOS-cargo-abc" }
+                ]
+            }),
+        )
+        .await;
+    }
+
+    async fn expect_delete(h: &TestHarness, id: u64, times: u64) {
+        Mock::given(method("DELETE"))
+            .and(path(format!("/repos/owner/repo/actions/caches/{id}")))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(times)
+            .mount(&h.server)
+            .await;
+    }
 
     #[tokio::test]
     async fn test_should_delete_cache_by_id() {
         let h = TestHarness::new().await;
         mock_rest_delete(&h.server, "/repos/owner/repo/actions/caches/42", 204).await;
 
-        let args = DeleteArgs {
-            cache: "42".to_string(),
-            repo: Some("owner/repo".to_string()),
-        };
-        args.run(&h.factory).await.unwrap();
+        delete_args(Some("42")).run(&h.factory).await.unwrap();
 
         let stderr = h.stderr();
         assert!(stderr.contains("Deleted cache"), "should confirm deletion");
         assert!(stderr.contains("42"), "should contain cache ID");
     }
+
+    #[tokio::test]
+    async fn test_should_delete_all_caches() {
+        let h = TestHarness::new().await;
+        mount_caches(&h).await;
+        for id in 1..=3 {
+            expect_delete(&h, id, 1).await;
+        }
+
+        let args = DeleteArgs {
+            all: true,
+            ..delete_args(None)
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert!(h.stderr().contains("Deleted 3 caches from owner/repo"));
+    }
+
+    #[tokio::test]
+    async fn test_should_delete_caches_matching_pattern() {
+        let h = TestHarness::new().await;
+        mount_caches(&h).await;
+        expect_delete(&h, 1, 1).await;
+        expect_delete(&h, 2, 1).await;
+        expect_delete(&h, 3, 0).await;
+
+        let args = DeleteArgs {
+            pattern: Some("Linux-*".into()),
+            ..delete_args(None)
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert!(h.stderr().contains("Deleted 2 caches"));
+    }
+
+    #[tokio::test]
+    async fn test_should_report_no_matching_caches() {
+        let h = TestHarness::new().await;
+        mount_caches(&h).await;
+
+        let args = DeleteArgs {
+            pattern: Some("Windows-*".into()),
+            ..delete_args(None)
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert_eq!(h.stderr(), "no caches matched\n");
+    }
+
+    #[tokio::test]
+    async fn test_should_require_yes_when_not_interactive() {
+        let h = TestHarness::new().await;
+        mount_caches(&h).await;
+        expect_delete(&h, 1, 0).await;
+
+        let args = DeleteArgs {
+            all: true,
+            yes: false,
+            ..delete_args(None)
+        };
+        let err = args.run(&h.factory).await.unwrap_err();
+
+        assert!(err.to_string().contains("--yes required"));
+    }
+
+    #[tokio::test]
+    async fn test_should_cancel_when_not_confirmed() {
+        let mut h = TestHarness::new().await;
+        h.factory.io.set_stdin_tty(true);
+        h.factory.io.set_stdout_tty(true);
+        h.factory.io.set_never_prompt(false);
+        h.prompter.confirm_answers.lock().unwrap().push(false);
+        mount_caches(&h).await;
+        expect_delete(&h, 1, 0).await;
+
+        let args = DeleteArgs {
+            all: true,
+            yes: false,
+            ..delete_args(None)
+        };
+        let err = args.run(&h.factory).await.unwrap_err();
+
+        assert!(err.to_string().contains("delete cancelled"));
+    }
+
+    #[test]
+    fn test_should_require_cache_or_bulk_flag() {
+        #[derive(clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: DeleteArgs,
+        }
+        use clap::Parser;
+
+        assert!(Cli::try_parse_from(["delete"]).is_err());
+        assert!(Cli::try_parse_from(["delete", "key", "--all"]).is_err());
+        assert!(Cli::try_parse_from(["delete", "--all", "--pattern", "x"]).is_err());
+        assert!(Cli::try_parse_from(["delete", "--pattern", "Linux-*"]).is_ok());
+    }
 }
@@ -6,7 +6,6 @@ use anyhow::{Context, Result};
 use clap::Args;
 use serde_json::Value;
 
-use ghc_core::repo::Repo;
 use ghc_core::table::TablePrinter;
 use ghc_core::{ios_eprintln, ios_println};
 
@@ -44,6 +43,10 @@ pub struct ListArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl ListArgs {
@@ -53,11 +56,7 @@ impl ListArgs {
     ///
     /// Returns an error if the caches cannot be listed.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = self
-            .repo
-            .as_deref()
-            .ok_or_else(|| anyhow::anyhow!("repository argument required (use -R OWNER/REPO)"))?;
-        let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
 
@@ -87,15 +86,15 @@ impl ListArgs {
             .unwrap_or(Value::Array(vec![]));
 
         // JSON output
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
-            let output = ghc_core::json::format_json_output(
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
+            ghc_core::json::output(
                 &items,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -129,7 +128,7 @@ impl ListArgs {
             tp.add_row(vec![
                 format!("{id}"),
                 cs.bold(key),
-                format_size(size),
+                ghc_core::text::format_size(size),
                 ref_name.to_string(),
                 last_accessed.to_string(),
             ]);
@@ -142,24 +141,6 @@ impl ListArgs {
     }
 }
 
-/// Format a byte size into a human-readable string.
-#[allow(clippy::cast_precision_loss)]
-fn format_size(bytes: u64) -> String {
-    const KB: u64 = 1024;
-    const MB: u64 = KB * 1024;
-    const GB: u64 = MB * 1024;
-
-    if bytes >= GB {
-        format!("{:.1} GB", bytes as f64 / GB as f64)
-    } else if bytes >= MB {
-        format!("{:.1} MB", bytes as f64 / MB as f64)
-    } else if bytes >= KB {
-        format!("{:.1} KB", bytes as f64 / KB as f64)
-    } else {
-        format!("{bytes} B")
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -196,6 +177,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -206,12 +188,4 @@ mod tests {
         );
         assert!(stdout.contains("2.0 MB"), "should contain formatted size");
     }
-
-    #[test]
-    fn test_should_format_size_correctly() {
-        assert_eq!(format_size(500), "500 B");
-        assert_eq!(format_size(1024), "1.0 KB");
-        assert_eq!(format_size(1_048_576), "1.0 MB");
-        assert_eq!(format_size(1_073_741_824), "1.0 GB");
-    }
 }
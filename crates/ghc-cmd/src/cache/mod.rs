@@ -10,7 +10,7 @@ use clap::Subcommand;
 /// Manage GitHub Actions caches.
 #[derive(Debug, Subcommand)]
 pub enum CacheCommand {
-    /// Delete a cache entry.
+    /// Delete cache entries.
     Delete(delete::DeleteArgs),
     /// List cache entries.
     #[command(alias = "ls")]
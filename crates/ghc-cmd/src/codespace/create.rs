@@ -57,12 +57,7 @@ impl CreateArgs {
     ///
     /// Returns an error if the codespace cannot be created.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo_name = self
-            .repo
-            .as_deref()
-            .ok_or_else(|| anyhow::anyhow!("repository argument required (use -R OWNER/REPO)"))?;
-        let repo =
-            ghc_core::repo::Repo::from_full_name(repo_name).context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
 
         if self.web {
             let url = format!(
@@ -74,7 +69,7 @@ impl CreateArgs {
             return Ok(());
         }
 
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
 
         // Resolve repository ID
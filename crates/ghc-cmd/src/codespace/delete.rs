@@ -37,7 +37,7 @@ impl DeleteArgs {
     ///
     /// Returns an error if the codespace cannot be deleted.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
 
@@ -33,7 +33,7 @@ impl EditArgs {
             .as_deref()
             .ok_or_else(|| anyhow::anyhow!("codespace name required (use -c NAME)"))?;
 
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
 
         let mut body = serde_json::json!({});
 
@@ -31,6 +31,10 @@ pub struct ListArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl ListArgs {
@@ -40,7 +44,7 @@ impl ListArgs {
     ///
     /// Returns an error if the codespaces cannot be listed.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
 
         let mut path = format!("user/codespaces?per_page={}", self.limit.min(100));
@@ -61,15 +65,15 @@ impl ListArgs {
             .unwrap_or(Value::Array(vec![]));
 
         // JSON output
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
-            let output = ghc_core::json::format_json_output(
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
+            ghc_core::json::output(
                 &items,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -165,6 +169,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
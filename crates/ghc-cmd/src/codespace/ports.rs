@@ -26,6 +26,10 @@ pub struct PortsArgs {
     #[arg(short = 't', long)]
     template: Option<String>,
 
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
+
     /// Subcommand.
     #[command(subcommand)]
     command: Option<PortsCommand>,
@@ -83,7 +87,7 @@ impl PortsArgs {
         factory: &crate::factory::Factory,
         codespace_name: &str,
     ) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
 
         let path = format!("user/codespaces/{codespace_name}");
@@ -93,15 +97,15 @@ impl PortsArgs {
             .context("failed to fetch codespace")?;
 
         // JSON output
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
-            let output = ghc_core::json::format_json_output(
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
+            ghc_core::json::output(
                 &codespace,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -206,7 +210,7 @@ impl PortsArgs {
         codespace_name: &str,
         args: &VisibilityArgs,
     ) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
 
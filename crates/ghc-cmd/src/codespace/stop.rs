@@ -24,7 +24,7 @@ impl StopArgs {
             .as_deref()
             .ok_or_else(|| anyhow::anyhow!("codespace name required (use -c NAME)"))?;
 
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
 
         let path = format!("user/codespaces/{codespace_name}/stop");
 
@@ -24,6 +24,10 @@ pub struct ViewArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl ViewArgs {
@@ -38,7 +42,7 @@ impl ViewArgs {
             .as_deref()
             .ok_or_else(|| anyhow::anyhow!("codespace name required (use -c NAME)"))?;
 
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
 
@@ -49,15 +53,15 @@ impl ViewArgs {
             .context("failed to fetch codespace")?;
 
         // JSON output
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
-            let output = ghc_core::json::format_json_output(
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
+            ghc_core::json::output(
                 &codespace,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
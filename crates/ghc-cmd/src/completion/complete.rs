@@ -0,0 +1,266 @@
+//! Hidden `ghc __complete` command.
+//!
+//! Maps from cobra's `__complete` command: the shell completion scripts call
+//! it with the words typed so far whenever a flag with a registered
+//! [`Completer`] is being completed, and offer the lines it prints.
+
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::Args;
+use serde_json::Value;
+
+use ghc_api::client::Client;
+use ghc_core::completion::{
+    Completer, Completers, CompletionFuture, CompletionRequest, filter_candidates, pending_flag,
+    repo_flag,
+};
+use ghc_core::ios_println;
+
+/// How long to wait for live values before giving up with no suggestions.
+const COMPLETION_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Print completion candidates for a partial command line.
+#[derive(Debug, Args)]
+pub struct CompleteArgs {
+    /// The words after `ghc`, ending with the (possibly empty) word being completed.
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+    words: Vec<String>,
+}
+
+impl CompleteArgs {
+    /// Run the complete command.
+    ///
+    /// Prints nothing when the cursor is not on a flag with a completer, or
+    /// when the values cannot be fetched within [`COMPLETION_TIMEOUT`].
+    ///
+    /// # Errors
+    ///
+    /// This command does not fail; lookup errors yield no suggestions.
+    pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
+        let Some((flag, prefix)) = pending_flag(&self.words) else {
+            return Ok(());
+        };
+        let completers = completers();
+        let Some(completer) = completers.find(flag) else {
+            return Ok(());
+        };
+
+        let lookup = async {
+            let repo = factory.resolve_repo(repo_flag(&self.words)).await.ok();
+            let client = match repo {
+                Some(ref repo) => factory.api_client(repo.host())?,
+                None => factory.http_client()?,
+            };
+            let request = CompletionRequest {
+                flag: flag.to_string(),
+                prefix: prefix.to_string(),
+                repo,
+            };
+            completer.complete(&client, &request).await
+        };
+
+        let Ok(Ok(candidates)) = tokio::time::timeout(COMPLETION_TIMEOUT, lookup).await else {
+            return Ok(());
+        };
+        let ios = &factory.io;
+        for candidate in filter_candidates(candidates, prefix) {
+            ios_println!(ios, "{candidate}");
+        }
+        Ok(())
+    }
+}
+
+/// The completers for flags whose values come from the API.
+pub fn completers() -> Completers<Client> {
+    let mut completers = Completers::new();
+    completers.register(RepoCompleter);
+    completers.register(LabelCompleter);
+    completers.register(BranchCompleter);
+    completers
+}
+
+/// Repositories the authenticated user has pushed to most recently.
+struct RepoCompleter;
+
+impl Completer<Client> for RepoCompleter {
+    fn flags(&self) -> &[&'static str] {
+        &["-R", "--repo"]
+    }
+
+    fn complete<'a>(
+        &'a self,
+        client: &'a Client,
+        _request: &'a CompletionRequest,
+    ) -> CompletionFuture<'a> {
+        Box::pin(async move {
+            let repos: Vec<Value> = client
+                .rest(
+                    reqwest::Method::GET,
+                    "user/repos?per_page=100&sort=pushed",
+                    None,
+                )
+                .await?;
+            Ok(field_values(&repos, "full_name"))
+        })
+    }
+}
+
+/// Labels of the resolved repository.
+struct LabelCompleter;
+
+impl Completer<Client> for LabelCompleter {
+    fn flags(&self) -> &[&'static str] {
+        &["--label", "--add-label", "--remove-label"]
+    }
+
+    fn complete<'a>(
+        &'a self,
+        client: &'a Client,
+        request: &'a CompletionRequest,
+    ) -> CompletionFuture<'a> {
+        Box::pin(async move {
+            let Some(ref repo) = request.repo else {
+                return Ok(Vec::new());
+            };
+            let path = format!("repos/{}/{}/labels?per_page=100", repo.owner(), repo.name());
+            let labels: Vec<Value> = client.rest(reqwest::Method::GET, &path, None).await?;
+            Ok(field_values(&labels, "name"))
+        })
+    }
+}
+
+/// Branches of the resolved repository.
+struct BranchCompleter;
+
+impl Completer<Client> for BranchCompleter {
+    fn flags(&self) -> &[&'static str] {
+        &["--base", "--head", "--branch"]
+    }
+
+    fn complete<'a>(
+        &'a self,
+        client: &'a Client,
+        request: &'a CompletionRequest,
+    ) -> CompletionFuture<'a> {
+        Box::pin(async move {
+            let Some(ref repo) = request.repo else {
+                return Ok(Vec::new());
+            };
+            let path = format!(
+                "repos/{}/{}/branches?per_page=100",
+                repo.owner(),
+                repo.name()
+            );
+            let branches: Vec<Value> = client.rest(reqwest::Method::GET, &path, None).await?;
+            Ok(field_values(&branches, "name"))
+        })
+    }
+}
+
+/// Collect a string field from each object.
+fn field_values(items: &[Value], field: &str) -> Vec<String> {
+    items
+        .iter()
+        .filter_map(|item| item.get(field).and_then(Value::as_str))
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{TestHarness, mock_rest_get};
+
+    fn complete_args(words: &[&str]) -> CompleteArgs {
+        CompleteArgs {
+            words: words.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_should_complete_labels_for_repo() {
+        let h = TestHarness::new().await;
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/labels",
+            serde_json::json!([{"name": "bug"}, {"name": "docs"}, {"name": "build"}]),
+        )
+        .await;
+
+        complete_args(&[
+            "issue",
+            "edit",
+            "1",
+            "-R",
+            "owner/repo",
+            "--add-label",
+            "bu",
+        ])
+        .run(&h.factory)
+        .await
+        .unwrap();
+
+        assert_eq!(h.stdout(), "bug\nbuild\n");
+    }
+
+    #[tokio::test]
+    async fn test_should_complete_branches_for_repo() {
+        let h = TestHarness::new().await;
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/branches",
+            serde_json::json!([{"name": "main"}, {"name": "feature"}]),
+        )
+        .await;
+
+        complete_args(&["pr", "create", "--repo=owner/repo", "--base", ""])
+            .run(&h.factory)
+            .await
+            .unwrap();
+
+        assert_eq!(h.stdout(), "feature\nmain\n");
+    }
+
+    #[tokio::test]
+    async fn test_should_complete_repositories() {
+        let h = TestHarness::new().await;
+        mock_rest_get(
+            &h.server,
+            "/user/repos",
+            serde_json::json!([{"full_name": "owner/repo"}, {"full_name": "other/tool"}]),
+        )
+        .await;
+
+        complete_args(&["issue", "list", "-R", "own"])
+            .run(&h.factory)
+            .await
+            .unwrap();
+
+        assert_eq!(h.stdout(), "owner/repo\n");
+    }
+
+    #[tokio::test]
+    async fn test_should_print_nothing_when_api_fails() {
+        let h = TestHarness::new().await;
+
+        complete_args(&["issue", "edit", "1", "-R", "owner/repo", "--label", ""])
+            .run(&h.factory)
+            .await
+            .unwrap();
+
+        assert!(h.stdout().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_should_print_nothing_without_completer() {
+        let h = TestHarness::new().await;
+
+        complete_args(&["issue", "list", "--state", ""])
+            .run(&h.factory)
+            .await
+            .unwrap();
+
+        assert!(h.stdout().is_empty());
+    }
+}
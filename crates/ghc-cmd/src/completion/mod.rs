@@ -2,121 +2,208 @@
 //!
 //! Generate shell completion scripts.
 
+mod complete;
+
+pub use complete::{CompleteArgs, completers};
+
+use std::fmt::Write as _;
+
 use anyhow::Result;
 use clap::Args;
+use clap_complete::Shell;
 
-use ghc_core::ios_println;
+use ghc_core::ios_print;
 
 /// Generate shell completion scripts.
 #[derive(Debug, Args)]
 pub struct CompletionArgs {
     /// Shell to generate completions for.
-    #[arg(value_name = "SHELL", value_parser = ["bash", "zsh", "fish", "powershell"])]
-    shell: String,
+    #[arg(value_name = "SHELL", value_enum)]
+    shell: Shell,
 }
 
 impl CompletionArgs {
     /// Run the completion command.
     ///
+    /// The command tree lives in the `ghc` binary, so the caller passes the
+    /// root [`clap::Command`] the script is generated from.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the shell is not supported.
-    #[allow(clippy::unused_async)]
-    pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let ios = &factory.io;
-        // Note: In a full implementation, we'd use clap_complete to generate
-        // the completions from our CLI definition. For now, we output
-        // a stub script that delegates to the binary.
-        match self.shell.as_str() {
-            "bash" => {
-                ios_println!(
-                    ios,
-                    r#"# bash completion for ghc
-_ghc_completions()
-{{
-    local cur prev
-    cur="${{COMP_WORDS[COMP_CWORD]}}"
-    prev="${{COMP_WORDS[COMP_CWORD-1]}}"
-    COMPREPLY=( $(compgen -W "issue pr repo auth gist release run workflow cache search secret variable label config alias ssh-key gpg-key org codespace project ruleset browse completion status api extension actions version" -- "$cur") )
+    /// Returns an error if the generated script is not valid UTF-8.
+    pub fn run(&self, factory: &crate::factory::Factory, cmd: clap::Command) -> Result<()> {
+        let script = generate_for(cmd, self.shell)?;
+        ios_print!(&factory.io, "{script}");
+        Ok(())
+    }
+}
+
+/// Generate the completion script for `shell` covering every subcommand,
+/// flag, and value hint of `cmd`.
+///
+/// For bash, zsh, and fish the script also asks `__complete` for the values
+/// of flags with a registered completer (labels, branches, repositories).
+///
+/// # Errors
+///
+/// Returns an error if the generated script is not valid UTF-8.
+pub fn generate_for(mut cmd: clap::Command, shell: Shell) -> Result<String> {
+    let name = cmd.get_name().to_string();
+    let mut buf = Vec::new();
+    clap_complete::generate(shell, &mut cmd, name.clone(), &mut buf);
+    let mut script = String::from_utf8(buf)?;
+    if shell == Shell::Zsh {
+        // The hook brings its own trailer, so `_{name}_dynamic` is defined
+        // before anything registers or calls it.
+        let trailer = format!("if [ \"$funcstack[1]\" = \"_{name}\" ]; then");
+        if let Some(pos) = script.rfind(&trailer) {
+            script.truncate(pos);
+            script.truncate(script.trim_end().len());
+            script.push('\n');
+        }
+    }
+    script.push_str(&dynamic_hook(shell, &name, &completers().flags()));
+    Ok(script)
+}
+
+/// Shell glue that routes completion of `flags` through `__complete`.
+fn dynamic_hook(shell: Shell, name: &str, flags: &[&str]) -> String {
+    let mut out = String::new();
+    match shell {
+        Shell::Bash => {
+            let _ = write!(
+                out,
+                r#"
+_{name}_dynamic() {{
+    case "${{COMP_WORDS[COMP_CWORD-1]}}" in
+        {cases})
+            local IFS=$'\n'
+            COMPREPLY=( $({name} __complete "${{COMP_WORDS[@]:1:COMP_CWORD}}" 2>/dev/null) )
+            return 0
+            ;;
+    esac
+    _{name} "$@"
 }}
-complete -F _ghc_completions ghc"#
-                );
-            }
-            "zsh" => {
-                ios_println!(
-                    ios,
-                    r"#compdef ghc
-
-_ghc() {{
-    local -a commands
-    commands=(
-        'issue:Manage issues'
-        'pr:Manage pull requests'
-        'repo:Manage repositories'
-        'auth:Authenticate with GitHub'
-        'gist:Manage gists'
-        'release:Manage releases'
-        'run:View workflow runs'
-        'workflow:Manage workflows'
-        'cache:Manage caches'
-        'search:Search GitHub'
-        'secret:Manage secrets'
-        'variable:Manage variables'
-        'label:Manage labels'
-        'config:Manage configuration'
-        'alias:Manage aliases'
-        'ssh-key:Manage SSH keys'
-        'gpg-key:Manage GPG keys'
-        'org:Manage organizations'
-        'codespace:Manage codespaces'
-        'project:Manage projects'
-        'ruleset:Manage rulesets'
-        'browse:Open in browser'
-        'completion:Generate completions'
-        'status:Show status'
-        'api:Make API requests'
-        'extension:Manage extensions'
-        'actions:Learn about Actions'
-        'version:Show version'
-    )
-    _describe 'command' commands
+complete -F _{name}_dynamic -o nosort -o bashdefault -o default {name}
+"#,
+                cases = flags.join("|"),
+            );
+        }
+        Shell::Zsh => {
+            let _ = write!(
+                out,
+                r#"
+_{name}_dynamic() {{
+    case "${{words[CURRENT-1]}}" in
+        ({cases})
+            local -a values
+            values=(${{(f)"$({name} __complete "${{(@)words[2,CURRENT]}}" 2>/dev/null)"}})
+            compadd -a values
+            ;;
+        (*)
+            _{name} "$@"
+            ;;
+    esac
 }}
 
-_ghc"
-                );
-            }
-            "fish" => {
-                ios_println!(
-                    ios,
-                    r"# fish completions for ghc
-complete -c ghc -n '__fish_use_subcommand' -a issue -d 'Manage issues'
-complete -c ghc -n '__fish_use_subcommand' -a pr -d 'Manage pull requests'
-complete -c ghc -n '__fish_use_subcommand' -a repo -d 'Manage repositories'
-complete -c ghc -n '__fish_use_subcommand' -a auth -d 'Authenticate with GitHub'
-complete -c ghc -n '__fish_use_subcommand' -a gist -d 'Manage gists'
-complete -c ghc -n '__fish_use_subcommand' -a release -d 'Manage releases'
-complete -c ghc -n '__fish_use_subcommand' -a run -d 'View workflow runs'
-complete -c ghc -n '__fish_use_subcommand' -a workflow -d 'Manage workflows'
-complete -c ghc -n '__fish_use_subcommand' -a cache -d 'Manage caches'
-complete -c ghc -n '__fish_use_subcommand' -a search -d 'Search GitHub'
-complete -c ghc -n '__fish_use_subcommand' -a version -d 'Show version'"
-                );
-            }
-            "powershell" => {
-                ios_println!(
-                    ios,
-                    r#"Register-ArgumentCompleter -Native -CommandName ghc -ScriptBlock {{
-    param($wordToComplete, $commandAst, $cursorPosition)
-    $commands = @('issue', 'pr', 'repo', 'auth', 'gist', 'release', 'run', 'workflow', 'cache', 'search', 'secret', 'variable', 'label', 'config', 'alias', 'ssh-key', 'gpg-key', 'org', 'codespace', 'project', 'ruleset', 'browse', 'completion', 'status', 'api', 'extension', 'actions', 'version')
-    $commands | Where-Object {{ $_ -like "$wordToComplete*" }} | ForEach-Object {{
-        [System.Management.Automation.CompletionResult]::new($_, $_, 'ParameterValue', $_)
-    }}
-}}"#
+if [ "$funcstack[1]" = "_{name}" ]; then
+    _{name}_dynamic "$@"
+else
+    compdef _{name}_dynamic {name}
+fi
+"#,
+                cases = flags.join("|"),
+            );
+        }
+        Shell::Fish => {
+            out.push('\n');
+            for flag in flags {
+                let option = match flag.strip_prefix("--") {
+                    Some(long) => format!("-l {long}"),
+                    None => format!("-s {}", flag.trim_start_matches('-')),
+                };
+                let _ = writeln!(
+                    out,
+                    "complete -c {name} {option} -x -a '({name} __complete (commandline -opc)[2..-1] (commandline -ct))'"
                 );
             }
-            other => anyhow::bail!("unsupported shell: {other}"),
         }
+        _ => {}
+    }
+    out
+}
 
-        Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::TestHarness;
+
+    fn sample_command() -> clap::Command {
+        clap::Command::new("ghc").subcommand(
+            clap::Command::new("pr")
+                .subcommand(clap::Command::new("checkout"))
+                .subcommand(clap::Command::new("list").arg(clap::Arg::new("state").long("state"))),
+        )
+    }
+
+    #[test]
+    fn test_should_complete_nested_subcommands_and_flags() {
+        let script = generate_for(sample_command(), Shell::Bash).unwrap();
+        assert!(script.contains("_ghc()"), "script: {script}");
+        assert!(script.contains("checkout"), "script: {script}");
+        assert!(script.contains("--state"), "script: {script}");
+    }
+
+    #[test]
+    fn test_should_hook_dynamic_flags_into_bash() {
+        let script = generate_for(sample_command(), Shell::Bash).unwrap();
+        assert!(
+            script.contains("-R|--repo|--label|--add-label|--remove-label|--base|--head|--branch)")
+        );
+        assert!(script.contains(r#"ghc __complete "${COMP_WORDS[@]:1:COMP_CWORD}""#));
+        assert!(script.contains("complete -F _ghc_dynamic"));
+    }
+
+    #[test]
+    fn test_should_define_zsh_hook_before_registering_it() {
+        let script = generate_for(sample_command(), Shell::Zsh).unwrap();
+        let defined = script.find("_ghc_dynamic() {").unwrap();
+        let registered = script.find("compdef _ghc_dynamic ghc").unwrap();
+        assert!(defined < registered, "script: {script}");
+        assert!(!script.contains("compdef _ghc ghc"), "script: {script}");
+        assert_eq!(script.matches("$funcstack[1]").count(), 1);
+    }
+
+    #[test]
+    fn test_should_hook_dynamic_flags_into_fish() {
+        let script = generate_for(sample_command(), Shell::Fish).unwrap();
+        assert!(script.contains(
+            "complete -c ghc -l add-label -x -a '(ghc __complete (commandline -opc)[2..-1] (commandline -ct))'"
+        ));
+        assert!(script.contains("complete -c ghc -s R -x -a"));
+    }
+
+    #[test]
+    fn test_should_generate_for_every_shell() {
+        for shell in [
+            Shell::Bash,
+            Shell::Zsh,
+            Shell::Fish,
+            Shell::PowerShell,
+            Shell::Elvish,
+        ] {
+            let script = generate_for(sample_command(), shell).unwrap();
+            assert!(script.contains("checkout"), "{shell}: {script}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_should_print_script_to_stdout() {
+        let h = TestHarness::new().await;
+        let args = CompletionArgs { shell: Shell::Fish };
+        args.run(&h.factory, sample_command()).unwrap();
+        let out = h.stdout();
+        assert!(out.contains("complete -c ghc"), "stdout: {out}");
+        assert!(out.contains("checkout"), "stdout: {out}");
     }
 }
@@ -1,12 +1,13 @@
 //! Configuration commands (`ghc config`).
 //!
-//! Maps from Go's `pkg/cmd/config/` package. Provides get, set, list,
-//! and clear-cache subcommands for managing GHC configuration.
+//! Maps from Go's `pkg/cmd/config/` package. Provides get, set, unset,
+//! list, and clear-cache subcommands for managing GHC configuration.
 
 pub mod clear_cache;
 pub mod get;
 pub mod list;
 pub mod set;
+pub mod unset;
 
 use clap::Subcommand;
 
@@ -19,6 +20,8 @@ pub enum ConfigCommand {
     Get(get::GetArgs),
     /// Update configuration with a value for the given key.
     Set(set::SetArgs),
+    /// Remove a configuration key so its default applies again.
+    Unset(unset::UnsetArgs),
     /// Print a list of configuration keys and values.
     #[command(alias = "ls")]
     List(list::ListArgs),
@@ -36,6 +39,7 @@ impl ConfigCommand {
         match self {
             Self::Get(args) => args.run(factory),
             Self::Set(args) => args.run(factory),
+            Self::Unset(args) => args.run(factory),
             Self::List(args) => args.run(factory),
             Self::ClearCache(args) => args.run(factory),
         }
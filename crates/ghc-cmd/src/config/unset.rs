@@ -0,0 +1,107 @@
+//! `ghc config unset` command.
+
+use anyhow::Result;
+use clap::Args;
+
+use crate::factory::Factory;
+
+/// Remove a configuration key so its default applies again.
+#[derive(Debug, Args)]
+pub struct UnsetArgs {
+    /// The configuration key to remove.
+    key: String,
+    /// Remove per-host setting.
+    #[arg(short = 'h', long)]
+    host: Option<String>,
+}
+
+impl UnsetArgs {
+    /// Run the config unset command.
+    ///
+    /// Unsetting a key that is not set succeeds without changes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration cannot be saved.
+    pub fn run(&self, factory: &Factory) -> Result<()> {
+        let cfg_lock = factory.config()?;
+        let mut cfg = cfg_lock
+            .lock()
+            .map_err(|e| anyhow::anyhow!("config lock: {e}"))?;
+
+        let hostname = self.host.as_deref().unwrap_or("");
+        cfg.unset(hostname, &self.key)?;
+        cfg.write()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test_helpers::TestHarness;
+
+    fn config_value(h: &TestHarness, hostname: &str, key: &str) -> Option<String> {
+        h.factory
+            .config()
+            .unwrap()
+            .lock()
+            .unwrap()
+            .get(hostname, key)
+    }
+
+    #[tokio::test]
+    async fn test_should_unset_global_value() {
+        let h = TestHarness::new().await;
+        h.factory
+            .config()
+            .unwrap()
+            .lock()
+            .unwrap()
+            .set("", "editor", "vim")
+            .unwrap();
+
+        let args = UnsetArgs {
+            key: "editor".to_string(),
+            host: None,
+        };
+        args.run(&h.factory).unwrap();
+
+        assert!(config_value(&h, "", "editor").is_none());
+        assert!(h.stdout().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_should_unset_host_specific_value() {
+        let h = TestHarness::new().await;
+        {
+            let mut cfg = h.factory.config().unwrap().lock().unwrap();
+            cfg.set("", "git_protocol", "https").unwrap();
+            cfg.set("github.com", "git_protocol", "ssh").unwrap();
+        }
+
+        let args = UnsetArgs {
+            key: "git_protocol".to_string(),
+            host: Some("github.com".to_string()),
+        };
+        args.run(&h.factory).unwrap();
+
+        assert_eq!(
+            config_value(&h, "github.com", "git_protocol"),
+            Some("https".to_string()),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_ignore_missing_key() {
+        let h = TestHarness::new().await;
+        let args = UnsetArgs {
+            key: "pager".to_string(),
+            host: None,
+        };
+        args.run(&h.factory).unwrap();
+        assert!(h.stderr().is_empty());
+    }
+}
@@ -2,9 +2,10 @@
 //!
 //! Interact with GitHub Copilot from the CLI.
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::Subcommand;
-use ghc_core::ios_println;
+use ghc_core::iostreams::IOStreams;
+use ghc_core::{ios_eprintln, ios_print, ios_println};
 use serde_json::Value;
 
 /// Interact with GitHub Copilot.
@@ -30,47 +31,49 @@ impl CopilotCommand {
     }
 }
 
+/// Models accepted by `--model`; omitting the flag uses Copilot's default.
+const COPILOT_MODELS: [&str; 6] = [
+    "gpt-4o",
+    "gpt-4o-mini",
+    "gpt-4.1",
+    "o3-mini",
+    "claude-3.5-sonnet",
+    "gemini-2.0-flash-001",
+];
+
 /// Explain code or a command.
 #[derive(Debug, clap::Args)]
 pub struct ExplainArgs {
     /// The text to explain.
     #[arg(value_name = "TEXT")]
     text: Vec<String>,
+
+    /// Model to use instead of the Copilot default.
+    #[arg(long, value_parser = clap::builder::PossibleValuesParser::new(COPILOT_MODELS))]
+    model: Option<String>,
 }
 
 impl ExplainArgs {
     async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
         let ios = &factory.io;
 
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let prompt = self.text.join(" ");
 
         if prompt.is_empty() {
             anyhow::bail!("text to explain is required");
         }
 
-        let body = serde_json::json!({
-            "messages": [
-                {
-                    "role": "user",
-                    "content": format!("Explain: {prompt}")
-                }
-            ]
-        });
-
-        let result: serde_json::Value = client
-            .rest(
-                reqwest::Method::POST,
-                "copilot/chat/completions",
-                Some(&body),
-            )
-            .await
-            .map_err(|e| anyhow::anyhow!("Copilot API request failed: {e}"))?;
-
-        let content = result
-            .pointer("/choices/0/message/content")
-            .and_then(Value::as_str)
-            .unwrap_or("No response from Copilot");
+        let messages = [serde_json::json!({
+            "role": "user",
+            "content": format!("Explain: {prompt}")
+        })];
+        if ios.is_stdout_tty() {
+            return stream_completion(&client, &messages, self.model.as_deref(), ios).await;
+        }
+        let content = complete(&client, &messages, self.model.as_deref())
+            .await?
+            .unwrap_or_else(|| "No response from Copilot".to_string());
 
         ios_println!(ios, "{content}");
 
@@ -88,43 +91,360 @@ pub struct SuggestArgs {
     /// Target shell (bash, zsh, fish, powershell).
     #[arg(short, long, default_value = "bash")]
     shell: String,
+
+    /// Model to use instead of the Copilot default.
+    #[arg(long, value_parser = clap::builder::PossibleValuesParser::new(COPILOT_MODELS))]
+    model: Option<String>,
 }
 
 impl SuggestArgs {
     async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
         let ios = &factory.io;
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let prompt = self.text.join(" ");
 
         if prompt.is_empty() {
             anyhow::bail!("description of what you want to do is required");
         }
 
-        let body = serde_json::json!({
-            "messages": [
-                {
+        let mut messages = vec![serde_json::json!({
+            "role": "user",
+            "content": format!("Suggest a {} shell command to: {}", self.shell, prompt)
+        })];
+
+        loop {
+            let suggestion = complete(&client, &messages, self.model.as_deref())
+                .await?
+                .unwrap_or_else(|| "No suggestion from Copilot".to_string());
+            ios_println!(ios, "{suggestion}");
+
+            if !ios.can_prompt() {
+                return Ok(());
+            }
+            let command = extract_command(&suggestion);
+            messages.push(serde_json::json!({ "role": "assistant", "content": suggestion }));
+
+            match self.follow_up(factory, &client, &command).await? {
+                Some(revision) => messages.push(serde_json::json!({
                     "role": "user",
-                    "content": format!("Suggest a {} shell command to: {}", self.shell, prompt)
+                    "content": format!("Revise the command: {revision}")
+                })),
+                None => return Ok(()),
+            }
+        }
+    }
+
+    /// Offer follow-up actions for a suggested command until the user copies
+    /// it, exits, or asks for a revision (returned as `Some`).
+    async fn follow_up(
+        &self,
+        factory: &crate::factory::Factory,
+        client: &ghc_api::client::Client,
+        command: &str,
+    ) -> Result<Option<String>> {
+        let ios = &factory.io;
+        let cs = ios.color_scheme();
+        let prompter = factory.prompter();
+        let options: Vec<String> = SUGGEST_ACTIONS.iter().map(ToString::to_string).collect();
+
+        loop {
+            match prompter.select("Select an option", Some(0), &options)? {
+                0 => {
+                    factory
+                        .clipboard()
+                        .copy(command)
+                        .context("failed to copy command to clipboard")?;
+                    ios_eprintln!(
+                        ios,
+                        "{} Command was copied to the clipboard",
+                        cs.success_icon()
+                    );
+                    return Ok(None);
                 }
-            ]
-        });
-
-        let result: serde_json::Value = client
-            .rest(
-                reqwest::Method::POST,
-                "copilot/chat/completions",
-                Some(&body),
-            )
-            .await
-            .map_err(|e| anyhow::anyhow!("Copilot API request failed: {e}"))?;
+                1 => {
+                    let revision = prompter.input("How should the command be revised?", "")?;
+                    if !revision.trim().is_empty() {
+                        return Ok(Some(revision));
+                    }
+                }
+                2 => {
+                    let messages = [serde_json::json!({
+                        "role": "user",
+                        "content": format!("Explain the {} shell command: {command}", self.shell)
+                    })];
+                    let explanation = complete(client, &messages, self.model.as_deref())
+                        .await?
+                        .unwrap_or_else(|| "No response from Copilot".to_string());
+                    ios_println!(ios, "{explanation}");
+                }
+                _ => return Ok(None),
+            }
+        }
+    }
+}
+
+/// Follow-up actions offered after an interactive suggestion.
+const SUGGEST_ACTIONS: [&str; 4] = [
+    "Copy command to clipboard",
+    "Revise command",
+    "Explain command",
+    "Exit",
+];
+
+/// Send a chat completion request and return the reply, if any.
+async fn complete(
+    client: &ghc_api::client::Client,
+    messages: &[Value],
+    model: Option<&str>,
+) -> Result<Option<String>> {
+    let body = completion_body(messages, model);
+    let result: Value = client
+        .rest(
+            reqwest::Method::POST,
+            "copilot/chat/completions",
+            Some(&body),
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("Copilot API request failed: {e}"))?;
+
+    Ok(result
+        .pointer("/choices/0/message/content")
+        .and_then(Value::as_str)
+        .map(String::from))
+}
+
+/// Print a chat completion reply token by token as it streams in.
+async fn stream_completion(
+    client: &ghc_api::client::Client,
+    messages: &[Value],
+    model: Option<&str>,
+    ios: &IOStreams,
+) -> Result<()> {
+    let mut body = completion_body(messages, model);
+    body["stream"] = Value::Bool(true);
+    let mut events = client
+        .rest_stream(
+            reqwest::Method::POST,
+            "copilot/chat/completions",
+            Some(&body),
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("Copilot API request failed: {e}"))?;
 
-        let content = result
-            .pointer("/choices/0/message/content")
+    let mut printed = false;
+    while let Some(event) = events.next().await {
+        let event = event.map_err(|e| anyhow::anyhow!("Copilot API request failed: {e}"))?;
+        if let Some(delta) = event
+            .pointer("/choices/0/delta/content")
             .and_then(Value::as_str)
-            .unwrap_or("No suggestion from Copilot");
+            .filter(|d| !d.is_empty())
+        {
+            ios_print!(ios, "{delta}");
+            ios.flush_out();
+            printed = true;
+        }
+    }
 
-        ios_println!(ios, "{content}");
+    if printed {
+        ios_println!(ios);
+    } else {
+        ios_println!(ios, "No response from Copilot");
+    }
+    Ok(())
+}
 
-        Ok(())
+/// Build a chat completion request body.
+fn completion_body(messages: &[Value], model: Option<&str>) -> Value {
+    let mut body = serde_json::json!({ "messages": messages });
+    if let Some(model) = model {
+        body["model"] = Value::from(model);
+    }
+    body
+}
+
+/// Pull the command out of a suggestion, preferring the first fenced code block.
+fn extract_command(suggestion: &str) -> String {
+    if let Some((_, rest)) = suggestion.split_once("```")
+        && let Some((block, _)) = rest.split_once("```")
+    {
+        // Drop the info string (e.g. "bash") on the opening fence line.
+        let code = block.split_once('\n').map_or(block, |(_, code)| code);
+        return code.trim().to_string();
+    }
+    suggestion.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wiremock::matchers::{body_string_contains, method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    use crate::test_helpers::TestHarness;
+
+    fn reply(content: &str) -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "choices": [{ "message": { "content": content } }]
+        }))
+    }
+
+    async fn mount_reply(h: &TestHarness, body_contains: &str, content: &str) {
+        Mock::given(method("POST"))
+            .and(path("/copilot/chat/completions"))
+            .and(body_string_contains(body_contains))
+            .respond_with(reply(content))
+            .mount(&h.server)
+            .await;
+    }
+
+    fn interactive(h: &mut TestHarness) {
+        h.factory.io.set_stdin_tty(true);
+        h.factory.io.set_stdout_tty(true);
+        h.factory.io.set_never_prompt(false);
+    }
+
+    fn suggest_args(text: &str) -> SuggestArgs {
+        SuggestArgs {
+            text: vec![text.into()],
+            shell: "bash".into(),
+            model: None,
+        }
+    }
+
+    #[test]
+    fn test_should_extract_command_from_code_block() {
+        assert_eq!(
+            extract_command("Try this:\n```bash\ngit status --short\n```\nDone."),
+            "git status --short"
+        );
+        assert_eq!(extract_command("  ls -la\n"), "ls -la");
+    }
+
+    #[test]
+    fn test_should_reject_unknown_model() {
+        use clap::Parser;
+
+        #[derive(Parser)]
+        struct Cli {
+            #[command(subcommand)]
+            command: CopilotCommand,
+        }
+
+        assert!(Cli::try_parse_from(["copilot", "suggest", "--model", "gpt-9", "x"]).is_err());
+        let cli = Cli::try_parse_from(["copilot", "explain", "--model", "gpt-4o", "x"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            CopilotCommand::Explain(ExplainArgs { model: Some(ref m), .. }) if m == "gpt-4o"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_should_send_selected_model() {
+        let h = TestHarness::new().await;
+        mount_reply(&h, r#""model":"gpt-4o""#, "find . -size +100M").await;
+
+        let args = SuggestArgs {
+            model: Some("gpt-4o".into()),
+            ..suggest_args("list large files")
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert_eq!(h.stdout(), "find . -size +100M\n");
+    }
+
+    fn explain_args(text: &str) -> ExplainArgs {
+        ExplainArgs {
+            text: vec![text.into()],
+            model: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_should_stream_explanation_on_tty() {
+        let mut h = TestHarness::new().await;
+        h.factory.io.set_stdout_tty(true);
+        let chunk = |content: &str| {
+            format!(
+                "data: {}\n\n",
+                serde_json::json!({ "choices": [{ "delta": { "content": content } }] })
+            )
+        };
+        let body = [
+            chunk("Lists "),
+            chunk(""),
+            chunk("files."),
+            "data: [DONE]\n\n".into(),
+        ]
+        .concat();
+        Mock::given(method("POST"))
+            .and(path("/copilot/chat/completions"))
+            .and(body_string_contains(r#""stream":true"#))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+            .mount(&h.server)
+            .await;
+
+        explain_args("ls").run(&h.factory).await.unwrap();
+
+        assert_eq!(h.stdout(), "Lists files.\n");
+    }
+
+    #[tokio::test]
+    async fn test_should_buffer_explanation_when_not_tty() {
+        let h = TestHarness::new().await;
+        mount_reply(&h, "Explain: ls", "Lists files.").await;
+
+        explain_args("ls").run(&h.factory).await.unwrap();
+
+        assert_eq!(h.stdout(), "Lists files.\n");
+    }
+
+    #[tokio::test]
+    async fn test_should_print_suggestion_once_when_not_interactive() {
+        let h = TestHarness::new().await;
+        mount_reply(&h, "list files", "ls -la").await;
+
+        suggest_args("list files").run(&h.factory).await.unwrap();
+
+        assert_eq!(h.stdout(), "ls -la\n");
+        assert!(h.copied().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_should_copy_suggestion_to_clipboard() {
+        let mut h = TestHarness::new().await;
+        interactive(&mut h);
+        mount_reply(&h, "list files", "```bash\nls -la\n```").await;
+        h.prompter.select_answers.lock().unwrap().push(0);
+
+        suggest_args("list files").run(&h.factory).await.unwrap();
+
+        assert_eq!(h.copied(), ["ls -la"]);
+        assert!(h.stderr().contains("Command was copied to the clipboard"));
+    }
+
+    #[tokio::test]
+    async fn test_should_revise_and_explain_suggestion() {
+        let mut h = TestHarness::new().await;
+        interactive(&mut h);
+        mount_reply(&h, "include hidden", "ls -la").await;
+        mount_reply(
+            &h,
+            "Explain the bash shell command: ls -la",
+            "Lists all files.",
+        )
+        .await;
+        mount_reply(&h, "list files", "ls").await;
+        h.prompter.select_answers.lock().unwrap().extend([1, 2, 3]);
+        h.prompter
+            .input_answers
+            .lock()
+            .unwrap()
+            .push("include hidden files".into());
+
+        suggest_args("list files").run(&h.factory).await.unwrap();
+
+        assert_eq!(h.stdout(), "ls\nls -la\nLists all files.\n");
+        assert!(h.copied().is_empty());
     }
 }
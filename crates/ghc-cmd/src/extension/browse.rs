@@ -25,6 +25,10 @@ pub struct BrowseArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl BrowseArgs {
@@ -41,7 +45,7 @@ impl BrowseArgs {
             return Ok(());
         }
 
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
 
@@ -58,16 +62,16 @@ impl BrowseArgs {
             .cloned()
             .unwrap_or_default();
 
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let arr = Value::Array(items.clone());
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &arr,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -137,6 +141,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -157,6 +162,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -31,7 +31,7 @@ impl InstallArgs {
     ///
     /// Returns an error if the extension cannot be installed.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let extensions_dir = ghc_core::config::config_dir().join("extensions");
+        let extensions_dir = ghc_core::config::extensions_dir();
 
         tokio::fs::create_dir_all(&extensions_dir)
             .await
@@ -195,7 +195,7 @@ async fn try_install_binary(
     ext_dir: &Path,
     pin: Option<&str>,
 ) -> Result<bool> {
-    let client = factory.api_client("github.com")?;
+    let client = factory.http_client()?;
 
     let release_path = if let Some(tag) = pin {
         format!("repos/{repo_full}/releases/tags/{tag}")
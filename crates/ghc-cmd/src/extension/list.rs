@@ -19,7 +19,7 @@ impl ListArgs {
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
         let ios = &factory.io;
         let cs = ios.color_scheme();
-        let extensions_dir = ghc_core::config::config_dir().join("extensions");
+        let extensions_dir = ghc_core::config::extensions_dir();
 
         if !extensions_dir.exists() {
             ios_eprintln!(ios, "No extensions installed");
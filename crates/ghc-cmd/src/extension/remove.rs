@@ -25,9 +25,7 @@ impl RemoveArgs {
             format!("gh-{}", self.name)
         };
 
-        let ext_dir = ghc_core::config::config_dir()
-            .join("extensions")
-            .join(&ext_name);
+        let ext_dir = ghc_core::config::extensions_dir().join(&ext_name);
 
         if !ext_dir.exists() {
             return Err(anyhow::anyhow!("extension {ext_name} is not installed"));
@@ -33,6 +33,10 @@ pub struct SearchArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl SearchArgs {
@@ -51,7 +55,7 @@ impl SearchArgs {
             return Ok(());
         }
 
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
 
@@ -72,16 +76,16 @@ impl SearchArgs {
             .cloned()
             .unwrap_or_default();
 
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let arr = Value::Array(items.clone());
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &arr,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
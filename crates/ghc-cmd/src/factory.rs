@@ -4,15 +4,24 @@
 //! of configuration, API clients, browser, and prompter. Supports test mode
 //! with dependency injection for isolated testing.
 
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex, OnceLock};
 
+use anyhow::Context;
+
 use ghc_core::browser::{Browser, StubBrowser, SystemBrowser};
+use ghc_core::clipboard::{Clipboard, StubClipboard, SystemClipboard};
 use ghc_core::config::{Config, FileConfig};
+use ghc_core::instance;
 use ghc_core::iostreams::{IOStreams, TestOutput};
 use ghc_core::prompter::{DialoguerPrompter, Prompter, StubPrompter};
 use ghc_git::client::GitClient;
 use secrecy::SecretString;
 
+/// How long cached API responses for read-heavy commands such as
+/// `repo view` and `status` are served without a request.
+pub(crate) const RESPONSE_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(5 * 60);
+
 /// Shared factory providing lazily-initialized dependencies to all commands.
 ///
 /// In production mode, dependencies are created from the real system.
@@ -26,13 +35,18 @@ pub struct Factory {
     config: OnceLock<Mutex<Box<dyn Config>>>,
     /// Git client (lazily loaded).
     git_client: OnceLock<GitClient>,
+    /// Repository named by `GH_REPO`, if any.
+    gh_repo: Option<String>,
 
     // Test overrides
     http_override: Option<reqwest::Client>,
     api_url_override: Option<String>,
     token_override: Option<SecretString>,
     browser_stub: Option<Arc<StubBrowser>>,
+    clipboard_stub: Option<Arc<StubClipboard>>,
     prompter_stub: Option<Arc<StubPrompter>>,
+    state_dir_override: Option<PathBuf>,
+    cache_dir_override: Option<PathBuf>,
 }
 
 impl std::fmt::Debug for Factory {
@@ -46,23 +60,22 @@ impl std::fmt::Debug for Factory {
 impl Factory {
     /// Create a new factory with the given version.
     pub fn new(app_version: String) -> Self {
-        let mut io = IOStreams::system();
-
-        // Apply environment overrides
-        if std::env::var("GH_PROMPT_DISABLED").is_ok() {
-            io.set_never_prompt(true);
-        }
+        let io = IOStreams::system();
 
         Self {
             app_version,
             io,
             config: OnceLock::new(),
             git_client: OnceLock::new(),
+            gh_repo: std::env::var("GH_REPO").ok(),
             http_override: None,
             api_url_override: None,
             token_override: None,
             browser_stub: None,
+            clipboard_stub: None,
             prompter_stub: None,
+            state_dir_override: None,
+            cache_dir_override: None,
         }
     }
 
@@ -78,11 +91,17 @@ impl Factory {
             io,
             config: OnceLock::new(),
             git_client: OnceLock::new(),
+            gh_repo: None,
             http_override: None,
             api_url_override: None,
             token_override: None,
             browser_stub: None,
+            clipboard_stub: None,
             prompter_stub: None,
+            state_dir_override: None,
+            cache_dir_override: Some(
+                std::env::temp_dir().join(format!("ghc-test-cache-{}", std::process::id())),
+            ),
         };
 
         (factory, output)
@@ -119,6 +138,48 @@ impl Factory {
         self
     }
 
+    /// Set a git client override for testing (e.g., backed by a stub git).
+    #[must_use]
+    pub fn with_git_client(mut self, client: GitClient) -> Self {
+        self.git_client = OnceLock::from(client);
+        self
+    }
+
+    /// Set the repository `GH_REPO` would name, for testing.
+    #[must_use]
+    pub fn with_gh_repo(mut self, name: impl Into<String>) -> Self {
+        self.gh_repo = Some(name.into());
+        self
+    }
+
+    /// Set a state directory override for testing.
+    #[must_use]
+    pub fn with_state_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.state_dir_override = Some(dir.into());
+        self
+    }
+
+    /// Get the state directory (for recovery files and other local state).
+    pub fn state_dir(&self) -> PathBuf {
+        self.state_dir_override
+            .clone()
+            .unwrap_or_else(ghc_core::config::state_dir)
+    }
+
+    /// Set a cache directory override for testing.
+    #[must_use]
+    pub fn with_cache_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir_override = Some(dir.into());
+        self
+    }
+
+    /// Get the cache directory (for short-lived API lookups).
+    pub fn cache_dir(&self) -> PathBuf {
+        self.cache_dir_override
+            .clone()
+            .unwrap_or_else(ghc_core::config::cache_dir)
+    }
+
     /// Set a stub browser and return the shared reference for verification.
     pub fn with_stub_browser(mut self) -> (Self, Arc<StubBrowser>) {
         let stub = Arc::new(StubBrowser::default());
@@ -151,6 +212,47 @@ impl Factory {
             .ok_or_else(|| anyhow::anyhow!("failed to initialize config"))
     }
 
+    /// Resolve whether commands may prompt, from the `--prompt`/`--no-prompt`
+    /// flag, `GH_PROMPT_DISABLED`, and the `prompt` config value.
+    pub fn apply_prompt_preference(&mut self, flag: Option<bool>) {
+        let env = std::env::var("GH_PROMPT_DISABLED").ok();
+        let config = self
+            .config()
+            .ok()
+            .and_then(|cfg| cfg.lock().ok().map(|cfg| cfg.prompt("")))
+            .unwrap_or_default();
+        self.io
+            .apply_prompt_preference(flag, env.as_deref(), &config);
+    }
+
+    /// Turn on screen-reader mode when `accessibility.screen_reader` is set.
+    pub fn apply_accessibility_preference(&mut self) {
+        let enabled = self
+            .config()
+            .ok()
+            .and_then(|cfg| cfg.lock().ok()?.get("", "accessibility.screen_reader"))
+            .is_some_and(|v| v == "true");
+        self.io.set_accessible(enabled);
+    }
+
+    /// Apply `color_labels` and `accent_color` from config, with
+    /// `GH_COLOR_LABELS` overriding `color_labels` when set.
+    pub fn apply_color_preferences(&mut self) {
+        let env = std::env::var("GH_COLOR_LABELS").ok();
+        let (labels, accent) = self
+            .config()
+            .ok()
+            .and_then(|cfg| {
+                let cfg = cfg.lock().ok()?;
+                Some((cfg.get("", "color_labels"), cfg.get("", "accent_color")))
+            })
+            .unwrap_or_default();
+        self.io
+            .set_color_labels(color_labels_enabled(env.as_deref(), labels.as_deref()));
+        self.io
+            .set_accent_color(accent.filter(|hex| !hex.is_empty()));
+    }
+
     /// Get the git client.
     ///
     /// # Errors
@@ -167,6 +269,76 @@ impl Factory {
             .ok_or_else(|| anyhow::anyhow!("failed to initialize git client"))
     }
 
+    /// The repository commands operate on when `-R` is not given.
+    ///
+    /// `GH_REPO` takes precedence. Otherwise the current directory's git
+    /// remotes on authenticated hosts are used, preferring the default
+    /// chosen with `ghc repo set-default`. When remotes point at several
+    /// repositories, prompts for one, or fails if prompting is not possible.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no repository can be determined.
+    pub async fn base_repo(&self) -> anyhow::Result<ghc_core::repo::Repo> {
+        if let Some(name) = self.gh_repo.as_deref().filter(|n| !n.is_empty()) {
+            return parse_repo_name(name)
+                .with_context(|| format!("invalid repository in GH_REPO: {name:?}"));
+        }
+
+        let remotes = match self.git_client() {
+            Ok(git) => git.remotes().await.unwrap_or_default(),
+            Err(_) => Vec::new(),
+        };
+        let hosts = self
+            .config()
+            .ok()
+            .and_then(|c| c.lock().ok().map(|cfg| cfg.authentication().hosts()))
+            .unwrap_or_default();
+        let mut candidates = base_repo_candidates(&remotes, &hosts);
+        match candidates.len() {
+            0 => anyhow::bail!(
+                "repository argument required (use -R OWNER/REPO); no GitHub remotes found in the current directory"
+            ),
+            1 => Ok(candidates.remove(0)),
+            _ => {
+                let names: Vec<String> = candidates.iter().map(ToString::to_string).collect();
+                if !self.io.can_prompt() {
+                    anyhow::bail!(
+                        "multiple remotes point at different repositories ({}); use -R OWNER/REPO or run `ghc repo set-default`",
+                        names.join(", ")
+                    );
+                }
+                let choice =
+                    self.prompter()
+                        .select("Which repository should be used?", Some(0), &names)?;
+                Ok(candidates.swap_remove(choice))
+            }
+        }
+    }
+
+    /// Resolve the repository from a `-R` value, falling back to
+    /// [`Self::base_repo`] when it is absent.
+    ///
+    /// The value may be `OWNER/REPO`, `HOST/OWNER/REPO`, or a repository URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is malformed or no repository can be
+    /// determined.
+    pub async fn resolve_repo(&self, flag: Option<&str>) -> anyhow::Result<ghc_core::repo::Repo> {
+        match flag {
+            Some(name) => parse_repo_name(name),
+            None => self.base_repo().await,
+        }
+    }
+
+    /// Set a stub clipboard and return the shared reference for verification.
+    pub fn with_stub_clipboard(mut self) -> (Self, Arc<StubClipboard>) {
+        let stub = Arc::new(StubClipboard::default());
+        self.clipboard_stub = Some(stub.clone());
+        (self, stub)
+    }
+
     /// Create a browser instance.
     ///
     /// In test mode with a stub browser, returns the stub.
@@ -183,6 +355,16 @@ impl Factory {
         Box::new(SystemBrowser::new())
     }
 
+    /// Create a clipboard instance.
+    ///
+    /// In test mode with a stub clipboard, returns the stub.
+    pub fn clipboard(&self) -> Box<dyn Clipboard> {
+        if let Some(ref stub) = self.clipboard_stub {
+            return Box::new(StubClipboardWrapper(stub.clone()));
+        }
+        Box::new(SystemClipboard)
+    }
+
     /// Create a prompter instance.
     ///
     /// In test mode with a stub prompter, returns the stub.
@@ -194,16 +376,58 @@ impl Factory {
         Box::new(DialoguerPrompter::new(editor))
     }
 
-    /// Build an HTTP client for API requests.
+    /// Resolve the host to use when a command is not given one explicitly.
+    ///
+    /// Checks `GH_HOST` first, then the authenticated hosts in the config
+    /// (preferring github.com), and falls back to github.com.
+    pub fn default_host(&self) -> String {
+        let hosts = self
+            .config()
+            .ok()
+            .and_then(|c| c.lock().ok().map(|cfg| cfg.authentication().hosts()))
+            .unwrap_or_default();
+        resolve_default_host(std::env::var("GH_HOST").ok(), hosts)
+    }
+
+    /// Build an API client for [`Self::default_host`].
     ///
     /// # Errors
     ///
-    /// Returns an error if the client cannot be built.
+    /// Returns an error naming the host if it has no credentials, or if the
+    /// client cannot be built.
     pub fn http_client(&self) -> anyhow::Result<ghc_api::client::Client> {
-        let hostname = "github.com"; // default
+        self.authenticated_client(&self.default_host())
+    }
+
+    /// Build an API client for `hostname`, requiring credentials for it.
+    fn authenticated_client(&self, hostname: &str) -> anyhow::Result<ghc_api::client::Client> {
+        let has_token = self.token_override.is_some()
+            || self.config().ok().is_some_and(|c| {
+                c.lock()
+                    .is_ok_and(|cfg| cfg.authentication().active_token(hostname).is_some())
+            });
+        if !has_token {
+            anyhow::bail!(
+                "not logged in to {hostname}; run `ghc auth login --hostname {hostname}` to authenticate"
+            );
+        }
         self.api_client(hostname)
     }
 
+    /// Enable the on-disk `ETag` response cache on an API client.
+    ///
+    /// Uses the cache directory override when one is set (as in tests).
+    pub fn with_response_cache(
+        &self,
+        client: ghc_api::client::Client,
+        ttl: std::time::Duration,
+    ) -> ghc_api::client::Client {
+        match self.cache_dir_override {
+            Some(ref dir) => client.with_cache_dir(dir.join("http"), ttl),
+            None => client.with_cache(ttl),
+        }
+    }
+
     /// Build an API client for a specific hostname.
     ///
     /// In test mode, uses the injected HTTP client and URL override.
@@ -212,13 +436,16 @@ impl Factory {
     ///
     /// Returns an error if the client cannot be built or auth is missing.
     pub fn api_client(&self, hostname: &str) -> anyhow::Result<ghc_api::client::Client> {
+        let mut unix_socket = None;
         let http = if let Some(ref client) = self.http_override {
             client.clone()
         } else {
+            unix_socket = self.http_unix_socket(hostname);
             let opts = ghc_api::http::HttpClientOptions {
                 app_version: self.app_version.clone(),
                 skip_default_headers: false,
                 log_verbose: std::env::var("GH_DEBUG").is_ok(),
+                unix_socket: unix_socket.clone(),
             };
             ghc_api::http::build_client(&opts)?
         };
@@ -232,12 +459,64 @@ impl Factory {
             })
         });
 
-        let mut client = ghc_api::client::Client::new(http, hostname, token);
+        let mut client = ghc_api::client::Client::new(http, hostname, token)
+            .with_stderr(self.io.stderr_handle());
         if let Some(ref url) = self.api_url_override {
             client = client.with_url_override(url.clone());
         }
+        if unix_socket.is_some() {
+            client = client.with_unix_socket();
+        }
         Ok(client)
     }
+
+    /// Resolve the Unix domain socket API requests should be sent through.
+    ///
+    /// A non-empty `GH_HTTP_UNIX_SOCKET` takes precedence over the
+    /// `http_unix_socket` config key; otherwise requests use TCP.
+    pub fn http_unix_socket(&self, hostname: &str) -> Option<PathBuf> {
+        let non_empty = |s: &String| !s.trim().is_empty();
+        std::env::var("GH_HTTP_UNIX_SOCKET")
+            .ok()
+            .filter(non_empty)
+            .or_else(|| {
+                let cfg = self.config().ok()?;
+                let cfg = cfg.lock().ok()?;
+                cfg.get(hostname, "http_unix_socket").filter(non_empty)
+            })
+            .map(PathBuf::from)
+    }
+}
+
+/// Decide whether labels are drawn in their own hex colors.
+///
+/// A non-empty `GH_COLOR_LABELS` (`env`) decides on its own: any value turns
+/// label colors on except `0`, `false`, `no` and `disabled`. When it is unset
+/// or empty, the `color_labels` config value (`config`) applies, and only
+/// `enabled` turns them on.
+fn color_labels_enabled(env: Option<&str>, config: Option<&str>) -> bool {
+    match env.map(str::trim).filter(|v| !v.is_empty()) {
+        Some(v) => !matches!(
+            v.to_ascii_lowercase().as_str(),
+            "0" | "false" | "no" | "disabled"
+        ),
+        None => config == Some("enabled"),
+    }
+}
+
+/// Pick the default host from `GH_HOST` and the authenticated hosts.
+fn resolve_default_host(gh_host: Option<String>, mut hosts: Vec<String>) -> String {
+    if let Some(host) = gh_host.filter(|h| !h.trim().is_empty()) {
+        return instance::normalize_hostname(host.trim());
+    }
+    if hosts.iter().any(|h| h == instance::GITHUB_COM) {
+        return instance::GITHUB_COM.to_string();
+    }
+    hosts.sort();
+    hosts
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| instance::GITHUB_COM.to_string())
 }
 
 /// Wrapper to use `Arc<StubBrowser>` as `Box<dyn Browser>`.
@@ -250,6 +529,16 @@ impl Browser for StubBrowserWrapper {
     }
 }
 
+/// Wrapper to use `Arc<StubClipboard>` as `Box<dyn Clipboard>`.
+#[derive(Debug)]
+struct StubClipboardWrapper(Arc<StubClipboard>);
+
+impl Clipboard for StubClipboardWrapper {
+    fn copy(&self, text: &str) -> anyhow::Result<()> {
+        self.0.copy(text)
+    }
+}
+
 /// Wrapper to use `Arc<StubPrompter>` as `Box<dyn Prompter>`.
 #[derive(Debug)]
 struct StubPrompterWrapper(Arc<StubPrompter>);
@@ -289,3 +578,260 @@ impl Prompter for StubPrompterWrapper {
         self.0.editor(prompt, default, allow_blank)
     }
 }
+
+/// Parse a repository given as `[HOST/]OWNER/REPO` or as a URL.
+fn parse_repo_name(name: &str) -> anyhow::Result<ghc_core::repo::Repo> {
+    let repo = if ghc_git::url_parser::is_url(name) {
+        ghc_core::repo::Repo::from_url(name)
+    } else {
+        ghc_core::repo::Repo::from_full_name(name)
+    };
+    repo.context("invalid repository format")
+}
+
+/// The distinct repositories git remotes on authenticated `hosts` point at,
+/// in remote priority order.
+///
+/// A remote marked with `ghc repo set-default` wins outright: `base` means
+/// the remote's own repository, any other value names the repository on the
+/// remote's host.
+fn base_repo_candidates(
+    remotes: &[ghc_git::remote::Remote],
+    hosts: &[String],
+) -> Vec<ghc_core::repo::Repo> {
+    let remotes: Vec<(&ghc_git::remote::Remote, &ghc_core::repo::Repo)> = remotes
+        .iter()
+        .filter_map(|r| Some((r, r.repo.as_ref()?)))
+        .filter(|(_, repo)| hosts.iter().any(|h| h.eq_ignore_ascii_case(repo.host())))
+        .collect();
+    let resolved = remotes
+        .iter()
+        .find_map(|&(r, repo)| match r.resolved.as_str() {
+            "" => None,
+            "base" => Some(repo.clone()),
+            name => ghc_core::repo::Repo::from_full_name(name)
+                .ok()
+                .map(|n| ghc_core::repo::Repo::with_host(n.owner(), n.name(), repo.host())),
+        });
+    if let Some(repo) = resolved {
+        return vec![repo];
+    }
+
+    let mut candidates: Vec<ghc_core::repo::Repo> = Vec::new();
+    for &(_, repo) in &remotes {
+        if !candidates.contains(repo) {
+            candidates.push(repo.clone());
+        }
+    }
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ghc_core::config::MemoryConfig;
+
+    #[test]
+    fn test_should_let_gh_color_labels_override_config() {
+        assert!(color_labels_enabled(None, Some("enabled")));
+        assert!(!color_labels_enabled(None, Some("disabled")));
+        assert!(!color_labels_enabled(None, None));
+        assert!(color_labels_enabled(Some("1"), Some("disabled")));
+        assert!(color_labels_enabled(Some("true"), None));
+        assert!(!color_labels_enabled(Some("0"), Some("enabled")));
+        assert!(!color_labels_enabled(Some("false"), Some("enabled")));
+        assert!(color_labels_enabled(Some(""), Some("enabled")));
+    }
+
+    #[test]
+    fn test_should_let_prompt_flag_override_config() {
+        let mut cfg = MemoryConfig::new();
+        cfg.set("", "prompt", "disabled").unwrap();
+        let (factory, _) = Factory::test();
+        let mut factory = factory.with_config(Box::new(cfg));
+        factory.io.set_never_prompt(false);
+
+        factory.apply_prompt_preference(Some(false));
+        assert!(factory.io.never_prompt());
+        factory.apply_prompt_preference(Some(true));
+        assert!(!factory.io.never_prompt());
+    }
+
+    #[test]
+    fn test_should_enable_accessible_output_from_config() {
+        let mut cfg = MemoryConfig::new();
+        cfg.set("", "accessibility.screen_reader", "true").unwrap();
+        let (factory, _) = Factory::test();
+        let mut factory = factory.with_config(Box::new(cfg));
+
+        factory.apply_accessibility_preference();
+        assert!(factory.io.is_accessible());
+    }
+
+    #[test]
+    fn test_should_apply_color_preferences_from_config() {
+        let mut cfg = MemoryConfig::new();
+        cfg.set("", "color_labels", "enabled").unwrap();
+        cfg.set("", "accent_color", "#ff8800").unwrap();
+        let (factory, _) = Factory::test();
+        let mut factory = factory.with_config(Box::new(cfg));
+
+        factory.apply_color_preferences();
+        assert!(factory.io.color_labels());
+        assert_eq!(factory.io.accent_color(), Some("#ff8800"));
+    }
+
+    #[test]
+    fn test_should_prefer_gh_host_for_default_host() {
+        let hosts = vec!["github.com".to_string()];
+        assert_eq!(
+            resolve_default_host(Some("GHE.corp.example".into()), hosts),
+            "ghe.corp.example"
+        );
+    }
+
+    #[test]
+    fn test_should_prefer_github_com_among_authenticated_hosts() {
+        let hosts = vec!["ghe.io".to_string(), "github.com".to_string()];
+        assert_eq!(resolve_default_host(None, hosts), "github.com");
+        assert_eq!(
+            resolve_default_host(Some(String::new()), vec!["ghe.io".into()]),
+            "ghe.io"
+        );
+    }
+
+    #[test]
+    fn test_should_fall_back_to_github_com_without_hosts() {
+        assert_eq!(resolve_default_host(None, vec![]), "github.com");
+    }
+
+    #[test]
+    fn test_should_name_unauthenticated_host_in_error() {
+        let (factory, _output) = Factory::test();
+        let factory = factory.with_config(Box::new(MemoryConfig::new().with_host(
+            "github.com",
+            "monalisa",
+            "ghp_abc",
+        )));
+
+        let err = factory
+            .authenticated_client("ghe.corp.example")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("not logged in to ghe.corp.example"), "{err}");
+        assert!(factory.authenticated_client("github.com").is_ok());
+    }
+
+    fn remotes(output: &str, resolved: &[(&str, &str)]) -> Vec<ghc_git::remote::Remote> {
+        let mut remotes = ghc_git::remote::Remote::parse_remotes(output);
+        for (name, value) in resolved {
+            if let Some(r) = remotes.iter_mut().find(|r| r.name == *name) {
+                r.resolved = (*value).to_string();
+            }
+        }
+        remotes
+    }
+
+    const FORK_REMOTES: &str = "origin\thttps://github.com/me/app.git (fetch)\n\
+        origin\thttps://github.com/me/app.git (push)\n\
+        upstream\tgit@github.com:team/app.git (fetch)\n\
+        upstream\tgit@github.com:team/app.git (push)\n";
+
+    #[test]
+    fn test_should_list_each_remote_repo_once_in_priority_order() {
+        let candidates = base_repo_candidates(&remotes(FORK_REMOTES, &[]), &github());
+        let names: Vec<String> = candidates.iter().map(ToString::to_string).collect();
+        assert_eq!(names, ["team/app", "me/app"]);
+
+        let single = "origin\thttps://ghe.corp/team/app.git (fetch)\n\
+            origin\thttps://ghe.corp/team/app.git (push)\n";
+        let candidates = base_repo_candidates(&remotes(single, &[]), &["ghe.corp".into()]);
+        assert_eq!(
+            candidates,
+            [ghc_core::repo::Repo::with_host("team", "app", "ghe.corp")]
+        );
+    }
+
+    fn github() -> Vec<String> {
+        vec!["github.com".into()]
+    }
+
+    #[test]
+    fn test_should_skip_remotes_on_unauthenticated_hosts() {
+        let mixed = "origin\thttps://ghe.corp/team/app.git (fetch)\n\
+            origin\thttps://ghe.corp/team/app.git (push)\n\
+            upstream\thttps://github.com/team/app.git (fetch)\n\
+            upstream\thttps://github.com/team/app.git (push)\n";
+        let candidates = base_repo_candidates(&remotes(mixed, &[("origin", "base")]), &github());
+        assert_eq!(candidates, [ghc_core::repo::Repo::new("team", "app")]);
+        assert!(base_repo_candidates(&remotes(mixed, &[]), &[]).is_empty());
+    }
+
+    #[test]
+    fn test_should_prefer_remote_resolved_by_set_default() {
+        let candidates =
+            base_repo_candidates(&remotes(FORK_REMOTES, &[("origin", "base")]), &github());
+        assert_eq!(candidates, [ghc_core::repo::Repo::new("me", "app")]);
+
+        let candidates = base_repo_candidates(
+            &remotes(FORK_REMOTES, &[("origin", "other/app")]),
+            &github(),
+        );
+        assert_eq!(candidates, [ghc_core::repo::Repo::new("other", "app")]);
+    }
+
+    #[tokio::test]
+    async fn test_should_resolve_repo_flag_as_name_or_url() {
+        let (factory, _) = Factory::test();
+        let repo = factory.resolve_repo(Some("team/app")).await.unwrap();
+        assert_eq!(repo, ghc_core::repo::Repo::new("team", "app"));
+
+        let repo = factory
+            .resolve_repo(Some("git@ghe.corp:team/app.git"))
+            .await
+            .unwrap();
+        assert_eq!(
+            repo,
+            ghc_core::repo::Repo::with_host("team", "app", "ghe.corp")
+        );
+
+        assert!(factory.resolve_repo(Some("app")).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_should_prefer_gh_repo_over_remotes() {
+        let h = crate::test_helpers::TestHarness::new()
+            .await
+            .with_git_remotes(&[("origin", "https://github.com/me/app.git")]);
+        assert_eq!(
+            h.factory.base_repo().await.unwrap(),
+            ghc_core::repo::Repo::new("me", "app")
+        );
+
+        let factory = h.factory.with_gh_repo("team/app");
+        assert_eq!(
+            factory.resolve_repo(None).await.unwrap(),
+            ghc_core::repo::Repo::new("team", "app")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_prefer_repo_flag_over_gh_repo() {
+        let (factory, _) = Factory::test();
+        let factory = factory.with_gh_repo("team/app");
+        let repo = factory.resolve_repo(Some("other/lib")).await.unwrap();
+        assert_eq!(repo, ghc_core::repo::Repo::new("other", "lib"));
+    }
+
+    #[tokio::test]
+    async fn test_should_reject_invalid_gh_repo() {
+        let (factory, _) = Factory::test();
+        let factory = factory.with_gh_repo("not-a-repo");
+        let err = factory.resolve_repo(None).await.unwrap_err();
+        assert!(
+            err.to_string().contains("invalid repository in GH_REPO"),
+            "{err}"
+        );
+    }
+}
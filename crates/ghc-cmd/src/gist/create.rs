@@ -21,9 +21,13 @@ pub struct CreateArgs {
     description: Option<String>,
 
     /// Create a public gist.
-    #[arg(short, long)]
+    #[arg(short, long, conflicts_with = "secret")]
     public: bool,
 
+    /// Create a secret gist (the default).
+    #[arg(long, conflicts_with = "public")]
+    secret: bool,
+
     /// Open the gist in the browser after creation.
     #[arg(short, long)]
     web: bool,
@@ -40,34 +44,13 @@ impl CreateArgs {
     ///
     /// Returns an error if the gist cannot be created.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let client = factory.api_client("github.com")?;
-
-        let mut gist_files: HashMap<String, Value> = HashMap::new();
+        let client = factory.http_client()?;
 
-        for file_path in &self.files {
-            if file_path == "-" {
-                let mut content = String::new();
-                std::io::Read::read_to_string(&mut std::io::stdin(), &mut content)
-                    .context("failed to read from stdin")?;
-                gist_files.insert(
-                    self.filename.clone(),
-                    serde_json::json!({ "content": content }),
-                );
-            } else {
-                let content = std::fs::read_to_string(file_path)
-                    .with_context(|| format!("failed to read file: {file_path}"))?;
-                let name = Path::new(file_path)
-                    .file_name()
-                    .and_then(std::ffi::OsStr::to_str)
-                    .unwrap_or(file_path)
-                    .to_string();
-                gist_files.insert(name, serde_json::json!({ "content": content }));
-            }
-        }
+        let gist_files = self.collect_files()?;
 
         let body = serde_json::json!({
             "description": self.description.as_deref().unwrap_or(""),
-            "public": self.public,
+            "public": self.public && !self.secret,
             "files": gist_files,
         });
 
@@ -90,6 +73,46 @@ impl CreateArgs {
 
         Ok(())
     }
+
+    /// Read every file argument into the `files` map of the request body.
+    ///
+    /// Gists hold only non-empty text, so empty and binary files are rejected
+    /// here rather than by the API.
+    fn collect_files(&self) -> Result<HashMap<String, Value>> {
+        if self.files.iter().filter(|f| *f == "-").count() > 1 {
+            anyhow::bail!("standard input (`-`) can only be given once");
+        }
+
+        let mut gist_files: HashMap<String, Value> = HashMap::new();
+        for file_path in &self.files {
+            let (name, bytes) = if file_path == "-" {
+                let mut bytes = Vec::new();
+                std::io::Read::read_to_end(&mut std::io::stdin(), &mut bytes)
+                    .context("failed to read from stdin")?;
+                (self.filename.clone(), bytes)
+            } else {
+                let bytes = std::fs::read(file_path)
+                    .with_context(|| format!("failed to read file: {file_path}"))?;
+                let name = Path::new(file_path)
+                    .file_name()
+                    .and_then(std::ffi::OsStr::to_str)
+                    .unwrap_or(file_path)
+                    .to_string();
+                (name, bytes)
+            };
+
+            let content = String::from_utf8(bytes)
+                .map_err(|_| anyhow::anyhow!("binary file not supported: {name}"))?;
+            if content.trim().is_empty() {
+                anyhow::bail!("unable to create a gist from an empty file: {name}");
+            }
+            if gist_files.contains_key(&name) {
+                anyhow::bail!("duplicate file name in gist: {name}");
+            }
+            gist_files.insert(name, serde_json::json!({ "content": content }));
+        }
+        Ok(gist_files)
+    }
 }
 
 #[cfg(test)]
@@ -119,6 +142,7 @@ mod tests {
             files: vec![tmp.to_string_lossy().into_owned()],
             description: Some("Test gist".into()),
             public: true,
+            secret: false,
             web: false,
             filename: "gistfile.txt".into(),
         };
@@ -141,10 +165,120 @@ mod tests {
             files: vec!["/nonexistent/path/file.rs".into()],
             description: None,
             public: false,
+            secret: false,
             web: false,
             filename: "gistfile.txt".into(),
         };
         let result = args.run(&h.factory).await;
         assert!(result.is_err());
     }
+
+    fn create_args(files: Vec<String>) -> CreateArgs {
+        CreateArgs {
+            files,
+            description: None,
+            public: false,
+            secret: false,
+            web: false,
+            filename: "gistfile.txt".into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_should_create_one_gist_from_multiple_files() {
+        let h = TestHarness::new().await;
+        let dir = tempfile::tempdir().unwrap();
+        let a = dir.path().join("a.txt");
+        let b = dir.path().join("b.txt");
+        std::fs::write(&a, "alpha").unwrap();
+        std::fs::write(&b, "beta").unwrap();
+        wiremock::Mock::given(wiremock::matchers::method("POST"))
+            .and(wiremock::matchers::path("/gists"))
+            .and(wiremock::matchers::body_json(serde_json::json!({
+                "description": "snippets",
+                "public": true,
+                "files": {
+                    "a.txt": { "content": "alpha" },
+                    "b.txt": { "content": "beta" }
+                }
+            })))
+            .respond_with(wiremock::ResponseTemplate::new(201).set_body_json(
+                serde_json::json!({ "id": "g1", "html_url": "https://gist.github.com/g1" }),
+            ))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let args = CreateArgs {
+            description: Some("snippets".into()),
+            public: true,
+            ..create_args(vec![
+                a.to_string_lossy().into_owned(),
+                b.to_string_lossy().into_owned(),
+            ])
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert!(h.stdout().contains("https://gist.github.com/g1"));
+    }
+
+    #[test]
+    fn test_should_reject_empty_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let empty = dir.path().join("empty.txt");
+        std::fs::write(&empty, "  \n").unwrap();
+
+        let err = create_args(vec![empty.to_string_lossy().into_owned()])
+            .collect_files()
+            .unwrap_err();
+        assert!(err.to_string().contains("empty file: empty.txt"));
+    }
+
+    #[test]
+    fn test_should_reject_binary_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let bin = dir.path().join("logo.png");
+        std::fs::write(&bin, [0x89, 0x50, 0xff, 0xfe]).unwrap();
+
+        let err = create_args(vec![bin.to_string_lossy().into_owned()])
+            .collect_files()
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("binary file not supported: logo.png")
+        );
+    }
+
+    #[test]
+    fn test_should_reject_duplicate_file_names() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("x")).unwrap();
+        let first = dir.path().join("notes.md");
+        let second = dir.path().join("x").join("notes.md");
+        std::fs::write(&first, "one").unwrap();
+        std::fs::write(&second, "two").unwrap();
+
+        let err = create_args(vec![
+            first.to_string_lossy().into_owned(),
+            second.to_string_lossy().into_owned(),
+        ])
+        .collect_files()
+        .unwrap_err();
+        assert!(err.to_string().contains("duplicate file name"));
+    }
+
+    #[test]
+    fn test_should_reject_public_with_secret() {
+        #[derive(clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: CreateArgs,
+        }
+        use clap::Parser;
+        assert!(Cli::try_parse_from(["create", "a.txt", "--public", "--secret"]).is_err());
+        let cli =
+            Cli::try_parse_from(["create", "-", "--filename", "notes.md", "--desc", "d"]).unwrap();
+        assert_eq!(cli.args.filename, "notes.md");
+        assert_eq!(cli.args.description.as_deref(), Some("d"));
+    }
 }
@@ -39,7 +39,7 @@ impl DeleteArgs {
             }
         }
 
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let path = format!("gists/{gist_id}");
 
         client
@@ -45,7 +45,7 @@ impl EditArgs {
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
         let gist_id = self.gist.rsplit('/').next().unwrap_or(&self.gist);
 
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
 
         // Determine if this is an interactive edit (no add/remove/description flags)
         let interactive =
@@ -38,6 +38,10 @@ pub struct ListArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl ListArgs {
@@ -47,7 +51,7 @@ impl ListArgs {
     ///
     /// Returns an error if the gists cannot be listed.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
 
         let path = format!("gists?per_page={}", self.limit.min(100));
@@ -57,16 +61,16 @@ impl ListArgs {
             .context("failed to list gists")?;
 
         // JSON output - always produces output (even [] for empty results)
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let items = Value::Array(gists.clone());
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &items,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -211,6 +215,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -253,6 +258,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -294,6 +300,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -31,7 +31,7 @@ impl RenameArgs {
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
         let gist_id = self.gist.rsplit('/').next().unwrap_or(&self.gist);
 
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
 
         // First fetch the gist to get the current file content
         let get_path = format!("gists/{gist_id}");
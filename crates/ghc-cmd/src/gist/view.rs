@@ -8,6 +8,7 @@ use ghc_core::ios_println;
 
 /// View a gist.
 #[derive(Debug, Args)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct ViewArgs {
     /// The gist ID or URL to view.
     #[arg(value_name = "GIST")]
@@ -40,6 +41,10 @@ pub struct ViewArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl ViewArgs {
@@ -57,7 +62,7 @@ impl ViewArgs {
             return Ok(());
         }
 
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
 
         let path = format!("gists/{gist_id}");
         let gist: Value = client
@@ -67,15 +72,15 @@ impl ViewArgs {
 
         // JSON output
         let ios = &factory.io;
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
-            let output = ghc_core::json::format_json_output(
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
+            ghc_core::json::output(
                 &gist,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -172,6 +177,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -210,6 +216,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -252,6 +259,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -276,6 +284,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -23,7 +23,7 @@ impl DeleteArgs {
     ///
     /// Returns an error if the key cannot be deleted.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
 
         let path = format!("user/gpg_keys/{}", self.id);
 
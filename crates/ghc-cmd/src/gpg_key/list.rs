@@ -22,6 +22,10 @@ pub struct ListArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl ListArgs {
@@ -31,7 +35,7 @@ impl ListArgs {
     ///
     /// Returns an error if the keys cannot be listed.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
 
         let keys: Vec<Value> = match client
@@ -49,16 +53,16 @@ impl ListArgs {
         };
 
         // JSON output
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let arr = Value::Array(keys.clone());
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &arr,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -129,6 +133,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -152,6 +157,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         let result = args.run(&h.factory).await;
         assert!(result.is_err());
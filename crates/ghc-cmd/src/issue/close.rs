@@ -16,7 +16,7 @@ pub struct CloseArgs {
 
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Reason for closing the issue.
     #[arg(short, long, default_value = "completed", value_parser = ["completed", "not_planned"])]
@@ -35,8 +35,7 @@ impl CloseArgs {
     /// Returns an error if the repository format is invalid, the issue is not
     /// found, or the API request fails.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -102,7 +101,7 @@ mod tests {
     fn default_args(number: i32, repo: &str) -> CloseArgs {
         CloseArgs {
             number,
-            repo: repo.to_string(),
+            repo: Some(repo.to_string()),
             reason: "completed".to_string(),
             comment: None,
         }
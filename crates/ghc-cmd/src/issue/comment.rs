@@ -19,7 +19,7 @@ pub struct CommentArgs {
 
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Comment body text.
     #[arg(short, long, conflicts_with = "body_file")]
@@ -63,8 +63,7 @@ impl CommentArgs {
     /// is provided, or the API request fails.
     #[allow(clippy::too_many_lines)]
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
 
         if self.web {
             let url = format!(
@@ -92,23 +91,19 @@ impl CommentArgs {
             return self.handle_delete_last(factory, &client, &repo, ios).await;
         }
 
-        // Resolve body from --body-file if provided
-        let body_from_file = if let Some(ref body_file) = self.body_file {
-            Some(super::create::read_body_file(body_file).context("failed to read body file")?)
-        } else {
-            None
-        };
+        // Resolve body from --body or --body-file
+        let body_input =
+            ghc_core::cmdutil::read_body(self.body.clone(), self.body_file.as_deref())?;
 
-        let body = match (&self.body, body_from_file, self.editor) {
-            (Some(b), _, _) => b.clone(),
-            (None, Some(b), _) => b,
-            (None, None, true) => {
+        let body = match (body_input, self.editor) {
+            (Some(b), _) => b,
+            (None, true) => {
                 let prompter = factory.prompter();
                 prompter
                     .editor("Comment body", "", true)
                     .context("failed to read comment body from editor")?
             }
-            (None, None, false) => {
+            (None, false) => {
                 let prompter = factory.prompter();
                 prompter
                     .input("Comment body", "")
@@ -351,7 +346,7 @@ mod tests {
     fn default_args(number: i32, repo: &str) -> CommentArgs {
         CommentArgs {
             number,
-            repo: repo.to_string(),
+            repo: Some(repo.to_string()),
             body: Some("Test comment".to_string()),
             body_file: None,
             editor: false,
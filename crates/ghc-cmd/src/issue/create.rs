@@ -6,6 +6,7 @@ use anyhow::{Context, Result};
 use clap::Args;
 use serde_json::Value;
 
+use ghc_core::cmdutil::RecoveryInput;
 use ghc_core::text;
 use ghc_core::{ios_eprintln, ios_println};
 
@@ -14,7 +15,7 @@ use ghc_core::{ios_eprintln, ios_println};
 pub struct CreateArgs {
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Issue title.
     #[arg(short, long)]
@@ -52,6 +53,10 @@ pub struct CreateArgs {
     #[arg(short = 'T', long)]
     template: Option<String>,
 
+    /// Recover input from a failed run of create.
+    #[arg(long, value_name = "PATH")]
+    recover: Option<PathBuf>,
+
     /// Open the new issue in the browser.
     #[arg(short, long)]
     web: bool,
@@ -66,8 +71,7 @@ impl CreateArgs {
     /// are missing, or the API request fails.
     #[allow(clippy::too_many_lines)]
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
 
         if self.template.is_some() && (self.body.is_some() || self.body_file.is_some()) {
             anyhow::bail!("`--template` is not supported when using `--body` or `--body-file`");
@@ -84,12 +88,16 @@ impl CreateArgs {
             return Ok(());
         }
 
-        // Resolve body from --body-file if provided
-        let body_from_file = if let Some(ref body_file) = self.body_file {
-            Some(read_body_file(body_file).context("failed to read body file")?)
-        } else {
-            None
-        };
+        // Resolve body from --body or --body-file
+        let body_input =
+            ghc_core::cmdutil::read_body(self.body.clone(), self.body_file.as_deref())?;
+
+        let recovered = self
+            .recover
+            .as_deref()
+            .map(RecoveryInput::load)
+            .transpose()?
+            .unwrap_or_default();
 
         // Resolve template body if --template is given
         let template_body = if let Some(ref template_name) = self.template {
@@ -102,6 +110,8 @@ impl CreateArgs {
         // Determine title
         let title = if let Some(t) = &self.title {
             t.clone()
+        } else if !recovered.title.is_empty() {
+            recovered.title.clone()
         } else if self.editor {
             // In editor mode, title is entered via editor (first line)
             String::new()
@@ -114,8 +124,9 @@ impl CreateArgs {
 
         // Determine body
         let (final_title, final_body) = if self.editor {
-            let default_body = body_from_file
+            let default_body = body_input
                 .as_deref()
+                .or(recovered.body_if_set())
                 .or(template_body.as_deref())
                 .unwrap_or("");
             let editor_content = format!("{title}\n{default_body}");
@@ -129,10 +140,10 @@ impl CreateArgs {
             let b = lines.next().unwrap_or("").trim().to_string();
             (t, b)
         } else {
-            let body = if let Some(b) = &self.body {
-                b.clone()
-            } else if let Some(b) = body_from_file {
+            let body = if let Some(b) = body_input {
                 b
+            } else if let Some(b) = recovered.body_if_set() {
+                b.to_string()
             } else {
                 let default_body = template_body.as_deref().unwrap_or("");
                 let prompter = factory.prompter();
@@ -190,10 +201,20 @@ impl CreateArgs {
             request_body["milestone"] = Value::Number(serde_json::Number::from(milestone_number));
         }
 
-        let result: Value = client
+        let result: Value = match client
             .rest(reqwest::Method::POST, &path, Some(&request_body))
             .await
-            .context("failed to create issue")?;
+        {
+            Ok(result) => result,
+            Err(err) => {
+                let input = RecoveryInput {
+                    title: final_title,
+                    body: final_body,
+                };
+                input.report(&factory.io, &factory.state_dir(), "issue");
+                return Err(anyhow::Error::new(err).context("failed to create issue"));
+            }
+        };
 
         let number = result.get("number").and_then(Value::as_i64).unwrap_or(0);
         let html_url = result.get("html_url").and_then(Value::as_str).unwrap_or("");
@@ -211,21 +232,6 @@ impl CreateArgs {
     }
 }
 
-/// Read body text from a file path or stdin (`-`).
-pub(crate) fn read_body_file(path: &std::path::Path) -> Result<String> {
-    if path == std::path::Path::new("-") {
-        use std::io::Read;
-        let mut buf = String::new();
-        std::io::stdin()
-            .read_to_string(&mut buf)
-            .context("failed to read from stdin")?;
-        Ok(buf)
-    } else {
-        std::fs::read_to_string(path)
-            .with_context(|| format!("failed to read file: {}", path.display()))
-    }
-}
-
 /// Fetch a specific issue template's body from the repository.
 async fn fetch_issue_template_body(
     client: &ghc_api::client::Client,
@@ -326,10 +332,12 @@ async fn resolve_milestone(
 mod tests {
     use super::*;
     use crate::test_helpers::{TestHarness, mock_rest_post};
+    use wiremock::matchers::{body_partial_json, method, path as wm_path};
+    use wiremock::{Mock, ResponseTemplate};
 
     fn default_args(repo: &str) -> CreateArgs {
         CreateArgs {
-            repo: repo.to_string(),
+            repo: Some(repo.to_string()),
             title: Some("Test Issue".to_string()),
             body: Some("Test body".to_string()),
             body_file: None,
@@ -339,6 +347,7 @@ mod tests {
             project: vec![],
             milestone: None,
             template: None,
+            recover: None,
             web: false,
         }
     }
@@ -458,4 +467,69 @@ mod tests {
         let result = strip_front_matter(content);
         assert_eq!(result, "Just regular content");
     }
+
+    #[tokio::test]
+    async fn test_should_save_recovery_file_on_api_failure() {
+        let state = tempfile::tempdir().unwrap();
+        let mut h = TestHarness::new().await;
+        h.factory = h.factory.with_state_dir(state.path());
+        mock_rest_post(
+            &h.server,
+            "/repos/owner/repo/issues",
+            422,
+            serde_json::json!({ "message": "Validation Failed" }),
+        )
+        .await;
+
+        let mut args = default_args("owner/repo");
+        args.body = Some("A long and painful body".to_string());
+        assert!(args.run(&h.factory).await.is_err());
+
+        let files: Vec<PathBuf> = std::fs::read_dir(state.path().join("recovery"))
+            .unwrap()
+            .map(|e| e.unwrap().path())
+            .collect();
+        assert_eq!(files.len(), 1);
+        let saved = RecoveryInput::load(&files[0]).unwrap();
+        assert_eq!(saved.title, "Test Issue");
+        assert_eq!(saved.body, "A long and painful body");
+        assert!(
+            h.stderr().contains(&files[0].display().to_string()),
+            "should print recovery path: {}",
+            h.stderr()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_reload_input_with_recover() {
+        let state = tempfile::tempdir().unwrap();
+        let path = RecoveryInput {
+            title: "Recovered title".to_string(),
+            body: "Recovered body".to_string(),
+        }
+        .save(state.path(), "issue")
+        .unwrap();
+
+        let h = TestHarness::new().await;
+        Mock::given(method("POST"))
+            .and(wm_path("/repos/owner/repo/issues"))
+            .and(body_partial_json(serde_json::json!({
+                "title": "Recovered title",
+                "body": "Recovered body",
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "number": 43,
+                "html_url": "https://github.com/owner/repo/issues/43"
+            })))
+            .mount(&h.server)
+            .await;
+
+        let mut args = default_args("owner/repo");
+        args.title = None;
+        args.body = None;
+        args.recover = Some(path);
+        args.run(&h.factory).await.unwrap();
+
+        assert!(h.stderr().contains("Created issue #43"));
+    }
 }
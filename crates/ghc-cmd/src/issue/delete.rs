@@ -21,7 +21,7 @@ pub struct DeleteArgs {
 
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Skip confirmation prompt.
     #[arg(long)]
@@ -37,8 +37,7 @@ impl DeleteArgs {
     /// confirm, or the API request fails. Note that deleting issues requires
     /// admin permissions.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
 
@@ -138,7 +137,7 @@ mod tests {
     fn default_args(number: i32, repo: &str) -> DeleteArgs {
         DeleteArgs {
             number,
-            repo: repo.to_string(),
+            repo: Some(repo.to_string()),
             confirm: true,
         }
     }
@@ -39,7 +39,7 @@ pub struct DevelopArgs {
 
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Custom branch name. Defaults to a name derived from the issue.
     #[arg(short, long)]
@@ -72,8 +72,7 @@ impl DevelopArgs {
     /// Returns an error if the repository format is invalid, the issue is not
     /// found, the branch cannot be created, or the API request fails.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -9,6 +9,9 @@ use serde_json::Value;
 use ghc_core::ios_eprintln;
 
 /// Edit one or more issues within the same repository.
+///
+/// When no fields are given on an interactive terminal, prompts to toggle
+/// the labels of a single issue.
 #[derive(Debug, Args)]
 pub struct EditArgs {
     /// Issue number(s) to edit.
@@ -17,7 +20,7 @@ pub struct EditArgs {
 
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// New title for the issue.
     #[arg(short, long)]
@@ -72,15 +75,14 @@ impl EditArgs {
     /// Returns an error if the repository format is invalid, no fields are
     /// specified to edit, or the API request fails.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
 
         // Resolve body from --body-file if provided
         let body_from_file = if let Some(ref body_file) = self.body_file {
-            Some(super::create::read_body_file(body_file).context("failed to read body file")?)
+            Some(ghc_core::cmdutil::read_body_file(body_file).context("failed to read body file")?)
         } else {
             None
         };
@@ -99,9 +101,20 @@ impl EditArgs {
             || self.remove_milestone;
 
         if !has_edits {
-            anyhow::bail!(
-                "no fields specified to edit; use --title, --body, --add-label, --add-assignee, or --milestone"
+            if !ios.can_prompt() || self.numbers.len() != 1 {
+                anyhow::bail!(
+                    "no fields specified to edit; use --title, --body, --add-label, --add-assignee, or --milestone"
+                );
+            }
+            let number = self.numbers[0];
+            edit_labels_interactively(factory, &client, &repo, number).await?;
+            ios_eprintln!(
+                ios,
+                "{} Edited issue #{number} in {}",
+                cs.success_icon(),
+                cs.bold(&repo.full_name()),
             );
+            return Ok(());
         }
 
         for &number in &self.numbers {
@@ -266,6 +279,58 @@ async fn fetch_current_assignees(
     Ok(assignees)
 }
 
+/// Prompt for the full set of labels on an issue and apply the selection.
+///
+/// The issue's current labels are preselected, so several can be toggled
+/// on or off in a single prompt.
+async fn edit_labels_interactively(
+    factory: &crate::factory::Factory,
+    client: &ghc_api::client::Client,
+    repo: &ghc_core::repo::Repo,
+    number: i32,
+) -> Result<()> {
+    let path = format!("repos/{}/{}/labels?per_page=100", repo.owner(), repo.name());
+    let repo_labels: Vec<Value> = client
+        .rest_paginate(reqwest::Method::GET, &path)
+        .await
+        .context("failed to fetch repository labels")?;
+    let names: Vec<String> = repo_labels
+        .iter()
+        .filter_map(|l| l.get("name").and_then(Value::as_str).map(str::to_string))
+        .collect();
+    if names.is_empty() {
+        anyhow::bail!("no labels found in {}", repo.full_name());
+    }
+
+    let current = fetch_current_labels(client, repo, number).await?;
+    let defaults: Vec<bool> = names
+        .iter()
+        .map(|name| current.iter().any(|c| c.eq_ignore_ascii_case(name)))
+        .collect();
+    let selected = factory
+        .prompter()
+        .multi_select("Labels", &defaults, &names)?;
+    // Labels on the issue that were not offered are kept as they are.
+    let labels: Vec<Value> = selected
+        .into_iter()
+        .filter_map(|i| names.get(i).cloned())
+        .chain(
+            current
+                .into_iter()
+                .filter(|c| !names.iter().any(|name| name.eq_ignore_ascii_case(c))),
+        )
+        .map(Value::String)
+        .collect();
+
+    let path = format!("repos/{}/{}/issues/{number}", repo.owner(), repo.name());
+    let body = serde_json::json!({ "labels": labels });
+    let _: Value = client
+        .rest(reqwest::Method::PATCH, &path, Some(&body))
+        .await
+        .context("failed to edit issue")?;
+    Ok(())
+}
+
 /// Fetch the current labels for an issue.
 async fn fetch_current_labels(
     client: &ghc_api::client::Client,
@@ -332,12 +397,12 @@ async fn resolve_milestone_number(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_helpers::{TestHarness, mock_rest_patch};
+    use crate::test_helpers::{TestHarness, mock_rest_get, mock_rest_patch};
 
     fn default_args(number: i32, repo: &str) -> EditArgs {
         EditArgs {
             numbers: vec![number],
-            repo: repo.to_string(),
+            repo: Some(repo.to_string()),
             title: None,
             body: None,
             body_file: None,
@@ -442,4 +507,103 @@ mod tests {
         let err = h.stderr();
         assert!(err.contains("Edited issue #7"), "should show edited: {err}");
     }
+
+    #[tokio::test]
+    async fn test_should_toggle_labels_interactively() {
+        let mut h = TestHarness::new().await;
+        h.factory.io.set_stdout_tty(true);
+        h.factory.io.set_stdin_tty(true);
+        h.factory.io.set_never_prompt(false);
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/labels",
+            serde_json::json!([{ "name": "bug" }, { "name": "docs" }, { "name": "p1" }]),
+        )
+        .await;
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/issues/7",
+            serde_json::json!({ "labels": [{ "name": "bug" }] }),
+        )
+        .await;
+        wiremock::Mock::given(wiremock::matchers::method("PATCH"))
+            .and(wiremock::matchers::path("/repos/owner/repo/issues/7"))
+            .and(wiremock::matchers::body_json(
+                serde_json::json!({ "labels": ["docs", "p1"] }),
+            ))
+            .respond_with(wiremock::ResponseTemplate::new(200).set_body_json(
+                serde_json::json!({ "html_url": "https://github.com/owner/repo/issues/7" }),
+            ))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+        h.prompter
+            .multi_select_answers
+            .lock()
+            .unwrap()
+            .push(vec![1, 2]);
+
+        default_args(7, "owner/repo").run(&h.factory).await.unwrap();
+
+        assert!(h.stderr().contains("Edited issue #7"));
+    }
+
+    #[tokio::test]
+    async fn test_should_offer_all_label_pages_and_keep_unlisted_labels() {
+        use wiremock::matchers::{body_json, method, path, query_param, query_param_is_missing};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let mut h = TestHarness::new().await;
+        h.factory.io.set_stdout_tty(true);
+        h.factory.io.set_stdin_tty(true);
+        h.factory.io.set_never_prompt(false);
+        let next = format!(
+            "<{}/repos/owner/repo/labels?per_page=100&page=2>; rel=\"next\"",
+            h.server.uri()
+        );
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/labels"))
+            .and(query_param_is_missing("page"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("link", next.as_str())
+                    .set_body_json(serde_json::json!([{ "name": "bug" }, { "name": "docs" }])),
+            )
+            .mount(&h.server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/labels"))
+            .and(query_param("page", "2"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!([{ "name": "p1" }])),
+            )
+            .mount(&h.server)
+            .await;
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/issues/7",
+            serde_json::json!({ "labels": [{ "name": "bug" }, { "name": "legacy" }] }),
+        )
+        .await;
+        Mock::given(method("PATCH"))
+            .and(path("/repos/owner/repo/issues/7"))
+            .and(body_json(
+                serde_json::json!({ "labels": ["docs", "p1", "legacy"] }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(
+                serde_json::json!({ "html_url": "https://github.com/owner/repo/issues/7" }),
+            ))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+        h.prompter
+            .multi_select_answers
+            .lock()
+            .unwrap()
+            .push(vec![1, 2]);
+
+        default_args(7, "owner/repo").run(&h.factory).await.unwrap();
+
+        assert!(h.stderr().contains("Edited issue #7"));
+    }
 }
@@ -4,18 +4,22 @@ use std::collections::HashMap;
 
 use anyhow::{Context, Result};
 use clap::Args;
+use ghc_api::client::Client;
 use serde_json::Value;
 
-use ghc_core::table::TablePrinter;
+use ghc_core::table::{FieldOpts, TablePrinter};
 use ghc_core::text;
 use ghc_core::{ios_eprintln, ios_println};
 
 /// List issues in a repository.
+///
+/// Filters are applied server-side. When `--search` or `--milestone` is
+/// given, the filters are combined into a single GitHub search query.
 #[derive(Debug, Args)]
 pub struct ListArgs {
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Filter by issue state.
     #[arg(short, long, default_value = "open", value_parser = ["open", "closed", "all"])]
@@ -33,11 +37,15 @@ pub struct ListArgs {
     #[arg(short = 'A', long)]
     author: Option<String>,
 
-    /// Filter by milestone name.
+    /// Filter by milestone title.
     #[arg(short, long)]
     milestone: Option<String>,
 
-    /// Search query to filter issues.
+    /// Filter by a user mentioned in the issue. Use `@me` for yourself.
+    #[arg(long)]
+    mention: Option<String>,
+
+    /// Search issues with GitHub search syntax, combined with the other filters.
     #[arg(short = 'S', long)]
     search: Option<String>,
 
@@ -60,6 +68,10 @@ pub struct ListArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl ListArgs {
@@ -71,8 +83,7 @@ impl ListArgs {
     /// fails, or the response cannot be parsed.
     #[allow(clippy::too_many_lines, clippy::cast_possible_wrap)]
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
 
         if self.web {
             let url = format!(
@@ -88,77 +99,26 @@ impl ListArgs {
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
 
-        let states = match self.state.as_str() {
-            "open" => vec![Value::String("OPEN".to_string())],
-            "closed" => vec![Value::String("CLOSED".to_string())],
-            _ => vec![
-                Value::String("OPEN".to_string()),
-                Value::String("CLOSED".to_string()),
-            ],
+        let (filtered, total) = if self.search.is_some() || self.milestone.is_some() {
+            self.fetch_by_search(&client, &repo).await?
+        } else {
+            self.fetch_by_filter(&client, &repo).await?
         };
 
-        let mut variables = HashMap::new();
-        variables.insert("owner".to_string(), Value::String(repo.owner().to_string()));
-        variables.insert("name".to_string(), Value::String(repo.name().to_string()));
-        variables.insert(
-            "first".to_string(),
-            Value::Number(serde_json::Number::from(self.limit.min(100))),
-        );
-        variables.insert("states".to_string(), Value::Array(states));
-
-        if !self.label.is_empty() {
-            let labels: Vec<Value> = self
-                .label
-                .iter()
-                .map(|l| Value::String(l.clone()))
-                .collect();
-            variables.insert("labels".to_string(), Value::Array(labels));
-        }
-
-        if let Some(ref assignee) = self.assignee {
-            variables.insert("assignee".to_string(), Value::String(assignee.clone()));
-        }
-
-        let data: Value = client
-            .graphql(ghc_api::queries::issue::ISSUE_LIST_QUERY, &variables)
-            .await
-            .context("failed to list issues")?;
-
-        let issues = data
-            .pointer("/repository/issues/nodes")
-            .and_then(Value::as_array)
-            .ok_or_else(|| anyhow::anyhow!("unexpected API response format"))?;
-
-        // Apply client-side author filter if specified
-        let filtered: Vec<&Value> = issues
-            .iter()
-            .filter(|issue| {
-                if let Some(ref author) = self.author {
-                    let issue_author = issue
-                        .pointer("/author/login")
-                        .and_then(Value::as_str)
-                        .unwrap_or("");
-                    issue_author.eq_ignore_ascii_case(author)
-                } else {
-                    true
-                }
-            })
-            .collect();
-
         // JSON output mode with field filtering, jq, or template
         // Always produces output (even [] for empty results)
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
-            let mut arr = Value::Array(filtered.iter().map(|v| (*v).clone()).collect());
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
+            let mut arr = Value::Array(filtered);
             ghc_core::json::normalize_graphql_connections(&mut arr);
             ghc_core::json::normalize_author(&mut arr);
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &arr,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -183,13 +143,22 @@ impl ListArgs {
             let number = issue.get("number").and_then(Value::as_i64).unwrap_or(0);
             let title = issue.get("title").and_then(Value::as_str).unwrap_or("");
             let state = issue.get("state").and_then(Value::as_str).unwrap_or("OPEN");
+            let url = issue.get("url").and_then(Value::as_str).unwrap_or("");
 
-            let labels: Vec<&str> = issue
+            let labels: Vec<String> = issue
                 .pointer("/labels/nodes")
                 .and_then(Value::as_array)
                 .map(|arr| {
                     arr.iter()
-                        .filter_map(|l| l.get("name").and_then(Value::as_str))
+                        .filter_map(|l| {
+                            let name = l.get("name").and_then(Value::as_str)?;
+                            let color = l.get("color").and_then(Value::as_str).unwrap_or("");
+                            Some(if ios.color_labels() {
+                                cs.hex(color, name)
+                            } else {
+                                name.to_string()
+                            })
+                        })
                         .collect()
                 })
                 .unwrap_or_default();
@@ -213,13 +182,25 @@ impl ListArgs {
                 |dt| text::relative_time_str(&dt.into(), ios.is_stdout_tty()),
             );
 
-            tp.add_row(vec![
-                format!("{number}"),
-                state_display,
-                text::truncate(title, 60),
-                label_display,
-                time_display,
-            ]);
+            let link = tp.link(&number.to_string(), url);
+            tp.add_field(&link);
+            tp.add_field(&state_display);
+            tp.add_field_with_opts(
+                title,
+                FieldOpts {
+                    truncate: true,
+                    max_width: Some(60),
+                },
+            );
+            tp.add_field_with_opts(
+                &label_display,
+                FieldOpts {
+                    truncate: true,
+                    ..FieldOpts::default()
+                },
+            );
+            tp.add_field(&time_display);
+            tp.end_row();
         }
 
         let output = tp.render();
@@ -228,38 +209,223 @@ impl ListArgs {
         if ios.is_stdout_tty() {
             ios_eprintln!(
                 ios,
-                "\nShowing {} of {} {}",
+                "\nShowing {} of {total} {}",
                 filtered.len(),
-                issues.len(),
-                text::pluralize(issues.len() as i64, "issue", "issues"),
+                text::pluralize(total, "issue", "issues"),
             );
         }
 
         Ok(())
     }
+
+    /// Fetch issues with the repository `issues(filterBy:)` connection.
+    async fn fetch_by_filter(
+        &self,
+        client: &Client,
+        repo: &ghc_core::repo::Repo,
+    ) -> Result<(Vec<Value>, i64)> {
+        let states = match self.state.as_str() {
+            "open" => vec![Value::String("OPEN".to_string())],
+            "closed" => vec![Value::String("CLOSED".to_string())],
+            _ => vec![
+                Value::String("OPEN".to_string()),
+                Value::String("CLOSED".to_string()),
+            ],
+        };
+
+        let mut variables = HashMap::new();
+        variables.insert("owner".to_string(), Value::String(repo.owner().to_string()));
+        variables.insert("name".to_string(), Value::String(repo.name().to_string()));
+        variables.insert("states".to_string(), Value::Array(states));
+
+        if !self.label.is_empty() {
+            let labels: Vec<Value> = self
+                .label
+                .iter()
+                .map(|l| Value::String(l.clone()))
+                .collect();
+            variables.insert("labels".to_string(), Value::Array(labels));
+        }
+
+        let mut viewer = None;
+        for (key, login) in [
+            ("assignee", &self.assignee),
+            ("author", &self.author),
+            ("mention", &self.mention),
+        ] {
+            let Some(login) = login else { continue };
+            let login = if login == "@me" {
+                if viewer.is_none() {
+                    viewer = Some(
+                        client
+                            .current_login()
+                            .await
+                            .context("failed to resolve @me")?,
+                    );
+                }
+                viewer.clone().unwrap_or_default()
+            } else {
+                login.clone()
+            };
+            variables.insert(key.to_string(), Value::String(login));
+        }
+
+        self.paginate(
+            client,
+            ghc_api::queries::issue::ISSUE_LIST_QUERY,
+            variables,
+            "/repository/issues",
+            "totalCount",
+        )
+        .await
+    }
+
+    /// Fetch issues with the search API, for `--search` and `--milestone`.
+    async fn fetch_by_search(
+        &self,
+        client: &Client,
+        repo: &ghc_core::repo::Repo,
+    ) -> Result<(Vec<Value>, i64)> {
+        let mut variables = HashMap::new();
+        variables.insert(
+            "query".to_string(),
+            Value::String(self.search_query(&repo.full_name())),
+        );
+        self.paginate(
+            client,
+            ghc_api::queries::issue::ISSUE_SEARCH_QUERY,
+            variables,
+            "/search",
+            "issueCount",
+        )
+        .await
+    }
+
+    /// Compose the search query from `--search` and the individual filters.
+    ///
+    /// The state qualifier is left out when the raw search already sets one.
+    fn search_query(&self, repo: &str) -> String {
+        let quote = |value: &str| {
+            if value.contains(char::is_whitespace) {
+                format!("\"{value}\"")
+            } else {
+                value.to_string()
+            }
+        };
+        let search = self.search.as_deref().unwrap_or("").trim();
+        let mut terms = vec![format!("repo:{repo}"), "is:issue".to_string()];
+        let sets_state = search
+            .split_whitespace()
+            .any(|term| matches!(term, "is:open" | "is:closed") || term.starts_with("state:"));
+        if !sets_state && self.state != "all" {
+            terms.push(format!("state:{}", self.state));
+        }
+        for label in &self.label {
+            terms.push(format!("label:{}", quote(label)));
+        }
+        for (qualifier, value) in [
+            ("assignee", &self.assignee),
+            ("author", &self.author),
+            ("mentions", &self.mention),
+            ("milestone", &self.milestone),
+        ] {
+            if let Some(value) = value {
+                terms.push(format!("{qualifier}:{}", quote(value)));
+            }
+        }
+        if !search.is_empty() {
+            terms.push(search.to_string());
+        }
+        terms.join(" ")
+    }
+
+    /// Run a paginated connection query until `--limit` issues are fetched.
+    ///
+    /// `pointer` locates the connection in the response and `count_field`
+    /// names its total count.
+    #[allow(clippy::cast_possible_wrap)]
+    async fn paginate(
+        &self,
+        client: &Client,
+        query: &str,
+        mut variables: HashMap<String, Value>,
+        pointer: &str,
+        count_field: &str,
+    ) -> Result<(Vec<Value>, i64)> {
+        let limit = self.limit as usize;
+        let mut issues: Vec<Value> = Vec::new();
+        let mut total;
+        loop {
+            let remaining = (limit - issues.len()).min(100);
+            variables.insert("first".to_string(), Value::from(remaining));
+            let data: Value = client
+                .graphql(query, &variables)
+                .await
+                .context("failed to list issues")?;
+            let connection = data
+                .pointer(pointer)
+                .ok_or_else(|| anyhow::anyhow!("unexpected API response format"))?;
+            let nodes = connection
+                .get("nodes")
+                .and_then(Value::as_array)
+                .ok_or_else(|| anyhow::anyhow!("unexpected API response format"))?;
+            // Search results may include non-issue nodes as empty objects
+            issues.extend(
+                nodes
+                    .iter()
+                    .filter(|node| node.get("number").is_some())
+                    .cloned(),
+            );
+            total = connection
+                .get(count_field)
+                .and_then(Value::as_i64)
+                .unwrap_or(issues.len() as i64);
+
+            let next = connection
+                .pointer("/pageInfo/hasNextPage")
+                .and_then(Value::as_bool)
+                .unwrap_or(false);
+            let cursor = connection
+                .pointer("/pageInfo/endCursor")
+                .and_then(Value::as_str);
+            match cursor {
+                Some(cursor) if next && issues.len() < limit => {
+                    variables.insert("after".to_string(), Value::String(cursor.to_string()));
+                }
+                _ => break,
+            }
+        }
+        issues.truncate(limit);
+        Ok((issues, total))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use wiremock::matchers::{body_partial_json, body_string_contains, method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
     use crate::test_helpers::{
         TestHarness, graphql_issue_list_response, issue_fixture, mock_graphql,
     };
 
     fn default_args(repo: &str) -> ListArgs {
         ListArgs {
-            repo: repo.to_string(),
+            repo: Some(repo.to_string()),
             state: "open".to_string(),
             assignee: None,
             label: vec![],
             author: None,
             milestone: None,
+            mention: None,
             search: None,
             limit: 30,
             web: false,
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         }
     }
 
@@ -294,6 +460,22 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_should_not_truncate_titles_when_piped() {
+        let h = TestHarness::new().await;
+        let title = "A".repeat(100);
+        mock_graphql(
+            &h.server,
+            "repository",
+            graphql_issue_list_response(&[issue_fixture(1, &title, "OPEN")]),
+        )
+        .await;
+
+        default_args("owner/repo").run(&h.factory).await.unwrap();
+
+        assert!(h.stdout().contains(&format!("\t{title}\t")));
+    }
+
     #[tokio::test]
     async fn test_should_show_no_issues_message_when_empty() {
         let h = TestHarness::new().await;
@@ -339,4 +521,133 @@ mod tests {
         assert_eq!(urls.len(), 1);
         assert!(urls[0].contains("/issues"), "should open issues URL");
     }
+
+    #[test]
+    fn test_should_merge_search_with_filters() {
+        let args = ListArgs {
+            label: vec!["bug".into(), "good first issue".into()],
+            assignee: Some("@me".into()),
+            milestone: Some("v1.0".into()),
+            search: Some("crash in:title".into()),
+            ..default_args("owner/repo")
+        };
+        assert_eq!(
+            args.search_query("owner/repo"),
+            "repo:owner/repo is:issue state:open label:bug label:\"good first issue\" \
+             assignee:@me milestone:v1.0 crash in:title"
+        );
+
+        let args = ListArgs {
+            search: Some("is:closed sort:updated".into()),
+            ..default_args("owner/repo")
+        };
+        assert_eq!(
+            args.search_query("owner/repo"),
+            "repo:owner/repo is:issue is:closed sort:updated"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_filter_by_assignee_and_label() {
+        let h = TestHarness::new().await;
+        mock_graphql(
+            &h.server,
+            "UserCurrent",
+            serde_json::json!({ "data": { "viewer": { "login": "monalisa" } } }),
+        )
+        .await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_partial_json(serde_json::json!({
+                "variables": {
+                    "assignee": "monalisa",
+                    "labels": ["bug"],
+                    "states": ["OPEN"]
+                }
+            })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(graphql_issue_list_response(&[
+                    issue_fixture(7, "Assigned bug", "OPEN"),
+                ])),
+            )
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let args = ListArgs {
+            assignee: Some("@me".into()),
+            label: vec!["bug".into()],
+            ..default_args("owner/repo")
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert!(h.stdout().contains("Assigned bug"));
+    }
+
+    #[tokio::test]
+    async fn test_should_list_issues_from_search() {
+        let h = TestHarness::new().await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("IssueSearch"))
+            .and(body_partial_json(serde_json::json!({
+                "variables": {
+                    "query": "repo:owner/repo is:issue state:open milestone:v1 crash"
+                }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "search": {
+                        "issueCount": 1,
+                        "pageInfo": { "hasNextPage": false, "endCursor": null },
+                        "nodes": [issue_fixture(3, "Crash on start", "OPEN"), {}]
+                    }
+                }
+            })))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let args = ListArgs {
+            milestone: Some("v1".into()),
+            search: Some("crash".into()),
+            json: vec!["number".into(), "title".into()],
+            ..default_args("owner/repo")
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let out: Value = serde_json::from_str(&h.stdout()).unwrap();
+        assert_eq!(
+            out,
+            serde_json::json!([{ "number": 3, "title": "Crash on start" }])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_list_issues_of_origin_remote_without_repo_flag() {
+        let h = TestHarness::new()
+            .await
+            .with_git_remotes(&[("origin", "https://github.com/monalisa/octo.git")]);
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_partial_json(serde_json::json!({
+                "variables": { "owner": "monalisa", "name": "octo" }
+            })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(graphql_issue_list_response(&[
+                    issue_fixture(7, "From the remote", "OPEN"),
+                ])),
+            )
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let args = ListArgs {
+            repo: None,
+            ..default_args("ignored/repo")
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert!(h.stdout().contains("From the remote"));
+    }
 }
@@ -20,7 +20,7 @@ pub struct PinArgs {
 
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 }
 
 impl PinArgs {
@@ -32,8 +32,7 @@ impl PinArgs {
     /// found, the maximum number of pinned issues is reached, or the API
     /// request fails.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -135,7 +134,7 @@ mod tests {
 
         let args = PinArgs {
             number: 5,
-            repo: "owner/repo".to_string(),
+            repo: Some("owner/repo".to_string()),
         };
         args.run(&h.factory).await.unwrap();
 
@@ -18,7 +18,7 @@ use ghc_core::text;
 pub struct StatusArgs {
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Output JSON with specified fields.
     #[arg(long, value_delimiter = ',')]
@@ -31,6 +31,10 @@ pub struct StatusArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl StatusArgs {
@@ -42,8 +46,7 @@ impl StatusArgs {
     /// fails, or the authenticated user cannot be determined.
     #[allow(clippy::too_many_lines)]
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -106,15 +109,15 @@ impl StatusArgs {
             .context("failed to fetch issue status")?;
 
         // JSON output
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
-            let output = ghc_core::json::format_json_output(
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
+            ghc_core::json::output(
                 &data,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -258,10 +261,11 @@ mod tests {
         mock_graphql(&h.server, "IssueStatus", status_response()).await;
 
         let args = StatusArgs {
-            repo: "owner/repo".to_string(),
+            repo: Some("owner/repo".to_string()),
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -287,10 +291,11 @@ mod tests {
         mock_graphql(&h.server, "IssueStatus", status_response()).await;
 
         let args = StatusArgs {
-            repo: "owner/repo".to_string(),
+            repo: Some("owner/repo".to_string()),
             json: vec!["assigned".to_string()],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -6,14 +6,15 @@ use anyhow::{Context, Result};
 use clap::Args;
 use serde_json::Value;
 
-use ghc_core::text;
+use ghc_core::repo::Repo;
 use ghc_core::{ios_eprintln, ios_println};
 
 /// Transfer an issue to another repository.
 ///
-/// Both repositories must be owned by the same owner or organization.
 /// The issue will be moved along with its comments, labels, and
-/// assignees when possible.
+/// assignees when possible. Transfers to a repository owned by a
+/// different user or organization ask for confirmation unless `--yes`
+/// is given. The URL of the transferred issue is printed to stdout.
 #[derive(Debug, Args)]
 pub struct TransferArgs {
     /// Issue number to transfer.
@@ -22,113 +23,137 @@ pub struct TransferArgs {
 
     /// Source repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Destination repository in OWNER/REPO format.
     #[arg(value_name = "DESTINATION")]
     destination: String,
+
+    /// Skip the confirmation prompt for cross-organization transfers.
+    #[arg(short, long)]
+    yes: bool,
 }
 
+/// Query for the issue node ID and the pull requests that reference it.
+const ISSUE_QUERY: &str = r"
+    query IssueNodeId($owner: String!, $name: String!, $number: Int!) {
+      repository(owner: $owner, name: $name) {
+        issue(number: $number) {
+          id
+          timelineItems(itemTypes: [CROSS_REFERENCED_EVENT], first: 100) {
+            nodes {
+              ... on CrossReferencedEvent {
+                source { __typename }
+              }
+            }
+          }
+        }
+      }
+    }
+";
+
+/// Query for the destination repository node ID.
+const REPO_QUERY: &str = r"
+    query RepoNodeId($owner: String!, $name: String!) {
+      repository(owner: $owner, name: $name) {
+        id
+      }
+    }
+";
+
+/// Mutation moving the issue; returns the transferred issue.
+const TRANSFER_MUTATION: &str = r"
+    mutation TransferIssue($issueId: ID!, $repositoryId: ID!) {
+      transferIssue(input: {issueId: $issueId, repositoryId: $repositoryId}) {
+        issue {
+          number
+          url
+        }
+      }
+    }
+";
+
 impl TransferArgs {
     /// Run the issue transfer command.
     ///
     /// # Errors
     ///
     /// Returns an error if the repository format is invalid, the issue or
-    /// destination repository is not found, or the API request fails.
-    #[allow(clippy::too_many_lines)]
+    /// destination repository is not found, a cross-organization transfer is
+    /// not confirmed, or the API request fails.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid source repository format")?;
-        let dest_repo = ghc_core::repo::Repo::from_full_name(&self.destination)
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
+        let dest_repo = Repo::from_full_name(&self.destination)
             .context("invalid destination repository format")?;
+        if !repo.host().eq_ignore_ascii_case(dest_repo.host()) {
+            anyhow::bail!("cannot transfer an issue to a repository on a different host");
+        }
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
 
-        // Get the issue node ID
-        let mut issue_vars = HashMap::new();
-        issue_vars.insert("owner".to_string(), Value::String(repo.owner().to_string()));
-        issue_vars.insert("name".to_string(), Value::String(repo.name().to_string()));
-        issue_vars.insert(
-            "number".to_string(),
-            Value::Number(serde_json::Number::from(self.number)),
-        );
-
-        let issue_query = r"
-            query IssueNodeId($owner: String!, $name: String!, $number: Int!) {
-              repository(owner: $owner, name: $name) {
-                issue(number: $number) {
-                  id
-                }
-              }
-            }
-        ";
-
+        let vars = HashMap::from([
+            ("owner".to_string(), Value::String(repo.owner().to_string())),
+            ("name".to_string(), Value::String(repo.name().to_string())),
+            ("number".to_string(), Value::from(self.number)),
+        ]);
         let issue_data: Value = client
-            .graphql(issue_query, &issue_vars)
+            .graphql(ISSUE_QUERY, &vars)
             .await
             .context("failed to fetch issue")?;
-
-        let issue_id = issue_data
-            .pointer("/repository/issue/id")
-            .and_then(|id| id.as_str())
+        let issue = issue_data
+            .pointer("/repository/issue")
+            .filter(|issue| !issue.is_null())
             .ok_or_else(|| {
                 anyhow::anyhow!("issue #{} not found in {}", self.number, repo.full_name())
             })?;
+        let issue_id = issue.get("id").and_then(Value::as_str).unwrap_or("");
 
-        // Get the destination repository node ID
-        let mut dest_vars = HashMap::new();
-        dest_vars.insert(
-            "owner".to_string(),
-            Value::String(dest_repo.owner().to_string()),
-        );
-        dest_vars.insert(
-            "name".to_string(),
-            Value::String(dest_repo.name().to_string()),
-        );
-
-        let dest_query = r"
-            query RepoNodeId($owner: String!, $name: String!) {
-              repository(owner: $owner, name: $name) {
-                id
-              }
-            }
-        ";
-
+        let vars = HashMap::from([
+            (
+                "owner".to_string(),
+                Value::String(dest_repo.owner().to_string()),
+            ),
+            (
+                "name".to_string(),
+                Value::String(dest_repo.name().to_string()),
+            ),
+        ]);
         let dest_data: Value = client
-            .graphql(dest_query, &dest_vars)
+            .graphql(REPO_QUERY, &vars)
             .await
             .context("failed to fetch destination repository")?;
-
         let dest_id = dest_data
             .pointer("/repository/id")
-            .and_then(|id| id.as_str())
+            .and_then(Value::as_str)
             .ok_or_else(|| {
-                anyhow::anyhow!("destination repository {} not found", dest_repo.full_name(),)
+                anyhow::anyhow!("destination repository {} not found", dest_repo.full_name())
             })?;
 
-        // Transfer the issue via GraphQL mutation
-        let mutation = r"
-            mutation TransferIssue($issueId: ID!, $repositoryId: ID!) {
-              transferIssue(input: {issueId: $issueId, repositoryId: $repositoryId}) {
-                issue {
-                  number
-                  url
-                }
-              }
-            }
-        ";
+        if !repo.owner().eq_ignore_ascii_case(dest_repo.owner()) {
+            self.confirm_cross_owner(factory, &repo, &dest_repo)?;
+        }
 
-        let mut mutation_vars = HashMap::new();
-        mutation_vars.insert("issueId".to_string(), Value::String(issue_id.to_string()));
-        mutation_vars.insert(
-            "repositoryId".to_string(),
-            Value::String(dest_id.to_string()),
-        );
+        let pr_references = count_pr_references(issue);
+        if pr_references > 0 {
+            ios_eprintln!(
+                ios,
+                "{} Issue #{} is referenced by {pr_references} pull request(s); \
+                 cross-references may break after the transfer",
+                cs.warning_icon(),
+                self.number,
+            );
+        }
 
+        let vars = HashMap::from([
+            ("issueId".to_string(), Value::String(issue_id.to_string())),
+            (
+                "repositoryId".to_string(),
+                Value::String(dest_id.to_string()),
+            ),
+        ]);
         let result: Value = client
-            .graphql(mutation, &mutation_vars)
+            .graphql(TRANSFER_MUTATION, &vars)
             .await
             .context("failed to transfer issue")?;
 
@@ -152,11 +177,60 @@ impl TransferArgs {
         );
 
         if !new_url.is_empty() {
-            ios_println!(ios, "{}", text::display_url(new_url));
+            ios_println!(ios, "{new_url}");
         }
 
         Ok(())
     }
+
+    /// Ask before moving the issue to a repository with a different owner.
+    fn confirm_cross_owner(
+        &self,
+        factory: &crate::factory::Factory,
+        repo: &Repo,
+        dest_repo: &Repo,
+    ) -> Result<()> {
+        if self.yes {
+            return Ok(());
+        }
+        if !factory.io.can_prompt() {
+            anyhow::bail!(
+                "--yes required to transfer an issue to another owner in non-interactive mode"
+            );
+        }
+        let confirmed = factory
+            .prompter()
+            .confirm(
+                &format!(
+                    "Transfer issue #{} from {} to {}, which belongs to a different owner?",
+                    self.number,
+                    repo.full_name(),
+                    dest_repo.full_name(),
+                ),
+                false,
+            )
+            .context("failed to read confirmation")?;
+        if !confirmed {
+            anyhow::bail!("transfer cancelled");
+        }
+        Ok(())
+    }
+}
+
+/// Number of pull requests that cross-reference the issue.
+fn count_pr_references(issue: &Value) -> usize {
+    issue
+        .pointer("/timelineItems/nodes")
+        .and_then(Value::as_array)
+        .map_or(0, |nodes| {
+            nodes
+                .iter()
+                .filter(|node| {
+                    node.pointer("/source/__typename").and_then(Value::as_str)
+                        == Some("PullRequest")
+                })
+                .count()
+        })
 }
 
 #[cfg(test)]
@@ -164,23 +238,32 @@ mod tests {
     use super::*;
     use crate::test_helpers::{TestHarness, mock_graphql};
 
-    #[tokio::test]
-    async fn test_should_transfer_issue() {
-        let h = TestHarness::new().await;
-        // Mock fetching the issue node ID
+    fn transfer_args(destination: &str) -> TransferArgs {
+        TransferArgs {
+            number: 42,
+            repo: Some("owner/repo".to_string()),
+            destination: destination.to_string(),
+            yes: false,
+        }
+    }
+
+    async fn mount_transfer(h: &TestHarness, references: &[&str], url: &str) {
+        let nodes: Vec<Value> = references
+            .iter()
+            .map(|kind| serde_json::json!({ "source": { "__typename": kind } }))
+            .collect();
         mock_graphql(
             &h.server,
             "IssueNodeId",
             serde_json::json!({
                 "data": {
                     "repository": {
-                        "issue": { "id": "I_abc123" }
+                        "issue": { "id": "I_abc123", "timelineItems": { "nodes": nodes } }
                     }
                 }
             }),
         )
         .await;
-        // Mock fetching the destination repo node ID
         mock_graphql(
             &h.server,
             "RepoNodeId",
@@ -191,39 +274,104 @@ mod tests {
             }),
         )
         .await;
-        // Mock the transfer mutation
         mock_graphql(
             &h.server,
             "TransferIssue",
             serde_json::json!({
                 "data": {
                     "transferIssue": {
-                        "issue": {
-                            "number": 99,
-                            "url": "https://github.com/owner/other-repo/issues/99"
-                        }
+                        "issue": { "number": 99, "url": url }
                     }
                 }
             }),
         )
         .await;
+    }
 
-        let args = TransferArgs {
-            number: 42,
-            repo: "owner/repo".to_string(),
-            destination: "owner/other-repo".to_string(),
-        };
-        args.run(&h.factory).await.unwrap();
+    #[tokio::test]
+    async fn test_should_transfer_issue() {
+        let h = TestHarness::new().await;
+        mount_transfer(&h, &[], "https://github.com/owner/other-repo/issues/99").await;
+
+        transfer_args("owner/other-repo")
+            .run(&h.factory)
+            .await
+            .unwrap();
 
         let err = h.stderr();
         assert!(
             err.contains("Transferred issue #42"),
             "should show transferred message"
         );
-        let out = h.stdout();
+        assert_eq!(
+            h.stdout(),
+            "https://github.com/owner/other-repo/issues/99\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_require_yes_across_owners_when_not_interactive() {
+        let h = TestHarness::new().await;
+        mount_transfer(&h, &[], "https://github.com/acme/repo/issues/99").await;
+
+        let err = transfer_args("acme/repo")
+            .run(&h.factory)
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("--yes required"), "{err}");
+        assert!(h.stdout().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_should_transfer_across_owners_with_yes() {
+        let h = TestHarness::new().await;
+        mount_transfer(&h, &[], "https://github.com/acme/repo/issues/99").await;
+
+        let args = TransferArgs {
+            yes: true,
+            ..transfer_args("acme/repo")
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert_eq!(h.stdout(), "https://github.com/acme/repo/issues/99\n");
+    }
+
+    #[tokio::test]
+    async fn test_should_cancel_cross_owner_transfer_when_declined() {
+        let mut h = TestHarness::new().await;
+        h.factory.io.set_stdin_tty(true);
+        h.factory.io.set_stdout_tty(true);
+        h.factory.io.set_never_prompt(false);
+        h.prompter.confirm_answers.lock().unwrap().push(false);
+        mount_transfer(&h, &[], "https://github.com/acme/repo/issues/99").await;
+
+        let err = transfer_args("acme/repo")
+            .run(&h.factory)
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("transfer cancelled"));
+    }
+
+    #[tokio::test]
+    async fn test_should_warn_about_pull_request_references() {
+        let h = TestHarness::new().await;
+        mount_transfer(
+            &h,
+            &["PullRequest", "Issue", "PullRequest"],
+            "https://github.com/owner/other-repo/issues/99",
+        )
+        .await;
+
+        transfer_args("owner/other-repo")
+            .run(&h.factory)
+            .await
+            .unwrap();
+
         assert!(
-            out.contains("github.com/owner/other-repo/issues/99"),
-            "should contain new URL"
+            h.stderr()
+                .contains("referenced by 2 pull request(s); cross-references may break")
         );
     }
 }
@@ -16,7 +16,7 @@ pub struct UnlockArgs {
 
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 }
 
 impl UnlockArgs {
@@ -27,8 +27,7 @@ impl UnlockArgs {
     /// Returns an error if the repository format is invalid, the issue is not
     /// found, or the API request fails.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -68,7 +67,7 @@ mod tests {
     fn default_args(number: i32, repo: &str) -> UnlockArgs {
         UnlockArgs {
             number,
-            repo: repo.to_string(),
+            repo: Some(repo.to_string()),
         }
     }
 
@@ -17,7 +17,7 @@ pub struct UnpinArgs {
 
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 }
 
 impl UnpinArgs {
@@ -28,8 +28,7 @@ impl UnpinArgs {
     /// Returns an error if the repository format is invalid, the issue is not
     /// found, or the API request fails.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -131,7 +130,7 @@ mod tests {
 
         let args = UnpinArgs {
             number: 5,
-            repo: "owner/repo".to_string(),
+            repo: Some("owner/repo".to_string()),
         };
         args.run(&h.factory).await.unwrap();
 
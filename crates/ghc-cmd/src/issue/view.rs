@@ -17,7 +17,7 @@ pub struct ViewArgs {
 
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Open the issue in the browser.
     #[arg(short, long)]
@@ -38,6 +38,10 @@ pub struct ViewArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl ViewArgs {
@@ -49,8 +53,7 @@ impl ViewArgs {
     /// fails, or the issue is not found.
     #[allow(clippy::too_many_lines)]
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
 
         if self.web {
             let url = format!(
@@ -92,18 +95,18 @@ impl ViewArgs {
             })?;
 
         // JSON output with field filtering, jq, or template
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let mut issue_owned = issue.clone();
             ghc_core::json::normalize_graphql_connections(&mut issue_owned);
             ghc_core::json::normalize_author(&mut issue_owned);
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &issue_owned,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -183,6 +186,10 @@ impl ViewArgs {
             }
         );
         ios_println!(ios, "comments:\t{comment_count}");
+        let (tasks_done, tasks_total) = ghc_core::markdown::count_tasks(body);
+        if tasks_total > 0 {
+            ios_println!(ios, "tasks:\t{tasks_done}/{tasks_total}");
+        }
         ios_println!(
             ios,
             "projects:\t{}",
@@ -197,8 +204,10 @@ impl ViewArgs {
         ios_println!(ios, "--");
         if body.is_empty() {
             ios_println!(ios, "{}", cs.gray("No description provided."));
+        } else if ios.is_stdout_tty() && ios.is_accessible() {
+            ios_println!(ios, "{}", ghc_core::markdown::render_plain(body));
         } else if ios.is_stdout_tty() {
-            let rendered = ghc_core::markdown::render(body, ios.terminal_width());
+            let rendered = ghc_core::markdown::render(body, ios.terminal_width(), &cs);
             ios_print!(ios, "{rendered}");
         } else {
             ios_println!(ios, "{body}");
@@ -289,12 +298,13 @@ mod tests {
     fn default_args(number: i32, repo: &str) -> ViewArgs {
         ViewArgs {
             number,
-            repo: repo.to_string(),
+            repo: Some(repo.to_string()),
             web: false,
             comments: false,
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         }
     }
 
@@ -325,6 +335,30 @@ mod tests {
             "should contain key-value author: {out}"
         );
         assert!(out.contains("Issue body text"), "should contain issue body");
+        assert!(
+            !out.contains("tasks:"),
+            "should omit tasks without a task list"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_show_task_progress() {
+        let h = TestHarness::new().await;
+        mock_graphql(
+            &h.server,
+            "repository",
+            view_issue_response(7, "Plan", "OPEN", "- [x] a\n- [ ] b\n- [x] c"),
+        )
+        .await;
+
+        let args = default_args(7, "owner/repo");
+        args.run(&h.factory).await.unwrap();
+
+        let out = h.stdout();
+        assert!(
+            out.lines().any(|l| l == "tasks:\t2/3"),
+            "should show task count: {out}"
+        );
     }
 
     #[tokio::test]
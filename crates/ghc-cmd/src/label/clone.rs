@@ -1,13 +1,19 @@
 //! `ghc label clone` command.
 
+use std::collections::HashMap;
+
 use anyhow::{Context, Result};
 use clap::Args;
+use ghc_api::client::Client;
 use serde_json::Value;
 
 use ghc_core::ios_eprintln;
 use ghc_core::repo::Repo;
 
 /// Clone labels from another repository.
+///
+/// Labels that already exist in the target (compared case-insensitively) are
+/// skipped, or updated with `--force` when their color or description differ.
 #[derive(Debug, Args)]
 pub struct CloneArgs {
     /// Source repository to clone labels from (OWNER/REPO).
@@ -18,9 +24,21 @@ pub struct CloneArgs {
     #[arg(short = 'R', long)]
     repo: Option<String>,
 
-    /// Overwrite existing labels with the same name.
+    /// Overwrite existing labels whose color or description differ.
     #[arg(long)]
     force: bool,
+
+    /// Print the labels that would be created or updated without changing them.
+    #[arg(long)]
+    dry_run: bool,
+}
+
+/// What cloning one label does to the target repository.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LabelAction {
+    Create,
+    Update,
+    Skip,
 }
 
 impl CloneArgs {
@@ -28,28 +46,18 @@ impl CloneArgs {
     ///
     /// # Errors
     ///
-    /// Returns an error if the labels cannot be cloned.
+    /// Returns an error if the labels cannot be listed, or any label fails to
+    /// be created or updated.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
         let source =
             Repo::from_full_name(&self.source).context("invalid source repository format")?;
-        let target = self
-            .repo
-            .as_deref()
-            .ok_or_else(|| anyhow::anyhow!("target repository required (use -R OWNER/REPO)"))?;
-        let target = Repo::from_full_name(target).context("invalid target repository format")?;
+        let target = factory.resolve_repo(self.repo.as_deref()).await?;
 
         let client = factory.api_client(source.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
 
-        // Fetch labels from source
-        let source_path = format!(
-            "repos/{}/{}/labels?per_page=100",
-            source.owner(),
-            source.name(),
-        );
-        let labels: Vec<Value> = client
-            .rest(reqwest::Method::GET, &source_path, None)
+        let labels = fetch_labels(&client, &source)
             .await
             .context("failed to list labels from source repository")?;
 
@@ -59,111 +67,239 @@ impl CloneArgs {
         }
 
         let target_client = factory.api_client(target.host())?;
+        let existing: HashMap<String, Value> = fetch_labels(&target_client, &target)
+            .await
+            .context("failed to list labels from target repository")?
+            .into_iter()
+            .map(|label| (label_field(&label, "name").to_lowercase(), label))
+            .collect();
 
+        let (mut created, mut updated, mut skipped, mut failed) = (0, 0, 0, 0);
         for label in &labels {
-            let name = label.get("name").and_then(Value::as_str).unwrap_or("");
-            let color = label.get("color").and_then(Value::as_str).unwrap_or("");
-            let description = label
-                .get("description")
-                .and_then(Value::as_str)
-                .unwrap_or("");
-
-            let body = serde_json::json!({
-                "name": name,
-                "color": color,
-                "description": description,
-            });
-
-            let create_path = format!("repos/{}/{}/labels", target.owner(), target.name(),);
-
-            match target_client
-                .rest::<Value>(reqwest::Method::POST, &create_path, Some(&body))
-                .await
-            {
-                Ok(_) => {
-                    ios_eprintln!(ios, "{} Cloned label {}", cs.success_icon(), cs.bold(name));
-                }
-                Err(e) => {
-                    if self.force {
-                        // Try to update existing label
-                        let encoded = ghc_core::text::percent_encode(name);
-                        let update_path = format!(
-                            "repos/{}/{}/labels/{encoded}",
-                            target.owner(),
-                            target.name(),
-                        );
-                        match target_client
-                            .rest::<Value>(reqwest::Method::PATCH, &update_path, Some(&body))
-                            .await
-                        {
-                            Ok(_) => {
-                                ios_eprintln!(
-                                    ios,
-                                    "{} Updated label {}",
-                                    cs.warning_icon(),
-                                    cs.bold(name),
-                                );
-                            }
-                            Err(update_err) => {
-                                ios_eprintln!(
-                                    ios,
-                                    "{} Failed to clone label {}: {update_err}",
-                                    cs.error_icon(),
-                                    name,
-                                );
-                            }
-                        }
-                    } else {
-                        ios_eprintln!(
-                            ios,
-                            "{} Skipped label {} (already exists): {e}",
-                            cs.warning_icon(),
-                            name,
-                        );
-                    }
-                }
+            let name = label_field(label, "name");
+            let current = existing.get(&name.to_lowercase());
+            let action = self.plan(label, current);
+            if action == LabelAction::Skip {
+                skipped += 1;
+                continue;
+            }
+            if self.dry_run {
+                let verb = if action == LabelAction::Create {
+                    "create"
+                } else {
+                    "update"
+                };
+                ios_eprintln!(ios, "Would {verb} label {}", cs.bold(name));
+            } else if let Err(e) = apply(&target_client, &target, label, current).await {
+                ios_eprintln!(ios, "{} Failed to clone label {name}: {e}", cs.error_icon());
+                failed += 1;
+                continue;
+            }
+            if action == LabelAction::Create {
+                created += 1;
+            } else {
+                updated += 1;
             }
         }
 
+        let summary = format!("created {created}, updated {updated}, skipped {skipped}");
+        if self.dry_run {
+            ios_eprintln!(
+                ios,
+                "Dry run: cloning labels from {} to {} would have {summary}",
+                source.full_name(),
+                target.full_name(),
+            );
+        } else {
+            ios_eprintln!(
+                ios,
+                "{} Cloned labels from {} to {}: {summary}",
+                cs.success_icon(),
+                source.full_name(),
+                target.full_name(),
+            );
+        }
+
+        if failed > 0 {
+            anyhow::bail!("failed to clone {failed} label(s)");
+        }
         Ok(())
     }
+
+    /// Decide what to do with `label` given the matching target label, if any.
+    fn plan(&self, label: &Value, current: Option<&Value>) -> LabelAction {
+        let Some(current) = current else {
+            return LabelAction::Create;
+        };
+        let differs = !label_field(label, "color")
+            .eq_ignore_ascii_case(label_field(current, "color"))
+            || label_field(label, "description") != label_field(current, "description")
+            || label_field(label, "name") != label_field(current, "name");
+        if self.force && differs {
+            LabelAction::Update
+        } else {
+            LabelAction::Skip
+        }
+    }
+}
+
+/// A string field of a label, or `""` if missing or null.
+fn label_field<'a>(label: &'a Value, key: &str) -> &'a str {
+    label.get(key).and_then(Value::as_str).unwrap_or("")
+}
+
+/// Fetch every label in `repo`.
+async fn fetch_labels(client: &Client, repo: &Repo) -> Result<Vec<Value>> {
+    let path = format!("repos/{}/{}/labels?per_page=100", repo.owner(), repo.name());
+    Ok(client.rest_paginate(reqwest::Method::GET, &path).await?)
+}
+
+/// Create `label` in `target`, or update `current` to match it.
+async fn apply(
+    client: &Client,
+    target: &Repo,
+    label: &Value,
+    current: Option<&Value>,
+) -> Result<()> {
+    let name = label_field(label, "name");
+    let mut body = serde_json::json!({
+        "color": label_field(label, "color"),
+        "description": label_field(label, "description"),
+    });
+    if let Some(current) = current {
+        let encoded = ghc_core::text::percent_encode(label_field(current, "name"));
+        let path = format!(
+            "repos/{}/{}/labels/{encoded}",
+            target.owner(),
+            target.name()
+        );
+        body["new_name"] = Value::String(name.to_string());
+        client
+            .rest::<Value>(reqwest::Method::PATCH, &path, Some(&body))
+            .await?;
+    } else {
+        let path = format!("repos/{}/{}/labels", target.owner(), target.name());
+        body["name"] = Value::String(name.to_string());
+        client
+            .rest::<Value>(reqwest::Method::POST, &path, Some(&body))
+            .await?;
+    }
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use crate::test_helpers::{TestHarness, mock_rest_get, mock_rest_post};
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, ResponseTemplate};
 
-    #[tokio::test]
-    async fn test_should_clone_labels() {
-        let h = TestHarness::new().await;
+    use crate::test_helpers::{TestHarness, mock_rest_get};
+
+    fn clone_args() -> CloneArgs {
+        CloneArgs {
+            source: "source/repo".into(),
+            repo: Some("target/repo".into()),
+            force: false,
+            dry_run: false,
+        }
+    }
+
+    async fn mount_labels(h: &TestHarness) {
         mock_rest_get(
             &h.server,
             "/repos/source/repo/labels",
             serde_json::json!([
                 {"name": "bug", "color": "d73a4a", "description": "Something isn't working"},
-                {"name": "enhancement", "color": "a2eeef", "description": "New feature"}
+                {"name": "enhancement", "color": "a2eeef", "description": "New feature"},
+                {"name": "docs", "color": "0075ca", "description": "Documentation"}
             ]),
         )
         .await;
-        mock_rest_post(
+        mock_rest_get(
             &h.server,
             "/repos/target/repo/labels",
-            201,
-            serde_json::json!({"name": "bug"}),
+            serde_json::json!([
+                {"name": "Bug", "color": "ff0000", "description": "Broken"},
+                {"name": "docs", "color": "0075CA", "description": "Documentation"}
+            ]),
         )
         .await;
+    }
+
+    async fn expect_writes(h: &TestHarness, creates: u64, updates: u64) {
+        Mock::given(method("POST"))
+            .and(path("/repos/target/repo/labels"))
+            .and(body_partial_json(
+                serde_json::json!({"name": "enhancement"}),
+            ))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({})))
+            .expect(creates)
+            .mount(&h.server)
+            .await;
+        Mock::given(method("PATCH"))
+            .and(path("/repos/target/repo/labels/Bug"))
+            .and(body_partial_json(
+                serde_json::json!({"new_name": "bug", "color": "d73a4a"}),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(updates)
+            .mount(&h.server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_should_clone_labels() {
+        let h = TestHarness::new().await;
+        mount_labels(&h).await;
+        expect_writes(&h, 1, 0).await;
+
+        clone_args().run(&h.factory).await.unwrap();
+
+        let err = h.stderr();
+        assert!(
+            err.contains(
+                "Cloned labels from source/repo to target/repo: created 1, updated 0, skipped 2"
+            ),
+            "{err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_update_differing_labels_with_force() {
+        let h = TestHarness::new().await;
+        mount_labels(&h).await;
+        expect_writes(&h, 1, 1).await;
 
         let args = CloneArgs {
-            source: "source/repo".into(),
-            repo: Some("target/repo".into()),
-            force: false,
+            force: true,
+            ..clone_args()
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert!(h.stderr().contains("created 1, updated 1, skipped 1"));
+    }
+
+    #[tokio::test]
+    async fn test_should_preview_changes_in_dry_run() {
+        let h = TestHarness::new().await;
+        mount_labels(&h).await;
+        expect_writes(&h, 0, 0).await;
+
+        let args = CloneArgs {
+            force: true,
+            dry_run: true,
+            ..clone_args()
         };
         args.run(&h.factory).await.unwrap();
 
         let err = h.stderr();
-        assert!(err.contains("Cloned label"));
+        assert!(err.contains("Would update label bug\n"), "{err}");
+        assert!(err.contains("Would create label enhancement\n"), "{err}");
+        assert!(
+            err.contains("would have created 1, updated 1, skipped 1"),
+            "{err}"
+        );
     }
 
     #[tokio::test]
@@ -171,9 +307,8 @@ mod tests {
         let h = TestHarness::new().await;
 
         let args = CloneArgs {
-            source: "source/repo".into(),
             repo: None,
-            force: false,
+            ..clone_args()
         };
         let result = args.run(&h.factory).await;
         assert!(result.is_err());
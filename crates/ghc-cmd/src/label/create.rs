@@ -5,7 +5,6 @@ use clap::Args;
 use serde_json::Value;
 
 use ghc_core::ios_eprintln;
-use ghc_core::repo::Repo;
 
 /// Create a label.
 #[derive(Debug, Args)]
@@ -38,11 +37,7 @@ impl CreateArgs {
     ///
     /// Returns an error if the label cannot be created.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = self
-            .repo
-            .as_deref()
-            .ok_or_else(|| anyhow::anyhow!("repository argument required (use -R OWNER/REPO)"))?;
-        let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
 
         let mut body = serde_json::json!({
@@ -4,9 +4,7 @@ use anyhow::{Context, Result};
 use clap::Args;
 use serde_json::Value;
 
-use ghc_core::repo::Repo;
-use ghc_core::table::TablePrinter;
-use ghc_core::text;
+use ghc_core::table::{FieldOpts, TablePrinter};
 use ghc_core::{ios_eprintln, ios_println};
 
 /// List labels in a repository.
@@ -31,6 +29,10 @@ pub struct ListArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl ListArgs {
@@ -40,11 +42,7 @@ impl ListArgs {
     ///
     /// Returns an error if the labels cannot be listed.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = self
-            .repo
-            .as_deref()
-            .ok_or_else(|| anyhow::anyhow!("repository argument required (use -R OWNER/REPO)"))?;
-        let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
 
@@ -68,7 +66,7 @@ impl ListArgs {
         });
 
         // JSON output
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let normalized: Vec<Value> = labels
                 .iter()
                 .cloned()
@@ -78,14 +76,14 @@ impl ListArgs {
                 })
                 .collect();
             let arr = Value::Array(normalized);
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &arr,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -107,11 +105,16 @@ impl ListArgs {
                 .unwrap_or("");
             let color = label.get("color").and_then(Value::as_str).unwrap_or("");
 
-            tp.add_row(vec![
-                cs.bold(name),
-                text::truncate(description, 50),
-                format!("#{color}"),
-            ]);
+            tp.add_field(&cs.hex(color, name));
+            tp.add_field_with_opts(
+                description,
+                FieldOpts {
+                    truncate: true,
+                    max_width: Some(50),
+                },
+            );
+            tp.add_field(&format!("#{color}"));
+            tp.end_row();
         }
 
         let output = tp.render();
@@ -164,6 +167,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -173,6 +177,35 @@ mod tests {
         assert!(out.contains("#d73a4a"));
     }
 
+    #[tokio::test]
+    async fn test_should_print_clean_tab_separated_labels_when_piped() {
+        let h = TestHarness::new().await;
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/labels",
+            serde_json::json!([
+                {"name": "bug", "color": "d73a4a", "description": "Something isn't working"},
+                {"name": "docs", "color": "0075ca", "description": null}
+            ]),
+        )
+        .await;
+
+        let args = ListArgs {
+            repo: Some("owner/repo".into()),
+            limit: 30,
+            json: vec![],
+            jq: None,
+            template: None,
+            yaml: false,
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert_eq!(
+            h.stdout(),
+            "bug\tSomething isn't working\t#d73a4a\ndocs\t\t#0075ca\n"
+        );
+    }
+
     #[tokio::test]
     async fn test_should_output_labels_as_json() {
         let h = TestHarness::new().await;
@@ -191,6 +224,7 @@ mod tests {
             json: vec!["name".into()],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -209,8 +243,37 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         let result = args.run(&h.factory).await;
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn test_should_output_labels_as_yaml() {
+        let h = TestHarness::new().await;
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/labels",
+            serde_json::json!([
+                {"name": "bug", "color": "d73a4a", "description": "Something isn't working"}
+            ]),
+        )
+        .await;
+
+        let args = ListArgs {
+            repo: Some("owner/repo".into()),
+            limit: 30,
+            json: vec!["name".into(), "color".into()],
+            jq: None,
+            template: None,
+            yaml: true,
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let out = h.stdout();
+        assert!(out.contains("name: bug"), "should output YAML: {out}");
+        assert!(out.contains("color: d73a4a"));
+        assert!(!out.contains("description"));
+    }
 }
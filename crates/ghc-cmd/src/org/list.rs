@@ -4,9 +4,29 @@ use anyhow::{Context, Result};
 use clap::Args;
 use serde_json::Value;
 
+use ghc_api::client::page_items;
 use ghc_core::table::TablePrinter;
 use ghc_core::{ios_eprintln, ios_println};
 
+/// Organization membership role.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OrgRole {
+    /// Regular organization member.
+    Member,
+    /// Organization owner.
+    Admin,
+}
+
+impl OrgRole {
+    /// The role name used by the memberships API.
+    fn as_api_str(self) -> &'static str {
+        match self {
+            Self::Member => "member",
+            Self::Admin => "admin",
+        }
+    }
+}
+
 /// List organizations for the authenticated user.
 #[derive(Debug, Args)]
 pub struct ListArgs {
@@ -14,6 +34,10 @@ pub struct ListArgs {
     #[arg(short = 'L', long, default_value = "30")]
     limit: u32,
 
+    /// Only list organizations where you have this role.
+    #[arg(long, value_enum)]
+    role: Option<OrgRole>,
+
     /// Output JSON with specified fields.
     #[arg(long, value_delimiter = ',')]
     json: Vec<String>,
@@ -25,6 +49,10 @@ pub struct ListArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl ListArgs {
@@ -34,26 +62,22 @@ impl ListArgs {
     ///
     /// Returns an error if the organizations cannot be listed.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
 
-        let path = format!("user/orgs?per_page={}", self.limit.min(100));
-        let orgs: Vec<Value> = client
-            .rest(reqwest::Method::GET, &path, None)
-            .await
-            .context("failed to list organizations")?;
+        let orgs = self.fetch_orgs(&client).await?;
 
         // JSON output
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let arr = Value::Array(orgs.clone());
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &arr,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -84,6 +108,35 @@ impl ListArgs {
 
         Ok(())
     }
+
+    /// Fetch up to `--limit` organizations, following `Link` pages.
+    ///
+    /// With `--role`, active memberships are listed instead and filtered by
+    /// role, yielding each membership's organization.
+    async fn fetch_orgs(&self, client: &ghc_api::client::Client) -> Result<Vec<Value>> {
+        let per_page = self.limit.min(100);
+        let path = match self.role {
+            Some(_) => format!("user/memberships/orgs?state=active&per_page={per_page}"),
+            None => format!("user/orgs?per_page={per_page}"),
+        };
+        let orgs = client
+            .rest_paginate_limit(&path, Some(self.limit as usize), |page| {
+                let items = page_items(page, None);
+                match self.role {
+                    Some(role) => items
+                        .into_iter()
+                        .filter(|m| {
+                            m.get("role").and_then(Value::as_str) == Some(role.as_api_str())
+                        })
+                        .filter_map(|mut m| m.get_mut("organization").map(Value::take))
+                        .collect(),
+                    None => items,
+                }
+            })
+            .await
+            .context("failed to list organizations")?;
+        Ok(orgs)
+    }
 }
 
 #[cfg(test)]
@@ -107,9 +160,11 @@ mod tests {
 
         let args = ListArgs {
             limit: 30,
+            role: None,
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -133,9 +188,11 @@ mod tests {
 
         let args = ListArgs {
             limit: 30,
+            role: None,
             json: vec!["login".into()],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -143,4 +200,79 @@ mod tests {
         assert!(out.contains("\"login\""));
         assert!(out.contains("\"my-org\""));
     }
+
+    fn json_args(limit: u32, role: Option<OrgRole>) -> ListArgs {
+        ListArgs {
+            limit,
+            role,
+            json: vec!["login".into()],
+            jq: None,
+            template: None,
+            yaml: false,
+        }
+    }
+
+    fn logins(out: &str) -> Vec<String> {
+        let orgs: Vec<Value> = serde_json::from_str(out).unwrap();
+        orgs.iter()
+            .map(|o| o["login"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_should_filter_orgs_by_role() {
+        let h = TestHarness::new().await;
+        mock_rest_get(
+            &h.server,
+            "/user/memberships/orgs",
+            serde_json::json!([
+                {"role": "admin", "organization": {"login": "owned-org"}},
+                {"role": "member", "organization": {"login": "joined-org"}},
+                {"role": "admin", "organization": {"login": "other-owned"}}
+            ]),
+        )
+        .await;
+
+        json_args(30, Some(OrgRole::Admin))
+            .run(&h.factory)
+            .await
+            .unwrap();
+
+        assert_eq!(logins(&h.stdout()), ["owned-org", "other-owned"]);
+    }
+
+    #[tokio::test]
+    async fn test_should_paginate_orgs_up_to_limit() {
+        use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let h = TestHarness::new().await;
+        let next = format!(
+            "<{}/user/orgs?per_page=3&page=2>; rel=\"next\"",
+            h.server.uri()
+        );
+        Mock::given(method("GET"))
+            .and(path("/user/orgs"))
+            .and(query_param_is_missing("page"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("link", next.as_str())
+                    .set_body_json(serde_json::json!([{"login": "a"}, {"login": "b"}])),
+            )
+            .mount(&h.server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/user/orgs"))
+            .and(query_param("page", "2"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!([{"login": "c"}, {"login": "d"}])),
+            )
+            .mount(&h.server)
+            .await;
+
+        json_args(3, None).run(&h.factory).await.unwrap();
+
+        assert_eq!(logins(&h.stdout()), ["a", "b", "c"]);
+    }
 }
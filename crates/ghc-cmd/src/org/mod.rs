@@ -3,6 +3,7 @@
 //! Manage GitHub organizations.
 
 pub mod list;
+pub mod view;
 
 use clap::Subcommand;
 
@@ -12,6 +13,8 @@ pub enum OrgCommand {
     /// List organizations for the authenticated user.
     #[command(alias = "ls")]
     List(list::ListArgs),
+    /// View an organization's profile.
+    View(view::ViewArgs),
 }
 
 impl OrgCommand {
@@ -23,6 +26,7 @@ impl OrgCommand {
     pub async fn run(&self, factory: &crate::factory::Factory) -> anyhow::Result<()> {
         match self {
             Self::List(args) => args.run(factory).await,
+            Self::View(args) => args.run(factory).await,
         }
     }
 }
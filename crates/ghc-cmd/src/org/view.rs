@@ -0,0 +1,294 @@
+//! `ghc org view` command.
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use clap::Args;
+use serde_json::Value;
+
+use ghc_core::iostreams::IOStreams;
+use ghc_core::{instance, ios_eprintln, ios_println, text};
+
+/// GraphQL query for the member count, which the REST profile omits.
+const MEMBERS_QUERY: &str = r"query OrganizationMembers($login: String!) {
+  organization(login: $login) { membersWithRole { totalCount } }
+}";
+
+/// View an organization's profile.
+///
+/// With `--web`, open the organization in a web browser instead.
+#[derive(Debug, Args)]
+pub struct ViewArgs {
+    /// Organization to view, as OWNER or HOST/OWNER.
+    #[arg(value_name = "ORGANIZATION")]
+    org: String,
+
+    /// Open in web browser.
+    #[arg(short, long)]
+    web: bool,
+
+    /// Output JSON with specified fields.
+    #[arg(long, value_delimiter = ',')]
+    json: Vec<String>,
+
+    /// Filter JSON output using a jq expression.
+    #[arg(short = 'q', long)]
+    jq: Option<String>,
+
+    /// Format JSON output using a Go template.
+    #[arg(short = 't', long)]
+    template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
+}
+
+impl ViewArgs {
+    /// Run the org view command.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the organization cannot be fetched.
+    pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
+        let (host, login) = match self.org.split_once('/') {
+            Some((host, login)) => (instance::normalize_hostname(host), login),
+            None => (factory.default_host(), self.org.as_str()),
+        };
+        if login.is_empty() || login.contains('/') {
+            anyhow::bail!(
+                "expected an organization as OWNER or HOST/OWNER, got {:?}",
+                self.org
+            );
+        }
+        let ios = &factory.io;
+
+        if self.web {
+            let url = format!("{}{login}", instance::host_prefix(&host));
+            if ios.is_stdout_tty() {
+                ios_eprintln!(ios, "Opening {} in your browser.", text::display_url(&url));
+            }
+            factory.browser().open(&url)?;
+            return Ok(());
+        }
+
+        let client = factory.api_client(&host)?;
+        let mut org: Value = client
+            .rest(reqwest::Method::GET, &format!("orgs/{login}"), None)
+            .await
+            .with_context(|| format!("failed to fetch organization {login}"))?;
+
+        let vars = HashMap::from([("login".to_string(), Value::String(login.to_string()))]);
+        let members = client
+            .graphql::<Value>(MEMBERS_QUERY, &vars)
+            .await
+            .ok()
+            .and_then(|data| {
+                data.pointer("/organization/membersWithRole/totalCount")?
+                    .as_i64()
+            });
+        if let (Some(count), Some(map)) = (members, org.as_object_mut()) {
+            map.insert("members_count".to_string(), count.into());
+        }
+
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
+            ghc_core::json::output(
+                &org,
+                &self.json,
+                self.jq.as_deref(),
+                self.template.as_deref(),
+                self.yaml,
+                ios,
+            )?;
+            return Ok(());
+        }
+
+        let fallback_url = format!("{}{login}", instance::host_prefix(&host));
+        print_profile(ios, &org, login, &fallback_url);
+        Ok(())
+    }
+}
+
+/// Print an organization profile as a card on a TTY, or `key:\tvalue` lines.
+fn print_profile(ios: &IOStreams, org: &Value, login: &str, fallback_url: &str) {
+    let field = |key: &str| {
+        org.get(key)
+            .and_then(Value::as_str)
+            .filter(|s| !s.is_empty())
+    };
+    let count = |key: &str| org.get(key).and_then(Value::as_i64).unwrap_or(0);
+    let name = field("name").unwrap_or(login);
+    let description = field("description").unwrap_or("No description");
+    let members = org
+        .get("members_count")
+        .and_then(Value::as_i64)
+        .map_or_else(|| "-".to_string(), |m| m.to_string());
+    let plan = org.get("plan").and_then(|plan| {
+        let plan_name = plan.get("name")?.as_str()?;
+        let seats = plan.get("seats").and_then(Value::as_i64);
+        let filled = plan.get("filled_seats").and_then(Value::as_i64);
+        Some(match (filled, seats) {
+            (Some(filled), Some(seats)) => format!("{plan_name} ({filled}/{seats} seats)"),
+            _ => plan_name.to_string(),
+        })
+    });
+    let url = field("html_url").unwrap_or(fallback_url);
+
+    if !ios.is_stdout_tty() {
+        ios_println!(ios, "login:\t{login}");
+        ios_println!(ios, "name:\t{name}");
+        ios_println!(ios, "description:\t{description}");
+        ios_println!(ios, "public_repos:\t{}", count("public_repos"));
+        ios_println!(ios, "members:\t{members}");
+        if let Some(ref plan) = plan {
+            ios_println!(ios, "plan:\t{plan}");
+        }
+        ios_println!(ios, "url:\t{url}");
+        return;
+    }
+
+    let cs = ios.color_scheme();
+    ios_println!(
+        ios,
+        "{} {}\n{description}\n",
+        cs.bold(name),
+        cs.gray(&format!("({login})"))
+    );
+    ios_println!(
+        ios,
+        "Public repos: {}  Members: {members}",
+        cs.cyan(&count("public_repos").to_string())
+    );
+    if let Some(ref plan) = plan {
+        ios_println!(ios, "Plan: {plan}");
+    }
+    for (label, key) in [
+        ("Location", "location"),
+        ("Website", "blog"),
+        ("Email", "email"),
+    ] {
+        if let Some(value) = field(key) {
+            ios_println!(ios, "{label}: {value}");
+        }
+    }
+    ios_println!(
+        ios,
+        "\n{}",
+        cs.gray(&format!("View this organization on GitHub: {url}"))
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test_helpers::{TestHarness, mock_graphql, mock_rest_get};
+
+    fn view_args(org: &str) -> ViewArgs {
+        ViewArgs {
+            org: org.into(),
+            web: false,
+            json: vec![],
+            jq: None,
+            template: None,
+            yaml: false,
+        }
+    }
+
+    async fn mount_org(h: &TestHarness) {
+        mock_rest_get(
+            &h.server,
+            "/orgs/github",
+            serde_json::json!({
+                "login": "github",
+                "name": "GitHub",
+                "description": "How people build software.",
+                "public_repos": 512,
+                "location": "San Francisco, CA",
+                "blog": "",
+                "html_url": "https://github.com/github",
+                "plan": { "name": "enterprise", "seats": 100, "filled_seats": 42 }
+            }),
+        )
+        .await;
+        mock_graphql(
+            &h.server,
+            "OrganizationMembers",
+            serde_json::json!({
+                "data": { "organization": { "membersWithRole": { "totalCount": 2900 } } }
+            }),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_should_print_org_profile_card() {
+        let mut h = TestHarness::new().await;
+        h.factory.io.set_stdout_tty(true);
+        mount_org(&h).await;
+
+        view_args("github").run(&h.factory).await.unwrap();
+
+        let out = h.stdout();
+        assert!(
+            out.starts_with("GitHub (github)\nHow people build software.\n"),
+            "{out}"
+        );
+        assert!(out.contains("Public repos: 512  Members: 2900"), "{out}");
+        assert!(out.contains("Plan: enterprise (42/100 seats)"), "{out}");
+        assert!(out.contains("Location: San Francisco, CA"), "{out}");
+        assert!(!out.contains("Website:"), "{out}");
+        assert!(out.contains("View this organization on GitHub: https://github.com/github"));
+    }
+
+    #[tokio::test]
+    async fn test_should_print_org_fields_when_not_tty() {
+        let h = TestHarness::new().await;
+        mount_org(&h).await;
+
+        view_args("github").run(&h.factory).await.unwrap();
+
+        let out = h.stdout();
+        assert!(out.contains("login:\tgithub\n"));
+        assert!(out.contains("members:\t2900\n"));
+        assert!(out.contains("url:\thttps://github.com/github\n"));
+    }
+
+    #[tokio::test]
+    async fn test_should_output_org_json_with_members_count() {
+        let h = TestHarness::new().await;
+        mount_org(&h).await;
+
+        let args = ViewArgs {
+            json: vec!["login".into(), "members_count".into()],
+            ..view_args("github")
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let out: Value = serde_json::from_str(&h.stdout()).unwrap();
+        assert_eq!(
+            out,
+            serde_json::json!({ "login": "github", "members_count": 2900 })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_open_org_in_browser() {
+        let h = TestHarness::new().await;
+
+        let args = ViewArgs {
+            web: true,
+            ..view_args("ghe.example.com/acme")
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert_eq!(h.opened_urls(), ["https://ghe.example.com/acme"]);
+    }
+
+    #[tokio::test]
+    async fn test_should_reject_malformed_org() {
+        let h = TestHarness::new().await;
+        let err = view_args("a/b/c").run(&h.factory).await.unwrap_err();
+        assert!(err.to_string().contains("OWNER or HOST/OWNER"));
+    }
+}
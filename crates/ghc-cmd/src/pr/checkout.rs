@@ -5,26 +5,35 @@ use clap::Args;
 use serde_json::Value;
 
 use ghc_core::ios_eprintln;
+use ghc_core::repo::Repo;
 
 /// Checkout a pull request branch locally.
+///
+/// The pull request can be given as a number, a URL, or the head branch as
+/// `OWNER:BRANCH`. Pull requests from forks add the fork as a remote named
+/// after its owner and check out a branch tracking it.
 #[derive(Debug, Args)]
 pub struct CheckoutArgs {
-    /// Pull request number.
-    #[arg(value_name = "NUMBER")]
-    number: i64,
+    /// Pull request number, URL, or `OWNER:BRANCH`.
+    #[arg(value_name = "NUMBER | URL | OWNER:BRANCH")]
+    pr: String,
 
-    /// Repository in OWNER/REPO format.
+    /// Repository in OWNER/REPO format (not needed when given a URL).
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Local branch name to use (defaults to the PR head branch name).
     #[arg(short, long)]
     branch: Option<String>,
 
-    /// Force checkout even if the branch already exists.
+    /// Reset the existing local branch to the pull request head, discarding local changes.
     #[arg(short, long)]
     force: bool,
 
+    /// Update all submodules after checkout.
+    #[arg(long)]
+    recurse_submodules: bool,
+
     /// Detach HEAD (checkout without creating a branch).
     #[arg(long)]
     detach: bool,
@@ -38,19 +47,17 @@ impl CheckoutArgs {
     /// Returns an error if the API request or git commands fail.
     #[allow(clippy::too_many_lines)]
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let (repo, selector) = self.parse_target(factory).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
+        let number = match selector {
+            PrSelector::Number(number) => number,
+            PrSelector::HeadBranch(ref head) => find_pr_for_head(&client, &repo, head).await?,
+        };
 
         // Fetch PR details to get head branch info
-        let path = format!(
-            "repos/{}/{}/pulls/{}",
-            repo.owner(),
-            repo.name(),
-            self.number,
-        );
+        let path = format!("repos/{}/{}/pulls/{number}", repo.owner(), repo.name(),);
         let pr_data: Value = client
             .rest(reqwest::Method::GET, &path, None::<&Value>)
             .await
@@ -83,88 +90,290 @@ impl CheckoutArgs {
                 name != base_full
             });
 
+        let git = factory.git_client()?;
+
         if self.detach {
             // Fetch the specific commit and checkout in detached HEAD mode
-            let fetch_ref = format!("pull/{}/head", self.number);
-            let status = tokio::process::Command::new("git")
-                .args(["fetch", "origin", &fetch_ref])
-                .status()
+            let fetch_ref = format!("pull/{number}/head");
+            git.fetch("origin", &fetch_ref)
                 .await
-                .context("failed to execute git fetch")?;
-            if !status.success() {
-                anyhow::bail!("git fetch failed");
-            }
-
-            let status = tokio::process::Command::new("git")
-                .args(["checkout", "--detach", "FETCH_HEAD"])
-                .status()
+                .context("git fetch failed")?;
+            git.checkout_detached("FETCH_HEAD")
                 .await
-                .context("failed to execute git checkout")?;
-            if !status.success() {
-                anyhow::bail!("git checkout failed");
-            }
+                .context("git checkout failed")?;
+            self.update_submodules(git).await?;
 
             ios_eprintln!(
                 ios,
-                "{} Checked out PR #{} in detached HEAD at {:.7}",
+                "{} Checked out PR #{number} in detached HEAD at {:.7}",
                 cs.success_icon(),
-                self.number,
                 head_sha,
             );
             return Ok(());
         }
 
         if is_cross_repo && !head_repo_url.is_empty() {
-            // Cross-repo PR: fetch from the fork
-            let fetch_ref = format!("{head_ref}:{local_branch}");
-            let mut fetch_args = vec!["fetch", head_repo_url, &fetch_ref];
-            if self.force {
-                fetch_args.insert(1, "--force");
-            }
-            let status = tokio::process::Command::new("git")
-                .args(&fetch_args)
-                .status()
-                .await
-                .context("failed to execute git fetch from fork")?;
-            if !status.success() {
-                anyhow::bail!("git fetch from fork failed");
-            }
+            let fork_owner = pr_data
+                .pointer("/head/repo/owner/login")
+                .and_then(Value::as_str)
+                .unwrap_or("fork");
+            let fork = ForkHead {
+                remote: fork_owner,
+                url: head_repo_url,
+                branch: head_ref,
+            };
+            self.checkout_fork(factory, git, &fork, local_branch)
+                .await?;
         } else {
-            // Same-repo PR: fetch from origin
-            let fetch_ref = format!("pull/{}/head:{local_branch}", self.number);
-            let mut fetch_args = vec!["fetch", "origin", &fetch_ref];
-            if self.force {
-                fetch_args.insert(1, "--force");
-            }
-            let status = tokio::process::Command::new("git")
-                .args(&fetch_args)
-                .status()
-                .await
-                .context("failed to execute git fetch")?;
-            if !status.success() {
-                anyhow::bail!("git fetch failed");
-            }
+            let source_ref = format!("pull/{number}/head");
+            self.checkout_same_repo(factory, git, &source_ref, local_branch)
+                .await?;
         }
 
-        // Checkout the local branch
-        let status = tokio::process::Command::new("git")
-            .args(["checkout", local_branch])
-            .status()
-            .await
-            .context("failed to execute git checkout")?;
-        if !status.success() {
-            anyhow::bail!("git checkout failed");
-        }
+        self.update_submodules(git).await?;
 
         ios_eprintln!(
             ios,
-            "{} Checked out PR #{} on branch {local_branch}",
+            "{} Checked out PR #{number} on branch {local_branch}",
             cs.success_icon(),
-            self.number,
         );
 
         Ok(())
     }
+
+    /// Work out the repository and pull request named by the arguments.
+    async fn parse_target(&self, factory: &crate::factory::Factory) -> Result<(Repo, PrSelector)> {
+        let arg = self.pr.trim();
+        if arg.starts_with("https://") || arg.starts_with("http://") {
+            return parse_pr_url(arg);
+        }
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
+        if let Ok(number) = arg.trim_start_matches('#').parse::<i64>() {
+            return Ok((repo, PrSelector::Number(number)));
+        }
+        match arg.split_once(':') {
+            Some((owner, branch)) if !owner.is_empty() && !branch.is_empty() => {
+                Ok((repo, PrSelector::HeadBranch(arg.to_string())))
+            }
+            _ => anyhow::bail!("invalid pull request argument: {arg:?}"),
+        }
+    }
+
+    /// Fetch a same-repository pull request from `origin` into `local_branch`.
+    async fn checkout_same_repo(
+        &self,
+        factory: &crate::factory::Factory,
+        git: &ghc_git::client::GitClient,
+        source_ref: &str,
+        local_branch: &str,
+    ) -> Result<()> {
+        let remote = "origin";
+        if self.force {
+            self.confirm_discard(factory, git).await?;
+            git.fetch(remote, source_ref)
+                .await
+                .context("git fetch failed")?;
+            git.reset_branch(local_branch, "FETCH_HEAD")
+                .await
+                .context("git checkout failed")?;
+        } else {
+            git.fetch(remote, &format!("{source_ref}:{local_branch}"))
+                .await
+                .context("git fetch failed")?;
+            git.checkout(local_branch)
+                .await
+                .context("git checkout failed")?;
+        }
+        Ok(())
+    }
+
+    /// Add the fork as a remote if needed, fetch its head branch, and check
+    /// out a local branch tracking it.
+    async fn checkout_fork(
+        &self,
+        factory: &crate::factory::Factory,
+        git: &ghc_git::client::GitClient,
+        fork: &ForkHead<'_>,
+        local_branch: &str,
+    ) -> Result<()> {
+        let remotes = git.remotes().await.context("failed to list remotes")?;
+        let (remote, exists) = fork_remote(&remotes, fork);
+        if !exists {
+            git.add_remote(&remote, fork.url, &[])
+                .await
+                .with_context(|| format!("failed to add remote {remote}"))?;
+        }
+        let remote = remote.as_str();
+        let tracking = format!("{remote}/{}", fork.branch);
+        git.fetch(
+            remote,
+            &format!("+refs/heads/{}:refs/remotes/{tracking}", fork.branch),
+        )
+        .await
+        .context("git fetch failed")?;
+
+        if self.force {
+            self.confirm_discard(factory, git).await?;
+            git.reset_branch(local_branch, &tracking)
+                .await
+                .context("git checkout failed")?;
+        } else if git.has_local_branch(local_branch).await {
+            git.checkout(local_branch)
+                .await
+                .context("git checkout failed")?;
+            return Ok(());
+        } else if local_branch == fork.branch {
+            git.checkout_new_branch(remote, fork.branch)
+                .await
+                .context("git checkout failed")?;
+            return Ok(());
+        } else {
+            git.fetch(remote, &format!("{}:{local_branch}", fork.branch))
+                .await
+                .context("git fetch failed")?;
+            git.checkout(local_branch)
+                .await
+                .context("git checkout failed")?;
+        }
+
+        git.set_branch_config(local_branch, "remote", remote)
+            .await
+            .context("failed to set up branch tracking")?;
+        git.set_branch_config(
+            local_branch,
+            "merge",
+            &format!("refs/heads/{}", fork.branch),
+        )
+        .await
+        .context("failed to set up branch tracking")?;
+        Ok(())
+    }
+
+    /// Ask before `--force` discards uncommitted changes.
+    ///
+    /// Without a terminal to prompt on, the explicit `--force` is taken as
+    /// consent.
+    async fn confirm_discard(
+        &self,
+        factory: &crate::factory::Factory,
+        git: &ghc_git::client::GitClient,
+    ) -> Result<()> {
+        let changes = git
+            .uncommitted_change_count()
+            .await
+            .context("failed to check for uncommitted changes")?;
+        if changes == 0 || !factory.io.can_prompt() {
+            return Ok(());
+        }
+        let confirmed = factory.prompter().confirm(
+            &format!(
+                "Discard {} and reset the branch?",
+                ghc_core::text::pluralize(
+                    i64::try_from(changes).unwrap_or(i64::MAX),
+                    "uncommitted change",
+                    "uncommitted changes",
+                ),
+            ),
+            false,
+        )?;
+        if !confirmed {
+            anyhow::bail!("checkout cancelled");
+        }
+        Ok(())
+    }
+
+    /// Update submodules when `--recurse-submodules` is set.
+    async fn update_submodules(&self, git: &ghc_git::client::GitClient) -> Result<()> {
+        if self.recurse_submodules {
+            git.update_submodules()
+                .await
+                .context("failed to update submodules")?;
+        }
+        Ok(())
+    }
+}
+
+/// How the pull request to check out was identified.
+#[derive(Debug, PartialEq, Eq)]
+enum PrSelector {
+    /// A pull request number.
+    Number(i64),
+    /// A head branch as `OWNER:BRANCH`.
+    HeadBranch(String),
+}
+
+/// The head of a cross-repository pull request.
+#[derive(Debug)]
+struct ForkHead<'a> {
+    /// Remote name for the fork, its owner's login.
+    remote: &'a str,
+    /// Clone URL of the fork.
+    url: &'a str,
+    /// Head branch in the fork.
+    branch: &'a str,
+}
+
+/// The remote to fetch a fork's branch from, and whether it already exists.
+///
+/// An existing remote pointing at the fork's repository is reused whatever
+/// its name. Otherwise a new remote is named after the fork's owner, with a
+/// numeric suffix when a remote of that name points somewhere else.
+fn fork_remote(remotes: &[ghc_git::remote::Remote], fork: &ForkHead<'_>) -> (String, bool) {
+    let fork_repo = Repo::from_url(fork.url).ok();
+    if let Some(existing) = remotes
+        .iter()
+        .find(|r| fork_repo.is_some() && r.repo == fork_repo)
+    {
+        return (existing.name.clone(), true);
+    }
+    let taken = |name: &str| remotes.iter().any(|r| r.name == name);
+    let name = std::iter::once(fork.remote.to_string())
+        .chain((1..=remotes.len()).map(|n| format!("{}-{n}", fork.remote)))
+        .find(|name| !taken(name))
+        .unwrap_or_else(|| fork.remote.to_string());
+    (name, false)
+}
+
+/// Parse a pull request URL such as `https://github.com/OWNER/REPO/pull/42`.
+fn parse_pr_url(arg: &str) -> Result<(Repo, PrSelector)> {
+    let url = url::Url::parse(arg).context("invalid pull request URL")?;
+    let repo = Repo::from_parsed_url(&url)
+        .map_err(|e| anyhow::anyhow!("invalid pull request URL: {e}"))?;
+    let segments: Vec<&str> = url
+        .path_segments()
+        .map(Iterator::collect)
+        .unwrap_or_default();
+    match segments.as_slice() {
+        [_, _, "pull", number, ..] => {
+            let number = number
+                .parse()
+                .with_context(|| format!("invalid pull request number in URL: {arg}"))?;
+            Ok((repo, PrSelector::Number(number)))
+        }
+        _ => anyhow::bail!("not a pull request URL: {arg}"),
+    }
+}
+
+/// Find the open pull request whose head is `OWNER:BRANCH`.
+async fn find_pr_for_head(
+    client: &ghc_api::client::Client,
+    repo: &Repo,
+    head: &str,
+) -> Result<i64> {
+    let path = format!(
+        "repos/{}/{}/pulls?head={}&state=open&per_page=1",
+        repo.owner(),
+        repo.name(),
+        urlencoding::encode(head),
+    );
+    let prs: Vec<Value> = client
+        .rest(reqwest::Method::GET, &path, None)
+        .await
+        .context("failed to find pull request")?;
+    prs.first()
+        .and_then(|pr| pr.get("number"))
+        .and_then(Value::as_i64)
+        .ok_or_else(|| anyhow::anyhow!("no open pull requests found for branch {head}"))
 }
 
 #[cfg(test)]
@@ -176,10 +385,11 @@ mod tests {
     async fn test_should_return_error_on_invalid_repo_for_checkout() {
         let h = TestHarness::new().await;
         let args = CheckoutArgs {
-            number: 1,
-            repo: "bad".into(),
+            pr: "1".into(),
+            repo: Some("bad".into()),
             branch: None,
             force: false,
+            recurse_submodules: false,
             detach: false,
         };
 
@@ -192,4 +402,250 @@ mod tests {
                 .contains("invalid repository")
         );
     }
+
+    #[cfg(unix)]
+    fn stub_git(dir: &std::path::Path, status_output: &str) -> std::path::PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+
+        let script = dir.join("git");
+        std::fs::write(
+            &script,
+            format!(
+                "#!/bin/sh\necho \"$*\" >> {}\nif [ \"$1\" = status ]; then printf '{status_output}'; fi\nif [ \"$1\" = rev-parse ]; then exit 1; fi\n",
+                dir.join("git.log").display(),
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+        script
+    }
+
+    #[cfg(unix)]
+    fn git_log(dir: &std::path::Path) -> Vec<String> {
+        std::fs::read_to_string(dir.join("git.log"))
+            .unwrap_or_default()
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[cfg(unix)]
+    async fn stub_harness(dir: &std::path::Path, status_output: &str) -> TestHarness {
+        let mut h = TestHarness::new().await;
+        let git = ghc_git::client::GitClient::new()
+            .unwrap()
+            .with_git_path(stub_git(dir, status_output));
+        h.factory = h.factory.with_git_client(git);
+        crate::test_helpers::mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/pulls/7",
+            serde_json::json!({
+                "head": {
+                    "ref": "feature",
+                    "sha": "abc1234def",
+                    "repo": { "full_name": "owner/repo", "clone_url": "https://github.com/owner/repo.git" }
+                },
+                "base": { "repo": { "full_name": "owner/repo" } }
+            }),
+        )
+        .await;
+        h
+    }
+
+    fn checkout_args(force: bool, recurse_submodules: bool) -> CheckoutArgs {
+        CheckoutArgs {
+            pr: "7".into(),
+            repo: Some("owner/repo".into()),
+            branch: None,
+            force,
+            recurse_submodules,
+            detach: false,
+        }
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_should_update_submodules_after_checkout() {
+        let dir = tempfile::tempdir().unwrap();
+        let h = stub_harness(dir.path(), "").await;
+
+        checkout_args(false, true).run(&h.factory).await.unwrap();
+
+        let log = git_log(dir.path());
+        assert_eq!(log.len(), 3, "unexpected git calls: {log:?}");
+        assert!(log[0].ends_with("fetch origin pull/7/head:feature"));
+        assert_eq!(log[1], "checkout feature");
+        assert!(log[2].ends_with("submodule update --init --recursive"));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_should_reset_branch_when_forced() {
+        let dir = tempfile::tempdir().unwrap();
+        let h = stub_harness(dir.path(), " M src/lib.rs\\n").await;
+
+        checkout_args(true, false).run(&h.factory).await.unwrap();
+
+        let log = git_log(dir.path());
+        assert_eq!(log.len(), 3, "unexpected git calls: {log:?}");
+        assert_eq!(log[0], "status --porcelain");
+        assert!(log[1].ends_with("fetch origin pull/7/head"));
+        assert_eq!(log[2], "checkout --force -B feature FETCH_HEAD");
+        assert!(h.stderr().contains("Checked out PR #7 on branch feature"));
+    }
+
+    #[test]
+    fn test_should_pick_fork_remote_by_repository() {
+        let fork = ForkHead {
+            remote: "contributor",
+            url: "https://github.com/contributor/repo.git",
+            branch: "fix",
+        };
+        let remotes = ghc_git::remote::Remote::parse_remotes(
+            "mine\tgit@github.com:contributor/repo.git (fetch)\n\
+             mine\tgit@github.com:contributor/repo.git (push)\n",
+        );
+        assert_eq!(fork_remote(&remotes, &fork), ("mine".into(), true));
+
+        let remotes = ghc_git::remote::Remote::parse_remotes(
+            "contributor\thttps://github.com/contributor/other.git (fetch)\n\
+             contributor\thttps://github.com/contributor/other.git (push)\n\
+             contributor-1\thttps://github.com/someone/else.git (fetch)\n\
+             contributor-1\thttps://github.com/someone/else.git (push)\n",
+        );
+        assert_eq!(
+            fork_remote(&remotes, &fork),
+            ("contributor-2".into(), false)
+        );
+
+        assert_eq!(fork_remote(&[], &fork), ("contributor".into(), false));
+    }
+
+    #[test]
+    fn test_should_parse_pr_url() {
+        let (repo, selector) = parse_pr_url("https://github.com/owner/repo/pull/42/files").unwrap();
+        assert_eq!(repo.full_name(), "owner/repo");
+        assert_eq!(selector, PrSelector::Number(42));
+
+        let err = parse_pr_url("https://github.com/owner/repo/issues/42").unwrap_err();
+        assert!(err.to_string().contains("not a pull request URL"));
+    }
+
+    #[tokio::test]
+    async fn test_should_parse_head_branch_selector() {
+        let h = TestHarness::new().await;
+        let args = CheckoutArgs {
+            pr: "contributor:fix-typo".into(),
+            ..checkout_args(false, false)
+        };
+        let (_, selector) = args.parse_target(&h.factory).await.unwrap();
+        assert_eq!(
+            selector,
+            PrSelector::HeadBranch("contributor:fix-typo".into())
+        );
+
+        let args = CheckoutArgs {
+            repo: None,
+            ..checkout_args(false, false)
+        };
+        let err = args.parse_target(&h.factory).await.unwrap_err();
+        assert!(err.to_string().contains("no GitHub remotes found"), "{err}");
+    }
+
+    #[cfg(unix)]
+    async fn fork_harness(dir: &std::path::Path) -> TestHarness {
+        let mut h = TestHarness::new().await;
+        let git = ghc_git::client::GitClient::new()
+            .unwrap()
+            .with_git_path(stub_git(dir, ""));
+        h.factory = h.factory.with_git_client(git);
+        crate::test_helpers::mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/pulls/42",
+            serde_json::json!({
+                "head": {
+                    "ref": "fix-typo",
+                    "sha": "abc1234def",
+                    "repo": {
+                        "full_name": "contributor/repo",
+                        "clone_url": "https://github.com/contributor/repo.git",
+                        "owner": { "login": "contributor" }
+                    }
+                },
+                "base": { "repo": { "full_name": "owner/repo" } }
+            }),
+        )
+        .await;
+        h
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_should_checkout_fork_by_url_with_tracking_branch() {
+        let dir = tempfile::tempdir().unwrap();
+        let h = fork_harness(dir.path()).await;
+
+        let args = CheckoutArgs {
+            pr: "https://github.com/owner/repo/pull/42".into(),
+            repo: None,
+            ..checkout_args(false, false)
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let log = git_log(dir.path());
+        assert!(
+            log.contains(&"remote add contributor https://github.com/contributor/repo.git".into()),
+            "{log:?}"
+        );
+        assert!(
+            log.iter().any(|l| l.ends_with(
+                "fetch contributor +refs/heads/fix-typo:refs/remotes/contributor/fix-typo"
+            )),
+            "{log:?}"
+        );
+        assert_eq!(
+            log.last().unwrap(),
+            "checkout -b fix-typo --track contributor/fix-typo"
+        );
+        assert!(h.stderr().contains("Checked out PR #42 on branch fix-typo"));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_should_checkout_pr_by_head_branch() {
+        let dir = tempfile::tempdir().unwrap();
+        let h = fork_harness(dir.path()).await;
+        crate::test_helpers::mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/pulls",
+            serde_json::json!([{ "number": 42 }]),
+        )
+        .await;
+
+        let args = CheckoutArgs {
+            pr: "contributor:fix-typo".into(),
+            ..checkout_args(false, false)
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert!(h.stderr().contains("Checked out PR #42 on branch fix-typo"));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_should_cancel_force_when_discard_declined() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut h = stub_harness(dir.path(), " M src/lib.rs\\n").await;
+        h.factory.io.set_stdin_tty(true);
+        h.factory.io.set_stdout_tty(true);
+        h.factory.io.set_never_prompt(false);
+        h.prompter.confirm_answers.lock().unwrap().push(false);
+
+        let err = checkout_args(true, false)
+            .run(&h.factory)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("checkout cancelled"));
+        assert_eq!(git_log(dir.path()), vec!["status --porcelain"]);
+    }
 }
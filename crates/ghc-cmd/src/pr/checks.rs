@@ -49,6 +49,7 @@ query PullRequestChecks($owner: String!, $name: String!, $number: Int!) {
 
 /// View CI status checks for a pull request.
 #[derive(Debug, Args)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct ChecksArgs {
     /// Pull request number.
     #[arg(value_name = "NUMBER")]
@@ -56,18 +57,18 @@ pub struct ChecksArgs {
 
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Watch for status changes (poll until all checks complete).
     #[arg(short, long)]
     watch: bool,
 
     /// Polling interval in seconds (used with --watch).
-    #[arg(short, long, default_value = "10")]
+    #[arg(short, long, default_value = "10", value_parser = clap::value_parser!(u64).range(1..))]
     interval: u64,
 
-    /// Fail if any required check fails.
-    #[arg(long)]
+    /// Stop watching as soon as any check fails.
+    #[arg(long, requires = "watch")]
     fail_fast: bool,
 
     /// Filter checks by name.
@@ -85,33 +86,57 @@ pub struct ChecksArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl ChecksArgs {
     /// Run the pr checks command.
     ///
+    /// Like `gh pr checks`, this fails when any check failed and exits with
+    /// the pending status (8) when checks are still running. With `--watch`
+    /// it polls every `--interval` seconds until the checks settle, or until
+    /// the first failure with `--fail-fast`.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the API request fails or checks are not available.
+    /// Returns an error if the API request fails, checks are not available,
+    /// or any check failed.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
+        let ios = &factory.io;
+        let json_requested =
+            !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml;
 
         loop {
             let (all_complete, has_failures) = self.display_checks(factory, &repo).await?;
+            if json_requested {
+                return Ok(());
+            }
 
-            if !self.watch || all_complete {
-                if self.fail_fast && has_failures {
+            let settled = all_complete || (self.fail_fast && has_failures);
+            if !self.watch || settled {
+                if has_failures {
                     anyhow::bail!("one or more checks failed");
                 }
-                break;
+                if !all_complete {
+                    return Err(ghc_core::cmdutil::PendingError.into());
+                }
+                return Ok(());
             }
 
-            ios_eprintln!(&factory.io, "\nWaiting for checks to complete...");
+            let progress = ios.start_progress_indicator_with_label(&format!(
+                "Waiting for checks to complete (refreshing every {}s)…",
+                self.interval,
+            ));
+            if !progress.is_active() {
+                ios_eprintln!(ios, "\nWaiting for checks to complete...");
+            }
             tokio::time::sleep(std::time::Duration::from_secs(self.interval)).await;
+            progress.stop();
         }
-
-        Ok(())
     }
 
     /// Fetch and display check status. Returns (all_complete, has_failures).
@@ -133,10 +158,12 @@ impl ChecksArgs {
             Value::Number(serde_json::Number::from(self.number)),
         );
 
+        let progress = ios.start_progress_indicator_with_label("Fetching checks…");
         let data: Value = client
             .graphql(PR_CHECKS_QUERY, &variables)
             .await
             .context("failed to fetch checks")?;
+        progress.stop();
 
         let rollup_state = data
             .pointer(
@@ -160,16 +187,16 @@ impl ChecksArgs {
         }
 
         // JSON output
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let contexts_val = Value::Array(contexts.clone());
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &contexts_val,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             let all_complete = rollup_state != "PENDING";
             let has_failures = rollup_state == "FAILURE" || rollup_state == "ERROR";
             return Ok((all_complete, has_failures));
@@ -312,7 +339,7 @@ mod tests {
 
         let args = ChecksArgs {
             number: 30,
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             watch: false,
             interval: 10,
             fail_fast: false,
@@ -320,6 +347,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
 
         args.run(&h.factory).await.unwrap();
@@ -342,7 +370,7 @@ mod tests {
 
         let args = ChecksArgs {
             number: 31,
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             watch: false,
             interval: 10,
             fail_fast: false,
@@ -350,6 +378,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
 
         let result = args.run(&h.factory).await;
@@ -385,7 +414,7 @@ mod tests {
 
         let args = ChecksArgs {
             number: 32,
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             watch: false,
             interval: 10,
             fail_fast: true,
@@ -393,6 +422,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
 
         let result = args.run(&h.factory).await;
@@ -400,12 +430,128 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("checks failed"));
     }
 
+    fn check_run(name: &str, status: &str, conclusion: Option<&str>) -> serde_json::Value {
+        serde_json::json!({
+            "__typename": "CheckRun",
+            "name": name,
+            "status": status,
+            "conclusion": conclusion,
+            "detailsUrl": "https://example.com",
+        })
+    }
+
+    fn watch_args(number: i64, fail_fast: bool) -> ChecksArgs {
+        ChecksArgs {
+            number,
+            repo: Some("owner/repo".into()),
+            watch: true,
+            interval: 0,
+            fail_fast,
+            required: false,
+            json: vec![],
+            jq: None,
+            template: None,
+            yaml: false,
+        }
+    }
+
+    /// Serve `first` once, then `rest` for every later poll.
+    async fn mount_polls(h: &TestHarness, first: serde_json::Value, rest: serde_json::Value) {
+        use wiremock::matchers::{body_string_contains, method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("PullRequestChecks"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(first))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&h.server)
+            .await;
+        mock_graphql(&h.server, "PullRequestChecks", rest).await;
+    }
+
+    #[tokio::test]
+    async fn test_should_report_pending_checks_with_pending_error() {
+        let h = TestHarness::new().await;
+        mock_graphql(
+            &h.server,
+            "PullRequestChecks",
+            checks_response("PENDING", &[check_run("CI / build", "IN_PROGRESS", None)]),
+        )
+        .await;
+
+        let args = ChecksArgs {
+            watch: false,
+            ..watch_args(33, false)
+        };
+        let err = args.run(&h.factory).await.unwrap_err();
+        assert!(
+            err.downcast_ref::<ghc_core::cmdutil::PendingError>()
+                .is_some()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_watch_until_checks_pass() {
+        let h = TestHarness::new().await;
+        mount_polls(
+            &h,
+            checks_response("PENDING", &[check_run("CI / build", "IN_PROGRESS", None)]),
+            checks_response(
+                "SUCCESS",
+                &[check_run("CI / build", "COMPLETED", Some("SUCCESS"))],
+            ),
+        )
+        .await;
+
+        watch_args(34, false).run(&h.factory).await.unwrap();
+
+        let err = h.stderr();
+        assert!(err.contains("Waiting for checks to complete"), "{err}");
+        assert!(err.contains("All checks passed"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn test_should_stop_watching_on_first_failure_with_fail_fast() {
+        let h = TestHarness::new().await;
+        let failing = checks_response(
+            "FAILURE",
+            &[
+                check_run("CI / lint", "COMPLETED", Some("FAILURE")),
+                check_run("CI / build", "IN_PROGRESS", None),
+            ],
+        );
+        mock_graphql(&h.server, "PullRequestChecks", failing).await;
+
+        let err = watch_args(35, true).run(&h.factory).await.unwrap_err();
+
+        assert!(err.to_string().contains("checks failed"));
+        assert!(!h.stderr().contains("Waiting for checks"));
+    }
+
+    #[test]
+    fn test_should_require_watch_for_fail_fast() {
+        use clap::Parser;
+
+        #[derive(clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: ChecksArgs,
+        }
+        let err = Cli::try_parse_from(["checks", "1", "-R", "o/r", "--fail-fast"]);
+        assert!(err.is_err());
+        assert!(
+            Cli::try_parse_from(["checks", "1", "-R", "o/r", "--watch", "--fail-fast"]).is_ok()
+        );
+    }
+
     #[tokio::test]
     async fn test_should_return_error_on_invalid_repo_for_checks() {
         let h = TestHarness::new().await;
         let args = ChecksArgs {
             number: 1,
-            repo: "bad".into(),
+            repo: Some("bad".into()),
             watch: false,
             interval: 10,
             fail_fast: false,
@@ -413,6 +559,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
 
         let result = args.run(&h.factory).await;
@@ -15,7 +15,7 @@ pub struct CloseArgs {
 
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Leave a comment when closing.
     #[arg(short, long)]
@@ -33,8 +33,7 @@ impl CloseArgs {
     ///
     /// Returns an error if the API request fails.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -117,7 +116,7 @@ mod tests {
 
         let args = CloseArgs {
             number: 5,
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             comment: None,
             delete_branch: false,
         };
@@ -152,7 +151,7 @@ mod tests {
 
         let args = CloseArgs {
             number: 5,
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             comment: Some("Closing this".into()),
             delete_branch: false,
         };
@@ -170,7 +169,7 @@ mod tests {
         let h = TestHarness::new().await;
         let args = CloseArgs {
             number: 1,
-            repo: "bad".into(),
+            repo: Some("bad".into()),
             comment: None,
             delete_branch: false,
         };
@@ -18,7 +18,7 @@ pub struct CommentArgs {
 
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Comment body text.
     #[arg(short, long, conflicts_with = "body_file")]
@@ -61,8 +61,7 @@ impl CommentArgs {
     /// Returns an error if the API request fails.
     #[allow(clippy::too_many_lines)]
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -77,26 +76,19 @@ impl CommentArgs {
             return self.handle_delete_last(factory, &client, &repo, ios).await;
         }
 
-        // Resolve body from --body-file if provided
-        let body_from_file = if let Some(ref body_file) = self.body_file {
-            Some(
-                crate::issue::create::read_body_file(body_file)
-                    .context("failed to read body file")?,
-            )
-        } else {
-            None
-        };
+        // Resolve body from --body or --body-file
+        let body_input =
+            ghc_core::cmdutil::read_body(self.body.clone(), self.body_file.as_deref())?;
 
-        let comment_body = match (&self.body, body_from_file, self.editor) {
-            (Some(b), _, _) => b.clone(),
-            (None, Some(b), _) => b,
-            (None, None, true) => {
+        let comment_body = match (body_input, self.editor) {
+            (Some(b), _) => b,
+            (None, true) => {
                 let prompter = factory.prompter();
                 prompter
                     .editor("Comment body", "", true)
                     .context("failed to read comment body from editor")?
             }
-            (None, None, false) => {
+            (None, false) => {
                 let prompter = factory.prompter();
                 prompter
                     .input("Comment body", "")
@@ -328,7 +320,7 @@ mod tests {
     fn default_args(number: i64, repo: &str) -> CommentArgs {
         CommentArgs {
             number,
-            repo: repo.into(),
+            repo: Some(repo.into()),
             body: Some("Looks good!".into()),
             body_file: None,
             editor: false,
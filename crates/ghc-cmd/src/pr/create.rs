@@ -9,13 +9,15 @@ use serde_json::Value;
 
 use ghc_core::ios_eprintln;
 
+use ghc_core::cmdutil::RecoveryInput;
+
 /// Create a pull request.
 #[derive(Debug, Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct CreateArgs {
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Title of the pull request.
     #[arg(short, long)]
@@ -46,7 +48,7 @@ pub struct CreateArgs {
     editor: bool,
 
     /// Use commit info for title and body.
-    #[arg(short, long = "fill")]
+    #[arg(short = 'f', long = "fill")]
     autofill: bool,
 
     /// Use commits msg+body for description.
@@ -86,8 +88,8 @@ pub struct CreateArgs {
     no_maintainer_edit: bool,
 
     /// Recover input from a failed run of create.
-    #[arg(long)]
-    recover: Option<String>,
+    #[arg(long, value_name = "PATH")]
+    recover: Option<PathBuf>,
 
     /// Print details instead of creating the PR.
     #[arg(long)]
@@ -106,8 +108,7 @@ impl CreateArgs {
     /// Returns an error if the API request fails.
     #[allow(clippy::too_many_lines)]
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -154,15 +155,16 @@ impl CreateArgs {
             }
         };
 
-        // Resolve body from --body-file if provided
-        let body_from_file = if let Some(ref body_file) = self.body_file {
-            Some(
-                crate::issue::create::read_body_file(body_file)
-                    .context("failed to read body file")?,
-            )
-        } else {
-            None
-        };
+        // Resolve body from --body or --body-file
+        let body_input =
+            ghc_core::cmdutil::read_body(self.body.clone(), self.body_file.as_deref())?;
+
+        let recovered = self
+            .recover
+            .as_deref()
+            .map(RecoveryInput::load)
+            .transpose()?
+            .unwrap_or_default();
 
         // Auto-fill from commit messages if --fill or --fill-verbose
         let (autofill_title, autofill_body) =
@@ -175,6 +177,8 @@ impl CreateArgs {
         // Determine title
         let title = if let Some(ref t) = self.title {
             t.clone()
+        } else if !recovered.title.is_empty() {
+            recovered.title.clone()
         } else if let Some(ref t) = autofill_title {
             t.clone()
         } else if self.editor {
@@ -188,8 +192,9 @@ impl CreateArgs {
 
         // Determine body
         let (final_title, final_body) = if self.editor {
-            let default_body = body_from_file
+            let default_body = body_input
                 .as_deref()
+                .or(recovered.body_if_set())
                 .or(autofill_body.as_deref())
                 .unwrap_or("");
             let editor_content = format!("{title}\n{default_body}");
@@ -202,10 +207,8 @@ impl CreateArgs {
             let b = lines.next().unwrap_or("").trim().to_string();
             (t, b)
         } else {
-            let body = self
-                .body
-                .clone()
-                .or(body_from_file)
+            let body = body_input
+                .or_else(|| recovered.body_if_set().map(str::to_string))
                 .or(autofill_body)
                 .unwrap_or_default();
             (title, body)
@@ -253,10 +256,20 @@ impl CreateArgs {
         }
 
         let path = format!("repos/{}/{}/pulls", repo.owner(), repo.name());
-        let result: Value = client
+        let result: Value = match client
             .rest(reqwest::Method::POST, &path, Some(&pr_body))
             .await
-            .context("failed to create pull request")?;
+        {
+            Ok(result) => result,
+            Err(err) => {
+                let input = RecoveryInput {
+                    title: final_title,
+                    body: final_body,
+                };
+                input.report(&factory.io, &factory.state_dir(), "pr");
+                return Err(anyhow::Error::new(err).context("failed to create pull request"));
+            }
+        };
 
         let number = result.get("number").and_then(Value::as_i64).unwrap_or(0);
         let html_url = result.get("html_url").and_then(Value::as_str).unwrap_or("");
@@ -390,7 +403,7 @@ mod tests {
 
     fn create_args(repo: &str) -> CreateArgs {
         CreateArgs {
-            repo: repo.into(),
+            repo: Some(repo.into()),
             title: Some("New feature".into()),
             body: Some("Description".into()),
             body_file: None,
@@ -497,4 +510,32 @@ mod tests {
                 .contains("title is required")
         );
     }
+
+    #[tokio::test]
+    async fn test_should_save_recovery_file_on_api_failure() {
+        let state = tempfile::tempdir().unwrap();
+        let mut h = TestHarness::new().await;
+        h.factory = h.factory.with_state_dir(state.path());
+        mock_rest_post(
+            &h.server,
+            "/repos/owner/repo/pulls",
+            422,
+            serde_json::json!({ "message": "Validation Failed" }),
+        )
+        .await;
+
+        let args = create_args("owner/repo");
+        assert!(args.run(&h.factory).await.is_err());
+
+        let file = std::fs::read_dir(state.path().join("recovery"))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap()
+            .path();
+        let saved = RecoveryInput::load(&file).unwrap();
+        assert_eq!(saved.title, "New feature");
+        assert_eq!(saved.body, "Description");
+        assert!(h.stderr().contains("ghc pr create --recover"));
+    }
 }
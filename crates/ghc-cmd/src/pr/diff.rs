@@ -16,7 +16,7 @@ pub struct DiffArgs {
 
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Use colored diff output (always, never, auto).
     #[arg(long, default_value = "auto", value_parser = ["always", "never", "auto"])]
@@ -38,16 +38,16 @@ pub struct DiffArgs {
 impl DiffArgs {
     /// Run the pr diff command.
     ///
-    /// Fetches the diff of the pull request from the GitHub API using the
-    /// diff media type endpoint. The diff is retrieved as plain text via
-    /// `rest_text` and printed to stdout.
+    /// When the pull request head is checked out locally the diff comes from
+    /// `git diff`; otherwise it is fetched from the API in the diff (or
+    /// patch) media type. Binary file contents are summarized rather than
+    /// printed.
     ///
     /// # Errors
     ///
     /// Returns an error if the API request fails.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
 
         if self.web {
             let url = format!(
@@ -64,7 +64,6 @@ impl DiffArgs {
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
 
-        // Fetch the diff using the REST text endpoint.
         let path = format!(
             "repos/{}/{}/pulls/{}",
             repo.owner(),
@@ -72,52 +71,42 @@ impl DiffArgs {
             self.number,
         );
 
-        // Get the diff via the PR files endpoint for name-only mode
         if self.name_only {
-            let files_path = format!("{path}/files");
-            let files: serde_json::Value = client
-                .rest(
-                    reqwest::Method::GET,
-                    &files_path,
-                    None::<&serde_json::Value>,
-                )
+            let files: Vec<Value> = client
+                .rest_paginate(reqwest::Method::GET, &format!("{path}/files?per_page=100"))
                 .await
                 .context("failed to fetch pull request files")?;
-
-            let file_list = files
-                .as_array()
-                .ok_or_else(|| anyhow::anyhow!("unexpected API response format"))?;
-
-            for file in file_list {
+            for file in &files {
                 let filename = file.get("filename").and_then(Value::as_str).unwrap_or("");
                 ios_println!(ios, "{filename}");
             }
             return Ok(());
         }
 
-        // Fetch the full diff content. The GitHub REST API at
-        // /repos/{owner}/{repo}/pulls/{number} returns the diff when the
-        // Accept header is set to application/vnd.github.v3.diff. Since our
-        // client does not support custom headers on REST calls, we use the
-        // web URL pattern which provides the diff as plain text.
-        let suffix = if self.patch { "patch" } else { "diff" };
-        let diff_url = format!(
-            "https://{}/{}/{}/pull/{}.{suffix}",
-            repo.host(),
-            repo.owner(),
-            repo.name(),
-            self.number,
-        );
-
-        let diff_text = client
-            .rest_text(reqwest::Method::GET, &diff_url, None)
-            .await
-            .context("failed to fetch pull request diff")?;
+        let local = if self.patch {
+            None
+        } else {
+            local_diff(factory, &client, &path).await
+        };
+        let diff_text = if let Some(diff) = local {
+            diff
+        } else {
+            let accept = if self.patch {
+                "application/vnd.github.v3.patch"
+            } else {
+                "application/vnd.github.v3.diff"
+            };
+            client
+                .rest_text_with_accept(&path, accept)
+                .await
+                .context("failed to fetch pull request diff")?
+        };
+        let diff_text = summarize_binary_patches(&diff_text);
 
         let use_color = match self.color.as_str() {
             "always" => true,
             "never" => false,
-            _ => ios.is_stdout_tty(),
+            _ => ios.color_enabled(),
         };
 
         if use_color {
@@ -142,10 +131,73 @@ impl DiffArgs {
     }
 }
 
+/// Diff the pull request with local git when its head commit is checked out.
+///
+/// Returns `None` whenever the local checkout cannot answer, so the caller
+/// falls back to the API.
+async fn local_diff(
+    factory: &crate::factory::Factory,
+    client: &ghc_api::client::Client,
+    pr_path: &str,
+) -> Option<String> {
+    let git = factory.git_client().ok()?;
+    let head = git.last_commit().await.ok()?;
+    let pr: Value = client
+        .rest(reqwest::Method::GET, pr_path, None)
+        .await
+        .ok()?;
+    let head_sha = pr.pointer("/head/sha").and_then(Value::as_str)?;
+    let base_sha = pr.pointer("/base/sha").and_then(Value::as_str)?;
+    if head.sha != head_sha {
+        return None;
+    }
+    let mut diff = git.diff(base_sha, head_sha).await.ok()?;
+    if !diff.is_empty() && !diff.ends_with('\n') {
+        diff.push('\n');
+    }
+    Some(diff)
+}
+
+/// Replace the encoded contents of `GIT binary patch` sections with a
+/// one-line summary.
+fn summarize_binary_patches(diff: &str) -> std::borrow::Cow<'_, str> {
+    if !diff.contains("GIT binary patch") {
+        return std::borrow::Cow::Borrowed(diff);
+    }
+    let mut out = String::with_capacity(diff.len());
+    let mut in_binary = false;
+    for line in diff.split_inclusive('\n') {
+        if line.starts_with("diff --git ") {
+            in_binary = false;
+        } else if line.trim_end() == "GIT binary patch" {
+            in_binary = true;
+            out.push_str("Binary file contents not shown\n");
+            continue;
+        }
+        if !in_binary {
+            out.push_str(line);
+        }
+    }
+    std::borrow::Cow::Owned(out)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::test_helpers::{TestHarness, mock_rest_get};
+    use wiremock::matchers::{header, method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    fn diff_args(number: i64) -> DiffArgs {
+        DiffArgs {
+            number,
+            repo: Some("owner/repo".into()),
+            color: "never".into(),
+            name_only: false,
+            patch: false,
+            web: false,
+        }
+    }
 
     #[tokio::test]
     async fn test_should_list_changed_file_names() {
@@ -162,7 +214,7 @@ mod tests {
 
         let args = DiffArgs {
             number: 40,
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             color: "auto".into(),
             name_only: true,
             patch: false,
@@ -178,12 +230,69 @@ mod tests {
         assert!(out.contains("README.md"), "should contain filename: {out}");
     }
 
+    #[tokio::test]
+    async fn test_should_list_file_names_across_pages() {
+        let h = TestHarness::new().await;
+        let next = format!("{}/repos/owner/repo/pulls/40/files?page=2", h.server.uri());
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/pulls/40/files"))
+            .and(query_param("page", "2"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!([{ "filename": "b.rs" }])),
+            )
+            .mount(&h.server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/pulls/40/files"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("Link", format!("<{next}>; rel=\"next\"").as_str())
+                    .set_body_json(serde_json::json!([{ "filename": "a.rs" }])),
+            )
+            .mount(&h.server)
+            .await;
+
+        let args = DiffArgs {
+            name_only: true,
+            ..diff_args(40)
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert_eq!(h.stdout(), "a.rs\nb.rs\n");
+    }
+
+    #[tokio::test]
+    async fn test_should_fetch_diff_media_type_and_summarize_binaries() {
+        let h = TestHarness::new().await;
+        let diff = "diff --git a/logo.png b/logo.png\nindex 1..2 100644\nGIT binary patch\nliteral 5\nzcmZ?wbhEHbRA\n\ndiff --git a/a.rs b/a.rs\n+fn main() {}\n";
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/pulls/40"))
+            .and(header("Accept", "application/vnd.github.v3.diff"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(diff))
+            .mount(&h.server)
+            .await;
+
+        diff_args(40).run(&h.factory).await.unwrap();
+
+        let out = h.stdout();
+        assert!(out.contains("Binary file contents not shown\n"), "{out}");
+        assert!(!out.contains("zcmZ"), "{out}");
+        assert!(out.contains("+fn main() {}"), "{out}");
+    }
+
+    #[test]
+    fn test_should_leave_text_diffs_untouched() {
+        let diff = "diff --git a/a.rs b/a.rs\n-old\n+new\n";
+        assert_eq!(summarize_binary_patches(diff), diff);
+    }
+
     #[tokio::test]
     async fn test_should_open_browser_in_web_mode() {
         let h = TestHarness::new().await;
         let args = DiffArgs {
             number: 40,
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             color: "auto".into(),
             name_only: false,
             patch: false,
@@ -205,7 +314,7 @@ mod tests {
         let h = TestHarness::new().await;
         let args = DiffArgs {
             number: 1,
-            repo: "bad".into(),
+            repo: Some("bad".into()),
             color: "auto".into(),
             name_only: false,
             patch: false,
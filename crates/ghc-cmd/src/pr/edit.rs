@@ -17,7 +17,7 @@ pub struct EditArgs {
 
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// New title.
     #[arg(short, long)]
@@ -84,18 +84,14 @@ impl EditArgs {
     /// Returns an error if the API request fails.
     #[allow(clippy::too_many_lines)]
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
 
         // Resolve body from --body-file if provided
         let body_from_file = if let Some(ref body_file) = self.body_file {
-            Some(
-                crate::issue::create::read_body_file(body_file)
-                    .context("failed to read body file")?,
-            )
+            Some(ghc_core::cmdutil::read_body_file(body_file).context("failed to read body file")?)
         } else {
             None
         };
@@ -262,7 +258,7 @@ mod tests {
     fn default_edit_args(number: i64, repo: &str) -> EditArgs {
         EditArgs {
             number,
-            repo: repo.into(),
+            repo: Some(repo.into()),
             title: None,
             body: None,
             body_file: None,
@@ -6,7 +6,7 @@ use anyhow::{Context, Result};
 use clap::Args;
 use serde_json::Value;
 
-use ghc_core::table::TablePrinter;
+use ghc_core::table::{TablePrinter, Truncation};
 use ghc_core::text;
 use ghc_core::{ios_eprintln, ios_println};
 
@@ -15,7 +15,7 @@ use ghc_core::{ios_eprintln, ios_println};
 pub struct ListArgs {
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Filter by state.
     #[arg(short, long, default_value = "open", value_parser = ["open", "closed", "merged", "all"])]
@@ -64,6 +64,10 @@ pub struct ListArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl ListArgs {
@@ -74,8 +78,7 @@ impl ListArgs {
     /// Returns an error if the API request fails or the response is malformed.
     #[allow(clippy::too_many_lines)]
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
 
         if self.web {
             let url = format!(
@@ -139,18 +142,18 @@ impl ListArgs {
 
         // JSON output mode with field filtering, jq, or template
         // Always produces output (even [] for empty results)
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let mut arr = Value::Array(prs.clone());
             ghc_core::json::normalize_graphql_connections(&mut arr);
             ghc_core::json::normalize_author(&mut arr);
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &arr,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -167,11 +170,13 @@ impl ListArgs {
 
         let cs = ios.color_scheme();
         let mut tp = TablePrinter::new(ios);
+        tp.set_column_max_width(2, 40, Truncation::Middle);
 
         for pr in prs {
             let number = pr.get("number").and_then(Value::as_i64).unwrap_or(0);
             let title = pr.get("title").and_then(Value::as_str).unwrap_or("");
             let state = pr.get("state").and_then(Value::as_str).unwrap_or("OPEN");
+            let url = pr.get("url").and_then(Value::as_str).unwrap_or("");
             let is_draft = pr.get("isDraft").and_then(Value::as_bool).unwrap_or(false);
             let author = pr
                 .pointer("/author/login")
@@ -209,7 +214,7 @@ impl ListArgs {
             );
 
             tp.add_row(vec![
-                format!("{number}"),
+                tp.link(&number.to_string(), url),
                 text::truncate(title, 60),
                 head_ref.to_string(),
                 state_display,
@@ -239,7 +244,7 @@ mod tests {
         mock_graphql(&h.server, "PullRequestList", graphql_pr_list_response(&prs)).await;
 
         let args = ListArgs {
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             state: "open".into(),
             limit: 30,
             head: None,
@@ -252,6 +257,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
 
         args.run(&h.factory).await.unwrap();
@@ -270,7 +276,7 @@ mod tests {
         let h = TestHarness::new().await;
 
         let args = ListArgs {
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             state: "open".into(),
             limit: 30,
             head: None,
@@ -283,6 +289,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
 
         args.run(&h.factory).await.unwrap();
@@ -298,7 +305,7 @@ mod tests {
         mock_graphql(&h.server, "PullRequestList", graphql_pr_list_response(&prs)).await;
 
         let args = ListArgs {
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             state: "open".into(),
             limit: 30,
             head: None,
@@ -311,6 +318,7 @@ mod tests {
             json: vec!["number".into()],
             jq: None,
             template: None,
+            yaml: false,
         };
 
         args.run(&h.factory).await.unwrap();
@@ -325,7 +333,7 @@ mod tests {
     async fn test_should_return_error_on_invalid_repo_format() {
         let h = TestHarness::new().await;
         let args = ListArgs {
-            repo: "invalid-repo".into(),
+            repo: Some("invalid-repo".into()),
             state: "open".into(),
             limit: 30,
             head: None,
@@ -338,6 +346,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
 
         let result = args.run(&h.factory).await;
@@ -29,7 +29,7 @@ pub struct MergeArgs {
 
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Merge method to use (alternative to --merge/--squash/--rebase).
     #[arg(long, value_enum, conflicts_with_all = ["merge_flag", "squash", "rebase"])]
@@ -64,7 +64,7 @@ pub struct MergeArgs {
     delete_branch: bool,
 
     /// Enable auto-merge when requirements are met.
-    #[arg(long)]
+    #[arg(long, conflicts_with_all = ["disable_auto", "admin"])]
     auto: bool,
 
     /// Disable auto-merge for this pull request.
@@ -105,8 +105,7 @@ impl MergeArgs {
     ///
     /// Returns an error if the API request fails.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -151,10 +150,7 @@ impl MergeArgs {
 
         // Resolve body from --body-file if provided
         let body_from_file = if let Some(ref body_file) = self.body_file {
-            Some(
-                crate::issue::create::read_body_file(body_file)
-                    .context("failed to read body file")?,
-            )
+            Some(ghc_core::cmdutil::read_body_file(body_file).context("failed to read body file")?)
         } else {
             None
         };
@@ -267,10 +263,7 @@ impl MergeArgs {
 
         // Resolve body from --body-file if provided
         let body_from_file = if let Some(ref body_file) = self.body_file {
-            Some(
-                crate::issue::create::read_body_file(body_file)
-                    .context("failed to read body file")?,
-            )
+            Some(ghc_core::cmdutil::read_body_file(body_file).context("failed to read body file")?)
         } else {
             None
         };
@@ -436,7 +429,7 @@ impl MergeArgs {
                 self.number,
             );
         } else {
-            let merge_method = match self.method {
+            let merge_method = match self.explicit_merge_method() {
                 Some(MergeMethod::Merge) | None => "MERGE",
                 Some(MergeMethod::Squash) => "SQUASH",
                 Some(MergeMethod::Rebase) => "REBASE",
@@ -463,9 +456,11 @@ impl MergeArgs {
 
             ios_eprintln!(
                 ios,
-                "{} Enabled auto-merge for pull request #{} ({merge_method})",
+                "{} Pull request {}#{} will be automatically merged via {} when all requirements are met",
                 cs.success_icon(),
+                repo.full_name(),
                 self.number,
+                merge_method.to_lowercase(),
             );
         }
 
@@ -476,14 +471,14 @@ impl MergeArgs {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_helpers::TestHarness;
-    use wiremock::matchers::{method, path};
+    use crate::test_helpers::{TestHarness, mock_graphql};
+    use wiremock::matchers::{body_partial_json, body_string_contains, method, path};
     use wiremock::{Mock, ResponseTemplate};
 
     fn default_merge_args(number: i64, repo: &str) -> MergeArgs {
         MergeArgs {
             number,
-            repo: repo.into(),
+            repo: Some(repo.into()),
             method: Some(MergeMethod::Merge),
             merge_flag: false,
             squash: false,
@@ -571,6 +566,101 @@ mod tests {
         );
     }
 
+    async fn mount_pr_node_id(h: &TestHarness) {
+        mock_graphql(
+            &h.server,
+            "PrNodeId",
+            serde_json::json!({
+                "data": { "repository": { "pullRequest": { "id": "PR_123" } } }
+            }),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_should_enable_auto_merge_with_squash_method() {
+        let h = TestHarness::new().await;
+        mount_pr_node_id(&h).await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("enablePullRequestAutoMerge"))
+            .and(body_partial_json(serde_json::json!({
+                "variables": { "prId": "PR_123", "mergeMethod": "SQUASH" }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "enablePullRequestAutoMerge": { "pullRequest": { "number": 123 } } }
+            })))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let args = MergeArgs {
+            method: None,
+            squash: true,
+            auto: true,
+            ..default_merge_args(123, "owner/repo")
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert!(
+            h.stderr()
+                .contains("Pull request owner/repo#123 will be automatically merged via squash"),
+            "{}",
+            h.stderr()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_surface_auto_merge_api_error() {
+        let h = TestHarness::new().await;
+        mount_pr_node_id(&h).await;
+        mock_graphql(
+            &h.server,
+            "enablePullRequestAutoMerge",
+            serde_json::json!({
+                "errors": [{ "message": "Pull request Auto merge is not allowed for this repository" }]
+            }),
+        )
+        .await;
+
+        let args = MergeArgs {
+            auto: true,
+            ..default_merge_args(123, "owner/repo")
+        };
+        let err = args.run(&h.factory).await.unwrap_err();
+
+        assert!(
+            ghc_api::errors::describe(&err)
+                .contains("Pull request Auto merge is not allowed for this repository"),
+            "{err:#}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_disable_auto_merge() {
+        let h = TestHarness::new().await;
+        mount_pr_node_id(&h).await;
+        mock_graphql(
+            &h.server,
+            "disablePullRequestAutoMerge",
+            serde_json::json!({
+                "data": { "disablePullRequestAutoMerge": { "pullRequest": { "number": 123 } } }
+            }),
+        )
+        .await;
+
+        let args = MergeArgs {
+            disable_auto: true,
+            ..default_merge_args(123, "owner/repo")
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert!(
+            h.stderr()
+                .contains("Disabled auto-merge for pull request #123")
+        );
+    }
+
     #[tokio::test]
     async fn test_should_return_error_on_invalid_repo_for_merge() {
         let h = TestHarness::new().await;
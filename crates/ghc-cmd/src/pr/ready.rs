@@ -17,7 +17,7 @@ pub struct ReadyArgs {
 
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 }
 
 impl ReadyArgs {
@@ -30,8 +30,7 @@ impl ReadyArgs {
     ///
     /// Returns an error if the API request fails or the PR is not a draft.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -149,7 +148,7 @@ mod tests {
 
         let args = ReadyArgs {
             number: 15,
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
         };
 
         args.run(&h.factory).await.unwrap();
@@ -182,7 +181,7 @@ mod tests {
 
         let args = ReadyArgs {
             number: 16,
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
         };
 
         args.run(&h.factory).await.unwrap();
@@ -198,7 +197,7 @@ mod tests {
         let h = TestHarness::new().await;
         let args = ReadyArgs {
             number: 1,
-            repo: "bad".into(),
+            repo: Some("bad".into()),
         };
 
         let result = args.run(&h.factory).await;
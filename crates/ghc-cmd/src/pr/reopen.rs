@@ -15,7 +15,7 @@ pub struct ReopenArgs {
 
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Leave a comment when reopening.
     #[arg(short, long)]
@@ -29,8 +29,7 @@ impl ReopenArgs {
     ///
     /// Returns an error if the API request fails.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -92,7 +91,7 @@ mod tests {
 
         let args = ReopenArgs {
             number: 7,
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             comment: None,
         };
 
@@ -126,7 +125,7 @@ mod tests {
 
         let args = ReopenArgs {
             number: 7,
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             comment: Some("Reopening".into()),
         };
 
@@ -143,7 +142,7 @@ mod tests {
         let h = TestHarness::new().await;
         let args = ReopenArgs {
             number: 1,
-            repo: "bad".into(),
+            repo: Some("bad".into()),
             comment: None,
         };
 
@@ -16,7 +16,7 @@ pub struct RevertArgs {
 
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Title for the revert pull request. Defaults to "Revert #{number}".
     #[arg(short, long)]
@@ -58,8 +58,7 @@ impl RevertArgs {
     /// Returns an error if the PR is not merged or the API request fails.
     #[allow(clippy::too_many_lines)]
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -184,7 +183,7 @@ impl RevertArgs {
         let body = if let Some(ref b) = self.body {
             b.clone()
         } else if let Some(ref body_file) = self.body_file {
-            crate::issue::create::read_body_file(body_file).context("failed to read body file")?
+            ghc_core::cmdutil::read_body_file(body_file).context("failed to read body file")?
         } else if self.editor {
             let prompter = factory.prompter();
             prompter
@@ -248,7 +247,7 @@ mod tests {
 
         let args = RevertArgs {
             number: 60,
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             title: None,
             body: None,
             body_file: None,
@@ -271,7 +270,7 @@ mod tests {
         let h = TestHarness::new().await;
         let args = RevertArgs {
             number: 1,
-            repo: "bad".into(),
+            repo: Some("bad".into()),
             title: None,
             body: None,
             body_file: None,
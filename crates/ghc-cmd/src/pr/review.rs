@@ -26,7 +26,7 @@ pub struct ReviewArgs {
 
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Review action to take (alternative to --approve/--comment/--request-changes).
     #[arg(long, value_enum, conflicts_with_all = ["approve", "comment_flag", "request_changes"])]
@@ -59,8 +59,7 @@ impl ReviewArgs {
     ///
     /// Returns an error if the API request fails.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -134,7 +133,7 @@ mod tests {
 
         let args = ReviewArgs {
             number: 20,
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             event: None,
             approve: true,
             comment_flag: false,
@@ -161,7 +160,7 @@ mod tests {
 
         let args = ReviewArgs {
             number: 21,
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             event: None,
             approve: false,
             comment_flag: false,
@@ -182,7 +181,7 @@ mod tests {
         let h = TestHarness::new().await;
         let args = ReviewArgs {
             number: 1,
-            repo: "bad".into(),
+            repo: Some("bad".into()),
             event: None,
             approve: true,
             comment_flag: false,
@@ -65,7 +65,7 @@ query PullRequestStatus($owner: String!, $name: String!, $headRefName: String!,
 pub struct StatusArgs {
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Output JSON with specified fields.
     #[arg(long, value_delimiter = ',')]
@@ -78,6 +78,10 @@ pub struct StatusArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl StatusArgs {
@@ -88,8 +92,7 @@ impl StatusArgs {
     /// Returns an error if the API request fails.
     #[allow(clippy::too_many_lines)]
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -127,15 +130,15 @@ impl StatusArgs {
             .context("failed to fetch pull request status")?;
 
         // JSON output
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
-            let output = ghc_core::json::format_json_output(
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
+            ghc_core::json::output(
                 &data,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -297,10 +300,11 @@ mod tests {
         .await;
 
         let args = StatusArgs {
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
 
         args.run(&h.factory).await.unwrap();
@@ -350,10 +354,11 @@ mod tests {
         .await;
 
         let args = StatusArgs {
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
 
         args.run(&h.factory).await.unwrap();
@@ -368,10 +373,11 @@ mod tests {
     async fn test_should_return_error_on_invalid_repo_for_status() {
         let h = TestHarness::new().await;
         let args = StatusArgs {
-            repo: "bad".into(),
+            repo: Some("bad".into()),
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
 
         let result = args.run(&h.factory).await;
@@ -24,7 +24,7 @@ pub struct UpdateBranchArgs {
 
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Update method.
     #[arg(short, long, value_enum, default_value = "merge")]
@@ -41,8 +41,7 @@ impl UpdateBranchArgs {
     ///
     /// Returns an error if the API request fails.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -132,7 +131,7 @@ mod tests {
 
         let args = UpdateBranchArgs {
             number: 25,
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             method: UpdateMethod::Merge,
         };
 
@@ -170,7 +169,7 @@ mod tests {
 
         let args = UpdateBranchArgs {
             number: 26,
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             method: UpdateMethod::Rebase,
         };
 
@@ -187,7 +186,7 @@ mod tests {
         let h = TestHarness::new().await;
         let args = UpdateBranchArgs {
             number: 1,
-            repo: "bad".into(),
+            repo: Some("bad".into()),
             method: UpdateMethod::Merge,
         };
 
@@ -17,7 +17,7 @@ pub struct ViewArgs {
 
     /// Repository in OWNER/REPO format.
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 
     /// Open in web browser.
     #[arg(short, long)]
@@ -38,6 +38,10 @@ pub struct ViewArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl ViewArgs {
@@ -48,8 +52,7 @@ impl ViewArgs {
     /// Returns an error if the API request fails or the PR is not found.
     #[allow(clippy::too_many_lines)]
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = ghc_core::repo::Repo::from_full_name(&self.repo)
-            .context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
 
         if self.web {
             let url = format!(
@@ -92,18 +95,18 @@ impl ViewArgs {
         let cs = ios.color_scheme();
 
         // JSON output with field filtering, jq, or template
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let mut pr_owned = pr.clone();
             ghc_core::json::normalize_graphql_connections(&mut pr_owned);
             ghc_core::json::normalize_author(&mut pr_owned);
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &pr_owned,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -230,8 +233,10 @@ impl ViewArgs {
         ios_println!(ios, "--");
         if body.is_empty() {
             ios_println!(ios, "{}", cs.gray("No description provided."));
+        } else if ios.is_stdout_tty() && ios.is_accessible() {
+            ios_println!(ios, "{}", ghc_core::markdown::render_plain(body));
         } else if ios.is_stdout_tty() {
-            let rendered = ghc_core::markdown::render(body, ios.terminal_width());
+            let rendered = ghc_core::markdown::render(body, ios.terminal_width(), &cs);
             ios_println!(ios, "{rendered}");
         } else {
             ios_println!(ios, "{body}");
@@ -446,12 +451,13 @@ mod tests {
 
         let args = ViewArgs {
             number: 42,
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             web: false,
             comments: false,
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
 
         args.run(&h.factory).await.unwrap();
@@ -484,12 +490,13 @@ mod tests {
 
         let args = ViewArgs {
             number: 42,
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             web: true,
             comments: false,
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
 
         args.run(&h.factory).await.unwrap();
@@ -510,12 +517,13 @@ mod tests {
 
         let args = ViewArgs {
             number: 42,
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             web: false,
             comments: false,
             json: vec!["number".into()],
             jq: None,
             template: None,
+            yaml: false,
         };
 
         args.run(&h.factory).await.unwrap();
@@ -582,12 +590,13 @@ mod tests {
 
         let args = ViewArgs {
             number: 42,
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             web: false,
             comments: true,
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
 
         args.run(&h.factory).await.unwrap();
@@ -638,12 +647,13 @@ mod tests {
 
         let args = ViewArgs {
             number: 42,
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             web: false,
             comments: false,
             json: vec![],
             jq: Some(".title".into()),
             template: None,
+            yaml: false,
         };
 
         args.run(&h.factory).await.unwrap();
@@ -667,12 +677,13 @@ mod tests {
 
         let args = ViewArgs {
             number: 999,
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             web: false,
             comments: false,
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
 
         let result = args.run(&h.factory).await;
@@ -701,12 +712,13 @@ mod tests {
 
         let args = ViewArgs {
             number: 99999,
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             web: false,
             comments: false,
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
 
         let result = args.run(&h.factory).await;
@@ -26,7 +26,7 @@ impl CloseArgs {
     ///
     /// Returns an error if the project cannot be closed.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
 
         let project_id = resolve_project_id(&client, &self.owner, self.number).await?;
 
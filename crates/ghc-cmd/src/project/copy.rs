@@ -38,7 +38,7 @@ impl CopyArgs {
     ///
     /// Returns an error if the project cannot be copied.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
 
         let project_id =
             super::close::resolve_project_id(&client, &self.source_owner, self.number).await?;
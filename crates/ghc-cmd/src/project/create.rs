@@ -26,7 +26,7 @@ impl CreateArgs {
     ///
     /// Returns an error if the project cannot be created.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
 
         let owner_id = super::copy::resolve_owner_id(&client, &self.owner).await?;
 
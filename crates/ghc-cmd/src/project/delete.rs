@@ -26,7 +26,7 @@ impl DeleteArgs {
     ///
     /// Returns an error if the project cannot be deleted.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
 
         let project_id =
             super::close::resolve_project_id(&client, &self.owner, self.number).await?;
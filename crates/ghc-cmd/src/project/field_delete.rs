@@ -22,7 +22,7 @@ impl FieldDeleteArgs {
     ///
     /// Returns an error if the field cannot be deleted.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
 
         let query = r"
             mutation DeleteField($fieldId: ID!) {
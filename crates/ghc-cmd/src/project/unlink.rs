@@ -7,7 +7,6 @@ use clap::Args;
 use serde_json::Value;
 
 use ghc_core::ios_eprintln;
-use ghc_core::repo::Repo;
 
 /// Unlink a project from a repository.
 #[derive(Debug, Args)]
@@ -22,7 +21,7 @@ pub struct UnlinkArgs {
 
     /// Repository to unlink (OWNER/REPO).
     #[arg(short = 'R', long)]
-    repo: String,
+    repo: Option<String>,
 }
 
 impl UnlinkArgs {
@@ -32,12 +31,12 @@ impl UnlinkArgs {
     ///
     /// Returns an error if the project cannot be unlinked.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
 
         let project_id =
             super::close::resolve_project_id(&client, &self.owner, self.number).await?;
 
-        let repo = Repo::from_full_name(&self.repo).context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let repo_id = super::link::resolve_repo_id(&client, repo.owner(), repo.name()).await?;
 
         let query = r"
@@ -67,7 +66,7 @@ impl UnlinkArgs {
             "{} Unlinked project #{} from {}",
             cs.success_icon(),
             self.number,
-            cs.bold(&self.repo),
+            cs.bold(&repo.full_name()),
         );
 
         Ok(())
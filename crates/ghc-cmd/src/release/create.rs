@@ -95,7 +95,7 @@ impl CreateArgs {
     /// Returns an error if the release cannot be created.
     #[allow(clippy::too_many_lines)]
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = resolve_repo(self.repo.as_deref())?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
 
         // Verify tag exists if --verify-tag is set
@@ -306,13 +306,6 @@ async fn upload_assets(
     Ok(())
 }
 
-/// Resolve a repository from the `--repo` flag or bail.
-fn resolve_repo(repo_flag: Option<&str>) -> Result<Repo> {
-    let name = repo_flag
-        .ok_or_else(|| anyhow::anyhow!("repository argument required (use -R OWNER/REPO)"))?;
-    Repo::from_full_name(name).context("invalid repository format")
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,12 +1,16 @@
 //! `ghc release download` command.
 
+use std::collections::HashMap;
 use std::io::Write;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use clap::Args;
 use serde_json::Value;
+use sha2::{Digest, Sha256};
 
 use ghc_core::ios_eprintln;
+use ghc_core::iostreams::ProgressHandle;
 use ghc_core::repo::Repo;
 
 /// Download release assets.
@@ -16,7 +20,13 @@ use ghc_core::repo::Repo;
 ///
 /// Use `--pattern` to filter assets by glob pattern. Use `--skip-existing`
 /// to skip assets that already exist on disk. Use `--clobber` to overwrite
-/// existing files.
+/// existing files. Use `--verify` to check each downloaded asset against the
+/// SHA256 sums in the release's checksums file or its own `.sha256` sidecar;
+/// an asset with no listed checksum is an error.
+///
+/// Assets are downloaded concurrently (see `--concurrency`) into `.tmp`
+/// files that are renamed once complete, so an interrupted download never
+/// leaves a truncated asset behind.
 #[derive(Debug, Args)]
 pub struct DownloadArgs {
     /// Tag name of the release (or "latest").
@@ -46,6 +56,14 @@ pub struct DownloadArgs {
     /// Include source code archive (zip/tarball) in the download.
     #[arg(short = 'A', long)]
     archive: Option<String>,
+
+    /// Verify downloaded assets against the release's checksums file.
+    #[arg(long)]
+    verify: bool,
+
+    /// Maximum number of assets to download at once.
+    #[arg(long, value_name = "N", default_value = "4", value_parser = clap::value_parser!(u32).range(1..))]
+    concurrency: u32,
 }
 
 impl DownloadArgs {
@@ -55,11 +73,7 @@ impl DownloadArgs {
     ///
     /// Returns an error if the assets cannot be downloaded.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = self
-            .repo
-            .as_deref()
-            .ok_or_else(|| anyhow::anyhow!("repository argument required (use -R OWNER/REPO)"))?;
-        let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
 
         let path = if self.tag == "latest" {
@@ -104,39 +118,71 @@ impl DownloadArgs {
             }
         }
 
-        download_assets(
-            &client,
-            assets,
-            &self.pattern,
-            &self.dir,
-            self.skip_existing,
-            self.clobber,
-            ios,
-        )
-        .await
+        let opts = AssetOptions {
+            patterns: &self.pattern,
+            dir: &self.dir,
+            skip_existing: self.skip_existing,
+            clobber: self.clobber,
+            verify: self.verify,
+            concurrency: self.concurrency as usize,
+        };
+        download_assets(&client, assets, &opts, ios).await
     }
 }
 
+/// Options controlling which assets are fetched and how.
+#[derive(Debug)]
+struct AssetOptions<'a> {
+    patterns: &'a [String],
+    dir: &'a str,
+    skip_existing: bool,
+    clobber: bool,
+    verify: bool,
+    concurrency: usize,
+}
+
+/// A single asset queued for download.
+#[derive(Debug)]
+struct AssetJob {
+    name: String,
+    url: String,
+    dest: PathBuf,
+    /// Expected hex SHA256, when verifying against a checksums file.
+    expected: Option<String>,
+}
+
 /// Download individual release assets, applying pattern filters.
-#[allow(clippy::too_many_arguments)]
 async fn download_assets(
     client: &ghc_api::client::Client,
     assets: &[Value],
-    patterns: &[String],
-    dir: &str,
-    skip_existing: bool,
-    clobber: bool,
+    opts: &AssetOptions<'_>,
     ios: &ghc_core::iostreams::IOStreams,
 ) -> Result<()> {
-    let cs = ios.color_scheme();
-
     if assets.is_empty() {
         ios_eprintln!(ios, "No assets to download");
         return Ok(());
     }
 
+    let mut jobs = plan_downloads(assets, opts, ios)?;
+    if opts.verify {
+        attach_checksums(client, assets, &mut jobs).await?;
+    }
+
+    let dir = opts.dir;
     std::fs::create_dir_all(dir).with_context(|| format!("failed to create directory: {dir}"))?;
 
+    run_downloads(client, jobs, opts.concurrency, ios).await
+}
+
+/// Select the assets to download, reporting the ones that are skipped.
+fn plan_downloads(
+    assets: &[Value],
+    opts: &AssetOptions<'_>,
+    ios: &ghc_core::iostreams::IOStreams,
+) -> Result<Vec<AssetJob>> {
+    let cs = ios.color_scheme();
+    let mut jobs = Vec::new();
+
     for asset in assets {
         let name = asset
             .get("name")
@@ -148,12 +194,17 @@ async fn download_assets(
             .ok_or_else(|| anyhow::anyhow!("no download URL for asset {name}"))?;
 
         // Apply pattern filter
-        if !patterns.is_empty() && !patterns.iter().any(|p| glob_match(p, name)) {
+        if !opts.patterns.is_empty()
+            && !opts
+                .patterns
+                .iter()
+                .any(|p| ghc_core::cmdutil::glob_match(p, name))
+        {
             continue;
         }
 
-        let dest = std::path::Path::new(dir).join(name);
-        if dest.exists() && skip_existing {
+        let dest = Path::new(opts.dir).join(name);
+        if dest.exists() && opts.skip_existing {
             ios_eprintln!(
                 ios,
                 "{} Skipping {name} (already exists)",
@@ -161,7 +212,7 @@ async fn download_assets(
             );
             continue;
         }
-        if dest.exists() && !clobber {
+        if dest.exists() && !opts.clobber {
             ios_eprintln!(
                 ios,
                 "{} Skipping {name} (already exists, use --clobber to overwrite)",
@@ -170,24 +221,114 @@ async fn download_assets(
             continue;
         }
 
-        ios_eprintln!(ios, "Downloading {name}...");
+        jobs.push(AssetJob {
+            name: name.to_string(),
+            url: download_url.to_string(),
+            dest,
+            expected: None,
+        });
+    }
 
-        let body = client
-            .rest_text(reqwest::Method::GET, download_url, None)
-            .await
-            .with_context(|| format!("failed to download {name}"))?;
+    Ok(jobs)
+}
 
-        let mut file = std::fs::File::create(&dest)
-            .with_context(|| format!("failed to create file: {}", dest.display()))?;
-        file.write_all(body.as_bytes())
-            .with_context(|| format!("failed to write file: {}", dest.display()))?;
+/// Download `jobs` with at most `concurrency` transfers in flight.
+///
+/// Each transfer in flight gets its own spinner showing the bytes received.
+/// On the first failure the remaining transfers are cancelled and their
+/// partial files removed.
+async fn run_downloads(
+    client: &ghc_api::client::Client,
+    jobs: Vec<AssetJob>,
+    concurrency: usize,
+    ios: &ghc_core::iostreams::IOStreams,
+) -> Result<()> {
+    let cs = ios.color_scheme();
+    let progress = ios.start_progress_group();
+    let mut pending = jobs.into_iter();
+    let mut in_flight = tokio::task::JoinSet::new();
+    let mut started = Vec::new();
+
+    loop {
+        while in_flight.len() < concurrency.max(1) {
+            let Some(job) = pending.next() else { break };
+            if !progress.is_active() {
+                ios_eprintln!(ios, "Downloading {}...", job.name);
+            }
+            let item = progress.add(&format!("Downloading {}…", job.name));
+            started.push(job.dest.clone());
+            let client = client.clone();
+            in_flight.spawn(async move {
+                let result =
+                    download_to(&client, &job.url, &job.dest, job.expected.as_deref(), &item).await;
+                (job, result)
+            });
+        }
 
-        ios_eprintln!(ios, "{} Downloaded {name}", cs.success_icon());
+        let Some(joined) = in_flight.join_next().await else {
+            break;
+        };
+        let (job, result) = joined.context("download task failed")?;
+        if let Err(err) = result {
+            in_flight.abort_all();
+            while in_flight.join_next().await.is_some() {}
+            for dest in &started {
+                let _ = std::fs::remove_file(tmp_path(dest));
+            }
+            return Err(err);
+        }
+        progress.suspend(|| {
+            ios_eprintln!(ios, "{} Downloaded {}", cs.success_icon(), job.name);
+        });
     }
 
     Ok(())
 }
 
+/// Download `url` into `dest` through a `.tmp` sibling that is renamed into
+/// place only once the transfer completes and, if `expected` is given, its
+/// SHA256 matches.
+///
+/// `progress` is relabeled with the bytes received as the transfer runs.
+async fn download_to(
+    client: &ghc_api::client::Client,
+    url: &str,
+    dest: &Path,
+    expected: Option<&str>,
+    progress: &ProgressHandle,
+) -> Result<()> {
+    let tmp = tmp_path(dest);
+    let name = dest.file_name().unwrap_or_default().to_string_lossy();
+    let on_chunk = |received| {
+        progress.set_label(&format!(
+            "Downloading {name} ({})…",
+            ghc_core::text::format_size(received)
+        ));
+    };
+    let digest = match stream_to_file(client, url, &tmp, on_chunk).await {
+        Ok(digest) => digest,
+        Err(err) => {
+            let _ = std::fs::remove_file(&tmp);
+            return Err(err.context(format!("failed to download {name}")));
+        }
+    };
+    if let Some(expected) = expected
+        && !expected.eq_ignore_ascii_case(&digest)
+    {
+        let _ = std::fs::remove_file(&tmp);
+        anyhow::bail!("checksum mismatch for {name}: expected {expected}, got {digest}");
+    }
+    std::fs::rename(&tmp, dest)
+        .with_context(|| format!("failed to move download into place: {}", dest.display()))
+}
+
+/// The partial-download path for `dest`: the same name with `.tmp` appended.
+fn tmp_path(dest: &Path) -> PathBuf {
+    let mut name = dest.file_name().unwrap_or_default().to_os_string();
+    name.push(".tmp");
+    dest.with_file_name(name)
+}
+
 /// Download a source code archive from a release.
 #[allow(clippy::too_many_arguments)]
 async fn download_archive(
@@ -217,7 +358,7 @@ async fn download_archive(
         repo.name(),
     );
     let archive_name = format!("{}-{tag}.{ext}", repo.name());
-    let dest = std::path::Path::new(dir).join(&archive_name);
+    let dest = Path::new(dir).join(&archive_name);
 
     if dest.exists() && skip_existing {
         ios_eprintln!(
@@ -237,96 +378,382 @@ async fn download_archive(
     }
 
     std::fs::create_dir_all(dir).with_context(|| format!("failed to create directory: {dir}"))?;
-    ios_eprintln!(ios, "Downloading {archive_name}...");
-    let body = client
-        .rest_text(reqwest::Method::GET, &archive_url, None)
-        .await
-        .with_context(|| format!("failed to download {archive_name}"))?;
-    let mut file = std::fs::File::create(&dest)
-        .with_context(|| format!("failed to create file: {}", dest.display()))?;
-    file.write_all(body.as_bytes())
-        .with_context(|| format!("failed to write file: {}", dest.display()))?;
+    let progress = ios.start_progress_indicator_with_label(&format!("Downloading {archive_name}…"));
+    if !progress.is_active() {
+        ios_eprintln!(ios, "Downloading {archive_name}...");
+    }
+    download_to(client, &archive_url, &dest, None, &progress).await?;
+    progress.stop();
     ios_eprintln!(ios, "{} Downloaded {archive_name}", cs.success_icon());
     Ok(())
 }
 
-/// Simple glob matching supporting `*` wildcards.
-fn glob_match(pattern: &str, text: &str) -> bool {
-    let parts: Vec<&str> = pattern.split('*').collect();
+/// Stream a download to `dest` chunk by chunk, returning its hex SHA256.
+///
+/// `on_chunk` is called with the total bytes received after every chunk.
+async fn stream_to_file(
+    client: &ghc_api::client::Client,
+    url: &str,
+    dest: &Path,
+    mut on_chunk: impl FnMut(u64),
+) -> Result<String> {
+    let mut resp = client.rest_response(reqwest::Method::GET, url).await?;
+    let mut file = std::fs::File::create(dest)
+        .with_context(|| format!("failed to create file: {}", dest.display()))?;
+    let mut hasher = Sha256::new();
+    let mut received = 0;
+    while let Some(chunk) = resp.chunk().await? {
+        hasher.update(&chunk);
+        file.write_all(&chunk)
+            .with_context(|| format!("failed to write file: {}", dest.display()))?;
+        received += chunk.len() as u64;
+        on_chunk(received);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
 
-    if parts.len() == 1 {
-        return pattern == text;
+/// Whether an asset name looks like a checksums file listing many assets,
+/// such as `checksums.txt`, `SHA256SUMS`, or `app_1.2.0_checksums.txt`.
+fn is_aggregate_checksums(name: &str) -> bool {
+    let lower = name.to_ascii_lowercase();
+    lower.contains("checksums") || lower.contains("sha256sums")
+}
+
+/// Whether an asset name is an aggregate checksums file or a per-asset
+/// `<asset>.sha256` sidecar.
+fn is_checksums_file(name: &str) -> bool {
+    is_aggregate_checksums(name) || name.to_ascii_lowercase().ends_with(".sha256")
+}
+
+/// Set the expected SHA256 of every job from the release's checksums.
+///
+/// Aggregate checksums files are consulted first; an asset they do not list
+/// is covered only by its own `<asset>.sha256` sidecar. Checksums files
+/// themselves are downloaded unverified.
+///
+/// # Errors
+///
+/// Fails if the release has no checksums file or any other asset has no
+/// checksum, so `--verify` never passes without verifying.
+async fn attach_checksums(
+    client: &ghc_api::client::Client,
+    assets: &[Value],
+    jobs: &mut [AssetJob],
+) -> Result<()> {
+    let urls: HashMap<&str, &str> = assets
+        .iter()
+        .filter_map(|asset| {
+            let name = asset.get("name").and_then(Value::as_str)?;
+            let url = asset.get("browser_download_url").and_then(Value::as_str)?;
+            Some((name, url))
+        })
+        .collect();
+    if !urls.keys().any(|name| is_checksums_file(name)) {
+        anyhow::bail!("cannot verify: release has no checksums file");
     }
 
-    let mut remaining = text;
+    let mut sums = HashMap::new();
+    for (name, url) in urls.iter().filter(|(name, _)| is_aggregate_checksums(name)) {
+        sums.extend(parse_checksums(&fetch_text(client, name, url).await?));
+    }
 
-    for (i, part) in parts.iter().enumerate() {
-        if part.is_empty() {
-            continue;
-        }
-        if i == 0 {
-            if !remaining.starts_with(part) {
-                return false;
-            }
-            remaining = &remaining[part.len()..];
-        } else if i == parts.len() - 1 {
-            if !remaining.ends_with(part) {
-                return false;
-            }
-            return true;
-        } else {
-            match remaining.find(part) {
-                Some(pos) => remaining = &remaining[pos + part.len()..],
-                None => return false,
-            }
+    for job in jobs.iter_mut().filter(|job| !is_checksums_file(&job.name)) {
+        let sidecar = format!("{}.sha256", job.name);
+        if !sums.contains_key(&job.name)
+            && let Some(url) = urls.get(sidecar.as_str())
+            && let Some(hash) = parse_sidecar(&fetch_text(client, &sidecar, url).await?)
+        {
+            sums.insert(job.name.clone(), hash);
         }
+        let hash = sums
+            .get(&job.name)
+            .ok_or_else(|| anyhow::anyhow!("cannot verify: no checksum listed for {}", job.name))?;
+        job.expected = Some(hash.clone());
     }
+    Ok(())
+}
+
+/// Download a small text asset such as a checksums file.
+async fn fetch_text(client: &ghc_api::client::Client, name: &str, url: &str) -> Result<String> {
+    client
+        .rest_text(reqwest::Method::GET, url, None)
+        .await
+        .with_context(|| format!("failed to download {name}"))
+}
 
-    true
+/// The hash in a `<asset>.sha256` sidecar, which holds either a bare hex
+/// digest or a single `sha256sum` line.
+fn parse_sidecar(body: &str) -> Option<String> {
+    body.split_whitespace().next().map(str::to_ascii_lowercase)
+}
+
+/// Parse `sha256sum` output: `<hex>  <name>` per line, where binary-mode
+/// entries prefix the name with `*`.
+fn parse_checksums(body: &str) -> HashMap<String, String> {
+    body.lines()
+        .filter_map(|line| {
+            let (hash, name) = line.trim().split_once(char::is_whitespace)?;
+            let name = name.trim_start().trim_start_matches('*');
+            (!name.is_empty()).then(|| (name.to_string(), hash.to_ascii_lowercase()))
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    // --- glob_match unit tests ---
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
 
-    #[test]
-    fn test_should_match_exact_pattern() {
-        assert!(glob_match("foo.tar.gz", "foo.tar.gz"));
-        assert!(!glob_match("foo.tar.gz", "bar.tar.gz"));
+    use crate::test_helpers::{TestHarness, mock_rest_get};
+
+    const ZIP_BYTES: &[u8] = b"PK\x03\x04\xff\xfe binary";
+
+    fn download_args(dir: &Path, verify: bool) -> DownloadArgs {
+        DownloadArgs {
+            tag: "v1.2.0".into(),
+            repo: Some("owner/repo".into()),
+            pattern: vec!["*.zip".into()],
+            dir: dir.display().to_string(),
+            clobber: false,
+            skip_existing: false,
+            archive: None,
+            verify,
+            concurrency: 4,
+        }
     }
 
-    #[test]
-    fn test_should_match_wildcard_prefix() {
-        assert!(glob_match("*.tar.gz", "foo.tar.gz"));
-        assert!(glob_match("*.tar.gz", "bar.tar.gz"));
-        assert!(!glob_match("*.tar.gz", "foo.zip"));
+    async fn mount_release(h: &TestHarness, checksums: Option<&str>) {
+        match checksums {
+            Some(checksums) => mount_release_with(h, &[("checksums.txt", checksums)]).await,
+            None => mount_release_with(h, &[]).await,
+        }
     }
 
-    #[test]
-    fn test_should_match_wildcard_suffix() {
-        assert!(glob_match("foo*", "foo.tar.gz"));
-        assert!(glob_match("foo*", "foobar"));
-        assert!(!glob_match("foo*", "barfoo"));
+    /// Mount a release with `app.zip`, `app.tar.gz` and the given extra
+    /// `(name, body)` text assets.
+    async fn mount_release_with(h: &TestHarness, extra: &[(&str, &str)]) {
+        let base = h.server.uri();
+        let mut assets = vec![
+            serde_json::json!({ "name": "app.zip", "browser_download_url": format!("{base}/dl/app.zip") }),
+            serde_json::json!({ "name": "app.tar.gz", "browser_download_url": format!("{base}/dl/app.tar.gz") }),
+        ];
+        for (name, body) in extra {
+            assets.push(serde_json::json!({
+                "name": name,
+                "browser_download_url": format!("{base}/dl/{name}"),
+            }));
+            Mock::given(method("GET"))
+                .and(path(format!("/dl/{name}")))
+                .respond_with(ResponseTemplate::new(200).set_body_string(*body))
+                .mount(&h.server)
+                .await;
+        }
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/releases/tags/v1.2.0",
+            serde_json::json!({ "tag_name": "v1.2.0", "assets": assets }),
+        )
+        .await;
+        Mock::given(method("GET"))
+            .and(path("/dl/app.zip"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(ZIP_BYTES))
+            .mount(&h.server)
+            .await;
+    }
+
+    fn sha256_hex(bytes: &[u8]) -> String {
+        format!("{:x}", Sha256::digest(bytes))
+    }
+
+    #[tokio::test]
+    async fn test_should_download_matching_assets_and_verify_checksums() {
+        let h = TestHarness::new().await;
+        let dir = tempfile::tempdir().unwrap();
+        let sums = format!(
+            "{}  app.zip\n{}  app.tar.gz\n",
+            sha256_hex(ZIP_BYTES),
+            sha256_hex(b"other")
+        );
+        mount_release(&h, Some(&sums)).await;
+
+        download_args(dir.path(), true)
+            .run(&h.factory)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            std::fs::read(dir.path().join("app.zip")).unwrap(),
+            ZIP_BYTES
+        );
+        assert!(!dir.path().join("app.tar.gz").exists());
+        assert!(h.stderr().contains("Downloaded app.zip"));
+    }
+
+    #[tokio::test]
+    async fn test_should_fail_on_checksum_mismatch() {
+        let h = TestHarness::new().await;
+        let dir = tempfile::tempdir().unwrap();
+        let sums = format!("{}  app.zip\n", sha256_hex(b"tampered"));
+        mount_release(&h, Some(&sums)).await;
+
+        let err = download_args(dir.path(), true)
+            .run(&h.factory)
+            .await
+            .unwrap_err();
+
+        assert!(
+            err.to_string().contains("checksum mismatch for app.zip"),
+            "{err}"
+        );
+        assert!(!dir.path().join("app.zip").exists());
+    }
+
+    #[tokio::test]
+    async fn test_should_fail_when_checksums_file_omits_asset() {
+        let h = TestHarness::new().await;
+        let dir = tempfile::tempdir().unwrap();
+        let sums = format!("{}  app.tar.gz\n", sha256_hex(b"other"));
+        mount_release(&h, Some(&sums)).await;
+
+        let err = download_args(dir.path(), true)
+            .run(&h.factory)
+            .await
+            .unwrap_err();
+
+        assert!(
+            err.to_string().contains("no checksum listed for app.zip"),
+            "{err}"
+        );
+        assert!(!dir.path().join("app.zip").exists());
+    }
+
+    #[tokio::test]
+    async fn test_should_verify_with_matching_sidecar_only() {
+        let h = TestHarness::new().await;
+        let dir = tempfile::tempdir().unwrap();
+        let other_sidecar = sha256_hex(b"other");
+        mount_release_with(&h, &[("app.tar.gz.sha256", &other_sidecar)]).await;
+
+        let err = download_args(dir.path(), true)
+            .run(&h.factory)
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("no checksum listed for app.zip"),
+            "{err}"
+        );
+
+        let h = TestHarness::new().await;
+        let sidecar = format!("{}  app.zip\n", sha256_hex(ZIP_BYTES));
+        mount_release_with(&h, &[("app.zip.sha256", &sidecar)]).await;
+
+        download_args(dir.path(), true)
+            .run(&h.factory)
+            .await
+            .unwrap();
+        assert_eq!(
+            std::fs::read(dir.path().join("app.zip")).unwrap(),
+            ZIP_BYTES
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_require_checksums_file_to_verify() {
+        let h = TestHarness::new().await;
+        let dir = tempfile::tempdir().unwrap();
+        mount_release(&h, None).await;
+
+        let err = download_args(dir.path(), true)
+            .run(&h.factory)
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("no checksums file"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn test_should_download_many_assets_without_leaving_tmp_files() {
+        let h = TestHarness::new().await;
+        let dir = tempfile::tempdir().unwrap();
+        let base = h.server.uri();
+        let assets: Vec<Value> = (0..10)
+            .map(|i| {
+                serde_json::json!({
+                    "name": format!("asset-{i}.zip"),
+                    "browser_download_url": format!("{base}/dl/asset-{i}.zip"),
+                })
+            })
+            .collect();
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/releases/tags/v1.2.0",
+            serde_json::json!({ "tag_name": "v1.2.0", "assets": assets }),
+        )
+        .await;
+        for i in 0..10 {
+            Mock::given(method("GET"))
+                .and(path(format!("/dl/asset-{i}.zip")))
+                .respond_with(ResponseTemplate::new(200).set_body_string(format!("body {i}")))
+                .expect(1)
+                .mount(&h.server)
+                .await;
+        }
+
+        let args = DownloadArgs {
+            concurrency: 3,
+            ..download_args(dir.path(), false)
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let mut names: Vec<String> = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        assert_eq!(names.len(), 10, "{names:?}");
+        assert!(
+            names
+                .iter()
+                .all(|n| Path::new(n).extension().is_none_or(|ext| ext != "tmp")),
+            "{names:?}"
+        );
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("asset-7.zip")).unwrap(),
+            "body 7"
+        );
     }
 
     #[test]
-    fn test_should_match_wildcard_middle() {
-        assert!(glob_match("foo*bar", "foobar"));
-        assert!(glob_match("foo*bar", "foo-something-bar"));
-        assert!(!glob_match("foo*bar", "foo-baz"));
+    fn test_should_reject_zero_concurrency() {
+        #[derive(clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: DownloadArgs,
+        }
+        use clap::Parser;
+        assert!(Cli::try_parse_from(["download", "--concurrency", "0"]).is_err());
+        let cli = Cli::try_parse_from(["download"]).unwrap();
+        assert_eq!(cli.args.concurrency, 4);
     }
 
     #[test]
-    fn test_should_match_star_only() {
-        assert!(glob_match("*", "anything"));
-        assert!(glob_match("*", ""));
+    fn test_should_parse_checksums_lines() {
+        let sums = parse_checksums("ABC123  app.zip\ndef456 *app.tar.gz\n\n");
+        assert_eq!(sums.get("app.zip").map(String::as_str), Some("abc123"));
+        assert_eq!(sums.get("app.tar.gz").map(String::as_str), Some("def456"));
+        assert_eq!(sums.len(), 2);
     }
 
     #[test]
-    fn test_should_match_multiple_wildcards() {
-        assert!(glob_match("*linux*amd64*", "myapp-linux-amd64.tar.gz"));
-        assert!(!glob_match("*linux*amd64*", "myapp-darwin-arm64.tar.gz"));
+    fn test_should_detect_checksums_files() {
+        assert!(is_checksums_file("checksums.txt"));
+        assert!(is_checksums_file("app_1.2.0_checksums.txt"));
+        assert!(is_checksums_file("SHA256SUMS"));
+        assert!(is_checksums_file("app.zip.sha256"));
+        assert!(!is_checksums_file("app.zip"));
+        assert!(is_aggregate_checksums("app_1.2.0_SHA256SUMS.txt"));
+        assert!(!is_aggregate_checksums("app.zip.sha256"));
     }
 }
@@ -4,7 +4,6 @@ use anyhow::{Context, Result};
 use clap::Args;
 use serde_json::Value;
 
-use ghc_core::repo::Repo;
 use ghc_core::{ios_eprintln, ios_println};
 
 /// Edit a release.
@@ -54,11 +53,7 @@ impl EditArgs {
     ///
     /// Returns an error if the release cannot be edited.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = self
-            .repo
-            .as_deref()
-            .ok_or_else(|| anyhow::anyhow!("repository argument required (use -R OWNER/REPO)"))?;
-        let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
 
         // Find the release by tag
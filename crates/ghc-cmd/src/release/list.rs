@@ -4,9 +4,8 @@ use anyhow::{Context, Result};
 use clap::Args;
 use serde_json::Value;
 
-use ghc_core::repo::Repo;
 use ghc_core::table::TablePrinter;
-use ghc_core::{ios_eprintln, ios_println};
+use ghc_core::{ios_eprintln, ios_println, text};
 
 /// List releases.
 ///
@@ -45,6 +44,10 @@ pub struct ListArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl ListArgs {
@@ -55,11 +58,7 @@ impl ListArgs {
     /// Returns an error if the releases cannot be listed.
     #[allow(clippy::too_many_lines)]
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = self
-            .repo
-            .as_deref()
-            .ok_or_else(|| anyhow::anyhow!("repository argument required (use -R OWNER/REPO)"))?;
-        let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
 
@@ -76,7 +75,7 @@ impl ListArgs {
             .context("failed to list releases")?;
 
         // JSON output mode - always produces output (even [] for empty results)
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let filtered: Vec<Value> = releases
                 .iter()
                 .filter(|r| {
@@ -101,14 +100,14 @@ impl ListArgs {
                 .collect();
             let mut arr = Value::Array(filtered);
             super::compute_is_latest(&mut arr);
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &arr,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -163,12 +162,12 @@ impl ListArgs {
                 String::new()
             };
 
-            tp.add_row(vec![
-                cs.bold(title),
-                status,
-                tag.to_string(),
-                published_at.to_string(),
-            ]);
+            let published = chrono::DateTime::parse_from_rfc3339(published_at).map_or_else(
+                |_| published_at.to_string(),
+                |dt| text::relative_time_str(&dt.into(), ios.is_stdout_tty()),
+            );
+
+            tp.add_row(vec![cs.bold(title), status, tag.to_string(), published]);
         }
 
         let output = tp.render();
@@ -218,6 +217,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -227,6 +227,40 @@ mod tests {
         assert!(out.contains("Beta Release"));
     }
 
+    #[tokio::test]
+    async fn test_should_show_relative_publish_time_on_tty() {
+        let mut h = TestHarness::new().await;
+        h.factory.io.set_stdout_tty(true);
+        let published = chrono::Utc::now() - chrono::Duration::days(3);
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/releases",
+            serde_json::json!([{
+                "tag_name": "v1.0.0",
+                "name": "Release 1.0",
+                "draft": false,
+                "prerelease": false,
+                "published_at": published.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+            }]),
+        )
+        .await;
+
+        let args = ListArgs {
+            repo: Some("owner/repo".into()),
+            limit: 30,
+            exclude_drafts: false,
+            exclude_pre_releases: false,
+            order: "desc".into(),
+            json: vec![],
+            jq: None,
+            template: None,
+            yaml: false,
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert!(h.stdout().contains("3 days ago"), "{}", h.stdout());
+    }
+
     #[tokio::test]
     async fn test_should_list_releases_excluding_prereleases() {
         let h = TestHarness::new().await;
@@ -261,6 +295,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -303,6 +338,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -329,6 +365,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         let result = args.run(&h.factory).await;
         assert!(result.is_err());
@@ -368,6 +405,7 @@ mod tests {
             json: vec!["tag_name".into()],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -412,6 +450,7 @@ mod tests {
             json: vec!["tag_name".into()],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
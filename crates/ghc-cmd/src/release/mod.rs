@@ -47,7 +47,9 @@ pub enum ReleaseCommand {
 /// Normalize REST API release field names to match gh CLI conventions.
 ///
 /// Maps: `draft` -> `isDraft`, `prerelease` -> `isPrerelease`,
-/// `tag_name` -> `tagName`, `published_at` -> `publishedAt`, etc.
+/// `tag_name` -> `tagName`, `published_at` -> `publishedAt`, etc. The
+/// nested `author` is reduced to `{id, login}` and each asset is reshaped
+/// as gh exports it (see [`normalize_asset`]).
 fn normalize_release_fields(release: &mut Value) {
     if let Some(obj) = release.as_object_mut() {
         let mappings: &[(&str, &str)] = &[
@@ -67,7 +69,43 @@ fn normalize_release_fields(release: &mut Value) {
                 obj.insert(gh_name.to_string(), val);
             }
         }
+        if let Some(author) = obj.get_mut("author").filter(|a| a.is_object()) {
+            *author = serde_json::json!({
+                "id": author.get("node_id").cloned().unwrap_or(Value::Null),
+                "login": author.get("login").cloned().unwrap_or(Value::Null),
+            });
+        }
+        if let Some(assets) = obj.get_mut("assets").and_then(Value::as_array_mut) {
+            for asset in assets {
+                *asset = normalize_asset(asset);
+            }
+        }
+    }
+}
+
+/// Reshape a REST release asset into gh's exported form.
+///
+/// REST's `url` is the API URL, so it becomes `apiUrl`, while
+/// `browser_download_url` becomes `url`.
+fn normalize_asset(asset: &Value) -> Value {
+    let field = |key: &str| asset.get(key).cloned().unwrap_or(Value::Null);
+    let mut normalized = serde_json::json!({
+        "url": field("browser_download_url"),
+        "apiUrl": field("url"),
+        "id": field("node_id"),
+        "name": field("name"),
+        "label": field("label"),
+        "size": field("size"),
+        "state": field("state"),
+        "createdAt": field("created_at"),
+        "updatedAt": field("updated_at"),
+        "downloadCount": field("download_count"),
+        "contentType": field("content_type"),
+    });
+    if let (Some(digest), Some(obj)) = (asset.get("digest"), normalized.as_object_mut()) {
+        obj.insert("digest".to_string(), digest.clone());
     }
+    normalized
 }
 
 /// Compute and insert the `isLatest` field for each release in a JSON array.
@@ -99,6 +137,29 @@ fn compute_is_latest(releases: &mut Value) {
     }
 }
 
+/// Insert the `isLatest` field into a single release.
+///
+/// A release is latest when its tag matches `latest_tag`, the tag of the
+/// repository's `releases/latest`. Drafts and prereleases are never latest.
+fn set_is_latest(release: &mut Value, latest_tag: Option<&str>) {
+    if let Some(obj) = release.as_object_mut() {
+        let flag = |gh_name: &str, rest_name: &str| {
+            obj.get(gh_name)
+                .or_else(|| obj.get(rest_name))
+                .and_then(Value::as_bool)
+                .unwrap_or(false)
+        };
+        let is_draft = flag("isDraft", "draft");
+        let is_pre = flag("isPrerelease", "prerelease");
+        let tag = obj
+            .get("tagName")
+            .or_else(|| obj.get("tag_name"))
+            .and_then(Value::as_str);
+        let is_latest = !is_draft && !is_pre && tag.is_some() && tag == latest_tag;
+        obj.insert("isLatest".to_string(), Value::Bool(is_latest));
+    }
+}
+
 impl ReleaseCommand {
     /// Run the selected subcommand.
     ///
@@ -17,12 +17,14 @@ use ghc_core::{ios_eprintln, ios_println};
 #[derive(Debug, Args)]
 pub struct VerifyAssetArgs {
     /// The release tag. If omitted, uses the latest release.
-    #[arg(value_name = "TAG")]
+    ///
+    /// With a single positional argument it is taken as the asset file.
+    #[arg(value_name = "TAG", required = true)]
     tag: Option<String>,
 
     /// Path to the asset file to verify.
     #[arg(value_name = "FILE")]
-    file: String,
+    file: Option<String>,
 
     /// Repository (OWNER/REPO).
     #[arg(short = 'R', long)]
@@ -39,41 +41,51 @@ pub struct VerifyAssetArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl VerifyAssetArgs {
+    /// Split the positional arguments into the optional tag and the asset file.
+    fn tag_and_file(&self) -> (Option<&str>, &str) {
+        match (self.tag.as_deref(), self.file.as_deref()) {
+            (tag, Some(file)) => (tag, file),
+            (Some(file), None) => (None, file),
+            (None, None) => (None, ""),
+        }
+    }
+
     /// Run the release verify-asset command.
     ///
     /// # Errors
     ///
     /// Returns an error if the asset cannot be verified.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo_str = self
-            .repo
-            .as_deref()
-            .ok_or_else(|| anyhow::anyhow!("repository argument required (use -R OWNER/REPO)"))?;
-        let repo = Repo::from_full_name(repo_str).context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
 
-        let file_path = std::path::PathBuf::from(&self.file);
+        let (tag, file) = self.tag_and_file();
+        let file_path = std::path::PathBuf::from(file);
         let file_path = file_path
             .canonicalize()
             .unwrap_or_else(|_| file_path.clone());
         let file_name = file_path
             .file_name()
             .and_then(std::ffi::OsStr::to_str)
-            .unwrap_or(&self.file);
+            .unwrap_or(file);
 
-        if !Path::new(&self.file).exists() {
-            return Err(anyhow::anyhow!("asset file not found: {}", self.file));
+        if !Path::new(file).exists() {
+            return Err(anyhow::anyhow!("asset file not found: {file}"));
         }
 
-        let file_digest = compute_sha256(&self.file).await?;
+        let file_digest = compute_sha256(file).await?;
         let file_digest_with_alg = format!("sha256:{file_digest}");
 
-        let tag_name = resolve_tag(&client, &repo, self.tag.as_deref()).await?;
+        let tag_name = resolve_tag(&client, &repo, tag).await?;
         let (release_digest, release_attestations) =
             fetch_release_attestations(&client, &repo, &tag_name).await?;
 
@@ -88,16 +100,16 @@ impl VerifyAssetArgs {
             ));
         }
 
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let arr = Value::Array(matching.iter().map(|v| (*v).clone()).collect());
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &arr,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -256,18 +268,44 @@ async fn compute_sha256(path: &str) -> Result<String> {
 
 #[cfg(test)]
 mod tests {
+    use clap::Parser;
+
+    #[derive(Parser)]
+    struct Cli {
+        #[command(flatten)]
+        args: super::VerifyAssetArgs,
+    }
+
     #[test]
     fn test_should_parse_args() {
         // Basic construction test
         let args = super::VerifyAssetArgs {
             tag: Some("v1.0.0".into()),
-            file: "my-binary.tar.gz".into(),
+            file: Some("my-binary.tar.gz".into()),
             repo: Some("owner/repo".into()),
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         assert_eq!(args.tag.as_deref(), Some("v1.0.0"));
-        assert_eq!(args.file, "my-binary.tar.gz");
+        assert_eq!(args.tag_and_file(), (Some("v1.0.0"), "my-binary.tar.gz"));
+    }
+
+    #[test]
+    fn test_should_take_single_argument_as_file() {
+        let cli = Cli::try_parse_from(["verify-asset", "my-binary.tar.gz"]).unwrap();
+        assert_eq!(cli.args.tag_and_file(), (None, "my-binary.tar.gz"));
+
+        let cli = Cli::try_parse_from(["verify-asset", "v1.0.0", "my-binary.tar.gz"]).unwrap();
+        assert_eq!(
+            cli.args.tag_and_file(),
+            (Some("v1.0.0"), "my-binary.tar.gz")
+        );
+    }
+
+    #[test]
+    fn test_should_require_asset_file() {
+        assert!(Cli::try_parse_from(["verify-asset"]).is_err());
     }
 }
@@ -33,6 +33,10 @@ pub struct ViewArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl ViewArgs {
@@ -43,11 +47,7 @@ impl ViewArgs {
     /// Returns an error if the release cannot be viewed.
     #[allow(clippy::too_many_lines)]
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = self
-            .repo
-            .as_deref()
-            .ok_or_else(|| anyhow::anyhow!("repository argument required (use -R OWNER/REPO)"))?;
-        let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
 
         if self.web {
             let url = if self.tag == "latest" {
@@ -92,30 +92,28 @@ impl ViewArgs {
         // (gh uses isDraft/isPrerelease/tagName/publishedAt/createdAt)
         super::normalize_release_fields(&mut release);
 
-        // For single release view, compute isLatest:
-        // - If fetched via "latest" endpoint, it is the latest
-        // - Otherwise, check draft/prerelease status (non-draft, non-prerelease = potentially latest)
-        if let Some(obj) = release.as_object_mut() {
-            let is_draft = obj.get("isDraft").and_then(Value::as_bool).unwrap_or(false);
-            let is_pre = obj
-                .get("isPrerelease")
-                .and_then(Value::as_bool)
-                .unwrap_or(false);
-            let is_latest = self.tag == "latest" || (!is_draft && !is_pre);
-            obj.insert("isLatest".to_string(), Value::Bool(is_latest));
-        }
-
         // JSON output
         let ios = &factory.io;
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
-            let output = ghc_core::json::format_json_output(
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
+            let latest_tag = if self.tag == "latest" {
+                release
+                    .get("tagName")
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+            } else {
+                fetch_latest_tag(&client, &repo)
+                    .await
+                    .context("failed to fetch latest release")?
+            };
+            super::set_is_latest(&mut release, latest_tag.as_deref());
+            ghc_core::json::output(
                 &release,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -201,11 +199,125 @@ impl ViewArgs {
     }
 }
 
+/// Tag of the repository's latest release, or `None` if it has none.
+async fn fetch_latest_tag(
+    client: &ghc_api::client::Client,
+    repo: &Repo,
+) -> Result<Option<String>, ghc_api::errors::ApiError> {
+    let path = format!("repos/{}/{}/releases/latest", repo.owner(), repo.name());
+    let latest: Value = match client.rest(reqwest::Method::GET, &path, None).await {
+        Ok(latest) => latest,
+        Err(e) if e.is_not_found() => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    Ok(latest
+        .get("tag_name")
+        .and_then(Value::as_str)
+        .map(str::to_string))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use crate::test_helpers::{TestHarness, mock_rest_get};
+    use crate::test_helpers::{TestHarness, mock_rest_get, mock_rest_get_status};
+
+    fn json_args(tag: &str) -> ViewArgs {
+        ViewArgs {
+            tag: tag.into(),
+            repo: Some("owner/repo".into()),
+            web: false,
+            json: vec!["isLatest".into(), "tagName".into()],
+            jq: None,
+            template: None,
+            yaml: false,
+        }
+    }
+
+    async fn mount_release(h: &TestHarness, tag: &str, prerelease: bool) {
+        mock_rest_get(
+            &h.server,
+            &format!("/repos/owner/repo/releases/tags/{tag}"),
+            serde_json::json!({ "tag_name": tag, "draft": false, "prerelease": prerelease }),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_should_report_is_latest_for_latest_tag() {
+        let h = TestHarness::new().await;
+        mount_release(&h, "v1.0.0", false).await;
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/releases/latest",
+            serde_json::json!({ "tag_name": "v1.0.0" }),
+        )
+        .await;
+
+        json_args("v1.0.0").run(&h.factory).await.unwrap();
+
+        let out: Value = serde_json::from_str(&h.stdout()).unwrap();
+        assert_eq!(
+            out,
+            serde_json::json!({ "isLatest": true, "tagName": "v1.0.0" })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_report_older_release_as_not_latest() {
+        let h = TestHarness::new().await;
+        mount_release(&h, "v0.9.0", false).await;
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/releases/latest",
+            serde_json::json!({ "tag_name": "v1.0.0" }),
+        )
+        .await;
+
+        json_args("v0.9.0").run(&h.factory).await.unwrap();
+
+        let out: Value = serde_json::from_str(&h.stdout()).unwrap();
+        assert_eq!(out["isLatest"], false);
+    }
+
+    #[tokio::test]
+    async fn test_should_never_report_prerelease_as_latest() {
+        let h = TestHarness::new().await;
+        mount_release(&h, "v2.0.0-rc.1", true).await;
+        mock_rest_get_status(
+            &h.server,
+            "/repos/owner/repo/releases/latest",
+            404,
+            serde_json::json!({ "message": "Not Found" }),
+        )
+        .await;
+
+        json_args("v2.0.0-rc.1").run(&h.factory).await.unwrap();
+
+        let out: Value = serde_json::from_str(&h.stdout()).unwrap();
+        assert_eq!(out["isLatest"], false);
+    }
+
+    #[tokio::test]
+    async fn test_should_fail_when_latest_release_lookup_fails() {
+        let h = TestHarness::new().await;
+        mount_release(&h, "v1.0.0", false).await;
+        mock_rest_get_status(
+            &h.server,
+            "/repos/owner/repo/releases/latest",
+            500,
+            serde_json::json!({ "message": "Server Error" }),
+        )
+        .await;
+
+        let err = json_args("v1.0.0").run(&h.factory).await.unwrap_err();
+
+        assert!(
+            err.to_string().contains("failed to fetch latest release"),
+            "unexpected error: {err:#}"
+        );
+        assert!(h.stdout().is_empty());
+    }
 
     #[tokio::test]
     async fn test_should_view_release() {
@@ -235,6 +347,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -259,6 +372,67 @@ mod tests {
         assert!(out.contains("binary.tar.gz"));
     }
 
+    #[tokio::test]
+    async fn test_should_export_gh_compatible_author_and_assets() {
+        let h = TestHarness::new().await;
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/releases/tags/v1.0.0",
+            serde_json::json!({
+                "tag_name": "v1.0.0",
+                "author": { "login": "octocat", "node_id": "MDQ6VXNlcjE=", "type": "User" },
+                "assets": [{
+                    "url": "https://api.github.com/repos/owner/repo/releases/assets/1",
+                    "browser_download_url": "https://github.com/owner/repo/releases/download/v1.0.0/app.zip",
+                    "node_id": "RA_1",
+                    "name": "app.zip",
+                    "label": "",
+                    "size": 2048,
+                    "state": "uploaded",
+                    "content_type": "application/zip",
+                    "download_count": 42,
+                    "created_at": "2024-01-15T10:00:00Z",
+                    "updated_at": "2024-01-15T10:05:00Z"
+                }]
+            }),
+        )
+        .await;
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/releases/latest",
+            serde_json::json!({ "tag_name": "v1.0.0" }),
+        )
+        .await;
+
+        let args = ViewArgs {
+            json: vec!["author".into(), "assets".into()],
+            ..json_args("v1.0.0")
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let out: Value = serde_json::from_str(&h.stdout()).unwrap();
+        assert_eq!(
+            out["author"],
+            serde_json::json!({ "id": "MDQ6VXNlcjE=", "login": "octocat" })
+        );
+        assert_eq!(
+            out["assets"][0],
+            serde_json::json!({
+                "url": "https://github.com/owner/repo/releases/download/v1.0.0/app.zip",
+                "apiUrl": "https://api.github.com/repos/owner/repo/releases/assets/1",
+                "id": "RA_1",
+                "name": "app.zip",
+                "label": "",
+                "size": 2048,
+                "state": "uploaded",
+                "createdAt": "2024-01-15T10:00:00Z",
+                "updatedAt": "2024-01-15T10:05:00Z",
+                "downloadCount": 42,
+                "contentType": "application/zip"
+            })
+        );
+    }
+
     #[tokio::test]
     async fn test_should_view_release_in_browser() {
         let h = TestHarness::new().await;
@@ -270,6 +444,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -289,6 +464,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
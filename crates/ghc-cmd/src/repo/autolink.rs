@@ -10,7 +10,6 @@ use clap::{Args, Subcommand};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use ghc_core::repo::Repo;
 use ghc_core::{ios_eprintln, ios_println};
 
 use crate::factory::Factory;
@@ -76,7 +75,7 @@ pub struct CreateArgs {
 
     /// Repository (OWNER/REPO).
     #[arg(short = 'R', long, value_name = "REPOSITORY")]
-    repo: String,
+    repo: Option<String>,
 
     /// Mark autolink as numeric only (default is alphanumeric).
     #[arg(short, long)]
@@ -85,8 +84,7 @@ pub struct CreateArgs {
 
 impl CreateArgs {
     async fn run(&self, factory: &Factory) -> Result<()> {
-        let repo =
-            Repo::from_full_name(&self.repo).context("invalid repository format (OWNER/REPO)")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -134,7 +132,7 @@ pub struct DeleteArgs {
 
     /// Repository (OWNER/REPO).
     #[arg(short = 'R', long, value_name = "REPOSITORY")]
-    repo: String,
+    repo: Option<String>,
 
     /// Skip confirmation prompt.
     #[arg(long)]
@@ -143,8 +141,7 @@ pub struct DeleteArgs {
 
 impl DeleteArgs {
     async fn run(&self, factory: &Factory) -> Result<()> {
-        let repo =
-            Repo::from_full_name(&self.repo).context("invalid repository format (OWNER/REPO)")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -225,7 +222,7 @@ impl DeleteArgs {
 pub struct ListArgs {
     /// Repository (OWNER/REPO).
     #[arg(short = 'R', long, value_name = "REPOSITORY")]
-    repo: String,
+    repo: Option<String>,
 
     /// Open autolink settings in the web browser.
     #[arg(short, long)]
@@ -242,12 +239,15 @@ pub struct ListArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl ListArgs {
     async fn run(&self, factory: &Factory) -> Result<()> {
-        let repo =
-            Repo::from_full_name(&self.repo).context("invalid repository format (OWNER/REPO)")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
 
@@ -287,16 +287,16 @@ impl ListArgs {
         }
 
         // JSON output
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let json_val: Value = serde_json::to_value(&autolinks)?;
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &json_val,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -355,7 +355,7 @@ pub struct ViewArgs {
 
     /// Repository (OWNER/REPO).
     #[arg(short = 'R', long, value_name = "REPOSITORY")]
-    repo: String,
+    repo: Option<String>,
 
     /// Output JSON with specified fields.
     #[arg(long, value_delimiter = ',')]
@@ -368,12 +368,15 @@ pub struct ViewArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl ViewArgs {
     async fn run(&self, factory: &Factory) -> Result<()> {
-        let repo =
-            Repo::from_full_name(&self.repo).context("invalid repository format (OWNER/REPO)")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -396,16 +399,16 @@ impl ViewArgs {
         };
 
         // JSON output
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let json_val: Value = serde_json::to_value(&autolink)?;
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &json_val,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -461,11 +464,12 @@ mod tests {
         .await;
 
         let args = ListArgs {
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             web: false,
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
         let stdout = h.stdout();
@@ -479,11 +483,12 @@ mod tests {
         mock_rest_get(&h.server, "/repos/owner/repo/autolinks", json!([])).await;
 
         let args = ListArgs {
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             web: false,
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         let result = args.run(&h.factory).await;
         assert!(result.is_err());
@@ -509,7 +514,7 @@ mod tests {
         let args = CreateArgs {
             key_prefix: "JIRA-".into(),
             url_template: "https://jira.example.com/browse/<num>".into(),
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             numeric: false,
         };
         args.run(&h.factory).await.unwrap();
@@ -537,7 +542,7 @@ mod tests {
         let args = CreateArgs {
             key_prefix: "STORY-".into(),
             url_template: "https://example.com/STORY?id=<num>".into(),
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             numeric: true,
         };
         let result = args.run(&h.factory).await;
@@ -561,10 +566,11 @@ mod tests {
 
         let args = ViewArgs {
             id: "1".into(),
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
         let stdout = h.stdout();
@@ -590,7 +596,7 @@ mod tests {
 
         let args = DeleteArgs {
             id: "42".into(),
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             yes: true,
         };
         let result = args.run(&h.factory).await;
@@ -601,11 +607,12 @@ mod tests {
     async fn test_should_open_web_for_autolink_list() {
         let h = TestHarness::new().await;
         let args = ListArgs {
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             web: true,
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         let result = args.run(&h.factory).await;
         assert!(result.is_ok());
@@ -23,7 +23,7 @@ use crate::factory::Factory;
 /// additional git remote called `upstream`. The remote name can be configured
 /// using `--upstream-remote-name`. The `--upstream-remote-name` option supports
 /// an `@owner` value which will name the remote after the owner of the parent
-/// repository.
+/// repository. Pass `--no-upstream` to skip adding the parent remote.
 #[derive(Debug, Args)]
 pub struct CloneArgs {
     /// Repository to clone (OWNER/REPO or URL).
@@ -38,6 +38,14 @@ pub struct CloneArgs {
     #[arg(short = 'u', long, default_value = "upstream")]
     upstream_remote_name: String,
 
+    /// Do not add the parent repository of a fork as a remote.
+    #[arg(long, conflicts_with = "upstream_remote_name")]
+    no_upstream: bool,
+
+    /// Suppress git's clone progress output.
+    #[arg(short, long)]
+    quiet: bool,
+
     /// Additional git clone arguments.
     #[arg(last = true)]
     git_args: Vec<String>,
@@ -79,7 +87,7 @@ impl CloneArgs {
                 self.repo.clone()
             } else {
                 // Default to current user's namespace
-                let client = factory.api_client("github.com")?;
+                let client = factory.http_client()?;
                 let viewer: HashMap<String, Value> = client
                     .graphql(ghc_api::queries::user::VIEWER_QUERY, &HashMap::new())
                     .await
@@ -172,84 +180,117 @@ impl CloneArgs {
         if let Some(ref dir) = self.directory {
             extra_args.push(dir);
         }
-        let clone_dir = git.clone(&clone_url, &extra_args).await?;
+        let clone_dir = match self.clone_output(&factory.io) {
+            CloneOutput::Progress => git.clone_with_progress(&clone_url, &extra_args).await?,
+            CloneOutput::Captured => git.clone(&clone_url, &extra_args).await?,
+        };
 
         // If repo is a fork, add parent as upstream remote
-        if let Some(parent_data) = repo_data.get("parent")
-            && !parent_data.is_null()
-        {
-            setup_upstream_remote(
-                factory,
-                parent_data,
-                repo_data,
-                &canonical_repo,
-                &protocol,
-                &self.upstream_remote_name,
-                &clone_dir,
-            )
-            .await?;
+        if let Some(upstream) = self.upstream_remote(repo_data, &canonical_repo, &protocol) {
+            let default_branch = repo_data
+                .pointer("/defaultBranchRef/name")
+                .and_then(Value::as_str)
+                .unwrap_or("main");
+            setup_upstream_remote(factory, &upstream, default_branch, &clone_dir).await?;
         }
 
         Ok(())
     }
+
+    /// The remote to add for the parent of a fork, or `None` for a non-fork
+    /// or with `--no-upstream`.
+    fn upstream_remote(
+        &self,
+        repo_data: &Value,
+        canonical_repo: &Repo,
+        protocol: &str,
+    ) -> Option<UpstreamRemote> {
+        if self.no_upstream {
+            return None;
+        }
+        let parent = repo_data.get("parent").filter(|p| !p.is_null())?;
+        let parent_owner = parent
+            .pointer("/owner/login")
+            .and_then(Value::as_str)
+            .filter(|s| !s.is_empty())?;
+        let parent_name = parent
+            .get("name")
+            .and_then(Value::as_str)
+            .filter(|s| !s.is_empty())?;
+
+        let repo = Repo::with_host(parent_owner, parent_name, canonical_repo.host());
+        let name = if self.upstream_remote_name == "@owner" {
+            parent_owner.to_string()
+        } else {
+            self.upstream_remote_name.clone()
+        };
+        Some(UpstreamRemote {
+            name,
+            url: url_parser::clone_url(&repo, protocol),
+            repo,
+        })
+    }
+}
+
+/// The parent repository of a fork and the remote it is added as.
+#[derive(Debug)]
+struct UpstreamRemote {
+    name: String,
+    url: String,
+    repo: Repo,
+}
+
+/// How git's clone output is handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CloneOutput {
+    /// Let git write progress straight to the terminal.
+    Progress,
+    /// Capture git's output.
+    Captured,
+}
+
+impl CloneArgs {
+    /// Show progress only when stderr is interactive and `--quiet` is not set.
+    fn clone_output(&self, ios: &ghc_core::iostreams::IOStreams) -> CloneOutput {
+        if !self.quiet && ios.is_stderr_tty() {
+            CloneOutput::Progress
+        } else {
+            CloneOutput::Captured
+        }
+    }
 }
 
 /// Set up the upstream remote for a forked repository after cloning.
+///
+/// The remote tracks the parent's default branch and is fetched right away.
 async fn setup_upstream_remote(
     factory: &Factory,
-    parent_data: &Value,
-    repo_data: &Value,
-    canonical_repo: &Repo,
-    protocol: &str,
-    upstream_remote_name: &str,
+    upstream: &UpstreamRemote,
+    default_branch: &str,
     clone_dir: &str,
 ) -> Result<()> {
-    let parent_owner = parent_data
-        .pointer("/owner/login")
-        .and_then(Value::as_str)
-        .unwrap_or("");
-    let parent_name = parent_data
-        .get("name")
-        .and_then(Value::as_str)
-        .unwrap_or("");
-
-    if parent_owner.is_empty() || parent_name.is_empty() {
-        return Ok(());
-    }
-
-    let parent_repo = Repo::with_host(parent_owner, parent_name, canonical_repo.host());
-    let upstream_url = url_parser::clone_url(&parent_repo, protocol);
-
-    let upstream_name = if upstream_remote_name == "@owner" {
-        parent_owner.to_string()
-    } else {
-        upstream_remote_name.to_string()
-    };
-
-    let default_branch = repo_data
-        .pointer("/defaultBranchRef/name")
-        .and_then(Value::as_str)
-        .unwrap_or("main");
+    let upstream_name = upstream.name.as_str();
+    let upstream_url = upstream.url.as_str();
 
     let clone_git = ghc_git::client::GitClient::new()?.with_repo_dir(clone_dir);
 
     clone_git
-        .add_remote(&upstream_name, &upstream_url, &[default_branch])
+        .add_remote(upstream_name, upstream_url, &[default_branch])
         .await
         .map_err(|e| anyhow::anyhow!("failed to add upstream remote '{upstream_name}': {e}"))?;
 
     clone_git
-        .fetch(&upstream_name, "")
+        .fetch(upstream_name, "")
         .await
         .map_err(|e| anyhow::anyhow!("failed to fetch upstream remote '{upstream_name}': {e}"))?;
 
     clone_git
-        .set_remote_branches(&upstream_name, "*")
+        .set_remote_branches(upstream_name, "*")
         .await
         .map_err(|e| anyhow::anyhow!("failed to set remote branches: {e}"))?;
 
     clone_git
-        .set_remote_resolution(&upstream_name, "base")
+        .set_remote_resolution(upstream_name, "base")
         .await
         .map_err(|e| anyhow::anyhow!("failed to set remote resolution: {e}"))?;
 
@@ -261,7 +302,7 @@ async fn setup_upstream_remote(
             "{} Repository {} set as the default repository. \
              To learn more about the default repository, run: ghc repo set-default --help",
             cs.warning_icon(),
-            cs.bold(&parent_repo.full_name()),
+            cs.bold(&upstream.repo.full_name()),
         );
     }
 
@@ -278,6 +319,8 @@ mod tests {
             repo: "owner/repo".into(),
             directory: None,
             upstream_remote_name: "upstream".into(),
+            no_upstream: false,
+            quiet: false,
             git_args: vec![],
         };
         assert_eq!(args.repo, "owner/repo");
@@ -290,6 +333,8 @@ mod tests {
             repo: "https://github.com/cli/cli".into(),
             directory: None,
             upstream_remote_name: "upstream".into(),
+            no_upstream: false,
+            quiet: false,
             git_args: vec![],
         };
         assert!(args.repo.contains(':'));
@@ -301,8 +346,115 @@ mod tests {
             repo: "owner/repo".into(),
             directory: None,
             upstream_remote_name: "@owner".into(),
+            no_upstream: false,
+            quiet: false,
             git_args: vec![],
         };
         assert_eq!(args.upstream_remote_name, "@owner");
     }
+
+    fn default_args() -> CloneArgs {
+        CloneArgs {
+            repo: "owner/repo".into(),
+            directory: None,
+            upstream_remote_name: "upstream".into(),
+            no_upstream: false,
+            quiet: false,
+            git_args: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_should_pass_through_progress_on_tty() {
+        let mut h = crate::test_helpers::TestHarness::new().await;
+        h.factory.io.set_stderr_tty(true);
+        assert_eq!(
+            default_args().clone_output(&h.factory.io),
+            CloneOutput::Progress
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_capture_output_when_not_tty() {
+        let h = crate::test_helpers::TestHarness::new().await;
+        assert_eq!(
+            default_args().clone_output(&h.factory.io),
+            CloneOutput::Captured
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_capture_output_when_quiet() {
+        let mut h = crate::test_helpers::TestHarness::new().await;
+        h.factory.io.set_stderr_tty(true);
+        let mut args = default_args();
+        args.quiet = true;
+        assert_eq!(args.clone_output(&h.factory.io), CloneOutput::Captured);
+    }
+
+    fn fork_data() -> Value {
+        serde_json::json!({
+            "name": "cli",
+            "owner": { "login": "me" },
+            "defaultBranchRef": { "name": "trunk" },
+            "parent": { "name": "cli", "owner": { "login": "cli" } }
+        })
+    }
+
+    #[test]
+    fn test_should_add_parent_of_fork_as_upstream() {
+        let repo = Repo::with_host("me", "cli", "github.com");
+        let upstream = default_args()
+            .upstream_remote(&fork_data(), &repo, "https")
+            .unwrap();
+        assert_eq!(upstream.name, "upstream");
+        assert_eq!(upstream.url, "https://github.com/cli/cli.git");
+        assert_eq!(upstream.repo.full_name(), "cli/cli");
+    }
+
+    #[test]
+    fn test_should_name_upstream_after_parent_owner() {
+        let repo = Repo::with_host("me", "cli", "github.com");
+        let args = CloneArgs {
+            upstream_remote_name: "@owner".into(),
+            ..default_args()
+        };
+        let upstream = args.upstream_remote(&fork_data(), &repo, "ssh").unwrap();
+        assert_eq!(upstream.name, "cli");
+        assert_eq!(upstream.url, "git@github.com:cli/cli.git");
+    }
+
+    #[test]
+    fn test_should_skip_upstream_for_non_fork_or_no_upstream() {
+        let repo = Repo::with_host("me", "cli", "github.com");
+        let non_fork = serde_json::json!({ "name": "cli", "parent": null });
+        assert!(
+            default_args()
+                .upstream_remote(&non_fork, &repo, "https")
+                .is_none()
+        );
+
+        let args = CloneArgs {
+            no_upstream: true,
+            ..default_args()
+        };
+        assert!(args.upstream_remote(&fork_data(), &repo, "https").is_none());
+    }
+
+    #[test]
+    fn test_should_reject_no_upstream_with_remote_name() {
+        use clap::Parser;
+
+        #[derive(clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: CloneArgs,
+        }
+
+        assert!(Cli::try_parse_from(["clone", "o/r", "--no-upstream", "-u", "up"]).is_err());
+        let cli =
+            Cli::try_parse_from(["clone", "o/r", "--no-upstream", "--", "--depth", "1"]).unwrap();
+        assert!(cli.args.no_upstream);
+        assert_eq!(cli.args.git_args, ["--depth", "1"]);
+    }
 }
@@ -382,7 +382,7 @@ impl CreateArgs {
                 || self.template.is_some();
 
             if has_content {
-                clone_with_retry(&remote_url, &template_repo_main_branch).await?;
+                clone_with_retry(ios, &remote_url, &template_repo_main_branch).await?;
             } else {
                 local_init(&remote_url, clone_name).await?;
             }
@@ -516,7 +516,7 @@ impl CreateArgs {
                 .unwrap_or(&repo_name);
             let repo_ref = Repo::with_host(clone_owner, clone_name, &host);
             let remote_url = url_parser::clone_url(&repo_ref, &protocol);
-            clone_with_retry(&remote_url, &template_main_branch).await?;
+            clone_with_retry(ios, &remote_url, &template_main_branch).await?;
         }
 
         Ok(())
@@ -1315,7 +1315,8 @@ async fn local_init(remote_url: &str, path: &str) -> Result<()> {
 }
 
 /// Clone a repository with retry logic for template repos that may not be ready.
-async fn clone_with_retry(remote_url: &str, branch: &str) -> Result<()> {
+async fn clone_with_retry(ios: &IOStreams, remote_url: &str, branch: &str) -> Result<()> {
+    let _progress = ios.start_progress_indicator_with_label("Cloning…");
     let mut last_err = None;
 
     for attempt in 0..=CLONE_MAX_RETRIES {
@@ -1603,6 +1604,42 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_should_not_prompt_on_tty_when_prompts_disabled() {
+        let mut h = TestHarness::new().await;
+        h.factory.io.set_stdin_tty(true);
+        h.factory.io.set_stdout_tty(true);
+        h.factory
+            .io
+            .apply_prompt_preference(None, Some("1"), "enabled");
+
+        let args = CreateArgs {
+            name: None,
+            description: None,
+            homepage: None,
+            team: None,
+            template: None,
+            public: false,
+            private: false,
+            internal: false,
+            clone: false,
+            add_readme: false,
+            license: None,
+            gitignore: None,
+            source: None,
+            remote: None,
+            push: false,
+            include_all_branches: false,
+            disable_issues: false,
+            disable_wiki: false,
+        };
+        let err = args.run(&h.factory).await.unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("at least one argument required in non-interactive mode")
+        );
+    }
+
     #[tokio::test]
     async fn test_should_reject_remote_without_source() {
         let h = TestHarness::new().await;
@@ -29,26 +29,25 @@ impl DeleteArgs {
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
         let ios = &factory.io;
         let cs = ios.color_scheme();
-        let client = factory.api_client("github.com")?;
-
-        let repo_arg = self
-            .repo
-            .as_deref()
-            .ok_or_else(|| anyhow::anyhow!("repository argument required (e.g. OWNER/REPO)"))?;
-
-        // If the arg doesn't contain '/', prepend the current user
-        let full_name = if repo_arg.contains('/') {
-            repo_arg.to_string()
-        } else {
-            let current_user = client
-                .current_login()
-                .await
-                .context("failed to get current user")?;
-            format!("{current_user}/{repo_arg}")
-        };
+        let client = factory.http_client()?;
 
-        let repo = Repo::from_full_name(&full_name).context("argument error")?;
+        let repo = match self.repo.as_deref() {
+            // If the arg doesn't contain '/', prepend the current user
+            Some(repo_arg) if !repo_arg.contains('/') => {
+                let current_user = client
+                    .current_login()
+                    .await
+                    .context("failed to get current user")?;
+                Repo::from_full_name(&format!("{current_user}/{repo_arg}"))
+                    .context("argument error")?
+            }
+            Some(repo_arg) => Repo::from_full_name(repo_arg).context("argument error")?,
+            None => factory.base_repo().await?,
+        };
         let display_name = repo.full_name();
+        let client = factory.api_client(repo.host())?;
+
+        check_delete_scope(&client).await?;
 
         // Require confirmation
         if !self.yes {
@@ -58,8 +57,10 @@ impl DeleteArgs {
             let answer = factory
                 .prompter()
                 .input(&format!("Type {display_name} to confirm deletion:"), "")?;
-            if answer != display_name {
-                anyhow::bail!("confirmation did not match repository name");
+            if answer.trim() != display_name {
+                anyhow::bail!(
+                    "confirmation did not match repository name; expected {display_name}"
+                );
             }
         }
 
@@ -99,10 +100,40 @@ impl DeleteArgs {
     }
 }
 
+/// Fail early, with the command that fixes it, when the token's OAuth scopes
+/// are known and lack `delete_repo`.
+///
+/// Fine-grained and app tokens report no scopes, so they are left for the
+/// API to accept or reject.
+async fn check_delete_scope(client: &ghc_api::client::Client) -> Result<()> {
+    let Some(token) = client.token().filter(|t| ghc_api::client::expect_scopes(t)) else {
+        return Ok(());
+    };
+    let Ok(scopes) = client.get_scopes(token).await else {
+        return Ok(());
+    };
+    if scopes.is_empty() || scopes.split(',').any(|s| s.trim() == "delete_repo") {
+        return Ok(());
+    }
+    let host = client.hostname();
+    let host_flag = if host == "github.com" {
+        String::new()
+    } else {
+        format!(" -h {host}")
+    };
+    anyhow::bail!(
+        "deleting a repository requires the `delete_repo` scope\n\
+         To request it, run: ghc auth refresh{host_flag} -s delete_repo"
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
     use crate::test_helpers::{TestHarness, mock_rest_delete};
 
     #[tokio::test]
@@ -144,4 +175,86 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("--yes required"));
     }
+
+    async fn mount_scopes(h: &TestHarness, scopes: &str) {
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("X-OAuth-Scopes", scopes)
+                    .set_body_json(serde_json::json!({})),
+            )
+            .mount(&h.server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_should_explain_missing_delete_repo_scope() {
+        let mut h = TestHarness::new().await;
+        h.factory = h.factory.with_token("ghp_test");
+        mount_scopes(&h, "repo, read:org").await;
+        Mock::given(method("DELETE"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(0)
+            .mount(&h.server)
+            .await;
+
+        let args = DeleteArgs {
+            repo: Some("owner/repo".into()),
+            yes: true,
+        };
+        let err = args.run(&h.factory).await.unwrap_err().to_string();
+        assert!(err.contains("ghc auth refresh -s delete_repo"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn test_should_delete_when_token_has_delete_repo_scope() {
+        let mut h = TestHarness::new().await;
+        h.factory = h.factory.with_token("ghp_test");
+        mount_scopes(&h, "repo, read:org, delete_repo").await;
+        mock_rest_delete(&h.server, "/repos/owner/repo", 204).await;
+
+        let args = DeleteArgs {
+            repo: Some("owner/repo".into()),
+            yes: true,
+        };
+        args.run(&h.factory).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_should_require_typed_full_name_to_confirm() {
+        let mut h = TestHarness::new().await;
+        h.factory.io.set_stdin_tty(true);
+        h.factory.io.set_stdout_tty(true);
+        h.factory.io.set_never_prompt(false);
+        h.prompter.input_answers.lock().unwrap().push("repo".into());
+
+        let args = DeleteArgs {
+            repo: Some("owner/repo".into()),
+            yes: false,
+        };
+        let err = args.run(&h.factory).await.unwrap_err();
+        assert!(err.to_string().contains("expected owner/repo"));
+    }
+
+    #[tokio::test]
+    async fn test_should_delete_after_typed_confirmation() {
+        let mut h = TestHarness::new().await;
+        h.factory.io.set_stdin_tty(true);
+        h.factory.io.set_stdout_tty(true);
+        h.factory.io.set_never_prompt(false);
+        h.prompter
+            .input_answers
+            .lock()
+            .unwrap()
+            .push("owner/repo".into());
+        mock_rest_delete(&h.server, "/repos/owner/repo", 204).await;
+
+        let args = DeleteArgs {
+            repo: Some("owner/repo".into()),
+            yes: false,
+        };
+        args.run(&h.factory).await.unwrap();
+        assert!(h.stdout().contains("Deleted repository owner/repo"));
+    }
 }
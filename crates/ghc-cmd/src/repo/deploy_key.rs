@@ -7,7 +7,7 @@ use clap::{Args, Subcommand};
 use serde::Deserialize;
 use serde_json::Value;
 
-use ghc_core::repo::Repo;
+use ghc_core::text::truncate_middle;
 use ghc_core::{ios_eprintln, ios_println};
 
 use crate::factory::Factory;
@@ -55,7 +55,7 @@ pub struct AddArgs {
 
     /// Repository (OWNER/REPO).
     #[arg(short = 'R', long, value_name = "REPOSITORY")]
-    repo: String,
+    repo: Option<String>,
 
     /// Title of the new key.
     #[arg(short, long)]
@@ -68,8 +68,7 @@ pub struct AddArgs {
 
 impl AddArgs {
     async fn run(&self, factory: &Factory) -> Result<()> {
-        let repo =
-            Repo::from_full_name(&self.repo).context("invalid repository format (OWNER/REPO)")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -125,13 +124,12 @@ pub struct DeleteArgs {
 
     /// Repository (OWNER/REPO).
     #[arg(short = 'R', long, value_name = "REPOSITORY")]
-    repo: String,
+    repo: Option<String>,
 }
 
 impl DeleteArgs {
     async fn run(&self, factory: &Factory) -> Result<()> {
-        let repo =
-            Repo::from_full_name(&self.repo).context("invalid repository format (OWNER/REPO)")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -169,7 +167,7 @@ impl DeleteArgs {
 pub struct ListArgs {
     /// Repository (OWNER/REPO).
     #[arg(short = 'R', long, value_name = "REPOSITORY")]
-    repo: String,
+    repo: Option<String>,
 
     /// Output JSON with specified fields.
     #[arg(long, value_delimiter = ',')]
@@ -182,6 +180,10 @@ pub struct ListArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 #[derive(Debug, Deserialize, serde::Serialize)]
@@ -195,8 +197,7 @@ struct DeployKey {
 
 impl ListArgs {
     async fn run(&self, factory: &Factory) -> Result<()> {
-        let repo =
-            Repo::from_full_name(&self.repo).context("invalid repository format (OWNER/REPO)")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -212,16 +213,16 @@ impl ListArgs {
         }
 
         // JSON output
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let json_val: Value = serde_json::to_value(&keys)?;
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &json_val,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -265,25 +266,6 @@ impl ListArgs {
     }
 }
 
-/// Truncate a string in the middle, replacing the middle with "...".
-fn truncate_middle(s: &str, max_width: usize) -> String {
-    if s.len() <= max_width {
-        return s.to_string();
-    }
-    let ellipsis = "...";
-    if max_width < ellipsis.len() + 2 {
-        return s[..max_width].to_string();
-    }
-    let half_width = (max_width - ellipsis.len()) / 2;
-    let remainder = (max_width - ellipsis.len()) % 2;
-    format!(
-        "{}{}{}",
-        &s[..half_width + remainder],
-        ellipsis,
-        &s[s.len() - half_width..]
-    )
-}
-
 #[cfg(test)]
 mod tests {
     use serde_json::json;
@@ -321,7 +303,7 @@ mod tests {
 
         let args = AddArgs {
             key_file: tmp.display().to_string(),
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             title: Some("test".into()),
             allow_write: false,
         };
@@ -356,10 +338,11 @@ mod tests {
         .await;
 
         let args = ListArgs {
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         let result = args.run(&h.factory).await;
         assert!(result.is_ok(), "list should succeed: {result:?}");
@@ -376,10 +359,11 @@ mod tests {
         mock_rest_get(&h.server, "/repos/owner/repo/keys", json!([])).await;
 
         let args = ListArgs {
-            repo: "owner/repo".into(),
+            repo: Some("owner/repo".into()),
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         let result = args.run(&h.factory).await;
         assert!(result.is_err());
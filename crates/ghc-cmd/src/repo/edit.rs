@@ -9,9 +9,27 @@ use serde_json::Value;
 use ghc_core::ios_println;
 use ghc_core::repo::Repo;
 
+/// Settings offered by the interactive field picker, in prompt order.
+const EDITABLE_FIELDS: &[&str] = &[
+    "Description",
+    "Home Page",
+    "Default Branch",
+    "Visibility",
+    "Topics",
+    "Issues",
+    "Projects",
+    "Wiki",
+    "Discussions",
+    "Merge Options",
+    "Template Repository",
+    "Automatically delete head branches",
+    "Allow Forking",
+];
+
 /// Edit repository settings.
 ///
-/// To toggle a setting off, use the `--<flag>=false` syntax.
+/// To toggle a setting off, use the `--<flag>=false` syntax. Without any
+/// flags on an interactive terminal, prompts for the settings to change.
 ///
 /// Changing repository visibility can have unexpected consequences including but
 /// not limited to: losing stars and watchers, detaching public forks from the
@@ -20,13 +38,17 @@ use ghc_core::repo::Repo;
 ///
 /// When the `--visibility` flag is used, `--accept-visibility-change-consequences`
 /// flag is required.
-#[derive(Debug, Args)]
+#[derive(Debug, Clone, Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct EditArgs {
-    /// Repository to edit (OWNER/REPO).
+    /// Repository to edit (OWNER/REPO). Defaults to the current repository.
     #[arg(value_name = "REPOSITORY")]
     repo: Option<String>,
 
+    /// Repository to edit, as an alternative to the argument.
+    #[arg(short = 'R', long = "repo", conflicts_with = "repo")]
+    repo_flag: Option<String>,
+
     /// Description of the repository.
     #[arg(short, long)]
     description: Option<String>,
@@ -118,28 +140,47 @@ pub struct EditArgs {
 
 impl EditArgs {
     /// Run the repo edit command.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be determined, nothing was
+    /// requested to change, or the API requests fail.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
         let ios = &factory.io;
         let cs = ios.color_scheme();
 
-        let repo = match &self.repo {
-            Some(r) => Repo::from_full_name(r).context("invalid repository format")?,
-            None => {
-                anyhow::bail!("repository argument required (e.g. OWNER/REPO)")
-            }
-        };
-
-        if self.visibility.is_some() && !self.accept_visibility_change_consequences {
-            anyhow::bail!(
-                "use of --visibility flag requires --accept-visibility-change-consequences flag"
-            );
-        }
+        let repo = factory
+            .resolve_repo(self.repo.as_deref().or(self.repo_flag.as_deref()))
+            .await?;
 
         let client = factory.api_client(repo.host())?;
         let api_path = format!("repos/{}/{}", repo.owner(), repo.name());
 
-        self.patch_repo_settings(&client, &api_path).await?;
-        self.update_topics(&client, &repo).await?;
+        let prompted;
+        let edits = if self.has_edits() {
+            if self.visibility.is_some() && !self.accept_visibility_change_consequences {
+                let accepted = ios.can_prompt()
+                    && factory.prompter().confirm(
+                        &visibility_warning(self.visibility.as_deref().unwrap_or_default()),
+                        false,
+                    )?;
+                if !accepted {
+                    anyhow::bail!(
+                        "use of --visibility flag requires --accept-visibility-change-consequences flag"
+                    );
+                }
+            }
+            self
+        } else {
+            if !ios.can_prompt() {
+                anyhow::bail!("specify properties to edit when not running interactively");
+            }
+            prompted = self.prompt_edits(factory, &client, &api_path).await?;
+            &prompted
+        };
+
+        edits.patch_repo_settings(&client, &api_path).await?;
+        edits.update_topics(&client, &repo).await?;
 
         if ios.is_stdout_tty() {
             ios_println!(
@@ -153,6 +194,179 @@ impl EditArgs {
         Ok(())
     }
 
+    fn has_edits(&self) -> bool {
+        let bools = [
+            self.template,
+            self.enable_issues,
+            self.enable_projects,
+            self.enable_wiki,
+            self.enable_discussions,
+            self.enable_merge_commit,
+            self.enable_squash_merge,
+            self.enable_rebase_merge,
+            self.enable_auto_merge,
+            self.enable_advanced_security,
+            self.enable_secret_scanning,
+            self.enable_secret_scanning_push_protection,
+            self.delete_branch_on_merge,
+            self.allow_forking,
+            self.allow_update_branch,
+        ];
+        self.description.is_some()
+            || self.homepage.is_some()
+            || self.default_branch.is_some()
+            || self.visibility.is_some()
+            || bools.iter().any(Option::is_some)
+            || !self.add_topics.is_empty()
+            || !self.remove_topics.is_empty()
+    }
+
+    /// Ask which settings to change, then prompt for each one, starting from
+    /// the repository's current values.
+    async fn prompt_edits(
+        &self,
+        factory: &crate::factory::Factory,
+        client: &ghc_api::client::Client,
+        api_path: &str,
+    ) -> Result<Self> {
+        let current: Value = client
+            .rest(reqwest::Method::GET, api_path, None)
+            .await
+            .context("failed to fetch repository")?;
+        let prompter = factory.prompter();
+        let options: Vec<String> = EDITABLE_FIELDS.iter().map(ToString::to_string).collect();
+        let selected = prompter.multi_select(
+            "What do you want to edit?",
+            &vec![false; options.len()],
+            &options,
+        )?;
+        if selected.is_empty() {
+            anyhow::bail!("no fields selected to edit");
+        }
+
+        let mut edits = self.clone();
+        for field in selected.into_iter().filter_map(|i| EDITABLE_FIELDS.get(i)) {
+            edits.prompt_field(prompter.as_ref(), field, &current)?;
+        }
+        Ok(edits)
+    }
+
+    /// Prompt for a single setting picked in [`Self::prompt_edits`].
+    fn prompt_field(
+        &mut self,
+        prompter: &dyn ghc_core::prompter::Prompter,
+        field: &str,
+        current: &Value,
+    ) -> Result<()> {
+        let text = |key: &str| current.get(key).and_then(Value::as_str).unwrap_or_default();
+        let flag = |key: &str| current.get(key).and_then(Value::as_bool).unwrap_or(false);
+        match field {
+            "Description" => {
+                self.description = Some(prompter.input("Description", text("description"))?);
+            }
+            "Home Page" => {
+                self.homepage = Some(prompter.input("Repository home page URL", text("homepage"))?);
+            }
+            "Default Branch" => {
+                self.default_branch =
+                    Some(prompter.input("Default branch name", text("default_branch"))?);
+            }
+            "Visibility" => {
+                let choices: Vec<String> = ["public", "private", "internal"]
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect();
+                let default = choices.iter().position(|c| c == text("visibility"));
+                let choice = prompter.select("Visibility", default, &choices)?;
+                let visibility = &choices[choice];
+                if visibility != text("visibility")
+                    && prompter.confirm(&visibility_warning(visibility), false)?
+                {
+                    self.visibility = Some(visibility.clone());
+                    self.accept_visibility_change_consequences = true;
+                }
+            }
+            "Topics" => self.prompt_topics(prompter, current)?,
+            "Issues" => {
+                self.enable_issues = Some(prompter.confirm("Enable issues?", flag("has_issues"))?);
+            }
+            "Projects" => {
+                self.enable_projects =
+                    Some(prompter.confirm("Enable projects?", flag("has_projects"))?);
+            }
+            "Wiki" => {
+                self.enable_wiki = Some(prompter.confirm("Enable wiki?", flag("has_wiki"))?);
+            }
+            "Discussions" => {
+                self.enable_discussions =
+                    Some(prompter.confirm("Enable discussions?", flag("has_discussions"))?);
+            }
+            "Merge Options" => {
+                self.enable_merge_commit =
+                    Some(prompter.confirm("Allow merge commits?", flag("allow_merge_commit"))?);
+                self.enable_squash_merge =
+                    Some(prompter.confirm("Allow squash merging?", flag("allow_squash_merge"))?);
+                self.enable_rebase_merge =
+                    Some(prompter.confirm("Allow rebase merging?", flag("allow_rebase_merge"))?);
+                if self.enable_merge_commit == Some(false)
+                    && self.enable_squash_merge == Some(false)
+                    && self.enable_rebase_merge == Some(false)
+                {
+                    anyhow::bail!("you need to allow at least one merge strategy");
+                }
+                self.enable_auto_merge =
+                    Some(prompter.confirm("Enable auto-merge?", flag("allow_auto_merge"))?);
+            }
+            "Template Repository" => {
+                self.template = Some(
+                    prompter.confirm("Convert into a template repository?", flag("is_template"))?,
+                );
+            }
+            "Automatically delete head branches" => {
+                self.delete_branch_on_merge = Some(prompter.confirm(
+                    "Automatically delete head branches after merging?",
+                    flag("delete_branch_on_merge"),
+                )?);
+            }
+            "Allow Forking" => {
+                self.allow_forking =
+                    Some(prompter.confirm("Allow forking?", flag("allow_forking"))?);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Prompt for topics to add, then offer the current topics for removal.
+    fn prompt_topics(
+        &mut self,
+        prompter: &dyn ghc_core::prompter::Prompter,
+        current: &Value,
+    ) -> Result<()> {
+        let added = prompter.input("Add topics? (comma-separated)", "")?;
+        self.add_topics.extend(
+            added
+                .split(',')
+                .map(str::trim)
+                .filter(|t| !t.is_empty())
+                .map(str::to_string),
+        );
+        let existing: Vec<String> = current
+            .get("topics")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|t| t.as_str().map(str::to_string))
+            .collect();
+        if !existing.is_empty() {
+            let removed =
+                prompter.multi_select("Remove topics", &vec![false; existing.len()], &existing)?;
+            self.remove_topics
+                .extend(removed.into_iter().filter_map(|i| existing.get(i).cloned()));
+        }
+        Ok(())
+    }
+
     async fn patch_repo_settings(
         &self,
         client: &ghc_api::client::Client,
@@ -277,6 +491,13 @@ struct TopicsResponse {
     names: Vec<String>,
 }
 
+fn visibility_warning(visibility: &str) -> String {
+    format!(
+        "Changing the repository visibility to {visibility} can lose stars and watchers, \
+         detach public forks, and expose Actions history. Continue?"
+    )
+}
+
 fn bool_to_status(v: bool) -> &'static str {
     if v { "enabled" } else { "disabled" }
 }
@@ -285,7 +506,10 @@ fn bool_to_status(v: bool) -> &'static str {
 mod tests {
     use super::*;
 
-    use crate::test_helpers::{TestHarness, mock_rest_patch};
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    use crate::test_helpers::{TestHarness, mock_rest_get, mock_rest_patch};
 
     #[tokio::test]
     async fn test_should_edit_repository_description() {
@@ -303,6 +527,7 @@ mod tests {
 
         let args = EditArgs {
             repo: Some("owner/repo".into()),
+            repo_flag: None,
             description: Some("Updated description".into()),
             homepage: None,
             default_branch: None,
@@ -336,6 +561,7 @@ mod tests {
 
         let args = EditArgs {
             repo: Some("owner/repo".into()),
+            repo_flag: None,
             description: None,
             homepage: None,
             default_branch: None,
@@ -374,4 +600,125 @@ mod tests {
         assert_eq!(bool_to_status(true), "enabled");
         assert_eq!(bool_to_status(false), "disabled");
     }
+
+    fn edit_args(repo: &str) -> EditArgs {
+        use clap::Parser;
+
+        #[derive(clap::Parser)]
+        #[command(disable_help_flag = true)]
+        struct Cli {
+            #[command(flatten)]
+            args: EditArgs,
+        }
+        Cli::try_parse_from(["edit", "-R", repo]).unwrap().args
+    }
+
+    #[tokio::test]
+    async fn test_should_apply_topics_and_visibility_with_repo_flag() {
+        let h = TestHarness::new().await;
+        Mock::given(method("PATCH"))
+            .and(path("/repos/o/r"))
+            .and(body_partial_json(
+                serde_json::json!({ "visibility": "private" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+        mock_rest_get(
+            &h.server,
+            "/repos/o/r/topics",
+            serde_json::json!({ "names": ["cli"] }),
+        )
+        .await;
+        Mock::given(method("PUT"))
+            .and(path("/repos/o/r/topics"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let args = EditArgs {
+            add_topics: vec!["rust".into()],
+            visibility: Some("private".into()),
+            accept_visibility_change_consequences: true,
+            ..edit_args("o/r")
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let requests = h.server.received_requests().await.unwrap();
+        let put = requests
+            .iter()
+            .find(|r| r.method.as_str() == "PUT")
+            .unwrap();
+        let body: Value = serde_json::from_slice(&put.body).unwrap();
+        let mut names: Vec<&str> = body["names"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(Value::as_str)
+            .collect();
+        names.sort_unstable();
+        assert_eq!(names, ["cli", "rust"]);
+    }
+
+    #[tokio::test]
+    async fn test_should_confirm_visibility_change_interactively() {
+        let mut h = TestHarness::new().await;
+        h.factory.io.set_stdin_tty(true);
+        h.factory.io.set_stdout_tty(true);
+        h.factory.io.set_never_prompt(false);
+        h.prompter.confirm_answers.lock().unwrap().push(false);
+
+        let args = EditArgs {
+            visibility: Some("private".into()),
+            ..edit_args("o/r")
+        };
+        let err = args.run(&h.factory).await.unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("accept-visibility-change-consequences")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_prompt_for_fields_when_no_flags_given() {
+        let mut h = TestHarness::new().await;
+        h.factory.io.set_stdin_tty(true);
+        h.factory.io.set_stdout_tty(true);
+        h.factory.io.set_never_prompt(false);
+        mock_rest_get(
+            &h.server,
+            "/repos/o/r",
+            serde_json::json!({ "description": "old", "has_wiki": true, "topics": [] }),
+        )
+        .await;
+        Mock::given(method("PATCH"))
+            .and(path("/repos/o/r"))
+            .and(body_partial_json(
+                serde_json::json!({ "description": "new", "has_wiki": false }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+        // Description and Wiki.
+        h.prompter
+            .multi_select_answers
+            .lock()
+            .unwrap()
+            .push(vec![0, 7]);
+        h.prompter.input_answers.lock().unwrap().push("new".into());
+        h.prompter.confirm_answers.lock().unwrap().push(false);
+
+        edit_args("o/r").run(&h.factory).await.unwrap();
+        assert!(h.stdout().contains("Edited repository o/r"));
+    }
+
+    #[tokio::test]
+    async fn test_should_require_fields_when_not_interactive() {
+        let h = TestHarness::new().await;
+        let err = edit_args("o/r").run(&h.factory).await.unwrap_err();
+        assert!(err.to_string().contains("specify properties to edit"));
+    }
 }
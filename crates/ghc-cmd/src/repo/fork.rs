@@ -6,6 +6,7 @@ use serde_json::Value;
 
 use ghc_core::repo::Repo;
 use ghc_core::{ios_eprintln, ios_println};
+use ghc_git::url_parser;
 
 /// Create a fork of a repository.
 ///
@@ -15,6 +16,9 @@ use ghc_core::{ios_eprintln, ios_println};
 /// By default, the new fork is set to be your `origin` remote and any existing
 /// origin remote is renamed to `upstream`. To alter this behavior, you can set
 /// a name for the new fork's remote with `--remote-name`.
+///
+/// When forking another repository, `--clone` clones the fork and adds the
+/// original repository as the `upstream` remote.
 #[derive(Debug, Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct ForkArgs {
@@ -23,11 +27,11 @@ pub struct ForkArgs {
     repository: Option<String>,
 
     /// Clone the fork.
-    #[arg(long)]
+    #[arg(long, num_args = 0..=1, require_equals = true, default_missing_value = "true")]
     clone: Option<bool>,
 
-    /// Add a git remote for the fork.
-    #[arg(long)]
+    /// Add a git remote for the fork (when forking the current repository).
+    #[arg(long, num_args = 0..=1, require_equals = true, default_missing_value = "true")]
     remote: Option<bool>,
 
     /// Specify the name for the new remote.
@@ -49,6 +53,11 @@ pub struct ForkArgs {
 
 impl ForkArgs {
     /// Run the repo fork command.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be determined, the fork
+    /// cannot be created, or cloning or adding the remote fails.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -63,11 +72,11 @@ impl ForkArgs {
             anyhow::bail!("--remote-name cannot be blank");
         }
 
-        let repo = match &self.repository {
-            Some(r) => parse_repo_arg(r)?,
-            None => {
-                anyhow::bail!("repository argument required (e.g. OWNER/REPO)")
-            }
+        let in_parent = self.repository.is_none();
+        let repo = if let Some(r) = &self.repository {
+            parse_repo_arg(r)?
+        } else {
+            factory.base_repo().await?
         };
 
         let client = factory.api_client(repo.host())?;
@@ -78,7 +87,11 @@ impl ForkArgs {
             .get("full_name")
             .and_then(Value::as_str)
             .unwrap_or("");
-        let forked_name = forked.get("name").and_then(Value::as_str).unwrap_or("");
+        let forked_name = forked
+            .get("name")
+            .and_then(Value::as_str)
+            .unwrap_or("")
+            .to_string();
 
         let already_existed = check_already_existed(&forked);
 
@@ -90,19 +103,27 @@ impl ForkArgs {
             already_existed,
             connected_to_terminal,
         );
-        self.rename_if_needed(
-            &client,
-            forked_name,
-            &forked,
-            ios,
-            &cs,
-            connected_to_terminal,
-        )
-        .await?;
-        self.clone_if_requested(factory, &forked, &repo, ios, &cs, connected_to_terminal)
+        let forked = self
+            .rename_if_needed(
+                &client,
+                &forked_name,
+                forked,
+                ios,
+                &cs,
+                connected_to_terminal,
+            )
             .await?;
+        let fork_repo = fork_repo(&forked, repo.host())
+            .ok_or_else(|| anyhow::anyhow!("unexpected fork response: missing owner or name"))?;
+        let protocol = git_protocol(factory, repo.host());
 
-        Ok(())
+        if in_parent {
+            self.add_remote_if_requested(factory, &fork_repo, &protocol, connected_to_terminal)
+                .await
+        } else {
+            self.clone_if_requested(factory, &fork_repo, &repo, &protocol, connected_to_terminal)
+                .await
+        }
     }
 
     async fn create_fork(&self, client: &ghc_api::client::Client, repo: &Repo) -> Result<Value> {
@@ -123,21 +144,24 @@ impl ForkArgs {
             .context("failed to fork repository")
     }
 
+    /// Rename the fork to `--fork-name` when the API kept the original name
+    /// (as it does for a fork that already existed), returning the fork as it
+    /// now stands.
     async fn rename_if_needed(
         &self,
         client: &ghc_api::client::Client,
         forked_name: &str,
-        forked: &Value,
+        forked: Value,
         ios: &ghc_core::iostreams::IOStreams,
         cs: &ghc_core::iostreams::ColorScheme,
         connected_to_terminal: bool,
-    ) -> Result<()> {
+    ) -> Result<Value> {
         let Some(ref desired_name) = self.fork_name else {
-            return Ok(());
+            return Ok(forked);
         };
         let normalized = normalize_repo_name(desired_name);
         if forked_name.eq_ignore_ascii_case(&normalized) {
-            return Ok(());
+            return Ok(forked);
         }
 
         let forked_owner = forked
@@ -163,18 +187,86 @@ impl ForkArgs {
                 cs.bold(renamed_full)
             );
         }
+        Ok(renamed)
+    }
+
+    /// Point a git remote of the current repository at the fork.
+    ///
+    /// An existing `origin` is renamed to `upstream` so the new fork can take
+    /// its place.
+    async fn add_remote_if_requested(
+        &self,
+        factory: &crate::factory::Factory,
+        fork_repo: &Repo,
+        protocol: &str,
+        connected_to_terminal: bool,
+    ) -> Result<()> {
+        let ios = &factory.io;
+        let cs = ios.color_scheme();
+        let should_add = match self.remote {
+            Some(v) => v,
+            None => {
+                ios.can_prompt()
+                    && factory
+                        .prompter()
+                        .confirm("Would you like to add a remote for the fork?", false)?
+            }
+        };
+        if !should_add {
+            return Ok(());
+        }
+
+        let git_client = factory.git_client()?;
+        let remotes = git_client
+            .remotes()
+            .await
+            .context("failed to list remotes")?;
+        let remote_exists = |name: &str| remotes.iter().any(|r| r.name == name);
+        if remote_exists(&self.remote_name) {
+            if self.remote_name != "origin" || remote_exists("upstream") {
+                anyhow::bail!("a git remote named '{}' already exists", self.remote_name);
+            }
+            git_client
+                .rename_remote("origin", "upstream")
+                .await
+                .context("failed to rename origin remote")?;
+            if connected_to_terminal {
+                ios_eprintln!(
+                    ios,
+                    "{} Renamed remote {} to {}",
+                    cs.success_icon(),
+                    cs.bold("origin"),
+                    cs.bold("upstream")
+                );
+            }
+        }
+
+        let fork_url = url_parser::clone_url(fork_repo, protocol);
+        git_client
+            .add_remote(&self.remote_name, &fork_url, &[])
+            .await
+            .context("failed to add remote for the fork")?;
+        if connected_to_terminal {
+            ios_eprintln!(
+                ios,
+                "{} Added remote {}",
+                cs.success_icon(),
+                cs.bold(&self.remote_name)
+            );
+        }
         Ok(())
     }
 
     async fn clone_if_requested(
         &self,
         factory: &crate::factory::Factory,
-        forked: &Value,
+        fork_repo: &Repo,
         repo: &Repo,
-        ios: &ghc_core::iostreams::IOStreams,
-        cs: &ghc_core::iostreams::ColorScheme,
+        protocol: &str,
         connected_to_terminal: bool,
     ) -> Result<()> {
+        let ios = &factory.io;
+        let cs = ios.color_scheme();
         let should_clone = match self.clone {
             Some(v) => v,
             None => {
@@ -192,23 +284,14 @@ impl ForkArgs {
             return Ok(());
         }
 
-        let clone_url = forked
-            .get("clone_url")
-            .and_then(Value::as_str)
-            .unwrap_or("");
-
-        if clone_url.is_empty() {
-            return Ok(());
-        }
-
         let git_client = factory.git_client()?;
         let clone_dir = git_client
-            .clone(clone_url, &[])
+            .clone(&url_parser::clone_url(fork_repo, protocol), &[])
             .await
             .context("failed to clone fork")?;
 
-        let upstream_url = format!("https://github.com/{}/{}.git", repo.owner(), repo.name());
-        let cloned_git = ghc_git::client::GitClient::new()?.with_repo_dir(&clone_dir);
+        let upstream_url = url_parser::clone_url(repo, protocol);
+        let cloned_git = Clone::clone(git_client).with_repo_dir(&clone_dir);
         cloned_git
             .add_remote("upstream", &upstream_url, &[])
             .await
@@ -230,6 +313,31 @@ impl ForkArgs {
     }
 }
 
+/// The fork as a repository on `host`, from the fork API response.
+fn fork_repo(forked: &Value, host: &str) -> Option<Repo> {
+    let owner = forked
+        .pointer("/owner/login")
+        .and_then(Value::as_str)
+        .filter(|s| !s.is_empty())?;
+    let name = forked
+        .get("name")
+        .and_then(Value::as_str)
+        .filter(|s| !s.is_empty())?;
+    Some(Repo::with_host(owner, name, host))
+}
+
+/// The configured git protocol for `host`, defaulting to HTTPS.
+fn git_protocol(factory: &crate::factory::Factory, host: &str) -> String {
+    factory
+        .config()
+        .ok()
+        .and_then(|c| {
+            let cfg = c.lock().ok()?;
+            Some(cfg.git_protocol(host))
+        })
+        .unwrap_or_else(|| "https".to_string())
+}
+
 /// Print the status of the fork operation (created or already existed).
 fn print_fork_status(
     ios: &ghc_core::iostreams::IOStreams,
@@ -278,16 +386,8 @@ fn check_already_existed(forked: &Value) -> bool {
 
 /// Parse a repository argument that could be OWNER/REPO or a URL.
 fn parse_repo_arg(arg: &str) -> Result<Repo> {
-    if arg.starts_with("http:/") || arg.starts_with("https:/") {
-        let parsed = url::Url::parse(arg).context("did not understand argument")?;
-        Repo::from_url(&parsed).map_err(|e| anyhow::anyhow!("did not understand argument: {e}"))
-    } else if arg.starts_with("git@") {
-        // Convert git@host:owner/repo to https://host/owner/repo for parsing
-        let rest = arg.strip_prefix("git@").unwrap_or(arg);
-        let normalized = rest.replacen(':', "/", 1);
-        let url_str = format!("https://{normalized}");
-        let parsed = url::Url::parse(&url_str).context("did not understand argument")?;
-        Repo::from_url(&parsed).map_err(|e| anyhow::anyhow!("did not understand argument: {e}"))
+    if url_parser::is_url(arg) {
+        Repo::from_url(arg).map_err(|e| anyhow::anyhow!("did not understand argument: {e}"))
     } else {
         Repo::from_full_name(arg).map_err(|e| anyhow::anyhow!("argument error: {e}"))
     }
@@ -305,8 +405,23 @@ fn normalize_repo_name(name: &str) -> String {
 mod tests {
     use super::*;
 
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
     use crate::test_helpers::{TestHarness, mock_rest_post};
 
+    fn fork_args(args: &[&str]) -> ForkArgs {
+        use clap::Parser;
+
+        #[derive(clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: ForkArgs,
+        }
+        let command_line = std::iter::once("fork").chain(args.iter().copied());
+        Cli::try_parse_from(command_line).unwrap().args
+    }
+
     #[tokio::test]
     async fn test_should_fork_repository() {
         let h = TestHarness::new().await;
@@ -399,4 +514,151 @@ mod tests {
         assert_eq!(normalize_repo_name("repo.git"), "repo");
         assert_eq!(normalize_repo_name("valid-name"), "valid-name");
     }
+
+    #[test]
+    fn test_should_parse_bare_clone_flag_before_repository() {
+        let args = fork_args(&[
+            "--clone",
+            "o/r",
+            "--org",
+            "myorg",
+            "--fork-name",
+            "r-mirror",
+        ]);
+        assert_eq!(args.clone, Some(true));
+        assert_eq!(args.repository.as_deref(), Some("o/r"));
+        assert_eq!(fork_args(&["--clone=false", "o/r"]).clone, Some(false));
+    }
+
+    #[tokio::test]
+    async fn test_should_fork_into_org_with_new_name() {
+        let h = TestHarness::new().await;
+        Mock::given(method("POST"))
+            .and(path("/repos/o/r/forks"))
+            .and(body_partial_json(serde_json::json!({
+                "organization": "myorg",
+                "name": "r-mirror",
+                "default_branch_only": true,
+            })))
+            .respond_with(ResponseTemplate::new(202).set_body_json(serde_json::json!({
+                "full_name": "myorg/r-mirror",
+                "name": "r-mirror",
+                "html_url": "https://github.com/myorg/r-mirror",
+                "owner": { "login": "myorg" },
+                "created_at": chrono::Utc::now().to_rfc3339(),
+            })))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let args = fork_args(&[
+            "o/r",
+            "--org",
+            "myorg",
+            "--fork-name",
+            "r-mirror",
+            "--default-branch-only",
+            "--clone=false",
+        ]);
+        args.run(&h.factory).await.unwrap();
+        assert_eq!(h.stdout(), "https://github.com/myorg/r-mirror\n");
+    }
+
+    #[tokio::test]
+    async fn test_should_rename_existing_fork_to_requested_name() {
+        let h = TestHarness::new().await;
+        let old_time = chrono::Utc::now() - chrono::Duration::hours(1);
+        mock_rest_post(
+            &h.server,
+            "/repos/o/r/forks",
+            202,
+            serde_json::json!({
+                "full_name": "myorg/r",
+                "name": "r",
+                "owner": { "login": "myorg" },
+                "created_at": old_time.to_rfc3339(),
+            }),
+        )
+        .await;
+        Mock::given(method("PATCH"))
+            .and(path("/repos/myorg/r"))
+            .and(body_partial_json(serde_json::json!({ "name": "r-mirror" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "full_name": "myorg/r-mirror",
+                "name": "r-mirror",
+                "owner": { "login": "myorg" },
+            })))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let args = fork_args(&[
+            "o/r",
+            "--org",
+            "myorg",
+            "--fork-name",
+            "r-mirror",
+            "--clone=false",
+        ]);
+        args.run(&h.factory).await.unwrap();
+        assert!(h.stderr().contains("myorg/r already exists"));
+    }
+
+    #[test]
+    fn test_should_build_fork_repo_from_response() {
+        let forked = serde_json::json!({ "name": "r-mirror", "owner": { "login": "myorg" } });
+        let repo = fork_repo(&forked, "ghe.example.com").unwrap();
+        assert_eq!(repo.full_name(), "myorg/r-mirror");
+        assert_eq!(repo.host(), "ghe.example.com");
+        assert!(fork_repo(&serde_json::json!({ "name": "r" }), "github.com").is_none());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_should_rename_origin_and_add_fork_remote() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let script = dir.path().join("git");
+        std::fs::write(
+            &script,
+            format!(
+                "#!/bin/sh\necho \"$*\" >> {log}\n\
+                 if [ \"$1\" = remote ] && [ \"$2\" = -v ]; then\n\
+                 printf 'origin\\thttps://github.com/owner/repo.git (fetch)\\norigin\\thttps://github.com/owner/repo.git (push)\\n'\n\
+                 fi\n\
+                 if [ \"$1\" = config ]; then exit 1; fi\n",
+                log = dir.path().join("git.log").display(),
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let mut h = TestHarness::new().await;
+        let git = ghc_git::client::GitClient::new()
+            .unwrap()
+            .with_git_path(script);
+        h.factory = h.factory.with_git_client(git);
+        mock_rest_post(
+            &h.server,
+            "/repos/owner/repo/forks",
+            202,
+            serde_json::json!({
+                "full_name": "testuser/repo",
+                "name": "repo",
+                "owner": { "login": "testuser" },
+                "created_at": chrono::Utc::now().to_rfc3339(),
+            }),
+        )
+        .await;
+
+        fork_args(&["--remote"]).run(&h.factory).await.unwrap();
+
+        let log = std::fs::read_to_string(dir.path().join("git.log")).unwrap();
+        assert!(log.contains("remote rename origin upstream\n"), "{log}");
+        assert!(
+            log.contains("remote add origin https://github.com/testuser/repo.git\n"),
+            "{log}"
+        );
+    }
 }
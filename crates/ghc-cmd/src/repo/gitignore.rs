@@ -41,7 +41,7 @@ pub struct ListArgs;
 
 impl ListArgs {
     async fn run(&self, factory: &Factory) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
 
@@ -88,7 +88,7 @@ struct GitIgnoreTemplate {
 
 impl ViewArgs {
     async fn run(&self, factory: &Factory) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
 
         let path = format!("gitignore/templates/{}", self.template);
@@ -50,7 +50,7 @@ struct LicenseSummary {
 
 impl ListArgs {
     async fn run(&self, factory: &Factory) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
 
@@ -124,7 +124,7 @@ impl ViewArgs {
             return Ok(());
         }
 
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
 
@@ -36,8 +36,8 @@ pub struct ListArgs {
     #[arg(short, long)]
     language: Option<String>,
 
-    /// Filter by topic.
-    #[arg(long)]
+    /// Filter by topic (repeat or comma-separate for several).
+    #[arg(long, value_delimiter = ',')]
     topic: Vec<String>,
 
     /// Show only forks.
@@ -67,6 +67,10 @@ pub struct ListArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 /// Result of listing repositories, including total count and ownership info.
@@ -93,7 +97,7 @@ impl ListArgs {
             anyhow::bail!("invalid limit: {}", self.limit);
         }
 
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
 
         let owner = if let Some(o) = &self.owner {
@@ -112,10 +116,8 @@ impl ListArgs {
                 .to_string()
         };
 
-        // Use the Search API when filters require it (language, archived, topic, internal)
+        // Use the Search API when filters require it (language, topic, internal)
         let needs_search = self.language.is_some()
-            || self.archived
-            || self.no_archived
             || !self.topic.is_empty()
             || self.visibility.as_deref() == Some("internal");
 
@@ -134,17 +136,17 @@ impl ListArgs {
 
         // JSON output mode with field filtering, jq, or template
         // Always produces output (even [] for empty results)
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let mut arr = Value::Array(result.repos.clone());
             ghc_core::json::normalize_graphql_connections(&mut arr);
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &arr,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -268,10 +270,10 @@ impl ListArgs {
         owner: &str,
     ) -> Result<RepoListResult> {
         let query = r"
-            query RepoList($owner: String!, $first: Int!, $after: String, $privacy: RepositoryPrivacy, $fork: Boolean) {
+            query RepoList($owner: String!, $first: Int!, $after: String, $privacy: RepositoryPrivacy, $fork: Boolean, $archived: Boolean) {
               repositoryOwner(login: $owner) {
                 login
-                repositories(first: $first, after: $after, privacy: $privacy, isFork: $fork, ownerAffiliations: OWNER, orderBy: {field: PUSHED_AT, direction: DESC}) {
+                repositories(first: $first, after: $after, privacy: $privacy, isFork: $fork, isArchived: $archived, ownerAffiliations: OWNER, orderBy: {field: PUSHED_AT, direction: DESC}) {
                   totalCount
                   pageInfo { hasNextPage endCursor }
                   nodes {
@@ -314,6 +316,13 @@ impl ListArgs {
             variables.insert("fork".to_string(), Value::Bool(false));
         }
 
+        // Apply archived filter at the API level
+        if self.archived {
+            variables.insert("archived".to_string(), Value::Bool(true));
+        } else if self.no_archived {
+            variables.insert("archived".to_string(), Value::Bool(false));
+        }
+
         let mut result = RepoListResult {
             owner: String::new(),
             repos: Vec::new(),
@@ -519,9 +528,13 @@ impl ListArgs {
             parts.push(format!("is:{vis}"));
         }
 
-        // Language
+        // Language, quoted when it has spaces (e.g. "Jupyter Notebook")
         if let Some(ref lang) = self.language {
-            parts.push(format!("language:{lang}"));
+            if lang.contains(' ') {
+                parts.push(format!("language:\"{lang}\""));
+            } else {
+                parts.push(format!("language:{lang}"));
+            }
         }
 
         // Topics
@@ -575,6 +588,9 @@ impl ListArgs {
 mod tests {
     use super::*;
 
+    use wiremock::matchers::{body_partial_json, body_string_contains, method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
     use crate::test_helpers::{TestHarness, mock_graphql};
 
     fn repo_list_response(repos: &[Value]) -> Value {
@@ -650,6 +666,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -681,6 +698,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -707,6 +725,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -753,6 +772,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -777,6 +797,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         let result = args.run(&h.factory).await;
         assert!(result.is_err());
@@ -800,6 +821,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         let result = args.run(&h.factory).await;
         assert!(result.is_err());
@@ -821,6 +843,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
 
         let query = args.build_search_query("myuser");
@@ -832,6 +855,87 @@ mod tests {
         assert!(query.contains("archived:false"));
     }
 
+    #[tokio::test]
+    async fn test_should_filter_archived_without_search() {
+        let h = TestHarness::new().await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("RepoList"))
+            .and(body_partial_json(serde_json::json!({
+                "variables": { "owner": "myorg", "archived": false }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "repositoryOwner": {
+                        "login": "myorg",
+                        "repositories": {
+                            "totalCount": 1,
+                            "nodes": [repo_fixture("active", false, false)],
+                            "pageInfo": { "hasNextPage": false, "endCursor": null }
+                        }
+                    }
+                }
+            })))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let args = ListArgs {
+            owner: Some("myorg".into()),
+            limit: 30,
+            visibility: None,
+            language: None,
+            topic: vec![],
+            fork: false,
+            source: false,
+            archived: false,
+            no_archived: true,
+            json: vec![],
+            jq: None,
+            template: None,
+            yaml: false,
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert!(h.stdout().contains("active"));
+    }
+
+    #[test]
+    fn test_should_parse_comma_separated_topics() {
+        use clap::Parser;
+
+        #[derive(clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: ListArgs,
+        }
+
+        let cli = Cli::try_parse_from(["list", "--topic", "cli,rust", "--topic", "git"]).unwrap();
+        assert_eq!(cli.args.topic, ["cli", "rust", "git"]);
+    }
+
+    #[test]
+    fn test_should_quote_language_with_spaces() {
+        let args = ListArgs {
+            owner: None,
+            limit: 30,
+            visibility: None,
+            language: Some("Jupyter Notebook".into()),
+            topic: vec![],
+            fork: false,
+            source: false,
+            archived: false,
+            no_archived: false,
+            json: vec![],
+            jq: None,
+            template: None,
+            yaml: false,
+        };
+
+        let query = args.build_search_query("org");
+        assert!(query.contains("language:\"Jupyter Notebook\""), "{query}");
+    }
+
     #[test]
     fn test_should_build_search_query_fork_only() {
         let args = ListArgs {
@@ -847,6 +951,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
 
         let query = args.build_search_query("org");
@@ -868,6 +973,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
 
         let header = args.list_header("testuser", 5, 42);
@@ -889,6 +995,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
 
         let header = args.list_header("testuser", 3, 100);
@@ -37,11 +37,7 @@ impl RenameArgs {
         let ios = &factory.io;
         let cs = ios.color_scheme();
 
-        let repo = if let Some(r) = &self.repo_override {
-            Repo::from_full_name(r).context("invalid repository format")?
-        } else {
-            anyhow::bail!("repository argument required (use -R OWNER/REPO)")
-        };
+        let repo = factory.resolve_repo(self.repo_override.as_deref()).await?;
 
         let client = factory.api_client(repo.host())?;
         let new_name = self.resolve_new_name(factory, &repo)?;
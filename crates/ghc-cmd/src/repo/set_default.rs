@@ -3,8 +3,8 @@
 use anyhow::{Context, Result};
 use clap::Args;
 
-use ghc_core::ios_println;
 use ghc_core::repo::Repo;
+use ghc_core::{ios_eprintln, ios_println};
 
 use ghc_git::remote::Remote;
 
@@ -25,16 +25,21 @@ pub struct SetDefaultArgs {
     repo: Option<String>,
 
     /// View the current default repository.
-    #[arg(short, long)]
+    #[arg(short, long, conflicts_with_all = ["repo", "unset"])]
     view: bool,
 
     /// Unset the current default repository.
-    #[arg(short, long)]
+    #[arg(short, long, conflicts_with = "repo")]
     unset: bool,
 }
 
 impl SetDefaultArgs {
     /// Run the repo set-default command.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not inside a git repository, no remote matches the
+    /// chosen repository, or the git config cannot be updated.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
         let git_client = factory.git_client()?;
 
@@ -55,9 +60,7 @@ impl SetDefaultArgs {
         }
 
         if self.unset {
-            return self
-                .handle_unset(factory, git_client, current_default)
-                .await;
+            return self.handle_unset(factory, git_client, &remotes).await;
         }
 
         self.handle_set(factory, git_client, &remotes, current_default)
@@ -80,7 +83,7 @@ impl SetDefaultArgs {
             };
             ios_println!(ios, "{display}");
         } else {
-            ios_println!(
+            ios_eprintln!(
                 ios,
                 "{} No default remote repository has been set. \
                  To learn more about the default repository, run: ghc repo set-default --help",
@@ -93,16 +96,13 @@ impl SetDefaultArgs {
         &self,
         factory: &crate::factory::Factory,
         git_client: &ghc_git::client::GitClient,
-        current_default: Option<&Remote>,
+        remotes: &[Remote],
     ) -> Result<()> {
         let ios = &factory.io;
         let cs = ios.color_scheme();
 
-        if let Some(remote) = current_default {
-            git_client
-                .unset_remote_resolution(&remote.name)
-                .await
-                .context("failed to unset remote resolution")?;
+        if let Some(remote) = remotes.iter().find(|r| !r.resolved.is_empty()) {
+            clear_resolutions(git_client, remotes).await?;
 
             let repo_name = remote_full_name(remote);
             if ios.is_stdout_tty() {
@@ -146,9 +146,7 @@ impl SetDefaultArgs {
                 )
             })?;
 
-        if let Some(current) = current_default {
-            git_client.unset_remote_resolution(&current.name).await.ok();
-        }
+        clear_resolutions(git_client, remotes).await?;
 
         let resolution =
             if remote_matches_repo(target_remote, target_repo.owner(), target_repo.name()) {
@@ -243,6 +241,20 @@ impl SetDefaultArgs {
     }
 }
 
+/// Remove the `gh-resolved` marker from every remote that has one.
+async fn clear_resolutions(
+    git_client: &ghc_git::client::GitClient,
+    remotes: &[Remote],
+) -> Result<()> {
+    for remote in remotes.iter().filter(|r| !r.resolved.is_empty()) {
+        git_client
+            .unset_remote_resolution(&remote.name)
+            .await
+            .context("failed to unset remote resolution")?;
+    }
+    Ok(())
+}
+
 /// Get the full name (owner/repo) from a Remote.
 fn remote_full_name(remote: &Remote) -> String {
     if let Some(ref repo) = remote.repo {
@@ -273,10 +285,150 @@ fn remote_matches_repo(remote: &Remote, owner: &str, name: &str) -> bool {
 mod tests {
     use super::*;
 
+    use crate::test_helpers::TestHarness;
+
+    fn set_default_args(repo: Option<&str>) -> SetDefaultArgs {
+        SetDefaultArgs {
+            repo: repo.map(String::from),
+            view: false,
+            unset: false,
+        }
+    }
+
+    fn git(dir: &std::path::Path, args: &[&str]) -> String {
+        let output = std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    /// A harness whose repository has `origin` pointing at a fork of `cli/cli`.
+    async fn harness_with_remotes() -> TestHarness {
+        TestHarness::new().await.with_git_remotes(&[
+            ("origin", "https://github.com/monalisa/cli.git"),
+            ("upstream", "https://github.com/cli/cli.git"),
+        ])
+    }
+
     #[test]
     fn test_should_parse_repo_from_full_name() {
         let repo = Repo::from_full_name("owner/repo").unwrap();
         assert_eq!(repo.owner(), "owner");
         assert_eq!(repo.name(), "repo");
     }
+
+    #[tokio::test]
+    async fn test_should_prompt_for_default_and_persist_it() {
+        let mut h = harness_with_remotes().await;
+        h.factory.io.set_stdin_tty(true);
+        h.factory.io.set_stdout_tty(true);
+        h.factory.io.set_never_prompt(false);
+        h.prompter.select_answers.lock().unwrap().push(1);
+
+        set_default_args(None).run(&h.factory).await.unwrap();
+
+        assert_eq!(
+            git(h.git_dir(), &["config", "remote.origin.gh-resolved"]),
+            "base"
+        );
+        assert!(
+            h.stdout()
+                .contains("Set monalisa/cli as the default repository for the current directory")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_replace_previous_default() {
+        let h = harness_with_remotes().await;
+        git(
+            h.git_dir(),
+            &["config", "remote.upstream.gh-resolved", "base"],
+        );
+
+        set_default_args(Some("origin"))
+            .run(&h.factory)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            git(h.git_dir(), &["config", "remote.origin.gh-resolved"]),
+            "base"
+        );
+        assert_eq!(
+            git(h.git_dir(), &["config", "remote.upstream.gh-resolved"]),
+            ""
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_view_current_default() {
+        let h = harness_with_remotes().await;
+        git(
+            h.git_dir(),
+            &["config", "remote.upstream.gh-resolved", "base"],
+        );
+
+        let args = SetDefaultArgs {
+            view: true,
+            ..set_default_args(None)
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert_eq!(h.stdout(), "cli/cli\n");
+    }
+
+    #[tokio::test]
+    async fn test_should_report_missing_default_on_view() {
+        let h = harness_with_remotes().await;
+
+        let args = SetDefaultArgs {
+            view: true,
+            ..set_default_args(None)
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert!(h.stdout().is_empty());
+        assert!(
+            h.stderr()
+                .contains("No default remote repository has been set")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_unset_default() {
+        let h = harness_with_remotes().await;
+        git(
+            h.git_dir(),
+            &["config", "--add", "remote.upstream.gh-resolved", "base"],
+        );
+        git(
+            h.git_dir(),
+            &["config", "--add", "remote.upstream.gh-resolved", "base"],
+        );
+
+        let args = SetDefaultArgs {
+            unset: true,
+            ..set_default_args(None)
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert_eq!(
+            git(
+                h.git_dir(),
+                &["config", "--get-all", "remote.upstream.gh-resolved"]
+            ),
+            ""
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_require_repo_when_not_interactive() {
+        let h = harness_with_remotes().await;
+
+        let err = set_default_args(None).run(&h.factory).await.unwrap_err();
+
+        assert!(err.to_string().contains("repository required"));
+    }
 }
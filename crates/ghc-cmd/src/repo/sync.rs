@@ -18,7 +18,8 @@ use ghc_core::repo::Repo;
 /// synced using a hard reset.
 ///
 /// Without an argument, the local repository is selected as the destination
-/// repository.
+/// repository, and the local branch is fast-forwarded from the `upstream`
+/// remote (or the remote marked as the default repository).
 ///
 /// The source repository is the parent of the destination repository by default.
 /// This can be overridden with the `--source` flag.
@@ -28,6 +29,10 @@ pub struct SyncArgs {
     #[arg(value_name = "DESTINATION")]
     destination: Option<String>,
 
+    /// Destination repository (OWNER/REPO), as an alternative to the argument.
+    #[arg(short = 'R', long = "repo", conflicts_with = "destination")]
+    repo: Option<String>,
+
     /// Source repository (OWNER/REPO).
     #[arg(short, long)]
     source: Option<String>,
@@ -44,8 +49,8 @@ pub struct SyncArgs {
 impl SyncArgs {
     /// Run the repo sync command.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        if self.destination.is_some() {
-            self.sync_remote(factory).await
+        if let Some(dest) = self.destination.as_deref().or(self.repo.as_deref()) {
+            self.sync_remote(factory, dest).await
         } else {
             self.sync_local(factory).await
         }
@@ -56,16 +61,26 @@ impl SyncArgs {
         let ios = &factory.io;
         let cs = ios.color_scheme();
 
-        let src_repo = if let Some(s) = &self.source {
-            Repo::from_full_name(s).context("invalid source repository")?
+        let git_client = factory.git_client()?;
+        let (src_repo, remote_name) = if let Some(s) = &self.source {
+            let src_repo = Repo::from_full_name(s).context("invalid source repository")?;
+            let remote_name = find_remote_name(git_client, &src_repo).await?;
+            (src_repo, remote_name)
         } else {
-            anyhow::bail!("source repository required for local sync (use --source OWNER/REPO)")
+            let remotes = git_client
+                .remotes()
+                .await
+                .context("failed to list remotes")?;
+            let remote = default_source_remote(&remotes).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "can't determine the source repository; add an `upstream` remote or use --source OWNER/REPO"
+                )
+            })?;
+            let src_repo = remote.repo.clone().unwrap_or_else(|| Repo::new("", ""));
+            (src_repo, remote.name.clone())
         };
 
         let client = factory.api_client(src_repo.host())?;
-        let git_client = factory.git_client()?;
-
-        let remote_name = find_remote_name(git_client, &src_repo).await?;
         let branch = self
             .resolve_branch(&client, src_repo.owner(), src_repo.name())
             .await?;
@@ -146,11 +161,10 @@ impl SyncArgs {
     }
 
     /// Sync a remote fork from its parent or from a specified source.
-    async fn sync_remote(&self, factory: &crate::factory::Factory) -> Result<()> {
+    async fn sync_remote(&self, factory: &crate::factory::Factory, dest: &str) -> Result<()> {
         let ios = &factory.io;
         let cs = ios.color_scheme();
 
-        let dest = self.destination.as_deref().unwrap_or_default();
         let dest_repo = Repo::from_full_name(dest).context("invalid destination repository")?;
 
         let client = factory.api_client(dest_repo.host())?;
@@ -158,10 +172,12 @@ impl SyncArgs {
             .resolve_branch(&client, dest_repo.owner(), dest_repo.name())
             .await?;
 
-        // Try merge-upstream API first
-        if let Some(()) = self
-            .try_merge_upstream(&client, &dest_repo, &branch, ios, &cs)
-            .await?
+        // Try merge-upstream API first; it always syncs from the parent, so
+        // an explicit --source goes straight to updating the ref.
+        if self.source.is_none()
+            && let Some(()) = self
+                .try_merge_upstream(&client, &dest_repo, &branch, ios, &cs)
+                .await?
         {
             return Ok(());
         }
@@ -312,6 +328,19 @@ impl SyncArgs {
     }
 }
 
+/// The remote to sync the local repository from when `--source` is not given.
+///
+/// Prefers the remote marked as the default repository, then the first
+/// remote pointing at a GitHub repository (remotes are ordered `upstream`,
+/// `github`, `origin`, then the rest).
+fn default_source_remote(remotes: &[ghc_git::remote::Remote]) -> Option<&ghc_git::remote::Remote> {
+    remotes
+        .iter()
+        .filter(|r| r.repo.is_some())
+        .find(|r| r.resolved == "base")
+        .or_else(|| remotes.iter().find(|r| r.repo.is_some()))
+}
+
 async fn find_remote_name(
     git_client: &ghc_git::client::GitClient,
     src_repo: &Repo,
@@ -534,6 +563,9 @@ impl GitClientSyncExt for ghc_git::client::GitClient {
 mod tests {
     use super::*;
 
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
     use crate::test_helpers::{TestHarness, mock_graphql, mock_rest_post};
 
     #[tokio::test]
@@ -567,6 +599,7 @@ mod tests {
 
         let args = SyncArgs {
             destination: Some("fork-owner/repo".into()),
+            repo: None,
             source: None,
             branch: None,
             force: false,
@@ -574,4 +607,93 @@ mod tests {
         // Succeeds without error (TTY output not checked since test IO is non-TTY)
         args.run(&h.factory).await.unwrap();
     }
+
+    #[tokio::test]
+    async fn test_should_sync_remote_named_by_repo_flag() {
+        let h = TestHarness::new().await;
+        mock_graphql(
+            &h.server,
+            "RepoDefaultBranch",
+            serde_json::json!({
+                "data": {
+                    "repository": {
+                        "defaultBranchRef": { "name": "trunk" }
+                    }
+                }
+            }),
+        )
+        .await;
+        Mock::given(method("POST"))
+            .and(path("/repos/fork-owner/repo/merge-upstream"))
+            .and(body_partial_json(serde_json::json!({ "branch": "trunk" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "message": "Successfully fetched and fast-forwarded from upstream",
+                "merge_type": "fast-forward",
+                "base_branch": "upstream-owner:trunk",
+            })))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let args = SyncArgs {
+            destination: None,
+            repo: Some("fork-owner/repo".into()),
+            source: None,
+            branch: None,
+            force: false,
+        };
+        args.run(&h.factory).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_should_skip_merge_upstream_with_explicit_source() {
+        let h = TestHarness::new().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&h.server)
+            .await;
+        crate::test_helpers::mock_rest_get(
+            &h.server,
+            "/repos/other/repo/git/refs/heads/main",
+            serde_json::json!({ "object": { "sha": "abc123" } }),
+        )
+        .await;
+        Mock::given(method("PATCH"))
+            .and(path("/repos/fork-owner/repo/git/refs/heads/main"))
+            .and(body_partial_json(
+                serde_json::json!({ "sha": "abc123", "force": true }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let args = SyncArgs {
+            destination: Some("fork-owner/repo".into()),
+            repo: None,
+            source: Some("other/repo".into()),
+            branch: Some("main".into()),
+            force: true,
+        };
+        args.run(&h.factory).await.unwrap();
+    }
+
+    #[test]
+    fn test_should_prefer_upstream_remote_as_local_source() {
+        let mut remotes = ghc_git::remote::Remote::parse_remotes(
+            "origin\thttps://github.com/me/repo.git (fetch)\n\
+             origin\thttps://github.com/me/repo.git (push)\n\
+             upstream\thttps://github.com/owner/repo.git (fetch)\n\
+             upstream\thttps://github.com/owner/repo.git (push)\n",
+        );
+        let remote = default_source_remote(&remotes).unwrap();
+        assert_eq!(remote.name, "upstream");
+
+        remotes[1].resolved = "base".into();
+        let remote = default_source_remote(&remotes).unwrap();
+        assert_eq!(remote.name, remotes[1].name);
+
+        assert!(default_source_remote(&[]).is_none());
+    }
 }
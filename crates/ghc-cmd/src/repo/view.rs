@@ -7,6 +7,7 @@ use clap::Args;
 use serde::Deserialize;
 use serde_json::Value;
 
+use ghc_api::queries::repo::Repository;
 use ghc_core::ios_println;
 use ghc_core::text;
 
@@ -44,6 +45,10 @@ pub struct ViewArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 /// README content fetched from the REST API.
@@ -93,88 +98,78 @@ impl ViewArgs {
             return Ok(());
         }
 
-        let client = factory.api_client(repo.host())?;
+        let client = factory.with_response_cache(
+            factory.api_client(repo.host())?,
+            crate::factory::RESPONSE_CACHE_TTL,
+        );
 
         let mut variables = HashMap::new();
         variables.insert("owner".to_string(), Value::String(repo.owner().to_string()));
         variables.insert("name".to_string(), Value::String(repo.name().to_string()));
 
-        let data: Value = client
-            .graphql(ghc_api::queries::repo::REPO_QUERY, &variables)
-            .await
-            .context("failed to fetch repository")?;
-
-        let repo_data = data
-            .get("repository")
-            .filter(|v| !v.is_null())
-            .ok_or_else(|| {
-                anyhow::anyhow!(
-                    "Could not resolve to a Repository with the name '{}'",
-                    repo.full_name(),
-                )
-            })?;
-
         let ios = &factory.io;
 
         // JSON output with field filtering, jq, or template
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
-            let mut repo_owned = repo_data.clone();
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
+            let data: Value = client
+                .graphql(ghc_api::queries::repo::REPO_JSON_QUERY, &variables)
+                .await
+                .context("failed to fetch repository")?;
+            let mut repo_owned = data
+                .get("repository")
+                .filter(|r| !r.is_null())
+                .cloned()
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Could not resolve to a Repository with the name '{}'",
+                        repo.full_name(),
+                    )
+                })?;
             ghc_core::json::normalize_graphql_connections(&mut repo_owned);
             normalize_repo_json_fields(&mut repo_owned);
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &repo_owned,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
+        let data: Value = client
+            .graphql(ghc_api::queries::repo::REPO_QUERY, &variables)
+            .await
+            .context("failed to fetch repository")?;
+
+        let info: Repository = ghc_api::queries::decode(&data, "/repository")
+            .context("failed to decode repository")?
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Could not resolve to a Repository with the name '{}'",
+                    repo.full_name(),
+                )
+            })?;
+
         // Fetch README via REST API
         let readme_content = self.fetch_readme(&client, &repo).await;
 
         let cs = ios.color_scheme();
 
-        let name = repo_data.get("name").and_then(Value::as_str).unwrap_or("");
-        let owner_login = repo_data
-            .pointer("/owner/login")
-            .and_then(Value::as_str)
-            .unwrap_or("");
-        let description = repo_data
-            .get("description")
-            .and_then(Value::as_str)
-            .unwrap_or("No description");
-        let url = repo_data.get("url").and_then(Value::as_str).unwrap_or("");
-        let is_private = repo_data
-            .get("isPrivate")
-            .and_then(Value::as_bool)
-            .unwrap_or(false);
-        let is_fork = repo_data
-            .get("isFork")
-            .and_then(Value::as_bool)
-            .unwrap_or(false);
-        let is_archived = repo_data
-            .get("isArchived")
-            .and_then(Value::as_bool)
-            .unwrap_or(false);
-        let stars = repo_data
-            .get("stargazerCount")
-            .and_then(Value::as_i64)
-            .unwrap_or(0);
-        let forks = repo_data
-            .get("forkCount")
-            .and_then(Value::as_i64)
-            .unwrap_or(0);
-        let default_branch = repo_data
-            .pointer("/defaultBranchRef/name")
-            .and_then(Value::as_str)
-            .unwrap_or("main");
-        let language = repo_data
-            .pointer("/primaryLanguage/name")
-            .and_then(Value::as_str)
-            .unwrap_or("");
+        let name = &info.name;
+        let owner_login = &info.owner.login;
+        let description = info.description.as_deref().unwrap_or("No description");
+        let stars = info.stargazer_count.unwrap_or(0);
+        let forks = info.fork_count.unwrap_or(0);
+        let default_branch = info
+            .default_branch_ref
+            .as_ref()
+            .map_or("main", |b| b.name.as_str());
+        let language = info
+            .primary_language
+            .as_ref()
+            .map_or("", |l| l.name.as_str());
 
         if !ios.is_stdout_tty() {
             // Machine-readable output (non-TTY)
@@ -194,16 +189,16 @@ impl ViewArgs {
             description
         );
 
-        let visibility = if is_private {
+        let visibility = if info.is_private {
             cs.warning("private")
         } else {
             cs.success("public")
         };
         let mut badges = vec![visibility];
-        if is_fork {
+        if info.is_fork {
             badges.push("fork".to_string());
         }
-        if is_archived {
+        if info.is_archived {
             badges.push(cs.warning("archived"));
         }
         ios_println!(ios, "{}", badges.join(" | "));
@@ -217,13 +212,18 @@ impl ViewArgs {
         // Display README
         if let Some(ref content) = readme_content {
             ios_println!(ios, "");
-            ios_println!(ios, "{content}");
+            if ios.is_accessible() {
+                ios_println!(ios, "{}", ghc_core::markdown::render_plain(content));
+            } else {
+                let rendered = ghc_core::markdown::render(content, ios.terminal_width(), &cs);
+                ios_println!(ios, "{rendered}");
+            }
         } else {
             ios_println!(ios, "");
             ios_println!(ios, "{}", cs.gray("This repository does not have a README"));
         }
 
-        ios_println!(ios, "\n{}", text::display_url(url));
+        ios_println!(ios, "\n{}", text::display_url(&info.url));
 
         Ok(())
     }
@@ -236,7 +236,7 @@ impl ViewArgs {
     ) -> Option<String> {
         let mut path = format!("repos/{}/{}/readme", repo.owner(), repo.name());
         if let Some(ref branch) = self.branch {
-            path = format!("{path}?ref={branch}");
+            path = format!("{path}?ref={}", urlencoding::encode(branch));
         }
 
         let response: Result<ReadmeResponse, _> =
@@ -259,9 +259,29 @@ impl ViewArgs {
 ///
 /// Flattens `watchers: {totalCount: N}` to `watchers: N`,
 /// flattens `issues: {totalCount: N}` to `openIssueCount: N`,
-/// flattens `pullRequests: {totalCount: N}` to `openPullRequestCount: N`.
+/// flattens `pullRequests: {totalCount: N}` to `openPullRequestCount: N`,
+/// flattens `repositoryTopics` to `[{name}]` and `languages` to its edges.
 fn normalize_repo_json_fields(value: &mut Value) {
     if let Some(obj) = value.as_object_mut() {
+        if let Some(topics) = obj.get_mut("repositoryTopics") {
+            let names: Vec<Value> = topics
+                .get("nodes")
+                .and_then(Value::as_array)
+                .map(|nodes| {
+                    nodes
+                        .iter()
+                        .filter_map(|n| n.pointer("/topic/name").cloned())
+                        .map(|name| serde_json::json!({ "name": name }))
+                        .collect()
+                })
+                .unwrap_or_default();
+            *topics = Value::Array(names);
+        }
+        if let Some(languages) = obj.get_mut("languages")
+            && let Some(edges) = languages.get("edges").cloned()
+        {
+            *languages = edges;
+        }
         // Flatten watchers { totalCount: N } -> watchers: N
         if let Some(w) = obj.get("watchers")
             && let Some(tc) = w.get("totalCount")
@@ -290,6 +310,9 @@ fn normalize_repo_json_fields(value: &mut Value) {
 mod tests {
     use super::*;
 
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
     use crate::test_helpers::{TestHarness, graphql_repo_response, mock_graphql};
 
     #[tokio::test]
@@ -309,6 +332,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -324,6 +348,74 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_should_serve_repeated_view_from_cache() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let h = TestHarness::new().await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(graphql_repo_response("owner", "repo")),
+            )
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        for _ in 0..2 {
+            let args = ViewArgs {
+                repo: Some("owner/repo".into()),
+                web: false,
+                branch: None,
+                json: vec!["name".into()],
+                jq: None,
+                template: None,
+                yaml: false,
+            };
+            args.run(&h.factory).await.unwrap();
+        }
+
+        assert_eq!(h.stdout().matches("\"name\"").count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_should_render_repository_details_on_tty() {
+        let mut h = TestHarness::new().await;
+        h.factory.io.set_stdout_tty(true);
+        mock_graphql(
+            &h.server,
+            "repository",
+            graphql_repo_response("owner", "repo"),
+        )
+        .await;
+
+        let args = ViewArgs {
+            repo: Some("owner/repo".into()),
+            web: false,
+            branch: None,
+            json: vec![],
+            jq: None,
+            template: None,
+            yaml: false,
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let out = h.stdout();
+        assert!(
+            out.contains("Language: Rust"),
+            "should show language: {out}"
+        );
+        assert!(
+            out.contains("Stars: 42  Forks: 5"),
+            "should show counts: {out}"
+        );
+        assert!(
+            out.contains("Default branch: main"),
+            "should show branch: {out}"
+        );
+    }
+
     #[tokio::test]
     async fn test_should_view_repository_in_browser() {
         let h = TestHarness::new().await;
@@ -335,6 +427,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -354,6 +447,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -379,6 +473,7 @@ mod tests {
             json: vec!["name".into()],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -387,6 +482,95 @@ mod tests {
         assert!(out.contains("\"repo\""));
     }
 
+    #[tokio::test]
+    async fn test_should_output_license_topics_and_stars_as_json() {
+        let h = TestHarness::new().await;
+        mock_graphql(
+            &h.server,
+            "RepositoryJson",
+            serde_json::json!({
+                "data": {
+                    "repository": {
+                        "name": "repo",
+                        "stargazerCount": 42,
+                        "licenseInfo": { "key": "mit", "name": "MIT License", "nickname": null },
+                        "repositoryTopics": {
+                            "nodes": [{ "topic": { "name": "cli" } }, { "topic": { "name": "rust" } }]
+                        },
+                        "languages": { "edges": [{ "size": 100, "node": { "name": "Rust" } }] }
+                    }
+                }
+            }),
+        )
+        .await;
+
+        let args = ViewArgs {
+            repo: Some("owner/repo".into()),
+            web: false,
+            branch: None,
+            json: vec![
+                "licenseInfo".into(),
+                "stargazerCount".into(),
+                "repositoryTopics".into(),
+                "languages".into(),
+            ],
+            jq: None,
+            template: None,
+            yaml: false,
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let out: Value = serde_json::from_str(&h.stdout()).unwrap();
+        assert_eq!(
+            out,
+            serde_json::json!({
+                "licenseInfo": { "key": "mit", "name": "MIT License", "nickname": null },
+                "stargazerCount": 42,
+                "repositoryTopics": [{ "name": "cli" }, { "name": "rust" }],
+                "languages": [{ "size": 100, "node": { "name": "Rust" } }]
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_render_readme_for_branch_on_tty() {
+        let mut h = TestHarness::new().await;
+        h.factory.io.set_stdout_tty(true);
+        mock_graphql(
+            &h.server,
+            "repository",
+            graphql_repo_response("owner", "repo"),
+        )
+        .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/readme"))
+            .and(query_param("ref", "develop"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "name": "README.md",
+                "content": ghc_core::text::base64_encode(b"# Project\n\nSome **bold** text."),
+                "html_url": "https://github.com/owner/repo/blob/develop/README.md"
+            })))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let args = ViewArgs {
+            repo: Some("owner/repo".into()),
+            web: false,
+            branch: Some("develop".into()),
+            json: vec![],
+            jq: None,
+            template: None,
+            yaml: false,
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let out = h.stdout();
+        assert!(out.contains("Project"), "{out}");
+        assert!(!out.contains("# Project"), "{out}");
+        assert!(!out.contains("**bold**"), "{out}");
+    }
+
     #[tokio::test]
     async fn test_should_fail_without_repository_argument() {
         let h = TestHarness::new().await;
@@ -398,6 +582,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         let result = args.run(&h.factory).await;
         assert!(result.is_err());
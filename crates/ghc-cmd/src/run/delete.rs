@@ -4,7 +4,6 @@ use anyhow::{Context, Result};
 use clap::Args;
 
 use ghc_core::ios_eprintln;
-use ghc_core::repo::Repo;
 
 /// Delete a workflow run.
 #[derive(Debug, Args)]
@@ -25,11 +24,7 @@ impl DeleteArgs {
     ///
     /// Returns an error if the run cannot be deleted.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = self
-            .repo
-            .as_deref()
-            .ok_or_else(|| anyhow::anyhow!("repository argument required (use -R OWNER/REPO)"))?;
-        let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
 
         let path = format!(
@@ -5,7 +5,6 @@ use clap::Args;
 use serde_json::Value;
 
 use ghc_core::ios_eprintln;
-use ghc_core::repo::Repo;
 
 /// Download artifacts from a workflow run.
 ///
@@ -41,11 +40,7 @@ impl DownloadArgs {
     ///
     /// Returns an error if the artifacts cannot be downloaded.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = self
-            .repo
-            .as_deref()
-            .ok_or_else(|| anyhow::anyhow!("repository argument required (use -R OWNER/REPO)"))?;
-        let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -91,7 +86,12 @@ impl DownloadArgs {
             }
 
             // Glob pattern filter
-            if !self.pattern.is_empty() && !self.pattern.iter().any(|p| glob_match(p, name)) {
+            if !self.pattern.is_empty()
+                && !self
+                    .pattern
+                    .iter()
+                    .any(|p| ghc_core::cmdutil::glob_match(p, name))
+            {
                 continue;
             }
 
@@ -123,58 +123,3 @@ impl DownloadArgs {
         Ok(())
     }
 }
-
-/// Simple glob matching supporting `*` wildcards.
-fn glob_match(pattern: &str, text: &str) -> bool {
-    let parts: Vec<&str> = pattern.split('*').collect();
-
-    if parts.len() == 1 {
-        return pattern == text;
-    }
-
-    let mut remaining = text;
-
-    for (i, part) in parts.iter().enumerate() {
-        if part.is_empty() {
-            continue;
-        }
-        if i == 0 {
-            if !remaining.starts_with(part) {
-                return false;
-            }
-            remaining = &remaining[part.len()..];
-        } else if i == parts.len() - 1 {
-            if !remaining.ends_with(part) {
-                return false;
-            }
-            return true;
-        } else {
-            match remaining.find(part) {
-                Some(pos) => remaining = &remaining[pos + part.len()..],
-                None => return false,
-            }
-        }
-    }
-
-    true
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_should_match_glob_patterns() {
-        assert!(glob_match("build-*", "build-linux"));
-        assert!(glob_match("build-*", "build-macos-arm64"));
-        assert!(!glob_match("build-*", "test-linux"));
-        assert!(glob_match("*-linux-*", "build-linux-amd64"));
-        assert!(!glob_match("*-linux-*", "build-macos-arm64"));
-    }
-
-    #[test]
-    fn test_should_match_exact_name() {
-        assert!(glob_match("my-artifact", "my-artifact"));
-        assert!(!glob_match("my-artifact", "other-artifact"));
-    }
-}
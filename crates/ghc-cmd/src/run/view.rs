@@ -58,6 +58,10 @@ pub struct ViewArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl ViewArgs {
@@ -75,11 +79,7 @@ impl ViewArgs {
             anyhow::bail!("specify only one of --log or --log-failed");
         }
 
-        let repo = self
-            .repo
-            .as_deref()
-            .ok_or_else(|| anyhow::anyhow!("repository argument required (use -R OWNER/REPO)"))?;
-        let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
 
         if self.web {
             let url = if let Some(ref job_id) = self.job {
@@ -123,20 +123,24 @@ impl ViewArgs {
             .context("failed to fetch run")?;
 
         // JSON output
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let mut run_owned = run.clone();
             super::normalize_run_fields(&mut run_owned);
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &run_owned,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
+        if self.log || self.log_failed {
+            return self.print_logs(&client, &repo, &run, ios).await;
+        }
+
         let name = run.get("name").and_then(Value::as_str).unwrap_or("");
         let display_title = run
             .get("display_title")
@@ -169,19 +173,9 @@ impl ViewArgs {
         ios_println!(ios, "Updated: {updated_at}");
 
         // Show jobs
-        let jobs_path = format!(
-            "repos/{}/{}/actions/runs/{}/jobs",
-            repo.owner(),
-            repo.name(),
-            self.run_id,
-        );
-        if let Ok(jobs_result) = client
-            .rest::<Value>(reqwest::Method::GET, &jobs_path, None)
-            .await
-            && let Some(jobs) = jobs_result.get("jobs").and_then(Value::as_array)
-        {
+        if let Ok(jobs) = fetch_jobs(&client, &repo, self.run_id).await {
             ios_println!(ios, "\nJobs:");
-            for job in jobs {
+            for job in &jobs {
                 let job_name = job.get("name").and_then(Value::as_str).unwrap_or("");
                 let job_id = job.get("id").and_then(Value::as_u64).unwrap_or(0);
                 let job_conclusion = job.get("conclusion").and_then(Value::as_str).unwrap_or("");
@@ -236,40 +230,150 @@ impl ViewArgs {
 
         ios_println!(ios, "\n{}", ghc_core::text::display_url(html_url));
 
-        if self.log || self.log_failed {
-            let logs_path = format!(
-                "repos/{}/{}/actions/runs/{}/logs",
-                repo.owner(),
-                repo.name(),
+        // Exit with non-zero status if run failed
+        if self.exit_status && conclusion == "failure" {
+            anyhow::bail!("run concluded with: {conclusion}");
+        }
+
+        Ok(())
+    }
+}
+
+impl ViewArgs {
+    /// Print step logs from the run's log archive as `JOB<TAB>STEP<TAB>LINE`.
+    ///
+    /// With `--log-failed` only failed steps of failed jobs are printed, and
+    /// `--job` narrows the output to a single job.
+    async fn print_logs(
+        &self,
+        client: &ghc_api::client::Client,
+        repo: &Repo,
+        run: &Value,
+        ios: &ghc_core::iostreams::IOStreams,
+    ) -> Result<()> {
+        if run.get("status").and_then(Value::as_str) != Some("completed") {
+            anyhow::bail!(
+                "run {} is still in progress; logs will be available when it is complete",
                 self.run_id,
             );
-            let log_content = client
-                .rest_text(reqwest::Method::GET, &logs_path, None)
-                .await
-                .context("failed to fetch run logs")?;
-
-            if self.log_failed {
-                // Only show log lines around failures
-                ios_println!(ios, "\n--- Failed Step Logs ---");
-                for line in log_content.lines() {
-                    if line.contains("##[error]") || line.contains("Process completed with exit") {
-                        ios_println!(ios, "{line}");
-                    }
-                }
-            } else {
-                ios_println!(ios, "\n--- Logs ---\n{log_content}");
-            }
         }
 
-        // Exit with non-zero status if run failed
-        if self.exit_status && conclusion == "failure" {
-            anyhow::bail!("run concluded with: {conclusion}");
+        let jobs: Vec<Value> = fetch_jobs(client, repo, self.run_id)
+            .await
+            .context("failed to fetch run jobs")?
+            .into_iter()
+            .filter(|job| {
+                self.job.as_ref().is_none_or(|id| {
+                    job.get("id")
+                        .and_then(Value::as_u64)
+                        .is_some_and(|n| n.to_string() == *id)
+                })
+            })
+            .collect();
+        if let Some(ref id) = self.job
+            && jobs.is_empty()
+        {
+            anyhow::bail!("job {id} not found in run {}", self.run_id);
         }
 
+        let mut logs_path = format!(
+            "repos/{}/{}/actions/runs/{}",
+            repo.owner(),
+            repo.name(),
+            self.run_id,
+        );
+        if let Some(attempt) = self.attempt {
+            logs_path = format!("{logs_path}/attempts/{attempt}");
+        }
+        let archive = match client
+            .rest_bytes(reqwest::Method::GET, &format!("{logs_path}/logs"))
+            .await
+        {
+            Ok(bytes) => bytes,
+            Err(ghc_api::errors::ApiError::Http { status: 410, .. }) => {
+                anyhow::bail!(
+                    "logs for run {} have expired and are no longer available",
+                    self.run_id
+                )
+            }
+            Err(e) => return Err(anyhow::Error::new(e).context("failed to fetch run logs")),
+        };
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(archive))
+            .context("failed to read run log archive")?;
+
+        for job in &jobs {
+            let failed = |v: &Value| v.get("conclusion").and_then(Value::as_str) == Some("failure");
+            if self.log_failed && !failed(job) {
+                continue;
+            }
+            let job_name = job.get("name").and_then(Value::as_str).unwrap_or("");
+            let steps = job
+                .get("steps")
+                .and_then(Value::as_array)
+                .cloned()
+                .unwrap_or_default();
+            for step in &steps {
+                if self.log_failed && !failed(step) {
+                    continue;
+                }
+                let step_name = step.get("name").and_then(Value::as_str).unwrap_or("");
+                let Some(number) = step.get("number").and_then(Value::as_u64) else {
+                    continue;
+                };
+                let Some(log) = read_step_log(&mut archive, job_name, number) else {
+                    continue;
+                };
+                for line in log.lines() {
+                    ios_println!(ios, "{job_name}\t{step_name}\t{line}");
+                }
+            }
+        }
         Ok(())
     }
 }
 
+/// Read a step's log from the run archive, stored as `JOB/N_STEP.txt`.
+///
+/// Slashes are dropped from job names in the archive's directory names.
+fn read_step_log<R: std::io::Read + std::io::Seek>(
+    archive: &mut zip::ZipArchive<R>,
+    job_name: &str,
+    step_number: u64,
+) -> Option<String> {
+    let prefix = format!("{}/{step_number}_", job_name.replace('/', ""));
+    let file_name = archive
+        .file_names()
+        .find(|name| {
+            name.starts_with(&prefix)
+                && std::path::Path::new(name)
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("txt"))
+        })?
+        .to_string();
+    let mut file = archive.by_name(&file_name).ok()?;
+    let mut log = String::new();
+    std::io::Read::read_to_string(&mut file, &mut log).ok()?;
+    Some(log)
+}
+
+/// Every job of run `run_id`, following pagination.
+async fn fetch_jobs(
+    client: &ghc_api::client::Client,
+    repo: &Repo,
+    run_id: u64,
+) -> Result<Vec<Value>, ghc_api::errors::ApiError> {
+    let path = format!(
+        "repos/{}/{}/actions/runs/{run_id}/jobs?per_page=100",
+        repo.owner(),
+        repo.name(),
+    );
+    client
+        .rest_paginate_limit(&path, None, |page| {
+            ghc_api::client::page_items(page, Some("jobs"))
+        })
+        .await
+}
+
 /// Format elapsed time between two ISO 8601 timestamps.
 fn format_elapsed(start: &str, end: &str) -> String {
     let start_dt = chrono::DateTime::parse_from_rfc3339(start).ok();
@@ -289,3 +393,164 @@ fn format_elapsed(start: &str, end: &str) -> String {
         _ => String::new(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Write;
+
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    use crate::test_helpers::{TestHarness, mock_rest_get};
+
+    fn view_args(log: bool, log_failed: bool) -> ViewArgs {
+        ViewArgs {
+            run_id: 123,
+            repo: Some("owner/repo".into()),
+            web: false,
+            verbose: false,
+            log,
+            log_failed,
+            job: None,
+            exit_status: false,
+            attempt: None,
+            json: vec![],
+            jq: None,
+            template: None,
+            yaml: false,
+        }
+    }
+
+    fn log_archive() -> Vec<u8> {
+        let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        let opts = zip::write::SimpleFileOptions::default();
+        for (name, body) in [
+            ("build/1_Set up job.txt", "setting up\n"),
+            ("build/2_Run tests.txt", "running\n##[error]test failed\n"),
+            ("lint/1_Run clippy.txt", "all clean\n"),
+            ("0_build.txt", "whole job log\n"),
+        ] {
+            zip.start_file(name, opts).unwrap();
+            zip.write_all(body.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    async fn mount_run(h: &TestHarness, logs: ResponseTemplate) {
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/actions/runs/123",
+            serde_json::json!({ "name": "CI", "status": "completed", "conclusion": "failure" }),
+        )
+        .await;
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/actions/runs/123/jobs",
+            serde_json::json!({ "jobs": [
+                { "id": 1, "name": "build", "conclusion": "failure", "steps": [
+                    { "number": 1, "name": "Set up job", "conclusion": "success" },
+                    { "number": 2, "name": "Run tests", "conclusion": "failure" }
+                ]},
+                { "id": 2, "name": "lint", "conclusion": "success", "steps": [
+                    { "number": 1, "name": "Run clippy", "conclusion": "success" }
+                ]}
+            ]}),
+        )
+        .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/actions/runs/123/logs"))
+            .respond_with(logs)
+            .mount(&h.server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_should_print_only_failed_step_logs() {
+        let h = TestHarness::new().await;
+        mount_run(&h, ResponseTemplate::new(200).set_body_bytes(log_archive())).await;
+
+        view_args(false, true).run(&h.factory).await.unwrap();
+
+        assert_eq!(
+            h.stdout(),
+            "build\tRun tests\trunning\nbuild\tRun tests\t##[error]test failed\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_print_all_logs_for_selected_job() {
+        let h = TestHarness::new().await;
+        mount_run(&h, ResponseTemplate::new(200).set_body_bytes(log_archive())).await;
+
+        let args = ViewArgs {
+            job: Some("2".into()),
+            ..view_args(true, false)
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert_eq!(h.stdout(), "lint\tRun clippy\tall clean\n");
+    }
+
+    #[tokio::test]
+    async fn test_should_report_expired_logs() {
+        let h = TestHarness::new().await;
+        mount_run(
+            &h,
+            ResponseTemplate::new(410).set_body_json(serde_json::json!({ "message": "Gone" })),
+        )
+        .await;
+
+        let err = view_args(true, false).run(&h.factory).await.unwrap_err();
+
+        assert!(err.to_string().contains("have expired"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn test_should_list_jobs_from_every_page() {
+        use wiremock::matchers::{query_param, query_param_is_missing};
+
+        let h = TestHarness::new().await;
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/actions/runs/123",
+            serde_json::json!({ "name": "CI", "status": "completed", "conclusion": "success" }),
+        )
+        .await;
+        let next = format!(
+            "<{}/repos/owner/repo/actions/runs/123/jobs?per_page=100&page=2>; rel=\"next\"",
+            h.server.uri()
+        );
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/actions/runs/123/jobs"))
+            .and(query_param_is_missing("page"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("link", next.as_str())
+                    .set_body_json(serde_json::json!({
+                        "total_count": 2,
+                        "jobs": [{ "id": 1, "name": "build", "conclusion": "success" }]
+                    })),
+            )
+            .expect(1)
+            .mount(&h.server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/actions/runs/123/jobs"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 2,
+                "jobs": [{ "id": 2, "name": "lint", "conclusion": "success" }]
+            })))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        view_args(false, false).run(&h.factory).await.unwrap();
+
+        let out = h.stdout();
+        assert!(out.contains("build (ID 1)"), "{out}");
+        assert!(out.contains("lint (ID 2)"), "{out}");
+    }
+}
@@ -5,13 +5,14 @@ use clap::Args;
 use serde_json::Value;
 
 use ghc_core::iostreams::{ColorScheme, IOStreams};
-use ghc_core::repo::Repo;
-use ghc_core::{ios_eprintln, ios_println};
+use ghc_core::{ios_eprint, ios_eprintln, ios_println, text};
 
 /// Watch a run until it completes.
 ///
 /// Displays real-time status updates with job-level detail, showing
-/// which jobs are queued, in progress, or completed.
+/// which jobs are queued, in progress, or completed. Updates go to stderr;
+/// on a terminal the job tree is redrawn in place on each poll, otherwise
+/// updates are appended.
 #[derive(Debug, Args)]
 pub struct WatchArgs {
     /// The run ID to watch.
@@ -42,11 +43,7 @@ impl WatchArgs {
     ///
     /// Returns an error if the run cannot be watched.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = self
-            .repo
-            .as_deref()
-            .ok_or_else(|| anyhow::anyhow!("repository argument required (use -R OWNER/REPO)"))?;
-        let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
         let ios = &factory.io;
         let cs = ios.color_scheme();
@@ -65,23 +62,44 @@ impl WatchArgs {
             self.run_id,
         );
 
+        let mut view = LiveView::default();
         loop {
             let run: Value = client
                 .rest(reqwest::Method::GET, &run_path, None)
                 .await
                 .context("failed to fetch run")?;
+            let jobs: Value = client
+                .rest(reqwest::Method::GET, &jobs_path, None)
+                .await
+                .unwrap_or(Value::Null);
 
             let status = run.get("status").and_then(Value::as_str).unwrap_or("");
             let conclusion = run.get("conclusion").and_then(Value::as_str).unwrap_or("");
             let name = run.get("name").and_then(Value::as_str).unwrap_or("");
 
-            // Fetch and display jobs
-            if let Ok(jobs_data) = client
-                .rest::<Value>(reqwest::Method::GET, &jobs_path, None)
-                .await
-            {
-                display_jobs(ios, &cs, &jobs_data, name, self.run_id, self.compact);
+            let mut frame = Vec::new();
+            if status != "completed" {
+                if ios.is_stderr_tty() {
+                    frame.push(format!(
+                        "Refreshing run status every {} seconds. Press Ctrl+C to quit.",
+                        self.interval,
+                    ));
+                    frame.push(String::new());
+                }
+                let status_display = match status {
+                    "in_progress" => cs.warning("in progress"),
+                    "queued" => cs.gray("queued"),
+                    "waiting" => cs.gray("waiting"),
+                    _ => status.to_string(),
+                };
+                frame.push(format!(
+                    "Run {} (#{}) is {status_display}",
+                    cs.bold(name),
+                    self.run_id,
+                ));
             }
+            job_lines(&mut frame, &cs, &jobs, name, self.run_id, self.compact);
+            view.draw(ios, &frame);
 
             if status == "completed" {
                 let result_display = match conclusion {
@@ -90,12 +108,16 @@ impl WatchArgs {
                     "cancelled" => cs.gray("was cancelled"),
                     _ => conclusion.to_string(),
                 };
+                let icon = if conclusion == "success" {
+                    cs.success_icon()
+                } else {
+                    cs.error_icon()
+                };
 
                 ios_println!(ios, "");
                 ios_eprintln!(
                     ios,
-                    "{} Run {} (#{}) {result_display}",
-                    cs.success_icon(),
+                    "{icon} Run {} (#{}) {result_display}",
                     cs.bold(name),
                     self.run_id,
                 );
@@ -107,29 +129,62 @@ impl WatchArgs {
                 return Ok(());
             }
 
-            let status_display = match status {
-                "in_progress" => cs.warning("in progress"),
-                "queued" => cs.gray("queued"),
-                "waiting" => cs.gray("waiting"),
-                _ => status.to_string(),
-            };
-
-            ios_eprintln!(
-                ios,
-                "Run {} (#{}) is {status_display}... (checking again in {}s)",
-                cs.bold(name),
-                self.run_id,
-                self.interval,
-            );
-
             tokio::time::sleep(std::time::Duration::from_secs(self.interval)).await;
         }
     }
 }
 
-/// Display jobs and their status for a workflow run.
-fn display_jobs(
-    ios: &IOStreams,
+/// A block of lines on stderr redrawn in place on each poll.
+///
+/// On a terminal the previous frame is erased with cursor movement before
+/// the next is printed; lines are truncated to the terminal width so each
+/// takes one row. Otherwise frames are appended, skipping unchanged ones.
+#[derive(Debug, Default)]
+struct LiveView {
+    /// Visible widths of the lines currently on screen.
+    drawn: Vec<usize>,
+    /// The last frame drawn, to skip repeats in append-only mode.
+    last_frame: Vec<String>,
+}
+
+impl LiveView {
+    fn draw(&mut self, ios: &IOStreams, frame: &[String]) {
+        if !ios.is_stderr_tty() {
+            if frame != self.last_frame.as_slice() {
+                for line in frame {
+                    ios_eprintln!(ios, "{line}");
+                }
+                self.last_frame = frame.to_vec();
+            }
+            return;
+        }
+
+        // Re-read the width every frame so a resize between polls is handled.
+        let width = ios.stderr_terminal_width().max(2);
+        let rows = rows_occupied(&self.drawn, width);
+        if rows > 0 {
+            ios_eprint!(ios, "\x1b[{rows}A\r\x1b[J");
+        }
+        self.drawn.clear();
+        for line in frame {
+            // Stay clear of the last column to avoid the terminal's pending wrap.
+            let line = text::truncate(line, width - 1);
+            self.drawn.push(text::display_width(&line));
+            ios_eprintln!(ios, "{line}");
+        }
+        self.last_frame = frame.to_vec();
+    }
+}
+
+/// Terminal rows taken by lines of the given visible widths at `width`
+/// columns, counting lines that re-wrapped after the terminal narrowed.
+fn rows_occupied(widths: &[usize], width: usize) -> usize {
+    widths.iter().map(|w| w.div_ceil(width).max(1)).sum()
+}
+
+/// Append the job and step tree for a workflow run to `frame`.
+fn job_lines(
+    frame: &mut Vec<String>,
     cs: &ColorScheme,
     jobs_data: &Value,
     name: &str,
@@ -146,37 +201,30 @@ fn display_jobs(
         return;
     }
 
-    ios_eprintln!(ios, "");
-    ios_eprintln!(ios, "Jobs for {} (#{})", cs.bold(name), run_id);
+    frame.push(String::new());
+    frame.push(format!("Jobs for {} (#{})", cs.bold(name), run_id));
 
     for job in &jobs {
         let job_name = job.get("name").and_then(Value::as_str).unwrap_or("unknown");
         let job_status = job.get("status").and_then(Value::as_str).unwrap_or("");
         let job_conclusion = job.get("conclusion").and_then(Value::as_str).unwrap_or("");
 
-        let status_icon = match (job_status, job_conclusion) {
-            ("completed", "success") => cs.success("v"),
-            ("completed", "failure") => cs.error("X"),
-            ("completed", "cancelled") => cs.gray("-"),
-            ("completed", "skipped") => cs.gray("o"),
-            ("in_progress", _) => cs.warning("*"),
-            ("queued" | "waiting", _) => cs.gray("."),
-            _ => " ".to_string(),
-        };
-
-        ios_eprintln!(ios, "  {status_icon} {job_name}");
+        frame.push(format!(
+            "  {} {job_name}",
+            status_icon(cs, job_status, job_conclusion)
+        ));
 
         // Show steps for in-progress or failed jobs
         if (job_status == "in_progress" || job_conclusion == "failure")
             && let Some(steps) = job.get("steps").and_then(Value::as_array)
         {
-            print_steps(ios, cs, steps, compact);
+            step_lines(frame, cs, steps, compact);
         }
     }
 }
 
-/// Print step-level details for an in-progress job.
-fn print_steps(ios: &IOStreams, cs: &ColorScheme, steps: &[Value], compact: bool) {
+/// Append step-level details for an in-progress or failed job.
+fn step_lines(frame: &mut Vec<String>, cs: &ColorScheme, steps: &[Value], compact: bool) {
     for step in steps {
         let step_name = step
             .get("name")
@@ -190,14 +238,136 @@ fn print_steps(ios: &IOStreams, cs: &ColorScheme, steps: &[Value], compact: bool
             continue;
         }
 
-        let step_icon = match (step_status, step_conclusion) {
-            ("completed", "success") => cs.success("v"),
-            ("completed", "failure") => cs.error("X"),
-            ("completed", "skipped") => cs.gray("o"),
-            ("in_progress", _) => cs.warning("*"),
-            _ => cs.gray("."),
-        };
+        frame.push(format!(
+            "    {} {step_name}",
+            status_icon(cs, step_status, step_conclusion)
+        ));
+    }
+}
+
+/// Icon for a job or step status.
+fn status_icon(cs: &ColorScheme, status: &str, conclusion: &str) -> String {
+    match (status, conclusion) {
+        ("completed", "success") => cs.success_icon(),
+        ("completed", "failure") => cs.error_icon(),
+        ("completed", "cancelled") => cs.gray("-"),
+        ("completed", "skipped") => cs.gray("o"),
+        ("in_progress", _) => cs.warning("*"),
+        _ => cs.gray("."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    use crate::test_helpers::{TestHarness, mock_rest_get};
+
+    fn watch_args(exit_status: bool) -> WatchArgs {
+        WatchArgs {
+            run_id: 9,
+            repo: Some("owner/repo".into()),
+            interval: 0,
+            exit_status,
+            compact: false,
+        }
+    }
+
+    fn job(status: &str, conclusion: Option<&str>) -> Value {
+        serde_json::json!({
+            "jobs": [{
+                "name": "build",
+                "status": status,
+                "conclusion": conclusion,
+                "steps": [
+                    { "name": "Checkout", "status": "completed", "conclusion": "success" },
+                    { "name": "Compile", "status": status, "conclusion": conclusion }
+                ]
+            }]
+        })
+    }
+
+    /// Serve an in-progress run once, then `conclusion` for every later poll.
+    async fn mount_run(h: &TestHarness, conclusion: &str) {
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/actions/runs/9"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "name": "CI", "status": "in_progress", "conclusion": null
+            })))
+            .up_to_n_times(1)
+            .mount(&h.server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/actions/runs/9/jobs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(job("in_progress", None)))
+            .up_to_n_times(1)
+            .mount(&h.server)
+            .await;
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/actions/runs/9",
+            serde_json::json!({ "name": "CI", "status": "completed", "conclusion": conclusion }),
+        )
+        .await;
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/actions/runs/9/jobs",
+            job("completed", Some(conclusion)),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_should_redraw_job_tree_in_place_on_tty() {
+        let mut h = TestHarness::new().await;
+        h.factory.io.set_stderr_tty(true);
+        mount_run(&h, "success").await;
+
+        watch_args(false).run(&h.factory).await.unwrap();
+
+        let out = h.stderr();
+        assert!(
+            out.contains("Refreshing run status every 0 seconds"),
+            "{out}"
+        );
+        // The in-progress frame is 8 lines; the final frame erases it.
+        assert!(out.contains("\x1b[8A\r\x1b[J"), "{out:?}");
+        assert!(out.contains("    * Compile"), "{out}");
+        assert!(out.contains("  ✓ build"), "{out}");
+        assert!(out.contains("Run CI (#9) completed successfully"));
+        assert!(!h.stdout().contains("build"), "{}", h.stdout());
+    }
+
+    #[tokio::test]
+    async fn test_should_append_frames_without_cursor_movement_when_piped() {
+        let h = TestHarness::new().await;
+        mount_run(&h, "success").await;
+
+        watch_args(false).run(&h.factory).await.unwrap();
+
+        let out = h.stderr();
+        assert!(!out.contains('\x1b'), "{out:?}");
+        assert!(out.contains("Run CI (#9) is in progress"), "{out}");
+        assert!(!out.contains("Refreshing"), "{out}");
+    }
+
+    #[tokio::test]
+    async fn test_should_fail_with_exit_status_when_run_fails() {
+        let h = TestHarness::new().await;
+        mount_run(&h, "failure").await;
+
+        let err = watch_args(true).run(&h.factory).await.unwrap_err();
+
+        assert!(err.to_string().contains("run concluded with: failure"));
+        assert!(h.stderr().contains("Run CI (#9) failed"));
+    }
 
-        ios_eprintln!(ios, "    {step_icon} {step_name}");
+    #[test]
+    fn test_should_count_rewrapped_rows_after_resize() {
+        assert_eq!(rows_occupied(&[10, 0, 30], 40), 3);
+        assert_eq!(rows_occupied(&[10, 0, 30], 20), 4);
     }
 }
@@ -6,7 +6,7 @@ use anyhow::{Context, Result};
 use clap::Args;
 use serde_json::Value;
 
-use ghc_core::table::TablePrinter;
+use ghc_core::iostreams::ColorScheme;
 use ghc_core::{ios_eprintln, ios_println};
 
 /// Search for code across GitHub repositories.
@@ -22,7 +22,11 @@ pub struct CodeArgs {
 
     /// Filter by repository (OWNER/REPO).
     #[arg(short = 'R', long)]
-    repo: Option<String>,
+    repo: Vec<String>,
+
+    /// Filter on repository owner.
+    #[arg(long)]
+    owner: Vec<String>,
 
     /// Filter by language.
     #[arg(short, long)]
@@ -48,6 +52,10 @@ pub struct CodeArgs {
     #[arg(short = 't', long)]
     template: Option<String>,
 
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
+
     /// Open results in the browser.
     #[arg(short, long)]
     web: bool,
@@ -62,9 +70,12 @@ impl CodeArgs {
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
         let mut q = self.query.join(" ");
 
-        if let Some(ref repo) = self.repo {
+        for repo in &self.repo {
             let _ = write!(q, " repo:{repo}");
         }
+        for owner in &self.owner {
+            let _ = write!(q, " user:{owner}");
+        }
         if let Some(ref lang) = self.language {
             let _ = write!(q, " language:{lang}");
         }
@@ -82,7 +93,7 @@ impl CodeArgs {
             return Ok(());
         }
 
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
 
         let encoded = ghc_core::text::percent_encode(&q);
@@ -104,16 +115,16 @@ impl CodeArgs {
             .ok_or_else(|| anyhow::anyhow!("unexpected search response format"))?;
 
         // JSON output - use items array, not the raw search response wrapper
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let items_value = Value::Array(items.clone());
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &items_value,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -123,41 +134,113 @@ impl CodeArgs {
         }
 
         let cs = ios.color_scheme();
-        let mut tp = TablePrinter::new(ios);
-
+        let tty = ios.is_stdout_tty();
         for item in items {
             let repo_name = item
                 .pointer("/repository/full_name")
                 .and_then(Value::as_str)
                 .unwrap_or("");
             let file_path = item.get("path").and_then(Value::as_str).unwrap_or("");
+            let lines = matched_lines(item, &cs);
 
-            // Extract matched line content from text_matches
-            let matched_text = item
-                .get("text_matches")
-                .and_then(Value::as_array)
-                .and_then(|matches| {
-                    matches.first().and_then(|m| {
-                        m.get("fragment")
-                            .and_then(Value::as_str)
-                            .map(|f| f.lines().next().unwrap_or("").to_string())
-                    })
-                })
-                .unwrap_or_default();
-
-            tp.add_row(vec![
-                format!("{}:{}", cs.bold(repo_name), file_path),
-                matched_text,
-            ]);
+            if tty {
+                ios_println!(ios, "{} {}", cs.cyan(repo_name), cs.bold(file_path));
+                for line in &lines {
+                    ios_println!(ios, "    {line}");
+                }
+                ios_println!(ios);
+            } else if lines.is_empty() {
+                ios_println!(ios, "{repo_name}:{file_path}");
+            } else {
+                for line in &lines {
+                    ios_println!(ios, "{repo_name}:{file_path}: {line}");
+                }
+            }
         }
 
-        let output = tp.render();
-        ios_println!(ios, "{output}");
-
         Ok(())
     }
 }
 
+/// The fragment lines of a code result that contain a match, with the
+/// matched terms highlighted.
+///
+/// Falls back to the first line of the first fragment when the response
+/// carries no match positions.
+fn matched_lines(item: &Value, cs: &ColorScheme) -> Vec<String> {
+    let text_matches = item
+        .get("text_matches")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    let mut lines = Vec::new();
+    for text_match in text_matches {
+        let Some(fragment) = text_match.get("fragment").and_then(Value::as_str) else {
+            continue;
+        };
+        let ranges = match_ranges(fragment, text_match);
+        let mut offset = 0;
+        for line in fragment.split('\n') {
+            let end = offset + line.len();
+            let in_line: Vec<(usize, usize)> = ranges
+                .iter()
+                .filter(|&&(start, stop)| start < end && stop > offset)
+                .map(|&(start, stop)| (start.max(offset) - offset, stop.min(end) - offset))
+                .collect();
+            if !in_line.is_empty() {
+                lines.push(highlight_line(line, &in_line, cs));
+            }
+            offset = end + 1;
+        }
+    }
+
+    if lines.is_empty()
+        && let Some(first) = text_matches
+            .first()
+            .and_then(|m| m.get("fragment"))
+            .and_then(Value::as_str)
+            .and_then(|f| f.lines().next())
+    {
+        lines.push(first.trim().to_string());
+    }
+    lines
+}
+
+/// Sorted byte ranges of the matched terms within `fragment`.
+fn match_ranges(fragment: &str, text_match: &Value) -> Vec<(usize, usize)> {
+    let mut ranges: Vec<(usize, usize)> = text_match
+        .get("matches")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|m| {
+            let indices = m.get("indices")?.as_array()?;
+            let start = usize::try_from(indices.first()?.as_u64()?).ok()?;
+            let end = usize::try_from(indices.get(1)?.as_u64()?).ok()?;
+            (start < end && fragment.get(start..end).is_some()).then_some((start, end))
+        })
+        .collect();
+    ranges.sort_unstable();
+    ranges
+}
+
+/// Highlight the non-overlapping `ranges` of `line`, trimming indentation.
+fn highlight_line(line: &str, ranges: &[(usize, usize)], cs: &ColorScheme) -> String {
+    let mut out = String::new();
+    let mut pos = 0;
+    for &(start, end) in ranges {
+        if start < pos {
+            continue;
+        }
+        out.push_str(&line[pos..start]);
+        out.push_str(&cs.highlight(&line[start..end]));
+        pos = end;
+    }
+    out.push_str(&line[pos..]);
+    out.trim().to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -167,13 +250,15 @@ mod tests {
         CodeArgs {
             query: vec![query.to_string()],
             limit: 30,
-            repo: None,
+            repo: vec![],
+            owner: vec![],
             language: None,
             filename: None,
             extension: None,
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
             web: false,
         }
     }
@@ -246,4 +331,98 @@ mod tests {
             "should show empty message"
         );
     }
+
+    fn highlighted_response() -> serde_json::Value {
+        serde_json::json!({
+            "total_count": 1,
+            "items": [{
+                "path": "src/main.rs",
+                "repository": { "full_name": "owner/repo" },
+                "text_matches": [{
+                    "fragment": "use std::io;\n\nfn main() {\n    run();",
+                    "matches": [{ "text": "fn main", "indices": [14, 21] }]
+                }]
+            }]
+        })
+    }
+
+    #[tokio::test]
+    async fn test_should_highlight_matched_terms_on_tty() {
+        let mut h = TestHarness::new().await;
+        h.factory.io.set_stdout_tty(true);
+        h.factory.io.set_color_enabled(true);
+        mock_rest_get(&h.server, "/search/code", highlighted_response()).await;
+
+        default_args("fn main").run(&h.factory).await.unwrap();
+
+        let cs = h.factory.io.color_scheme();
+        let out = h.stdout();
+        assert!(
+            out.starts_with(&format!(
+                "{} {}\n",
+                cs.cyan("owner/repo"),
+                cs.bold("src/main.rs")
+            )),
+            "{out:?}"
+        );
+        assert!(
+            out.contains(&format!("    {}() {{\n", cs.highlight("fn main"))),
+            "{out:?}"
+        );
+        assert!(!out.contains("use std::io"), "{out:?}");
+    }
+
+    #[tokio::test]
+    async fn test_should_print_matched_lines_with_path_when_not_tty() {
+        let h = TestHarness::new().await;
+        mock_rest_get(&h.server, "/search/code", highlighted_response()).await;
+
+        default_args("fn main").run(&h.factory).await.unwrap();
+
+        assert_eq!(h.stdout(), "owner/repo:src/main.rs: fn main() {\n");
+    }
+
+    #[tokio::test]
+    async fn test_should_add_qualifiers_to_query() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let h = TestHarness::new().await;
+        Mock::given(method("GET"))
+            .and(path("/search/code"))
+            .and(query_param(
+                "q",
+                "fn main user:rust-lang language:rust extension:rs",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(search_code_response()))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let args = CodeArgs {
+            owner: vec!["rust-lang".into()],
+            language: Some("rust".into()),
+            extension: Some("rs".into()),
+            ..default_args("fn main")
+        };
+        args.run(&h.factory).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_should_include_match_fragments_in_json() {
+        let h = TestHarness::new().await;
+        mock_rest_get(&h.server, "/search/code", highlighted_response()).await;
+
+        let args = CodeArgs {
+            json: vec!["path".into(), "text_matches".into()],
+            ..default_args("fn main")
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let out: Value = serde_json::from_str(&h.stdout()).unwrap();
+        assert_eq!(
+            out.pointer("/0/text_matches/0/matches/0/text"),
+            Some(&serde_json::json!("fn main"))
+        );
+    }
 }
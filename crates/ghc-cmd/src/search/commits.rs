@@ -44,6 +44,10 @@ pub struct CommitsArgs {
     #[arg(short = 't', long)]
     template: Option<String>,
 
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
+
     /// Open results in the browser.
     #[arg(short, long)]
     web: bool,
@@ -75,7 +79,7 @@ impl CommitsArgs {
             return Ok(());
         }
 
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
 
         let encoded = ghc_core::text::percent_encode(&q);
@@ -95,16 +99,16 @@ impl CommitsArgs {
             .ok_or_else(|| anyhow::anyhow!("unexpected search response format"))?;
 
         // JSON output - use items array, not the raw search response wrapper
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let items_value = Value::Array(items.clone());
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &items_value,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -181,6 +185,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
             web: false,
         }
     }
@@ -130,9 +130,9 @@ pub struct IssuesArgs {
     #[arg(long, value_parser = ["comments", "created", "interactions", "reactions", "updated"])]
     sort: Option<String>,
 
-    /// Sort order.
-    #[arg(long, value_parser = ["asc", "desc"], default_value = "desc")]
-    order: String,
+    /// Sort order, `desc` unless given.
+    #[arg(long, value_parser = ["asc", "desc"])]
+    order: Option<String>,
 
     /// Output JSON with specified fields.
     #[arg(long, value_delimiter = ',')]
@@ -146,6 +146,10 @@ pub struct IssuesArgs {
     #[arg(short = 't', long)]
     template: Option<String>,
 
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
+
     /// Open results in the browser.
     #[arg(short, long)]
     web: bool,
@@ -247,36 +251,33 @@ impl IssuesArgs {
             return Ok(());
         }
 
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
 
-        let encoded = ghc_core::text::percent_encode(&q);
-        let mut path = format!("search/issues?q={encoded}&per_page={}", self.limit.min(100));
-        if let Some(ref sort) = self.sort {
-            let _ = write!(path, "&sort={sort}&order={}", self.order);
-        }
-
-        let result: Value = client
-            .rest(reqwest::Method::GET, &path, None)
-            .await
-            .context("failed to search issues")?;
-
-        let items = result
-            .get("items")
-            .and_then(Value::as_array)
-            .ok_or_else(|| anyhow::anyhow!("unexpected search response format"))?;
+        super::warn_sort_conflict(ios, &q, self.sort.as_deref(), self.order.as_deref());
+        let items = super::search_items(
+            &client,
+            ios,
+            "search/issues",
+            &q,
+            self.sort.as_deref(),
+            self.order.as_deref(),
+            self.limit as usize,
+        )
+        .await
+        .context("failed to search issues")?;
 
         // JSON output - use items array, not the raw search response wrapper
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
-            let items_value = Value::Array(items.clone());
-            let output = ghc_core::json::format_json_output(
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
+            let items_value = Value::Array(items);
+            ghc_core::json::output(
                 &items_value,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -288,7 +289,7 @@ impl IssuesArgs {
         let cs = ios.color_scheme();
         let mut tp = TablePrinter::new(ios);
 
-        for item in items {
+        for item in &items {
             let number = item.get("number").and_then(Value::as_u64).unwrap_or(0);
             let title = item.get("title").and_then(Value::as_str).unwrap_or("");
             let state = item.get("state").and_then(Value::as_str).unwrap_or("");
@@ -375,10 +376,11 @@ mod tests {
             updated: None,
             owner: vec![],
             sort: None,
-            order: "desc".to_string(),
+            order: None,
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
             web: false,
         }
     }
@@ -448,4 +450,94 @@ mod tests {
             "should show empty message"
         );
     }
+
+    #[tokio::test]
+    async fn test_should_warn_once_when_limit_exceeds_search_cap() {
+        let h = TestHarness::new().await;
+        mock_rest_get(
+            &h.server,
+            "/search/issues",
+            serde_json::json!({ "total_count": 0, "items": [] }),
+        )
+        .await;
+
+        let args = IssuesArgs {
+            limit: 1500,
+            ..default_args("bug")
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let err = h.stderr();
+        assert_eq!(
+            err.matches("! warning: the Search API is capped at 1000 results maximum")
+                .count(),
+            1,
+            "{err}"
+        );
+    }
+
+    fn issue_page(numbers: std::ops::Range<u64>) -> Vec<serde_json::Value> {
+        numbers
+            .map(|n| serde_json::json!({ "number": n, "title": format!("Issue {n}") }))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_should_pass_sort_and_order_to_search_api() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let h = TestHarness::new().await;
+        Mock::given(method("GET"))
+            .and(path("/search/issues"))
+            .and(query_param("sort", "reactions"))
+            .and(query_param("order", "desc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(search_issues_response()))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let args = IssuesArgs {
+            sort: Some("reactions".into()),
+            order: Some("desc".into()),
+            ..default_args("label:bug")
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert!(h.stdout().contains("Found Issue"));
+        assert!(h.stderr().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_should_page_through_results_up_to_limit() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let h = TestHarness::new().await;
+        for (page, numbers) in [("1", 1..101), ("2", 101..201)] {
+            Mock::given(method("GET"))
+                .and(path("/search/issues"))
+                .and(query_param("per_page", "100"))
+                .and(query_param("page", page))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "total_count": 500,
+                    "items": issue_page(numbers),
+                })))
+                .expect(1)
+                .mount(&h.server)
+                .await;
+        }
+
+        let args = IssuesArgs {
+            limit: 150,
+            json: vec!["number".into()],
+            ..default_args("bug")
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let out: Value = serde_json::from_str(&h.stdout()).unwrap();
+        let numbers = out.as_array().unwrap();
+        assert_eq!(numbers.len(), 150);
+        assert_eq!(numbers[149], serde_json::json!({ "number": 150 }));
+    }
 }
@@ -8,7 +8,20 @@ pub mod issues;
 pub mod prs;
 pub mod repos;
 
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
 use clap::Subcommand;
+use serde_json::Value;
+
+use ghc_core::ios_eprintln;
+use ghc_core::iostreams::IOStreams;
+
+/// Largest page size the search API accepts.
+const SEARCH_PAGE_SIZE: usize = 100;
+
+/// Number of results the search API returns for any single query.
+const SEARCH_RESULT_CAP: usize = 1000;
 
 /// Search across GitHub.
 #[derive(Debug, Subcommand)]
@@ -41,3 +54,89 @@ impl SearchCommand {
         }
     }
 }
+
+/// Fetch up to `limit` results from a search endpoint such as `search/issues`.
+///
+/// Pages through the results 100 at a time, stopping early once the
+/// reported total or the search API's 1000-result cap is reached. Warns
+/// when `limit` asks for more than the cap allows.
+///
+/// # Errors
+///
+/// Returns an error if a request fails or the response has no `items`.
+pub(crate) async fn search_items(
+    client: &ghc_api::client::Client,
+    ios: &IOStreams,
+    endpoint: &str,
+    query: &str,
+    sort: Option<&str>,
+    order: Option<&str>,
+    limit: usize,
+) -> Result<Vec<Value>> {
+    if limit > SEARCH_RESULT_CAP {
+        ios_eprintln!(
+            ios,
+            "! warning: the Search API is capped at {SEARCH_RESULT_CAP} results maximum"
+        );
+    }
+    let limit = limit.min(SEARCH_RESULT_CAP);
+    let per_page = limit.clamp(1, SEARCH_PAGE_SIZE);
+    let encoded = ghc_core::text::percent_encode(query);
+    let mut items = Vec::new();
+
+    for page in 1.. {
+        let mut path = format!("{endpoint}?q={encoded}&per_page={per_page}&page={page}");
+        if let Some(sort) = sort {
+            let _ = write!(path, "&sort={sort}&order={}", order.unwrap_or("desc"));
+        }
+        let result: Value = client
+            .rest(reqwest::Method::GET, &path, None)
+            .await
+            .with_context(|| format!("failed to query {endpoint}"))?;
+        let page_items = result
+            .get("items")
+            .and_then(Value::as_array)
+            .ok_or_else(|| anyhow::anyhow!("unexpected search response format"))?;
+        let total = result
+            .get("total_count")
+            .and_then(Value::as_u64)
+            .and_then(|n| usize::try_from(n).ok())
+            .unwrap_or(usize::MAX);
+
+        let fetched = page_items.len();
+        items.extend(page_items.iter().take(limit - items.len()).cloned());
+        if items.len() >= limit || items.len() >= total || fetched < per_page {
+            break;
+        }
+    }
+
+    Ok(items)
+}
+
+/// Warn about `--sort`/`--order` combinations that do not do what they say.
+///
+/// `--order` is only sent along with `--sort`, so on its own it has no
+/// effect. `--sort` replaces whatever ordering a `sort:` qualifier in the
+/// query asked for.
+pub(crate) fn warn_sort_conflict(
+    ios: &IOStreams,
+    query: &str,
+    sort: Option<&str>,
+    order: Option<&str>,
+) {
+    if sort.is_none() {
+        if order.is_some() {
+            ios_eprintln!(ios, "! warning: --order has no effect without --sort");
+        }
+        return;
+    }
+    let has_qualifier = query
+        .split_whitespace()
+        .any(|term| term.to_ascii_lowercase().starts_with("sort:"));
+    if has_qualifier {
+        ios_eprintln!(
+            ios,
+            "! warning: --sort and --order override the sort: qualifier in the query"
+        );
+    }
+}
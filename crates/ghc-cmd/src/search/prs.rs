@@ -142,9 +142,9 @@ pub struct PrsArgs {
     #[arg(long, value_parser = ["comments", "created", "interactions", "reactions", "updated"])]
     sort: Option<String>,
 
-    /// Sort order.
-    #[arg(long, value_parser = ["asc", "desc"], default_value = "desc")]
-    order: String,
+    /// Sort order, `desc` unless given.
+    #[arg(long, value_parser = ["asc", "desc"])]
+    order: Option<String>,
 
     /// Output JSON with specified fields.
     #[arg(long, value_delimiter = ',')]
@@ -158,6 +158,10 @@ pub struct PrsArgs {
     #[arg(short = 't', long)]
     template: Option<String>,
 
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
+
     /// Open results in the browser.
     #[arg(short, long)]
     web: bool,
@@ -273,36 +277,33 @@ impl PrsArgs {
             return Ok(());
         }
 
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
 
-        let encoded = ghc_core::text::percent_encode(&q);
-        let mut path = format!("search/issues?q={encoded}&per_page={}", self.limit.min(100));
-        if let Some(ref sort) = self.sort {
-            let _ = write!(path, "&sort={sort}&order={}", self.order);
-        }
-
-        let result: Value = client
-            .rest(reqwest::Method::GET, &path, None)
-            .await
-            .context("failed to search pull requests")?;
-
-        let items = result
-            .get("items")
-            .and_then(Value::as_array)
-            .ok_or_else(|| anyhow::anyhow!("unexpected search response format"))?;
+        super::warn_sort_conflict(ios, &q, self.sort.as_deref(), self.order.as_deref());
+        let items = super::search_items(
+            &client,
+            ios,
+            "search/issues",
+            &q,
+            self.sort.as_deref(),
+            self.order.as_deref(),
+            self.limit as usize,
+        )
+        .await
+        .context("failed to search pull requests")?;
 
         // JSON output - use items array, not the raw search response wrapper
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
-            let items_value = Value::Array(items.clone());
-            let output = ghc_core::json::format_json_output(
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
+            let items_value = Value::Array(items);
+            ghc_core::json::output(
                 &items_value,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -314,7 +315,7 @@ impl PrsArgs {
         let cs = ios.color_scheme();
         let mut tp = TablePrinter::new(ios);
 
-        for item in items {
+        for item in &items {
             let number = item.get("number").and_then(Value::as_u64).unwrap_or(0);
             let title = item.get("title").and_then(Value::as_str).unwrap_or("");
             let state = item.get("state").and_then(Value::as_str).unwrap_or("");
@@ -411,10 +412,11 @@ mod tests {
             merged_at: None,
             merged: false,
             sort: None,
-            order: "desc".to_string(),
+            order: None,
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
             web: false,
         }
     }
@@ -483,4 +485,53 @@ mod tests {
             "should show empty message"
         );
     }
+
+    #[tokio::test]
+    async fn test_should_warn_when_sort_conflicts_with_sort_qualifier() {
+        let h = TestHarness::new().await;
+        mock_rest_get(
+            &h.server,
+            "/search/issues",
+            serde_json::json!({ "total_count": 0, "items": [] }),
+        )
+        .await;
+
+        let args = PrsArgs {
+            sort: Some("created".into()),
+            order: Some("asc".into()),
+            ..default_args("sort:updated-desc fix")
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert!(
+            h.stderr()
+                .contains("--sort and --order override the sort: qualifier"),
+            "{}",
+            h.stderr()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_warn_that_order_needs_sort() {
+        let h = TestHarness::new().await;
+        mock_rest_get(
+            &h.server,
+            "/search/issues",
+            serde_json::json!({ "total_count": 0, "items": [] }),
+        )
+        .await;
+
+        let args = PrsArgs {
+            order: Some("asc".into()),
+            ..default_args("sort:updated-desc fix")
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let stderr = h.stderr();
+        assert!(
+            stderr.contains("! warning: --order has no effect without --sort"),
+            "{stderr}"
+        );
+        assert!(!stderr.contains("override the sort: qualifier"), "{stderr}");
+    }
 }
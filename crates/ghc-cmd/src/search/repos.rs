@@ -13,8 +13,8 @@ use ghc_core::{ios_eprintln, ios_println};
 /// Search for repositories across GitHub.
 #[derive(Debug, Args)]
 pub struct ReposArgs {
-    /// Search query.
-    #[arg(value_name = "QUERY", required = true)]
+    /// Search query. May be omitted when qualifier flags are given.
+    #[arg(value_name = "QUERY")]
     query: Vec<String>,
 
     /// Maximum number of results.
@@ -37,14 +37,23 @@ pub struct ReposArgs {
     #[arg(long, value_parser = ["stars", "forks", "help-wanted-issues", "updated"])]
     sort: Option<String>,
 
-    /// Sort order.
-    #[arg(long, value_parser = ["asc", "desc"], default_value = "desc")]
-    order: String,
+    /// Sort order, `desc` unless given.
+    #[arg(long, value_parser = ["asc", "desc"])]
+    order: Option<String>,
 
     /// Filter on repository owner.
     #[arg(long)]
     owner: Vec<String>,
 
+    /// Filter based on archived state.
+    #[arg(
+        long,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true"
+    )]
+    archived: Option<bool>,
+
     /// Filter based on created at date.
     #[arg(long)]
     created: Option<String>,
@@ -105,6 +114,10 @@ pub struct ReposArgs {
     #[arg(short = 't', long)]
     template: Option<String>,
 
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
+
     /// Open results in the browser.
     #[arg(short, long)]
     web: bool,
@@ -132,6 +145,9 @@ impl ReposArgs {
         for owner in &self.owner {
             let _ = write!(q, " user:{owner}");
         }
+        if let Some(archived) = self.archived {
+            let _ = write!(q, " archived:{archived}");
+        }
         if let Some(ref created) = self.created {
             let _ = write!(q, " created:{created}");
         }
@@ -169,6 +185,11 @@ impl ReposArgs {
             let _ = write!(q, " topics:{nt}");
         }
 
+        let q = q.trim().to_string();
+        if q.is_empty() {
+            anyhow::bail!("specify search keywords or flags");
+        }
+
         if self.web {
             let encoded = ghc_core::text::percent_encode(&q);
             let url = format!("https://github.com/search?q={encoded}&type=repositories");
@@ -176,39 +197,33 @@ impl ReposArgs {
             return Ok(());
         }
 
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
 
-        let encoded = ghc_core::text::percent_encode(&q);
-        let mut path = format!(
-            "search/repositories?q={encoded}&per_page={}",
-            self.limit.min(100),
-        );
-        if let Some(ref sort) = self.sort {
-            let _ = write!(path, "&sort={sort}&order={}", self.order);
-        }
-
-        let result: Value = client
-            .rest(reqwest::Method::GET, &path, None)
-            .await
-            .context("failed to search repositories")?;
-
-        let items = result
-            .get("items")
-            .and_then(Value::as_array)
-            .ok_or_else(|| anyhow::anyhow!("unexpected search response format"))?;
+        super::warn_sort_conflict(ios, &q, self.sort.as_deref(), self.order.as_deref());
+        let items = super::search_items(
+            &client,
+            ios,
+            "search/repositories",
+            &q,
+            self.sort.as_deref(),
+            self.order.as_deref(),
+            self.limit as usize,
+        )
+        .await
+        .context("failed to search repositories")?;
 
         // JSON output - use items array, not the raw search response wrapper
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
-            let items_value = Value::Array(items.clone());
-            let output = ghc_core::json::format_json_output(
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
+            let items_value = Value::Array(items);
+            ghc_core::json::output(
                 &items_value,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -220,7 +235,7 @@ impl ReposArgs {
         let cs = ios.color_scheme();
         let mut tp = TablePrinter::new(ios);
 
-        for item in items {
+        for item in &items {
             let full_name = item.get("full_name").and_then(Value::as_str).unwrap_or("");
             let description = item
                 .get("description")
@@ -273,8 +288,9 @@ mod tests {
             topic: vec![],
             visibility: None,
             sort: None,
-            order: "desc".to_string(),
+            order: None,
             owner: vec![],
+            archived: None,
             created: None,
             followers: None,
             include_forks: None,
@@ -290,6 +306,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
             web: false,
         }
     }
@@ -356,4 +373,62 @@ mod tests {
             "should show empty message"
         );
     }
+
+    #[tokio::test]
+    async fn test_should_search_by_qualifiers_alone_and_project_json() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let h = TestHarness::new().await;
+        Mock::given(method("GET"))
+            .and(path("/search/repositories"))
+            .and(query_param("q", "topic:cli archived:false stars:>500"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(search_repos_response()))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let args = ReposArgs {
+            topic: vec!["cli".into()],
+            archived: Some(false),
+            stars: Some(">500".into()),
+            json: vec!["fullName".into(), "stargazersCount".into()],
+            ..default_args("")
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let out: Value = serde_json::from_str(&h.stdout()).unwrap();
+        assert_eq!(
+            out,
+            serde_json::json!([{ "fullName": "owner/my-repo", "stargazersCount": 100 }])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_require_keywords_or_flags() {
+        let h = TestHarness::new().await;
+        let args = ReposArgs {
+            query: vec![],
+            ..default_args("")
+        };
+        let err = args.run(&h.factory).await.unwrap_err();
+        assert_eq!(err.to_string(), "specify search keywords or flags");
+    }
+
+    #[test]
+    fn test_should_parse_archived_flag() {
+        #[derive(clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: ReposArgs,
+        }
+        use clap::Parser;
+
+        let cli = Cli::try_parse_from(["repos", "--archived"]).unwrap();
+        assert_eq!(cli.args.archived, Some(true));
+        let cli = Cli::try_parse_from(["repos", "--archived=false"]).unwrap();
+        assert_eq!(cli.args.archived, Some(false));
+        let cli = Cli::try_parse_from(["repos", "cli"]).unwrap();
+        assert_eq!(cli.args.archived, None);
+    }
 }
@@ -4,7 +4,6 @@ use anyhow::{Context, Result};
 use clap::Args;
 
 use ghc_core::ios_eprintln;
-use ghc_core::repo::Repo;
 
 /// Delete a secret.
 #[derive(Debug, Args)]
@@ -47,7 +46,7 @@ impl DeleteArgs {
             anyhow::bail!("specify only one of `--org`, `--env`, or `--user`");
         }
 
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
 
         let app = if let Some(ref a) = self.app {
             a.as_str()
@@ -62,11 +61,7 @@ impl DeleteArgs {
         } else if self.user {
             format!("user/codespaces/secrets/{}", self.name)
         } else if let Some(ref env) = self.env {
-            let repo = self
-                .repo
-                .as_deref()
-                .ok_or_else(|| anyhow::anyhow!("repository required for environment secrets"))?;
-            let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+            let repo = factory.resolve_repo(self.repo.as_deref()).await?;
             format!(
                 "repos/{}/{}/environments/{env}/secrets/{}",
                 repo.owner(),
@@ -74,10 +69,7 @@ impl DeleteArgs {
                 self.name,
             )
         } else {
-            let repo = self.repo.as_deref().ok_or_else(|| {
-                anyhow::anyhow!("repository argument required (use -R OWNER/REPO)")
-            })?;
-            let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+            let repo = factory.resolve_repo(self.repo.as_deref()).await?;
             format!(
                 "repos/{}/{}/{app}/secrets/{}",
                 repo.owner(),
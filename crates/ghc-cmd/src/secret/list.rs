@@ -4,7 +4,6 @@ use anyhow::{Context, Result};
 use clap::Args;
 use serde_json::Value;
 
-use ghc_core::repo::Repo;
 use ghc_core::table::TablePrinter;
 use ghc_core::{ios_eprintln, ios_println};
 
@@ -42,6 +41,10 @@ pub struct ListArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl ListArgs {
@@ -57,7 +60,7 @@ impl ListArgs {
             anyhow::bail!("specify only one of `--org`, `--env`, or `--user`");
         }
 
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
 
         let app = if let Some(ref a) = self.app {
@@ -73,21 +76,14 @@ impl ListArgs {
         } else if self.user {
             "user/codespaces/secrets".to_string()
         } else if let Some(ref env) = self.env {
-            let repo = self
-                .repo
-                .as_deref()
-                .ok_or_else(|| anyhow::anyhow!("repository required for environment secrets"))?;
-            let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+            let repo = factory.resolve_repo(self.repo.as_deref()).await?;
             format!(
                 "repos/{}/{}/environments/{env}/secrets",
                 repo.owner(),
                 repo.name(),
             )
         } else {
-            let repo = self.repo.as_deref().ok_or_else(|| {
-                anyhow::anyhow!("repository argument required (use -R OWNER/REPO)")
-            })?;
-            let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+            let repo = factory.resolve_repo(self.repo.as_deref()).await?;
             format!("repos/{}/{}/{app}/secrets", repo.owner(), repo.name(),)
         };
 
@@ -103,17 +99,17 @@ impl ListArgs {
             .unwrap_or(Value::Array(vec![]));
 
         // JSON output
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let mut items_owned = items.clone();
             normalize_secret_fields(&mut items_owned);
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &items_owned,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -203,6 +199,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -235,6 +232,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -124,6 +124,17 @@ impl SetArgs {
         if self.user && self.no_repos_selected {
             anyhow::bail!("`--no-repos-selected` must be omitted when used with `--user`");
         }
+        if self.visibility.is_some() && self.org.is_none() {
+            anyhow::bail!("`--visibility` is only supported with `--org`");
+        }
+        if (!self.repos.is_empty() || self.no_repos_selected) && self.org.is_none() && !self.user {
+            anyhow::bail!("`--repos` is only supported with `--org` or `--user`");
+        }
+        if (!self.repos.is_empty() || self.no_repos_selected)
+            && self.visibility.as_deref().is_some_and(|v| v != "selected")
+        {
+            anyhow::bail!("`--repos` is only supported with `--visibility=selected`");
+        }
         Ok(())
     }
 
@@ -138,77 +149,124 @@ impl SetArgs {
         }
     }
 
-    /// Resolve visibility, auto-setting to "selected" when --repos or --no-repos-selected is used.
+    /// Resolve visibility, auto-setting to "selected" when --repos or --no-repos-selected is used
+    /// and defaulting org secrets to "private".
     fn resolve_visibility(&self) -> Option<String> {
         if let Some(ref vis) = self.visibility {
             Some(vis.clone())
         } else if !self.repos.is_empty() || self.no_repos_selected {
             Some("selected".to_string())
+        } else if self.org.is_some() {
+            Some("private".to_string())
         } else {
             None
         }
     }
 
     /// Set a single secret.
-    #[allow(clippy::too_many_lines)]
     async fn set_single_secret(
         &self,
         factory: &crate::factory::Factory,
         name: &str,
         secret_value: &str,
     ) -> Result<()> {
-        let client = factory.api_client("github.com")?;
-        let app = self.resolve_app();
+        let client = factory.http_client()?;
+        let dest = self.destination(factory, &client).await?;
+        let key = self.fetch_public_key(&client, &dest).await?;
 
-        // Get the public key for encryption
-        let key_path = if let Some(ref org) = self.org {
-            format!("orgs/{org}/{app}/secrets/public-key")
-        } else if self.user {
-            "user/codespaces/secrets/public-key".to_string()
-        } else if let Some(ref env) = self.env {
-            let repo = self.resolve_repo()?;
-            format!(
-                "repos/{}/{}/environments/{env}/secrets/public-key",
-                repo.owner(),
-                repo.name(),
-            )
-        } else {
-            let repo = self.resolve_repo()?;
-            format!(
-                "repos/{}/{}/{app}/secrets/public-key",
-                repo.owner(),
-                repo.name(),
-            )
-        };
+        // --no-store: print encrypted value and return
+        if self.no_store {
+            let encrypted =
+                encrypt_secret(&key.key, secret_value).context("failed to encrypt secret")?;
+            let ios = &factory.io;
+            ghc_core::ios_println!(ios, "{encrypted}");
+            return Ok(());
+        }
 
+        self.store_secret(factory, &client, &key, &dest, name, secret_value)
+            .await
+    }
+
+    /// Fetch the public key that secret values are encrypted with.
+    async fn fetch_public_key(
+        &self,
+        client: &ghc_api::client::Client,
+        dest: &Destination,
+    ) -> Result<PublicKey> {
+        let key_path = format!("{}/secrets/public-key", dest.scope);
         let key_data: Value = client
             .rest(reqwest::Method::GET, &key_path, None)
             .await
             .context("failed to get public key")?;
 
-        let key_id = key_data
+        let id = key_data
             .get("key_id")
             .and_then(Value::as_str)
             .ok_or_else(|| anyhow::anyhow!("failed to get key_id from public key response"))?;
 
-        let public_key = key_data
+        let key = key_data
             .get("key")
             .and_then(Value::as_str)
             .ok_or_else(|| anyhow::anyhow!("failed to get key from public key response"))?;
 
-        let encrypted =
-            encrypt_secret(public_key, secret_value).context("failed to encrypt secret")?;
+        Ok(PublicKey {
+            id: id.to_string(),
+            key: key.to_string(),
+        })
+    }
 
-        // --no-store: print encrypted value and return
-        if self.no_store {
-            let ios = &factory.io;
-            ghc_core::ios_println!(ios, "{encrypted}");
-            return Ok(());
-        }
+    /// Resolve where secrets go, including `--repos` as repository IDs.
+    ///
+    /// Repository and environment secrets fall back to the current
+    /// repository when `-R` is not given.
+    async fn destination(
+        &self,
+        factory: &crate::factory::Factory,
+        client: &ghc_api::client::Client,
+    ) -> Result<Destination> {
+        let app = self.resolve_app();
+        let (scope, target) = if let Some(ref org) = self.org {
+            (format!("orgs/{org}/{app}"), org.clone())
+        } else if self.user {
+            ("user/codespaces".to_string(), "your user".to_string())
+        } else {
+            let repo = factory.resolve_repo(self.repo.as_deref()).await?;
+            let base = format!("repos/{}/{}", repo.owner(), repo.name());
+            let scope = match self.env {
+                Some(ref env) => format!("{base}/environments/{env}"),
+                None => format!("{base}/{app}"),
+            };
+            (scope, repo.full_name())
+        };
+        let repo_ids = if self.repos.is_empty() {
+            None
+        } else {
+            Some(resolve_repo_ids(client, self.org.as_deref().unwrap_or(""), &self.repos).await?)
+        };
+        Ok(Destination {
+            scope,
+            target,
+            repo_ids,
+        })
+    }
+
+    /// Encrypt `secret_value` with `key` and store it as secret `name`.
+    async fn store_secret(
+        &self,
+        factory: &crate::factory::Factory,
+        client: &ghc_api::client::Client,
+        key: &PublicKey,
+        dest: &Destination,
+        name: &str,
+        secret_value: &str,
+    ) -> Result<()> {
+        let app = self.resolve_app();
+        let encrypted =
+            encrypt_secret(&key.key, secret_value).context("failed to encrypt secret")?;
 
         let mut body = serde_json::json!({
             "encrypted_value": encrypted,
-            "key_id": key_id,
+            "key_id": key.id,
         });
 
         // Add visibility for org/user secrets
@@ -218,115 +276,89 @@ impl SetArgs {
             body["visibility"] = Value::String(vis);
         }
 
-        // Resolve repository IDs for --repos
-        if !self.repos.is_empty() {
-            let repo_ids =
-                resolve_repo_ids(&client, self.org.as_deref().unwrap_or(""), &self.repos).await?;
+        if let Some(ref repo_ids) = dest.repo_ids {
             body["selected_repository_ids"] = Value::Array(
                 repo_ids
-                    .into_iter()
-                    .map(|id| Value::Number(serde_json::Number::from(id)))
+                    .iter()
+                    .map(|&id| Value::Number(serde_json::Number::from(id)))
                     .collect(),
             );
         }
 
-        let secret_path = if let Some(ref org) = self.org {
-            format!("orgs/{org}/{app}/secrets/{name}")
-        } else if self.user {
-            format!("user/codespaces/secrets/{name}")
-        } else if let Some(ref env) = self.env {
-            let repo = self.resolve_repo()?;
-            format!(
-                "repos/{}/{}/environments/{env}/secrets/{name}",
-                repo.owner(),
-                repo.name(),
-            )
-        } else {
-            let repo = self.resolve_repo()?;
-            format!(
-                "repos/{}/{}/{app}/secrets/{name}",
-                repo.owner(),
-                repo.name(),
-            )
-        };
+        let secret_path = format!("{}/secrets/{name}", dest.scope);
 
         client
             .rest_text(reqwest::Method::PUT, &secret_path, Some(&body))
             .await
-            .context("failed to set secret")?;
+            .with_context(|| format!("failed to set secret {name}"))?;
 
         let ios = &factory.io;
         let cs = ios.color_scheme();
 
         let app_title = capitalize(app);
-        let target = if self.user {
-            "your user".to_string()
-        } else if let Some(ref org) = self.org {
-            org.clone()
-        } else {
-            self.repo
-                .clone()
-                .unwrap_or_else(|| "repository".to_string())
-        };
-
         ios_eprintln!(
             ios,
-            "{} Set {app_title} secret {} for {target}",
+            "{} Set {app_title} secret {} for {}",
             cs.success_icon(),
             cs.bold(name),
+            dest.target,
         );
 
         Ok(())
     }
 
-    /// Resolve repo from --repo flag.
-    fn resolve_repo(&self) -> Result<Repo> {
-        let name = self
-            .repo
-            .as_deref()
-            .ok_or_else(|| anyhow::anyhow!("repository argument required (use -R OWNER/REPO)"))?;
-        Repo::from_full_name(name).context("invalid repository format")
-    }
-
     /// Batch set secrets from a .env file.
+    ///
+    /// The whole file is parsed before anything is sent, so a malformed line
+    /// leaves every secret untouched.
     async fn run_batch(&self, factory: &crate::factory::Factory, env_file: &str) -> Result<()> {
         let content = std::fs::read_to_string(env_file)
             .with_context(|| format!("failed to read env file: {env_file}"))?;
+        let entries = ghc_core::cmdutil::parse_env_file(&content)
+            .with_context(|| format!("invalid env file: {env_file}"))?;
+        if entries.is_empty() {
+            anyhow::bail!("no secrets found in {env_file}");
+        }
 
-        let mut count = 0;
-        for line in content.lines() {
-            let line = line.trim();
-
-            // Skip empty lines and comments
-            if line.is_empty() || line.starts_with('#') {
-                continue;
-            }
-
-            if let Some((key, value)) = line.split_once('=') {
-                let key = key.trim();
-                let value = value.trim().trim_matches('"').trim_matches('\'');
-
-                if key.is_empty() {
-                    continue;
-                }
-
-                self.set_single_secret(factory, key, value).await?;
-                count += 1;
-            }
+        let client = factory.http_client()?;
+        let dest = self.destination(factory, &client).await?;
+        let key = self.fetch_public_key(&client, &dest).await?;
+        for (name, value) in &entries {
+            self.store_secret(factory, &client, &key, &dest, name, value)
+                .await?;
         }
 
         let ios = &factory.io;
         let cs = ios.color_scheme();
         ios_eprintln!(
             ios,
-            "{} Set {count} secret(s) from {env_file}",
+            "{} Set {} secret(s) from {env_file}",
             cs.success_icon(),
+            entries.len(),
         );
 
         Ok(())
     }
 }
 
+/// Where secrets are stored.
+#[derive(Debug)]
+struct Destination {
+    /// API path prefix for the org, user, environment, or repository.
+    scope: String,
+    /// The owner of the secrets, as named in messages.
+    target: String,
+    /// IDs of the repositories selected with `--repos`.
+    repo_ids: Option<Vec<i64>>,
+}
+
+/// A public key secret values are sealed with before upload.
+#[derive(Debug)]
+struct PublicKey {
+    id: String,
+    key: String,
+}
+
 /// Resolve repository names to IDs for org/user secrets with selected visibility.
 async fn resolve_repo_ids(
     client: &ghc_api::client::Client,
@@ -394,6 +426,296 @@ fn encrypt_secret(public_key_b64: &str, secret: &str) -> Result<String> {
 mod tests {
     use super::*;
 
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    use crate::test_helpers::{TestHarness, mock_rest_get};
+
+    fn batch_args(env_file: &std::path::Path) -> SetArgs {
+        SetArgs {
+            name: None,
+            repo: Some("owner/repo".into()),
+            org: None,
+            env: None,
+            user: false,
+            body: None,
+            env_file: Some(env_file.display().to_string()),
+            visibility: None,
+            repos: vec![],
+            no_repos_selected: false,
+            no_store: false,
+            app: None,
+        }
+    }
+
+    async fn mount_public_key(h: &TestHarness) {
+        let secret_key = crypto_box::SecretKey::generate(&mut crypto_box::aead::OsRng);
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/actions/secrets/public-key",
+            serde_json::json!({
+                "key_id": "k1",
+                "key": ghc_core::text::base64_encode(secret_key.public_key().as_bytes())
+            }),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_should_set_org_secret_for_selected_repos() {
+        let h = TestHarness::new().await;
+        mock_rest_get(
+            &h.server,
+            "/orgs/acme/actions/secrets/public-key",
+            serde_json::json!({
+                "key_id": "k1",
+                "key": ghc_core::text::base64_encode(&key_bytes(KNOWN_PUBLIC_KEY))
+            }),
+        )
+        .await;
+        mock_rest_get(
+            &h.server,
+            "/repos/acme/web",
+            serde_json::json!({ "id": 11 }),
+        )
+        .await;
+        mock_rest_get(
+            &h.server,
+            "/repos/acme/api",
+            serde_json::json!({ "id": 12 }),
+        )
+        .await;
+        Mock::given(method("PUT"))
+            .and(path("/orgs/acme/actions/secrets/DEPLOY_KEY"))
+            .and(body_partial_json(serde_json::json!({
+                "key_id": "k1",
+                "visibility": "selected",
+                "selected_repository_ids": [11, 12]
+            })))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let args = SetArgs {
+            name: Some("DEPLOY_KEY".into()),
+            repo: None,
+            org: Some("acme".into()),
+            body: Some("value".into()),
+            env_file: None,
+            visibility: Some("selected".into()),
+            repos: vec!["web".into(), "api".into()],
+            ..batch_args(std::path::Path::new(""))
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert!(
+            h.stderr()
+                .contains("Set Actions secret DEPLOY_KEY for acme")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_default_org_secret_to_private_visibility() {
+        let h = TestHarness::new().await;
+        mock_rest_get(
+            &h.server,
+            "/orgs/acme/actions/secrets/public-key",
+            serde_json::json!({
+                "key_id": "k1",
+                "key": ghc_core::text::base64_encode(&key_bytes(KNOWN_PUBLIC_KEY))
+            }),
+        )
+        .await;
+        Mock::given(method("PUT"))
+            .and(path("/orgs/acme/actions/secrets/DEPLOY_KEY"))
+            .and(body_partial_json(serde_json::json!({
+                "key_id": "k1",
+                "visibility": "private"
+            })))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let args = SetArgs {
+            name: Some("DEPLOY_KEY".into()),
+            repo: None,
+            org: Some("acme".into()),
+            body: Some("value".into()),
+            env_file: None,
+            ..batch_args(std::path::Path::new(""))
+        };
+        args.run(&h.factory).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_should_set_environment_secret() {
+        let h = TestHarness::new().await;
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/environments/production/secrets/public-key",
+            serde_json::json!({
+                "key_id": "k1",
+                "key": ghc_core::text::base64_encode(&key_bytes(KNOWN_PUBLIC_KEY))
+            }),
+        )
+        .await;
+        Mock::given(method("PUT"))
+            .and(path(
+                "/repos/owner/repo/environments/production/secrets/DEPLOY_KEY",
+            ))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let args = SetArgs {
+            name: Some("DEPLOY_KEY".into()),
+            env: Some("production".into()),
+            body: Some("value".into()),
+            env_file: None,
+            ..batch_args(std::path::Path::new(""))
+        };
+        args.run(&h.factory).await.unwrap();
+    }
+
+    #[test]
+    fn test_should_reject_repos_without_org() {
+        let args = SetArgs {
+            name: Some("DEPLOY_KEY".into()),
+            body: Some("value".into()),
+            env_file: None,
+            repos: vec!["web".into()],
+            ..batch_args(std::path::Path::new(""))
+        };
+        let err = args.validate().unwrap_err();
+        assert!(err.to_string().contains("only supported with `--org`"));
+
+        let args = SetArgs {
+            org: Some("acme".into()),
+            visibility: Some("all".into()),
+            ..args
+        };
+        let err = args.validate().unwrap_err();
+        assert!(err.to_string().contains("--visibility=selected"));
+    }
+
+    #[tokio::test]
+    async fn test_should_set_secrets_from_env_file() {
+        let h = TestHarness::new().await;
+        mount_public_key(&h).await;
+        for name in ["API_KEY", "TOKEN"] {
+            Mock::given(method("PUT"))
+                .and(path(format!("/repos/owner/repo/actions/secrets/{name}")))
+                .respond_with(ResponseTemplate::new(201))
+                .expect(1)
+                .mount(&h.server)
+                .await;
+        }
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join(".env");
+        std::fs::write(&file, "API_KEY=abc\n# skip\nTOKEN=def\n").unwrap();
+
+        batch_args(&file).run(&h.factory).await.unwrap();
+
+        assert!(h.stderr().contains("Set 2 secret(s) from"));
+    }
+
+    #[tokio::test]
+    async fn test_should_not_send_secrets_when_env_file_is_malformed() {
+        let h = TestHarness::new().await;
+        mount_public_key(&h).await;
+        Mock::given(method("PUT"))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(0)
+            .mount(&h.server)
+            .await;
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join(".env");
+        std::fs::write(&file, "API_KEY=abc\nbroken\n").unwrap();
+
+        let err = batch_args(&file).run(&h.factory).await.unwrap_err();
+
+        assert!(
+            format!("{err:#}").contains("line 2: expected KEY=VALUE"),
+            "{err:#}"
+        );
+    }
+
+    /// X25519 key pair for "Alice" from RFC 7748, section 6.1.
+    const KNOWN_SECRET_KEY: &str =
+        "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a";
+    const KNOWN_PUBLIC_KEY: &str =
+        "8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a";
+
+    fn key_bytes(hex: &str) -> [u8; 32] {
+        let bytes: Vec<u8> = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect();
+        bytes.try_into().unwrap()
+    }
+
+    fn known_secret_key() -> crypto_box::SecretKey {
+        crypto_box::SecretKey::from(key_bytes(KNOWN_SECRET_KEY))
+    }
+
+    #[test]
+    fn test_should_round_trip_sealed_secret_with_known_keypair() {
+        let secret_key = known_secret_key();
+        assert_eq!(
+            secret_key.public_key().as_bytes(),
+            &key_bytes(KNOWN_PUBLIC_KEY)
+        );
+        let public_key_b64 = ghc_core::text::base64_encode(&key_bytes(KNOWN_PUBLIC_KEY));
+
+        let encrypted = encrypt_secret(&public_key_b64, "hunter2 \u{1f512}").unwrap();
+
+        let ciphertext = ghc_core::text::base64_decode(&encrypted).unwrap();
+        let plaintext = secret_key.unseal(&ciphertext).unwrap();
+        assert_eq!(plaintext, "hunter2 \u{1f512}".as_bytes());
+    }
+
+    #[tokio::test]
+    async fn test_should_send_sealed_value_with_key_id() {
+        let h = TestHarness::new().await;
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/actions/secrets/public-key",
+            serde_json::json!({
+                "key_id": "568250167242549743",
+                "key": ghc_core::text::base64_encode(&key_bytes(KNOWN_PUBLIC_KEY))
+            }),
+        )
+        .await;
+        Mock::given(method("PUT"))
+            .and(path("/repos/owner/repo/actions/secrets/DEPLOY_TOKEN"))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let args = SetArgs {
+            name: Some("DEPLOY_TOKEN".into()),
+            body: Some("s3cr3t".into()),
+            env_file: None,
+            ..batch_args(std::path::Path::new(""))
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let requests = h.server.received_requests().await.unwrap();
+        let put = requests
+            .iter()
+            .find(|r| r.method == wiremock::http::Method::PUT)
+            .unwrap();
+        let body: Value = serde_json::from_slice(&put.body).unwrap();
+        assert_eq!(body["key_id"], "568250167242549743");
+        let ciphertext =
+            ghc_core::text::base64_decode(body["encrypted_value"].as_str().unwrap()).unwrap();
+        assert_eq!(known_secret_key().unseal(&ciphertext).unwrap(), b"s3cr3t");
+    }
+
     #[test]
     fn test_should_encrypt_secret_with_valid_key() {
         // Generate a test keypair
@@ -12,12 +12,17 @@ pub struct AddArgs {
     #[arg(value_name = "KEY_FILE")]
     key_file: String,
 
-    /// A descriptive title for the key.
+    /// A descriptive title for the key. Defaults to the key's comment.
     #[arg(short, long)]
     title: Option<String>,
 
-    /// Key type.
-    #[arg(long, value_parser = ["authentication", "signing"], default_value = "authentication")]
+    /// Key type: `authentication` for git access, `signing` for commit signing.
+    #[arg(
+        long = "type",
+        alias = "key-type",
+        value_parser = ["authentication", "signing"],
+        default_value = "authentication"
+    )]
     key_type: String,
 }
 
@@ -28,7 +33,7 @@ impl AddArgs {
     ///
     /// Returns an error if the key cannot be added.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
 
         let key_content = std::fs::read_to_string(&self.key_file)
             .with_context(|| format!("failed to read key file: {}", self.key_file))?;
@@ -73,3 +78,83 @@ impl AddArgs {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test_helpers::{TestHarness, mock_rest_post};
+
+    fn key_file(dir: &tempfile::TempDir) -> String {
+        let path = dir.path().join("id_ed25519.pub");
+        std::fs::write(&path, "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5 mona@laptop\n").unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    #[tokio::test]
+    async fn test_should_add_signing_key_with_title() {
+        let h = TestHarness::new().await;
+        mock_rest_post(
+            &h.server,
+            "/user/ssh_signing_keys",
+            201,
+            serde_json::json!({ "id": 7 }),
+        )
+        .await;
+        let dir = tempfile::tempdir().unwrap();
+
+        let args = AddArgs {
+            key_file: key_file(&dir),
+            title: Some("laptop".into()),
+            key_type: "signing".into(),
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let requests = h.server.received_requests().await.unwrap();
+        let body: Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({ "title": "laptop", "key": "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5 mona@laptop" })
+        );
+        assert!(
+            h.stderr()
+                .contains("Added SSH signing key (ID: 7) with title \"laptop\"")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_default_title_to_key_comment() {
+        let h = TestHarness::new().await;
+        mock_rest_post(&h.server, "/user/keys", 201, serde_json::json!({ "id": 8 })).await;
+        let dir = tempfile::tempdir().unwrap();
+
+        let args = AddArgs {
+            key_file: key_file(&dir),
+            title: None,
+            key_type: "authentication".into(),
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let requests = h.server.received_requests().await.unwrap();
+        let body: Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body["title"], "mona@laptop");
+    }
+
+    #[test]
+    fn test_should_parse_type_flag() {
+        #[derive(clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: AddArgs,
+        }
+        use clap::Parser;
+
+        let cli = Cli::try_parse_from(["add", "key.pub"]).unwrap();
+        assert_eq!(cli.args.key_type, "authentication");
+        let cli = Cli::try_parse_from(["add", "key.pub", "--type", "signing"]).unwrap();
+        assert_eq!(cli.args.key_type, "signing");
+        assert!(Cli::try_parse_from(["add", "key.pub", "--type", "deploy"]).is_err());
+        let cli = Cli::try_parse_from(["add", "key.pub", "--key-type", "signing"]).unwrap();
+        assert_eq!(cli.args.key_type, "signing");
+    }
+}
@@ -22,6 +22,10 @@ pub struct ListArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl ListArgs {
@@ -31,31 +35,46 @@ impl ListArgs {
     ///
     /// Returns an error if the keys cannot be listed.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
 
-        let keys: Vec<Value> = match client.rest(reqwest::Method::GET, "user/keys", None).await {
+        let mut keys: Vec<Value> = match client.rest(reqwest::Method::GET, "user/keys", None).await
+        {
             Ok(keys) => keys,
             Err(ApiError::Http { status: 404, .. }) => {
                 anyhow::bail!(
                     "insufficient OAuth scopes to list SSH keys\n\
-                     Run the following to grant scopes: ghc auth refresh -s admin:public_key"
+                         Run the following to grant scopes: ghc auth refresh -s admin:public_key"
                 );
             }
             Err(e) => return Err(e).context("failed to list SSH keys"),
         };
+        set_key_type(&mut keys, "authentication");
+
+        // Signing keys need the separate admin:ssh_signing_key scope; list
+        // authentication keys alone when it is missing.
+        let mut signing_keys: Vec<Value> = match client
+            .rest(reqwest::Method::GET, "user/ssh_signing_keys", None)
+            .await
+        {
+            Ok(keys) => keys,
+            Err(ApiError::Http { status: 404, .. }) => Vec::new(),
+            Err(e) => return Err(e).context("failed to list SSH signing keys"),
+        };
+        set_key_type(&mut signing_keys, "signing");
+        keys.append(&mut signing_keys);
 
         // JSON output
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let arr = Value::Array(keys.clone());
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &arr,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -74,6 +93,7 @@ impl ListArgs {
             let title = key.get("title").and_then(Value::as_str).unwrap_or("");
             let key_str = key.get("key").and_then(Value::as_str).unwrap_or("");
             let created_at = key.get("created_at").and_then(Value::as_str).unwrap_or("");
+            let key_type = key.get("type").and_then(Value::as_str).unwrap_or("");
 
             // Show only first/last part of the key
             let key_preview = if key_str.len() > 30 {
@@ -86,6 +106,7 @@ impl ListArgs {
                 format!("{id}"),
                 cs.bold(title),
                 key_preview,
+                key_type.to_string(),
                 created_at.to_string(),
             ]);
         }
@@ -97,6 +118,15 @@ impl ListArgs {
     }
 }
 
+/// Tag each key with its `type` (`authentication` or `signing`).
+fn set_key_type(keys: &mut [Value], key_type: &str) {
+    for key in keys {
+        if let Some(obj) = key.as_object_mut() {
+            obj.insert("type".to_string(), Value::String(key_type.to_string()));
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -130,6 +160,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -139,6 +170,41 @@ mod tests {
             stdout.contains("Home desktop"),
             "should contain second key title"
         );
+        assert!(stdout.contains("authentication"), "{stdout}");
+    }
+
+    #[tokio::test]
+    async fn test_should_include_signing_keys_with_type() {
+        let h = TestHarness::new().await;
+        mock_rest_get(
+            &h.server,
+            "/user/keys",
+            serde_json::json!([{ "id": 1, "title": "Work laptop", "key": "ssh-ed25519 AAAA" }]),
+        )
+        .await;
+        mock_rest_get(
+            &h.server,
+            "/user/ssh_signing_keys",
+            serde_json::json!([{ "id": 2, "title": "Signing", "key": "ssh-ed25519 BBBB" }]),
+        )
+        .await;
+
+        let args = ListArgs {
+            json: vec!["title".into(), "type".into()],
+            jq: None,
+            template: None,
+            yaml: false,
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let out: Value = serde_json::from_str(&h.stdout()).unwrap();
+        assert_eq!(
+            out,
+            serde_json::json!([
+                { "title": "Work laptop", "type": "authentication" },
+                { "title": "Signing", "type": "signing" }
+            ])
+        );
     }
 
     #[tokio::test]
@@ -156,6 +222,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         let result = args.run(&h.factory).await;
         assert!(result.is_err());
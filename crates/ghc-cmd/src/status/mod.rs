@@ -6,10 +6,21 @@ use std::collections::HashMap;
 use std::fmt::Write;
 
 use anyhow::{Context, Result};
+use chrono::{DateTime, SecondsFormat, Utc};
 use clap::Args;
+use serde::Serialize;
 use serde_json::Value;
 
-use ghc_core::ios_eprintln;
+use ghc_api::client::page_items;
+use ghc_core::iostreams::ColorScheme;
+use ghc_core::table::{Align, TablePrinter};
+use ghc_core::{ios_eprintln, ios_println, text};
+
+/// Maximum page size for both the GraphQL search and notifications APIs.
+const MAX_PER_PAGE: u32 = 100;
+
+/// Rate limit resources shown by `--rate-limit`, in display order.
+const RATE_LIMIT_RESOURCES: &[&str] = &["core", "search", "graphql"];
 
 /// Show status of relevant issues, pull requests, and notifications.
 #[derive(Debug, Args)]
@@ -21,9 +32,58 @@ pub struct StatusArgs {
     /// Only show items from a specific organization.
     #[arg(short, long)]
     org: Option<String>,
+
+    /// Maximum number of items to fetch per section.
+    #[arg(short = 'L', long, default_value = "25", value_parser = clap::value_parser!(u32).range(1..))]
+    limit: u32,
+
+    /// Show API rate limit usage instead.
+    #[arg(long)]
+    rate_limit: bool,
+
+    /// Output JSON with specified fields.
+    #[arg(long, value_delimiter = ',')]
+    json: Vec<String>,
+
+    /// Filter JSON output using a jq expression.
+    #[arg(short = 'q', long)]
+    jq: Option<String>,
+
+    /// Format JSON output using a Go template.
+    #[arg(short = 't', long)]
+    template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
+}
+
+/// JSON output structure for `ghc status --json`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatusJson {
+    assigned: Vec<StatusItemJson>,
+    review_requested: Vec<StatusItemJson>,
+    notifications: Vec<StatusItemJson>,
+}
+
+/// A single issue, pull request, or notification in the JSON output.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatusItemJson {
+    repo: String,
+    number: Option<i64>,
+    title: String,
+    url: String,
+    updated_at: String,
 }
 
 impl StatusArgs {
+    /// Whether any of `--json`, `--jq`, `--template` or `--yaml` was given.
+    fn wants_json(&self) -> bool {
+        !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml
+    }
+
     /// Run the status command.
     ///
     /// # Errors
@@ -31,14 +91,28 @@ impl StatusArgs {
     /// Returns an error if the API request fails.
     #[allow(clippy::too_many_lines)]
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
+
+        if self.rate_limit {
+            let data: Value = client
+                .rest(reqwest::Method::GET, "rate_limit", None::<&Value>)
+                .await
+                .context("failed to fetch rate limit")?;
+
+            print_rate_limit(ios, &data);
+            return Ok(());
+        }
+        let client = factory.with_response_cache(client, crate::factory::RESPONSE_CACHE_TTL);
+
         let cs = ios.color_scheme();
 
         // Fetch assigned issues
         let assigned_query = r"
-            query AssignedSearch($query: String!) {
-              search(query: $query, type: ISSUE, first: 25) {
+            query AssignedSearch($query: String!, $first: Int!, $after: String) {
+              search(query: $query, type: ISSUE, first: $first, after: $after) {
+                issueCount
+                pageInfo { hasNextPage endCursor }
                 nodes {
                   ... on Issue {
                     __typename
@@ -61,113 +135,109 @@ impl StatusArgs {
             }
         ";
 
-        let mut search_query = "assignee:@me is:open".to_string();
-        if let Some(ref org) = self.org {
-            let _ = write!(search_query, " org:{org}");
-        }
-        for excluded in &self.exclude {
-            let _ = write!(search_query, " -org:{excluded}");
-        }
-
-        let mut variables = HashMap::new();
-        variables.insert("query".to_string(), Value::String(search_query));
-
-        let assigned_data: Value = client
-            .graphql(assigned_query, &variables)
+        let assigned = self
+            .search(&client, assigned_query, "assignee:@me is:open")
             .await
             .context("failed to fetch assigned items")?;
 
         // Fetch review requests
-        let mut review_query = "review-requested:@me is:open is:pr".to_string();
-        if let Some(ref org) = self.org {
-            let _ = write!(review_query, " org:{org}");
-        }
-        for excluded in &self.exclude {
-            let _ = write!(review_query, " -org:{excluded}");
-        }
-
-        let mut review_variables = HashMap::new();
-        review_variables.insert("query".to_string(), Value::String(review_query));
+        let review_query = r"
+            query ReviewSearch($query: String!, $first: Int!, $after: String) {
+              search(query: $query, type: ISSUE, first: $first, after: $after) {
+                issueCount
+                pageInfo { hasNextPage endCursor }
+                nodes {
+                  ... on PullRequest {
+                    __typename
+                    number
+                    title
+                    url
+                    repository { nameWithOwner }
+                    updatedAt
+                  }
+                }
+              }
+            }
+        ";
 
-        let review_data: Value = client
-            .graphql(assigned_query, &review_variables)
+        let reviews = self
+            .search(&client, review_query, "review-requested:@me is:open is:pr")
             .await
             .context("failed to fetch review requests")?;
 
-        // Display assigned items
-        let assigned_nodes = assigned_data
-            .pointer("/search/nodes")
-            .and_then(Value::as_array);
+        // Fetch notifications
+        let notifications = self
+            .notifications(&client)
+            .await
+            .context("failed to fetch notifications")?;
 
+        if self.wants_json() {
+            let output = StatusJson {
+                assigned: search_items(&assigned.nodes),
+                review_requested: search_items(&reviews.nodes),
+                notifications: notifications.iter().map(notification_item).collect(),
+            };
+            ghc_core::json::output(
+                &serde_json::to_value(&output)?,
+                &self.json,
+                self.jq.as_deref(),
+                self.template.as_deref(),
+                self.yaml,
+                ios,
+            )?;
+            return Ok(());
+        }
+
+        // Display assigned items
         ios_eprintln!(ios, "{}", cs.bold("Assigned Issues and Pull Requests"));
-        if let Some(nodes) = assigned_nodes {
-            if nodes.is_empty() {
-                ios_eprintln!(ios, "  Nothing assigned to you");
-            } else {
-                for node in nodes {
-                    let typename = node
-                        .get("__typename")
-                        .and_then(Value::as_str)
-                        .unwrap_or("Issue");
-                    let repo_name = node
-                        .pointer("/repository/nameWithOwner")
-                        .and_then(Value::as_str)
-                        .unwrap_or("");
-                    let number = node.get("number").and_then(Value::as_i64).unwrap_or(0);
-                    let title = node.get("title").and_then(Value::as_str).unwrap_or("");
-
-                    let icon = if typename == "PullRequest" {
-                        "PR"
-                    } else {
-                        "Issue"
-                    };
-                    ios_eprintln!(ios, "  {icon} {repo_name}#{number} {title}");
-                }
-            }
-        } else {
+        if assigned.nodes.is_empty() {
             ios_eprintln!(ios, "  Nothing assigned to you");
+        } else {
+            for node in &assigned.nodes {
+                let typename = node
+                    .get("__typename")
+                    .and_then(Value::as_str)
+                    .unwrap_or("Issue");
+                let repo_name = node
+                    .pointer("/repository/nameWithOwner")
+                    .and_then(Value::as_str)
+                    .unwrap_or("");
+                let number = node.get("number").and_then(Value::as_i64).unwrap_or(0);
+                let title = node.get("title").and_then(Value::as_str).unwrap_or("");
+
+                let icon = if typename == "PullRequest" {
+                    "PR"
+                } else {
+                    "Issue"
+                };
+                ios_eprintln!(ios, "  {icon} {repo_name}#{number} {title}");
+            }
+            ios_eprintln!(ios, "  {}", cs.gray(&assigned.summary("assigned")));
         }
 
         // Display review requests
-        let review_nodes = review_data
-            .pointer("/search/nodes")
-            .and_then(Value::as_array);
-
         ios_eprintln!(ios, "\n{}", cs.bold("Review Requests"));
-        if let Some(nodes) = review_nodes {
-            if nodes.is_empty() {
-                ios_eprintln!(ios, "  No review requests");
-            } else {
-                for node in nodes {
-                    let repo_name = node
-                        .pointer("/repository/nameWithOwner")
-                        .and_then(Value::as_str)
-                        .unwrap_or("");
-                    let number = node.get("number").and_then(Value::as_i64).unwrap_or(0);
-                    let title = node.get("title").and_then(Value::as_str).unwrap_or("");
-
-                    ios_eprintln!(ios, "  PR {repo_name}#{number} {title}");
-                }
-            }
-        } else {
+        if reviews.nodes.is_empty() {
             ios_eprintln!(ios, "  No review requests");
-        }
+        } else {
+            for node in &reviews.nodes {
+                let repo_name = node
+                    .pointer("/repository/nameWithOwner")
+                    .and_then(Value::as_str)
+                    .unwrap_or("");
+                let number = node.get("number").and_then(Value::as_i64).unwrap_or(0);
+                let title = node.get("title").and_then(Value::as_str).unwrap_or("");
 
-        // Fetch notifications
-        let notifications: Vec<Value> = client
-            .rest(
-                reqwest::Method::GET,
-                "notifications?per_page=10",
-                None::<&Value>,
-            )
-            .await
-            .context("failed to fetch notifications")?;
+                ios_eprintln!(ios, "  PR {repo_name}#{number} {title}");
+            }
+            ios_eprintln!(ios, "  {}", cs.gray(&reviews.summary("review requests")));
+        }
 
         ios_eprintln!(ios, "\n{}", cs.bold("Notifications"));
         if notifications.is_empty() {
             ios_eprintln!(ios, "  No unread notifications");
         } else {
-            for notif in notifications.iter().take(10) {
+            for notif in &notifications {
                 let reason = notif
                     .get("reason")
                     .and_then(Value::as_str)
@@ -181,12 +251,224 @@ impl StatusArgs {
                     .and_then(Value::as_str)
                     .unwrap_or("");
 
-                ios_eprintln!(ios, "  [{reason}] {repo_name}: {title}");
+                let label = reason_label(&cs, reason);
+                ios_eprintln!(ios, "  {label} {repo_name}: {title}");
             }
         }
 
         Ok(())
     }
+
+    /// Append the `--org` and `--exclude` qualifiers to a search string.
+    fn search_string(&self, base: &str) -> String {
+        let mut search = base.to_string();
+        if let Some(ref org) = self.org {
+            let _ = write!(search, " org:{org}");
+        }
+        for excluded in &self.exclude {
+            let _ = write!(search, " -org:{excluded}");
+        }
+        search
+    }
+
+    /// Run a GraphQL issue search, following cursors until `--limit` nodes
+    /// have been collected or the results are exhausted.
+    async fn search(
+        &self,
+        client: &ghc_api::client::Client,
+        query: &str,
+        base: &str,
+    ) -> Result<SearchResult> {
+        let mut variables = HashMap::new();
+        variables.insert("query".to_string(), Value::String(self.search_string(base)));
+        variables.insert(
+            "first".to_string(),
+            Value::Number(serde_json::Number::from(self.limit.min(MAX_PER_PAGE))),
+        );
+
+        let mut result = SearchResult {
+            nodes: Vec::new(),
+            total_count: 0,
+        };
+
+        loop {
+            let data: Value = client.graphql(query, &variables).await?;
+            let search_data = data
+                .get("search")
+                .ok_or_else(|| anyhow::anyhow!("unexpected search API response"))?;
+
+            result.total_count = search_data
+                .get("issueCount")
+                .and_then(Value::as_u64)
+                .unwrap_or(0);
+
+            let nodes = search_data
+                .get("nodes")
+                .and_then(Value::as_array)
+                .cloned()
+                .unwrap_or_default();
+            for node in nodes {
+                result.nodes.push(node);
+                if result.nodes.len() >= self.limit as usize {
+                    return Ok(result);
+                }
+            }
+
+            let has_next = search_data
+                .pointer("/pageInfo/hasNextPage")
+                .and_then(Value::as_bool)
+                .unwrap_or(false);
+            let end_cursor = search_data
+                .pointer("/pageInfo/endCursor")
+                .and_then(Value::as_str);
+            match end_cursor {
+                Some(cursor) if has_next => {
+                    variables.insert("after".to_string(), Value::String(cursor.to_string()));
+                }
+                _ => break,
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Fetch up to `--limit` unread notifications, following `Link` headers.
+    async fn notifications(&self, client: &ghc_api::client::Client) -> Result<Vec<Value>> {
+        let path = format!("notifications?per_page={}", self.limit.min(MAX_PER_PAGE));
+        let notifications = client
+            .rest_paginate_limit(&path, Some(self.limit as usize), |page| {
+                page_items(page, None)
+            })
+            .await?;
+        Ok(notifications)
+    }
+}
+
+/// Nodes collected from a paginated search plus the total match count.
+#[derive(Debug)]
+struct SearchResult {
+    nodes: Vec<Value>,
+    total_count: u64,
+}
+
+impl SearchResult {
+    /// Summary line such as `Showing 25 of 112 assigned`.
+    fn summary(&self, noun: &str) -> String {
+        format!(
+            "Showing {} of {} {noun}",
+            self.nodes.len(),
+            self.total_count.max(self.nodes.len() as u64),
+        )
+    }
+}
+
+/// Convert GraphQL search nodes into JSON output items.
+fn search_items(nodes: &[Value]) -> Vec<StatusItemJson> {
+    nodes
+        .iter()
+        .map(|node| StatusItemJson {
+            repo: str_at(node, "/repository/nameWithOwner"),
+            number: node.get("number").and_then(Value::as_i64),
+            title: str_at(node, "/title"),
+            url: str_at(node, "/url"),
+            updated_at: str_at(node, "/updatedAt"),
+        })
+        .collect()
+}
+
+/// Convert a REST notification thread into a JSON output item.
+///
+/// The number and web URL are derived from the subject's API URL; subjects
+/// without a number (releases, discussions) link to the repository instead.
+fn notification_item(notif: &Value) -> StatusItemJson {
+    let repo_url = str_at(notif, "/repository/html_url");
+    let subject_type = str_at(notif, "/subject/type");
+    let number = notif
+        .pointer("/subject/url")
+        .and_then(Value::as_str)
+        .and_then(|url| url.rsplit('/').next())
+        .and_then(|n| n.parse::<i64>().ok());
+    let url = match (subject_type.as_str(), number) {
+        ("PullRequest", Some(n)) => format!("{repo_url}/pull/{n}"),
+        ("Issue", Some(n)) => format!("{repo_url}/issues/{n}"),
+        _ => repo_url,
+    };
+    StatusItemJson {
+        repo: str_at(notif, "/repository/full_name"),
+        number,
+        title: str_at(notif, "/subject/title"),
+        url,
+        updated_at: str_at(notif, "/updated_at"),
+    }
+}
+
+fn str_at(value: &Value, pointer: &str) -> String {
+    value
+        .pointer(pointer)
+        .and_then(Value::as_str)
+        .unwrap_or("")
+        .to_string()
+}
+
+/// Render a notification reason as a colored label with an icon.
+///
+/// Without color the reason is shown as plain `[reason]` text.
+pub(crate) fn reason_label(cs: &ColorScheme, reason: &str) -> String {
+    if !cs.is_enabled() {
+        return format!("[{reason}]");
+    }
+    match reason {
+        "review_requested" => cs.cyan(&format!("◉ {reason}")),
+        "mention" | "team_mention" => cs.warning(&format!("@ {reason}")),
+        "assign" => cs.success(&format!("● {reason}")),
+        "author" => cs.magenta(&format!("✎ {reason}")),
+        "security_alert" => cs.error(&format!("! {reason}")),
+        _ => cs.gray(&format!("• {reason}")),
+    }
+}
+
+/// Print the quotas of a `/rate_limit` response as a table, as shown by
+/// both `ghc status --rate-limit` and `ghc api rate-limit`.
+pub(crate) fn print_rate_limit(ios: &ghc_core::iostreams::IOStreams, data: &Value) {
+    let mut tp = TablePrinter::new(ios).with_headers(&["RESOURCE", "REMAINING", "LIMIT", "RESET"]);
+    tp.set_column_align(1, Align::Right);
+    tp.set_column_align(2, Align::Right);
+    for row in rate_limit_rows(data, Utc::now(), ios.is_stdout_tty()) {
+        tp.add_row(row);
+    }
+    ios_println!(ios, "{}", tp.render());
+}
+
+/// Build table rows from a `/rate_limit` payload.
+///
+/// Resets are relative (e.g. "in 12 minutes") on a TTY and RFC 3339
+/// timestamps otherwise.
+fn rate_limit_rows(data: &Value, now: DateTime<Utc>, is_tty: bool) -> Vec<Vec<String>> {
+    RATE_LIMIT_RESOURCES
+        .iter()
+        .filter_map(|name| {
+            let resource = data.pointer(&format!("/resources/{name}"))?;
+            let remaining = resource.get("remaining").and_then(Value::as_i64)?;
+            let limit = resource.get("limit").and_then(Value::as_i64)?;
+            let reset = resource
+                .get("reset")
+                .and_then(Value::as_i64)
+                .and_then(|ts| DateTime::from_timestamp(ts, 0))
+                .map_or_else(String::new, |reset| {
+                    if is_tty {
+                        text::fuzzy_until(reset.signed_duration_since(now))
+                    } else {
+                        reset.to_rfc3339_opts(SecondsFormat::Secs, true)
+                    }
+                });
+            Some(vec![
+                (*name).to_string(),
+                remaining.to_string(),
+                limit.to_string(),
+                reset,
+            ])
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -199,7 +481,7 @@ mod tests {
     async fn test_should_display_empty_status() {
         let h = TestHarness::new().await;
 
-        // Mock GraphQL search (assigned items)
+        // Mock GraphQL searches (assigned items and review requests)
         mock_graphql(
             &h.server,
             "AssignedSearch",
@@ -212,6 +494,12 @@ mod tests {
             }),
         )
         .await;
+        mock_graphql(
+            &h.server,
+            "ReviewSearch",
+            serde_json::json!({ "data": { "search": { "nodes": [] } } }),
+        )
+        .await;
 
         // Mock notifications REST endpoint
         mock_rest_get(&h.server, "/notifications", serde_json::json!([])).await;
@@ -219,6 +507,12 @@ mod tests {
         let args = StatusArgs {
             exclude: vec![],
             org: None,
+            limit: 25,
+            rate_limit: false,
+            json: vec![],
+            jq: None,
+            template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -256,16 +550,403 @@ mod tests {
             }),
         )
         .await;
+        mock_graphql(
+            &h.server,
+            "ReviewSearch",
+            serde_json::json!({ "data": { "search": { "nodes": [] } } }),
+        )
+        .await;
 
         mock_rest_get(&h.server, "/notifications", serde_json::json!([])).await;
 
         let args = StatusArgs {
             exclude: vec![],
             org: None,
+            limit: 25,
+            rate_limit: false,
+            json: vec![],
+            jq: None,
+            template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
         let stderr = h.stderr();
         assert!(stderr.contains("Issue owner/repo#42 Fix bug"));
+        assert!(stderr.contains("Showing 1 of 1 assigned"));
+        assert!(stderr.contains("No review requests"));
+    }
+
+    #[tokio::test]
+    async fn test_should_query_review_requests_separately() {
+        let h = TestHarness::new().await;
+
+        mock_graphql(
+            &h.server,
+            "AssignedSearch",
+            serde_json::json!({ "data": { "search": { "nodes": [] } } }),
+        )
+        .await;
+        mock_graphql(
+            &h.server,
+            "ReviewSearch",
+            serde_json::json!({
+                "data": {
+                    "search": {
+                        "nodes": [
+                            {
+                                "__typename": "PullRequest",
+                                "number": 12,
+                                "title": "Refactor parser",
+                                "url": "https://github.com/owner/repo/pull/12",
+                                "repository": { "nameWithOwner": "owner/repo" },
+                                "updatedAt": "2024-01-15T10:00:00Z"
+                            }
+                        ]
+                    }
+                }
+            }),
+        )
+        .await;
+        mock_rest_get(&h.server, "/notifications", serde_json::json!([])).await;
+
+        let args = StatusArgs {
+            exclude: vec![],
+            org: None,
+            limit: 25,
+            rate_limit: false,
+            json: vec![],
+            jq: None,
+            template: None,
+            yaml: false,
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let stderr = h.stderr();
+        assert!(stderr.contains("Nothing assigned to you"));
+        assert!(stderr.contains("PR owner/repo#12 Refactor parser"));
+    }
+
+    #[tokio::test]
+    async fn test_should_output_json() {
+        let h = TestHarness::new().await;
+
+        mock_graphql(
+            &h.server,
+            "AssignedSearch",
+            serde_json::json!({ "data": { "search": { "nodes": [] } } }),
+        )
+        .await;
+
+        mock_graphql(
+            &h.server,
+            "ReviewSearch",
+            serde_json::json!({
+                "data": {
+                    "search": {
+                        "nodes": [
+                            {
+                                "__typename": "PullRequest",
+                                "number": 7,
+                                "title": "Add feature",
+                                "url": "https://github.com/owner/repo/pull/7",
+                                "repository": { "nameWithOwner": "owner/repo" },
+                                "updatedAt": "2024-01-15T10:00:00Z"
+                            }
+                        ]
+                    }
+                }
+            }),
+        )
+        .await;
+
+        mock_rest_get(
+            &h.server,
+            "/notifications",
+            serde_json::json!([
+                {
+                    "reason": "mention",
+                    "updated_at": "2024-01-16T09:00:00Z",
+                    "subject": {
+                        "title": "Crash on start",
+                        "type": "Issue",
+                        "url": "https://api.github.com/repos/owner/repo/issues/3"
+                    },
+                    "repository": {
+                        "full_name": "owner/repo",
+                        "html_url": "https://github.com/owner/repo"
+                    }
+                }
+            ]),
+        )
+        .await;
+
+        let args = StatusArgs {
+            exclude: vec![],
+            org: None,
+            limit: 25,
+            rate_limit: false,
+            json: vec![
+                "assigned".into(),
+                "reviewRequested".into(),
+                "notifications".into(),
+            ],
+            jq: None,
+            template: None,
+            yaml: false,
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert!(h.stderr().is_empty(), "should not print headings");
+        let parsed: Value = serde_json::from_str(&h.stdout()).unwrap();
+        assert_eq!(parsed["assigned"], serde_json::json!([]));
+        assert_eq!(parsed["reviewRequested"][0]["repo"], "owner/repo");
+        assert_eq!(parsed["reviewRequested"][0]["number"], 7);
+        assert_eq!(parsed["reviewRequested"][0]["title"], "Add feature");
+        assert_eq!(
+            parsed["reviewRequested"][0]["updatedAt"],
+            "2024-01-15T10:00:00Z"
+        );
+        assert_eq!(parsed["notifications"][0]["number"], 3);
+        assert_eq!(
+            parsed["notifications"][0]["url"],
+            "https://github.com/owner/repo/issues/3"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_output_empty_json_sections() {
+        let h = TestHarness::new().await;
+        mock_graphql(
+            &h.server,
+            "AssignedSearch",
+            serde_json::json!({ "data": { "search": { "nodes": [] } } }),
+        )
+        .await;
+        mock_graphql(
+            &h.server,
+            "ReviewSearch",
+            serde_json::json!({ "data": { "search": { "nodes": [] } } }),
+        )
+        .await;
+        mock_rest_get(&h.server, "/notifications", serde_json::json!([])).await;
+
+        let args = StatusArgs {
+            exclude: vec![],
+            org: None,
+            limit: 25,
+            rate_limit: false,
+            json: vec![
+                "assigned".into(),
+                "reviewRequested".into(),
+                "notifications".into(),
+            ],
+            jq: None,
+            template: None,
+            yaml: false,
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let parsed: Value = serde_json::from_str(&h.stdout()).unwrap();
+        assert_eq!(
+            parsed,
+            serde_json::json!({
+                "assigned": [],
+                "reviewRequested": [],
+                "notifications": []
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_filter_json_with_jq() {
+        let h = TestHarness::new().await;
+        mock_graphql(
+            &h.server,
+            "AssignedSearch",
+            serde_json::json!({ "data": { "search": { "nodes": [] } } }),
+        )
+        .await;
+        mock_graphql(
+            &h.server,
+            "ReviewSearch",
+            serde_json::json!({ "data": { "search": { "nodes": [] } } }),
+        )
+        .await;
+        mock_rest_get(&h.server, "/notifications", serde_json::json!([])).await;
+
+        let args = StatusArgs {
+            exclude: vec![],
+            org: None,
+            limit: 25,
+            rate_limit: false,
+            json: vec!["assigned".into()],
+            jq: Some(".assigned | length".into()),
+            template: None,
+            yaml: false,
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert_eq!(h.stdout().trim(), "0");
+    }
+
+    fn search_page(start: i64, count: i64, total: i64, has_next: bool) -> Value {
+        let nodes: Vec<Value> = (start..start + count)
+            .map(|n| {
+                serde_json::json!({
+                    "__typename": "Issue",
+                    "number": n,
+                    "title": format!("Issue {n}"),
+                    "url": format!("https://github.com/owner/repo/issues/{n}"),
+                    "repository": { "nameWithOwner": "owner/repo" },
+                    "updatedAt": "2024-01-15T10:00:00Z"
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "data": {
+                "search": {
+                    "issueCount": total,
+                    "pageInfo": { "hasNextPage": has_next, "endCursor": "cursor1" },
+                    "nodes": nodes
+                }
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_should_paginate_search_beyond_one_page() {
+        use wiremock::matchers::{body_string_contains, method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let h = TestHarness::new().await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("AssignedSearch"))
+            .and(body_string_contains("cursor1"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(search_page(101, 100, 250, true)),
+            )
+            .with_priority(1)
+            .mount(&h.server)
+            .await;
+        mock_graphql(&h.server, "AssignedSearch", search_page(1, 100, 250, true)).await;
+        mock_graphql(
+            &h.server,
+            "ReviewSearch",
+            serde_json::json!({ "data": { "search": { "issueCount": 0, "nodes": [] } } }),
+        )
+        .await;
+        mock_rest_get(&h.server, "/notifications", serde_json::json!([])).await;
+
+        let args = StatusArgs {
+            exclude: vec![],
+            org: None,
+            limit: 150,
+            rate_limit: false,
+            json: vec![],
+            jq: None,
+            template: None,
+            yaml: false,
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let stderr = h.stderr();
+        assert!(stderr.contains("Issue owner/repo#150 Issue 150"));
+        assert!(!stderr.contains("Issue owner/repo#151 "));
+        assert!(stderr.contains("Showing 150 of 250 assigned"));
+    }
+
+    #[test]
+    fn test_should_reject_zero_limit() {
+        use clap::Parser;
+
+        #[derive(Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: StatusArgs,
+        }
+
+        assert!(Cli::try_parse_from(["status", "--limit", "0"]).is_err());
+        let cli = Cli::try_parse_from(["status", "-L", "5"]).unwrap();
+        assert_eq!(cli.args.limit, 5);
+    }
+
+    fn rate_limit_payload() -> Value {
+        serde_json::json!({
+            "resources": {
+                "core": { "limit": 5000, "remaining": 4990, "reset": 1_705_312_800, "used": 10 },
+                "search": { "limit": 30, "remaining": 30, "reset": 1_705_311_060, "used": 0 },
+                "graphql": { "limit": 5000, "remaining": 4200, "reset": 1_705_315_200, "used": 800 }
+            },
+            "rate": { "limit": 5000, "remaining": 4990, "reset": 1_705_312_800, "used": 10 }
+        })
+    }
+
+    #[test]
+    fn test_should_render_plain_reason_without_color() {
+        let cs = ghc_core::iostreams::IOStreams::test().color_scheme();
+        assert_eq!(reason_label(&cs, "review_requested"), "[review_requested]");
+    }
+
+    #[test]
+    fn test_should_prefix_reason_with_icon_when_colored() {
+        let mut ios = ghc_core::iostreams::IOStreams::test();
+        ios.set_color_enabled(true);
+        let cs = ios.color_scheme();
+        assert!(reason_label(&cs, "review_requested").contains("◉ review_requested"));
+        assert!(reason_label(&cs, "mention").contains("@ mention"));
+        assert!(reason_label(&cs, "assign").contains("● assign"));
+        assert!(reason_label(&cs, "subscribed").contains("• subscribed"));
+    }
+
+    #[test]
+    fn test_should_build_rate_limit_rows() {
+        let now = DateTime::from_timestamp(1_705_311_000, 0).unwrap();
+        let rows = rate_limit_rows(&rate_limit_payload(), now, true);
+        assert_eq!(
+            rows,
+            vec![
+                vec!["core", "4990", "5000", "in 30 minutes"],
+                vec!["search", "30", "30", "in 1 minute"],
+                vec!["graphql", "4200", "5000", "in 1 hour"],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_should_use_timestamps_for_rate_limit_when_not_tty() {
+        let now = DateTime::from_timestamp(1_705_311_000, 0).unwrap();
+        let rows = rate_limit_rows(&rate_limit_payload(), now, false);
+        assert_eq!(rows[0][3], "2024-01-15T10:00:00Z");
+    }
+
+    #[tokio::test]
+    async fn test_should_show_rate_limit() {
+        let h = TestHarness::new().await;
+        mock_rest_get(&h.server, "/rate_limit", rate_limit_payload()).await;
+
+        let args = StatusArgs {
+            exclude: vec![],
+            org: None,
+            limit: 25,
+            rate_limit: true,
+            json: vec![],
+            jq: None,
+            template: None,
+            yaml: false,
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let out = h.stdout();
+        assert!(
+            out.contains("core\t4990\t5000\t2024-01-15T10:00:00Z"),
+            "should contain core row: {out}"
+        );
+        assert!(
+            out.contains("graphql\t4200\t5000"),
+            "should contain graphql row: {out}"
+        );
+        assert!(h.stderr().is_empty(), "should skip the status sections");
     }
 }
@@ -6,6 +6,7 @@
 use std::sync::Arc;
 
 use ghc_core::browser::StubBrowser;
+use ghc_core::clipboard::StubClipboard;
 use ghc_core::config::MemoryConfig;
 use ghc_core::iostreams::TestOutput;
 use ghc_core::prompter::StubPrompter;
@@ -25,8 +26,15 @@ pub struct TestHarness {
     pub server: MockServer,
     /// Stub browser for verifying opened URLs.
     pub browser: Arc<StubBrowser>,
+    /// Stub clipboard for verifying copied text.
+    pub clipboard: Arc<StubClipboard>,
     /// Stub prompter for providing test answers.
     pub prompter: Arc<StubPrompter>,
+    /// Empty git repository the factory's git client runs in, so tests
+    /// never see the remotes of the checkout they run from.
+    git_dir: tempfile::TempDir,
+    /// Response cache directory private to this harness.
+    _cache_dir: tempfile::TempDir,
 }
 
 impl TestHarness {
@@ -45,22 +53,35 @@ impl TestHarness {
 
     /// Create a test harness with a custom `MemoryConfig`.
     pub async fn with_config(config: MemoryConfig) -> Self {
+        let git_dir = tempfile::tempdir().expect("create temp dir");
+        run_git(git_dir.path(), &["init", "--quiet"]);
+        let cache_dir = tempfile::tempdir().expect("create temp dir");
         let server = MockServer::start().await;
         let (factory, output) = Factory::test();
         let (factory, browser) = factory.with_stub_browser();
+        let (factory, clipboard) = factory.with_stub_clipboard();
         let (factory, prompter) = factory.with_stub_prompter();
         let factory = factory
             .with_http_client(reqwest::Client::new())
             .with_api_url(format!("{}/", server.uri()))
             .with_token("ghp_test_token_123")
-            .with_config(Box::new(config));
+            .with_config(Box::new(config))
+            .with_cache_dir(cache_dir.path())
+            .with_git_client(
+                ghc_git::client::GitClient::new()
+                    .expect("git client")
+                    .with_repo_dir(git_dir.path()),
+            );
 
         Self {
             factory,
             output,
             server,
             browser,
+            clipboard,
             prompter,
+            git_dir,
+            _cache_dir: cache_dir,
         }
     }
 
@@ -74,6 +95,15 @@ impl TestHarness {
         self.output.stderr()
     }
 
+    /// Get text copied to the stub clipboard.
+    pub fn copied(&self) -> Vec<String> {
+        self.clipboard
+            .contents
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+    }
+
     /// Get URLs opened in the stub browser.
     pub fn opened_urls(&self) -> Vec<String> {
         self.browser
@@ -82,6 +112,32 @@ impl TestHarness {
             .unwrap_or_else(std::sync::PoisonError::into_inner)
             .clone()
     }
+
+    /// The harness's git repository, which the factory's git client runs in.
+    pub fn git_dir(&self) -> &std::path::Path {
+        self.git_dir.path()
+    }
+
+    /// Add `(name, url)` remotes to the harness's git repository, so
+    /// commands run without `-R` resolve them.
+    #[must_use]
+    pub fn with_git_remotes(self, remotes: &[(&str, &str)]) -> Self {
+        for (name, url) in remotes {
+            run_git(self.git_dir.path(), &["remote", "add", name, url]);
+        }
+        self
+    }
+}
+
+/// Run git in `dir`, panicking if it fails.
+fn run_git(dir: &std::path::Path, args: &[&str]) {
+    let status = std::process::Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("run git")
+        .status;
+    assert!(status.success(), "git {args:?} failed");
 }
 
 // --- Wiremock helpers ---
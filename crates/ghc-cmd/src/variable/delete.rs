@@ -4,7 +4,6 @@ use anyhow::{Context, Result};
 use clap::Args;
 
 use ghc_core::ios_eprintln;
-use ghc_core::repo::Repo;
 
 /// Delete a variable.
 #[derive(Debug, Args)]
@@ -33,16 +32,12 @@ impl DeleteArgs {
     ///
     /// Returns an error if the variable cannot be deleted.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
 
         let path = if let Some(ref org) = self.org {
             format!("orgs/{org}/actions/variables/{}", self.name)
         } else if let Some(ref env) = self.env {
-            let repo = self
-                .repo
-                .as_deref()
-                .ok_or_else(|| anyhow::anyhow!("repository required for environment variables"))?;
-            let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+            let repo = factory.resolve_repo(self.repo.as_deref()).await?;
             format!(
                 "repos/{}/{}/environments/{env}/variables/{}",
                 repo.owner(),
@@ -50,10 +45,7 @@ impl DeleteArgs {
                 self.name,
             )
         } else {
-            let repo = self.repo.as_deref().ok_or_else(|| {
-                anyhow::anyhow!("repository argument required (use -R OWNER/REPO)")
-            })?;
-            let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+            let repo = factory.resolve_repo(self.repo.as_deref()).await?;
             format!(
                 "repos/{}/{}/actions/variables/{}",
                 repo.owner(),
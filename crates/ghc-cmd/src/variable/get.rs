@@ -2,12 +2,15 @@
 
 use anyhow::{Context, Result};
 use clap::Args;
+use ghc_api::errors::ApiError;
 use serde_json::Value;
 
 use ghc_core::ios_println;
-use ghc_core::repo::Repo;
 
 /// Get a variable value.
+///
+/// Prints only the value, so it can be captured in scripts with
+/// `V=$(ghc variable get NAME)`.
 #[derive(Debug, Args)]
 pub struct GetArgs {
     /// The variable name.
@@ -37,6 +40,10 @@ pub struct GetArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl GetArgs {
@@ -48,16 +55,12 @@ impl GetArgs {
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
         let ios = &factory.io;
 
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
 
         let path = if let Some(ref org) = self.org {
             format!("orgs/{org}/actions/variables/{}", self.name)
         } else if let Some(ref env) = self.env {
-            let repo = self
-                .repo
-                .as_deref()
-                .ok_or_else(|| anyhow::anyhow!("repository required for environment variables"))?;
-            let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+            let repo = factory.resolve_repo(self.repo.as_deref()).await?;
             format!(
                 "repos/{}/{}/environments/{env}/variables/{}",
                 repo.owner(),
@@ -65,10 +68,7 @@ impl GetArgs {
                 self.name,
             )
         } else {
-            let repo = self.repo.as_deref().ok_or_else(|| {
-                anyhow::anyhow!("repository argument required (use -R OWNER/REPO)")
-            })?;
-            let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+            let repo = factory.resolve_repo(self.repo.as_deref()).await?;
             format!(
                 "repos/{}/{}/actions/variables/{}",
                 repo.owner(),
@@ -77,21 +77,25 @@ impl GetArgs {
             )
         };
 
-        let variable: Value = client
-            .rest(reqwest::Method::GET, &path, None)
-            .await
-            .context("failed to get variable")?;
+        let mut variable: Value = match client.rest(reqwest::Method::GET, &path, None).await {
+            Ok(variable) => variable,
+            Err(ApiError::Http { status: 404, .. }) => {
+                anyhow::bail!("variable not found: {}", self.name)
+            }
+            Err(e) => return Err(e).context("failed to get variable"),
+        };
 
         // JSON output
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
-            let output = ghc_core::json::format_json_output(
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
+            super::list::normalize_variable(&mut variable);
+            ghc_core::json::output(
                 &variable,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -101,3 +105,86 @@ impl GetArgs {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test_helpers::{TestHarness, mock_rest_get, mock_rest_get_status};
+
+    fn get_args(name: &str) -> GetArgs {
+        GetArgs {
+            name: name.into(),
+            repo: Some("owner/repo".into()),
+            org: None,
+            env: None,
+            json: vec![],
+            jq: None,
+            template: None,
+            yaml: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_should_print_only_the_value() {
+        let h = TestHarness::new().await;
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/actions/variables/DEPLOY_ENV",
+            serde_json::json!({
+                "name": "DEPLOY_ENV",
+                "value": "production",
+                "updated_at": "2024-01-15T10:00:00Z"
+            }),
+        )
+        .await;
+
+        get_args("DEPLOY_ENV").run(&h.factory).await.unwrap();
+
+        assert_eq!(h.stdout(), "production\n");
+    }
+
+    #[tokio::test]
+    async fn test_should_output_variable_json() {
+        let h = TestHarness::new().await;
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/actions/variables/DEPLOY_ENV",
+            serde_json::json!({
+                "name": "DEPLOY_ENV",
+                "value": "production",
+                "updated_at": "2024-01-15T10:00:00Z"
+            }),
+        )
+        .await;
+
+        let args = GetArgs {
+            json: vec!["name".into(), "updatedAt".into()],
+            ..get_args("DEPLOY_ENV")
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let out: Value = serde_json::from_str(&h.stdout()).unwrap();
+        assert_eq!(
+            out,
+            serde_json::json!({ "name": "DEPLOY_ENV", "updatedAt": "2024-01-15T10:00:00Z" })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_fail_when_variable_is_missing() {
+        let h = TestHarness::new().await;
+        mock_rest_get_status(
+            &h.server,
+            "/repos/owner/repo/actions/variables/MISSING",
+            404,
+            serde_json::json!({ "message": "Not Found" }),
+        )
+        .await;
+
+        let err = get_args("MISSING").run(&h.factory).await.unwrap_err();
+
+        assert_eq!(err.to_string(), "variable not found: MISSING");
+        assert!(h.stdout().is_empty());
+    }
+}
@@ -2,9 +2,9 @@
 
 use anyhow::{Context, Result};
 use clap::Args;
+use ghc_api::client::page_items;
 use serde_json::Value;
 
-use ghc_core::repo::Repo;
 use ghc_core::table::TablePrinter;
 use ghc_core::{ios_eprintln, ios_println};
 
@@ -34,6 +34,10 @@ pub struct ListArgs {
     /// Format JSON output using a Go template.
     #[arg(short = 't', long)]
     template: Option<String>,
+
+    /// Output YAML instead of JSON (combine with `--json` to select fields).
+    #[arg(long)]
+    yaml: bool,
 }
 
 impl ListArgs {
@@ -43,53 +47,38 @@ impl ListArgs {
     ///
     /// Returns an error if the variables cannot be listed.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
         let ios = &factory.io;
 
         let path = if let Some(ref org) = self.org {
             format!("orgs/{org}/actions/variables")
         } else if let Some(ref env) = self.env {
-            let repo = self
-                .repo
-                .as_deref()
-                .ok_or_else(|| anyhow::anyhow!("repository required for environment variables"))?;
-            let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+            let repo = factory.resolve_repo(self.repo.as_deref()).await?;
             format!(
                 "repos/{}/{}/environments/{env}/variables",
                 repo.owner(),
                 repo.name(),
             )
         } else {
-            let repo = self.repo.as_deref().ok_or_else(|| {
-                anyhow::anyhow!("repository argument required (use -R OWNER/REPO)")
-            })?;
-            let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+            let repo = factory.resolve_repo(self.repo.as_deref()).await?;
             format!("repos/{}/{}/actions/variables", repo.owner(), repo.name(),)
         };
+        let path = format!("{path}?per_page=100");
 
-        let result: Value = client
-            .rest(reqwest::Method::GET, &path, None)
-            .await
-            .context("failed to list variables")?;
-
-        // Extract inner array from wrapper object
-        let items = result
-            .get("variables")
-            .cloned()
-            .unwrap_or(Value::Array(vec![]));
+        let items = Value::Array(fetch_variables(&client, &path).await?);
 
         // JSON output
-        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
+        if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() || self.yaml {
             let mut items_owned = items.clone();
             normalize_variable_fields(&mut items_owned);
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &items_owned,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                self.yaml,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -126,6 +115,14 @@ impl ListArgs {
     }
 }
 
+/// Fetch every variable at `path`, following `Link` pages.
+async fn fetch_variables(client: &ghc_api::client::Client, path: &str) -> Result<Vec<Value>> {
+    client
+        .rest_paginate_limit(path, None, |page| page_items(page, Some("variables")))
+        .await
+        .context("failed to list variables")
+}
+
 /// Normalize variable fields to match gh CLI conventions.
 ///
 /// Ensures `visibility` field is present (empty string for repo-level variables),
@@ -133,19 +130,24 @@ impl ListArgs {
 fn normalize_variable_fields(value: &mut Value) {
     if let Some(arr) = value.as_array_mut() {
         for var in arr {
-            if let Some(obj) = var.as_object_mut() {
-                // Ensure visibility is present
-                if !obj.contains_key("visibility") {
-                    obj.insert("visibility".to_string(), Value::String(String::new()));
-                }
-                // Map snake_case -> camelCase
-                if let Some(val) = obj.get("updated_at").cloned() {
-                    obj.insert("updatedAt".to_string(), val);
-                }
-                if let Some(val) = obj.get("created_at").cloned() {
-                    obj.insert("createdAt".to_string(), val);
-                }
-            }
+            normalize_variable(var);
+        }
+    }
+}
+
+/// Normalize a single variable object; see [`normalize_variable_fields`].
+pub(super) fn normalize_variable(var: &mut Value) {
+    if let Some(obj) = var.as_object_mut() {
+        // Ensure visibility is present
+        if !obj.contains_key("visibility") {
+            obj.insert("visibility".to_string(), Value::String(String::new()));
+        }
+        // Map snake_case -> camelCase
+        if let Some(val) = obj.get("updated_at").cloned() {
+            obj.insert("updatedAt".to_string(), val);
+        }
+        if let Some(val) = obj.get("created_at").cloned() {
+            obj.insert("createdAt".to_string(), val);
         }
     }
 }
@@ -179,6 +181,7 @@ mod tests {
             json: vec![],
             jq: None,
             template: None,
+            yaml: false,
         };
         args.run(&h.factory).await.unwrap();
 
@@ -189,4 +192,39 @@ mod tests {
             "should contain variable value"
         );
     }
+
+    #[tokio::test]
+    async fn test_should_output_variables_json() {
+        let h = TestHarness::new().await;
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/actions/variables",
+            serde_json::json!({
+                "total_count": 1,
+                "variables": [
+                    {"name": "NODE_ENV", "value": "production", "updated_at": "2024-01-15T10:00:00Z"}
+                ]
+            }),
+        )
+        .await;
+
+        let args = ListArgs {
+            repo: Some("owner/repo".to_string()),
+            org: None,
+            env: None,
+            json: vec!["name".into(), "value".into(), "updatedAt".into()],
+            jq: None,
+            template: None,
+            yaml: false,
+        };
+        args.run(&h.factory).await.unwrap();
+
+        let out: Value = serde_json::from_str(&h.stdout()).unwrap();
+        assert_eq!(
+            out,
+            serde_json::json!([
+                {"name": "NODE_ENV", "value": "production", "updatedAt": "2024-01-15T10:00:00Z"}
+            ])
+        );
+    }
 }
@@ -52,6 +52,8 @@ impl SetArgs {
     ///
     /// Returns an error if the variable cannot be set.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
+        self.validate()?;
+
         // Batch mode: read variables from .env file
         if let Some(ref env_file) = self.env_file {
             return self.run_batch(factory, env_file).await;
@@ -75,6 +77,23 @@ impl SetArgs {
         self.set_single_variable(factory, name, &var_value).await
     }
 
+    /// Validate flag combinations.
+    fn validate(&self) -> Result<()> {
+        if self.org.is_some() && self.env.is_some() {
+            anyhow::bail!("specify only one of `--org` or `--env`");
+        }
+        if self.body.is_some() && self.env_file.is_some() {
+            anyhow::bail!("specify only one of `--body` or `--env-file`");
+        }
+        if (self.visibility.is_some() || !self.repos.is_empty()) && self.org.is_none() {
+            anyhow::bail!("`--visibility` and `--repos` are only supported with `--org`");
+        }
+        if !self.repos.is_empty() && self.visibility.as_deref().is_some_and(|v| v != "selected") {
+            anyhow::bail!("`--repos` is only supported with `--visibility=selected`");
+        }
+        Ok(())
+    }
+
     /// Set a single variable.
     async fn set_single_variable(
         &self,
@@ -82,19 +101,21 @@ impl SetArgs {
         name: &str,
         var_value: &str,
     ) -> Result<()> {
-        let client = factory.api_client("github.com")?;
+        let client = factory.http_client()?;
 
         let mut body = serde_json::json!({
             "name": name,
             "value": var_value,
         });
 
-        // Add visibility for org variables
+        // Add visibility for org variables, defaulting to "private"
         if self.org.is_some() {
             if let Some(ref vis) = self.visibility {
                 body["visibility"] = Value::String(vis.clone());
             } else if !self.repos.is_empty() {
                 body["visibility"] = Value::String("selected".to_string());
+            } else {
+                body["visibility"] = Value::String("private".to_string());
             }
         }
 
@@ -118,11 +139,7 @@ impl SetArgs {
                 .is_ok();
             (format!("orgs/{org}/actions/variables"), exists)
         } else if let Some(ref env) = self.env {
-            let repo = self
-                .repo
-                .as_deref()
-                .ok_or_else(|| anyhow::anyhow!("repository required for environment variables"))?;
-            let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+            let repo = factory.resolve_repo(self.repo.as_deref()).await?;
             let check_path = format!(
                 "repos/{}/{}/environments/{env}/variables/{name}",
                 repo.owner(),
@@ -141,10 +158,7 @@ impl SetArgs {
                 exists,
             )
         } else {
-            let repo = self.repo.as_deref().ok_or_else(|| {
-                anyhow::anyhow!("repository argument required (use -R OWNER/REPO)")
-            })?;
-            let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+            let repo = factory.resolve_repo(self.repo.as_deref()).await?;
             let check_path = format!(
                 "repos/{}/{}/actions/variables/{name}",
                 repo.owner(),
@@ -189,38 +203,29 @@ impl SetArgs {
     }
 
     /// Batch set variables from a .env file.
+    ///
+    /// The whole file is parsed before anything is sent, so a malformed line
+    /// leaves every variable untouched.
     async fn run_batch(&self, factory: &crate::factory::Factory, env_file: &str) -> Result<()> {
         let content = std::fs::read_to_string(env_file)
             .with_context(|| format!("failed to read env file: {env_file}"))?;
+        let entries = ghc_core::cmdutil::parse_env_file(&content)
+            .with_context(|| format!("invalid env file: {env_file}"))?;
+        if entries.is_empty() {
+            anyhow::bail!("no variables found in {env_file}");
+        }
 
-        let mut count = 0;
-        for line in content.lines() {
-            let line = line.trim();
-
-            // Skip empty lines and comments
-            if line.is_empty() || line.starts_with('#') {
-                continue;
-            }
-
-            if let Some((key, value)) = line.split_once('=') {
-                let key = key.trim();
-                let value = value.trim().trim_matches('"').trim_matches('\'');
-
-                if key.is_empty() {
-                    continue;
-                }
-
-                self.set_single_variable(factory, key, value).await?;
-                count += 1;
-            }
+        for (name, value) in &entries {
+            self.set_single_variable(factory, name, value).await?;
         }
 
         let ios = &factory.io;
         let cs = ios.color_scheme();
         ios_eprintln!(
             ios,
-            "{} Set {count} variable(s) from {env_file}",
+            "{} Set {} variable(s) from {env_file}",
             cs.success_icon(),
+            entries.len(),
         );
 
         Ok(())
@@ -257,3 +262,200 @@ async fn resolve_repo_ids(
     }
     Ok(ids)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    use crate::test_helpers::{TestHarness, mock_rest_get, mock_rest_get_status};
+
+    fn set_args(name: &str, value: &str) -> SetArgs {
+        SetArgs {
+            name: Some(name.into()),
+            repo: None,
+            org: None,
+            env: None,
+            body: Some(value.into()),
+            env_file: None,
+            visibility: None,
+            repos: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_should_create_org_variable_for_selected_repos() {
+        let h = TestHarness::new().await;
+        mock_rest_get_status(
+            &h.server,
+            "/orgs/acme/actions/variables/REGION",
+            404,
+            serde_json::json!({ "message": "Not Found" }),
+        )
+        .await;
+        mock_rest_get(
+            &h.server,
+            "/repos/acme/web",
+            serde_json::json!({ "id": 11 }),
+        )
+        .await;
+        mock_rest_get(
+            &h.server,
+            "/repos/acme/api",
+            serde_json::json!({ "id": 12 }),
+        )
+        .await;
+        Mock::given(method("POST"))
+            .and(path("/orgs/acme/actions/variables"))
+            .and(body_partial_json(serde_json::json!({
+                "name": "REGION",
+                "value": "eu",
+                "visibility": "selected",
+                "selected_repository_ids": [11, 12]
+            })))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let args = SetArgs {
+            org: Some("acme".into()),
+            repos: vec!["web".into(), "api".into()],
+            ..set_args("REGION", "eu")
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert!(h.stderr().contains("Set variable REGION"));
+    }
+
+    #[tokio::test]
+    async fn test_should_default_org_variable_to_private_visibility() {
+        let h = TestHarness::new().await;
+        mock_rest_get_status(
+            &h.server,
+            "/orgs/acme/actions/variables/REGION",
+            404,
+            serde_json::json!({ "message": "Not Found" }),
+        )
+        .await;
+        Mock::given(method("POST"))
+            .and(path("/orgs/acme/actions/variables"))
+            .and(body_partial_json(serde_json::json!({
+                "name": "REGION",
+                "value": "eu",
+                "visibility": "private"
+            })))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let args = SetArgs {
+            org: Some("acme".into()),
+            ..set_args("REGION", "eu")
+        };
+        args.run(&h.factory).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_should_update_existing_environment_variable() {
+        let h = TestHarness::new().await;
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/environments/production/variables/REGION",
+            serde_json::json!({ "name": "REGION", "value": "us" }),
+        )
+        .await;
+        Mock::given(method("PATCH"))
+            .and(path(
+                "/repos/owner/repo/environments/production/variables/REGION",
+            ))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+
+        let args = SetArgs {
+            repo: Some("owner/repo".into()),
+            env: Some("production".into()),
+            ..set_args("REGION", "eu")
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert!(h.stderr().contains("Updated variable REGION"));
+    }
+
+    #[tokio::test]
+    async fn test_should_set_variables_from_env_file() {
+        let h = TestHarness::new().await;
+        for name in ["REGION", "TIER"] {
+            mock_rest_get_status(
+                &h.server,
+                &format!("/repos/owner/repo/actions/variables/{name}"),
+                404,
+                serde_json::json!({ "message": "Not Found" }),
+            )
+            .await;
+        }
+        Mock::given(method("POST"))
+            .and(path("/repos/owner/repo/actions/variables"))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(2)
+            .mount(&h.server)
+            .await;
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join(".env");
+        std::fs::write(&file, "# vars\nREGION=eu\nTIER='gold'\n").unwrap();
+
+        let args = SetArgs {
+            repo: Some("owner/repo".into()),
+            name: None,
+            body: None,
+            env_file: Some(file.display().to_string()),
+            ..set_args("", "")
+        };
+        args.run(&h.factory).await.unwrap();
+
+        assert!(h.stderr().contains("Set 2 variable(s) from"));
+    }
+
+    #[tokio::test]
+    async fn test_should_not_set_variables_when_env_file_is_malformed() {
+        let h = TestHarness::new().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(0)
+            .mount(&h.server)
+            .await;
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join(".env");
+        std::fs::write(&file, "REGION=eu\nTIER\n").unwrap();
+
+        let args = SetArgs {
+            repo: Some("owner/repo".into()),
+            name: None,
+            body: None,
+            env_file: Some(file.display().to_string()),
+            ..set_args("", "")
+        };
+        let err = args.run(&h.factory).await.unwrap_err();
+
+        assert!(
+            format!("{err:#}").contains("line 2: expected KEY=VALUE"),
+            "{err:#}"
+        );
+    }
+
+    #[test]
+    fn test_should_reject_visibility_without_org() {
+        let args = SetArgs {
+            repo: Some("owner/repo".into()),
+            visibility: Some("all".into()),
+            ..set_args("REGION", "eu")
+        };
+        let err = args.validate().unwrap_err();
+        assert!(err.to_string().contains("only supported with `--org`"));
+    }
+}
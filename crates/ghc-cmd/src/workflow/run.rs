@@ -4,11 +4,16 @@ use std::collections::HashMap;
 
 use anyhow::{Context, Result};
 use clap::Args;
+use serde_json::Value;
 
 use ghc_core::ios_eprintln;
 use ghc_core::repo::Repo;
 
 /// Trigger a workflow run.
+///
+/// Inputs declared under the workflow's `workflow_dispatch` trigger that are
+/// not given with `-f`/`-F` are prompted for interactively. Without a
+/// terminal, required inputs that have no default must be provided.
 #[derive(Debug, Args)]
 pub struct RunArgs {
     /// Workflow ID or filename.
@@ -23,10 +28,15 @@ pub struct RunArgs {
     #[arg(short, long, default_value = "main")]
     r#ref: String,
 
-    /// Input parameters as KEY=VALUE pairs.
-    #[arg(short = 'f', long = "field", value_name = "KEY=VALUE")]
+    /// Input parameters as KEY=VALUE pairs, reading the value from a file
+    /// when given as KEY=@FILE.
+    #[arg(short = 'F', long = "field", value_name = "KEY=VALUE")]
     fields: Vec<String>,
 
+    /// Input parameters as KEY=VALUE pairs, taken literally.
+    #[arg(short = 'f', long = "raw-field", value_name = "KEY=VALUE")]
+    raw_fields: Vec<String>,
+
     /// Read input parameters as JSON from stdin.
     #[arg(long)]
     json_input: bool,
@@ -39,11 +49,7 @@ impl RunArgs {
     ///
     /// Returns an error if the workflow cannot be triggered.
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = self
-            .repo
-            .as_deref()
-            .ok_or_else(|| anyhow::anyhow!("repository argument required (use -R OWNER/REPO)"))?;
-        let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
         let client = factory.api_client(repo.host())?;
 
         let mut inputs: HashMap<String, String> = HashMap::new();
@@ -57,12 +63,22 @@ impl RunArgs {
             inputs.extend(json_inputs);
         }
 
-        for field in &self.fields {
-            let (key, value) = field.split_once('=').ok_or_else(|| {
-                anyhow::anyhow!("invalid field format: {field}, expected KEY=VALUE")
-            })?;
+        for field in &self.raw_fields {
+            let (key, value) = split_field(field)?;
             inputs.insert(key.to_string(), value.to_string());
         }
+        for field in &self.fields {
+            let (key, value) = split_field(field)?;
+            let value = match value.strip_prefix('@') {
+                Some(file) => std::fs::read_to_string(file)
+                    .with_context(|| format!("failed to read {file}"))?,
+                None => value.to_string(),
+            };
+            inputs.insert(key.to_string(), value);
+        }
+
+        let declared = self.fetch_declared_inputs(&client, &repo).await;
+        fill_inputs(factory, &declared, &mut inputs)?;
 
         let body = serde_json::json!({
             "ref": self.r#ref,
@@ -93,4 +109,327 @@ impl RunArgs {
 
         Ok(())
     }
+
+    /// The `workflow_dispatch` inputs declared in the workflow file at the
+    /// requested ref, or none if the file cannot be fetched or parsed.
+    async fn fetch_declared_inputs(
+        &self,
+        client: &ghc_api::client::Client,
+        repo: &Repo,
+    ) -> Vec<WorkflowInput> {
+        let workflow_path = format!(
+            "repos/{}/{}/actions/workflows/{}",
+            repo.owner(),
+            repo.name(),
+            self.workflow,
+        );
+        let Ok(workflow) = client
+            .rest::<Value>(reqwest::Method::GET, &workflow_path, None)
+            .await
+        else {
+            return Vec::new();
+        };
+        let Some(file_path) = workflow.get("path").and_then(Value::as_str) else {
+            return Vec::new();
+        };
+        let content_path = format!(
+            "repos/{}/{}/contents/{file_path}?ref={}",
+            repo.owner(),
+            repo.name(),
+            urlencoding::encode(&self.r#ref),
+        );
+        let Ok(content) = client
+            .rest::<Value>(reqwest::Method::GET, &content_path, None)
+            .await
+        else {
+            return Vec::new();
+        };
+        let encoded: String = content
+            .get("content")
+            .and_then(Value::as_str)
+            .unwrap_or("")
+            .split_whitespace()
+            .collect();
+        ghc_core::text::base64_decode(&encoded)
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .map(|yaml| parse_dispatch_inputs(&yaml))
+            .unwrap_or_default()
+    }
+}
+
+/// An input declared under `on.workflow_dispatch.inputs`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct WorkflowInput {
+    name: String,
+    description: String,
+    required: bool,
+    default: Option<String>,
+    /// Input type: `string`, `choice`, `boolean`, `number`, or `environment`.
+    kind: String,
+    /// Allowed values for `choice` inputs.
+    options: Vec<String>,
+}
+
+/// Split a `KEY=VALUE` field argument.
+fn split_field(field: &str) -> Result<(&str, &str)> {
+    field
+        .split_once('=')
+        .ok_or_else(|| anyhow::anyhow!("invalid field format: {field}, expected KEY=VALUE"))
+}
+
+/// Parse the `workflow_dispatch` inputs from a workflow file, in the order
+/// they are declared.
+fn parse_dispatch_inputs(yaml: &str) -> Vec<WorkflowInput> {
+    let Ok(doc) = serde_yaml::from_str::<serde_yaml::Value>(yaml) else {
+        return Vec::new();
+    };
+    let Some(inputs) = doc
+        .get("on")
+        .and_then(|on| on.get("workflow_dispatch"))
+        .and_then(|dispatch| dispatch.get("inputs"))
+        .and_then(serde_yaml::Value::as_mapping)
+    else {
+        return Vec::new();
+    };
+    let scalar = |value: &serde_yaml::Value| match value {
+        serde_yaml::Value::String(s) => Some(s.clone()),
+        serde_yaml::Value::Bool(b) => Some(b.to_string()),
+        serde_yaml::Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    };
+    inputs
+        .iter()
+        .filter_map(|(name, spec)| {
+            let name = name.as_str()?.to_string();
+            let field = |key: &str| spec.get(key).and_then(scalar);
+            Some(WorkflowInput {
+                description: field("description").unwrap_or_default(),
+                required: spec
+                    .get("required")
+                    .and_then(serde_yaml::Value::as_bool)
+                    .unwrap_or(false),
+                default: field("default"),
+                kind: field("type").unwrap_or_else(|| "string".to_string()),
+                options: spec
+                    .get("options")
+                    .and_then(serde_yaml::Value::as_sequence)
+                    .map(|opts| opts.iter().filter_map(scalar).collect())
+                    .unwrap_or_default(),
+                name,
+            })
+        })
+        .collect()
+}
+
+/// Prompt for declared inputs missing from `inputs`, or, when prompting is
+/// not possible, fail if any of them are required and have no default.
+fn fill_inputs(
+    factory: &crate::factory::Factory,
+    declared: &[WorkflowInput],
+    inputs: &mut HashMap<String, String>,
+) -> Result<()> {
+    let missing: Vec<&WorkflowInput> = declared
+        .iter()
+        .filter(|input| !inputs.contains_key(&input.name))
+        .collect();
+
+    if !factory.io.can_prompt() {
+        let required: Vec<&str> = missing
+            .iter()
+            .filter(|input| input.required && input.default.is_none())
+            .map(|input| input.name.as_str())
+            .collect();
+        if !required.is_empty() {
+            anyhow::bail!(
+                "missing required inputs: {} (provide them with -f KEY=VALUE)",
+                required.join(", ")
+            );
+        }
+        return Ok(());
+    }
+
+    let prompter = factory.prompter();
+    for input in missing {
+        let label = if input.description.is_empty() {
+            input.name.clone()
+        } else {
+            format!("{} ({})", input.name, input.description)
+        };
+        let default = input.default.as_deref().unwrap_or("");
+        let value = match input.kind.as_str() {
+            "choice" if !input.options.is_empty() => {
+                let default_idx = input.options.iter().position(|o| o == default);
+                let idx = prompter.select(&label, default_idx, &input.options)?;
+                input.options[idx].clone()
+            }
+            "boolean" => prompter.confirm(&label, default == "true")?.to_string(),
+            _ => prompter.input(&label, default)?,
+        };
+        if value.is_empty() && input.required {
+            anyhow::bail!("input {} is required", input.name);
+        }
+        if !value.is_empty() {
+            inputs.insert(input.name.clone(), value);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wiremock::matchers::{body_json, method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    use crate::test_helpers::{TestHarness, mock_rest_get};
+
+    const WORKFLOW_YAML: &str = r"
+name: Deploy
+on:
+  workflow_dispatch:
+    inputs:
+      environment:
+        description: Target environment
+        type: choice
+        required: true
+        options: [staging, production]
+        default: staging
+      dry_run:
+        type: boolean
+        default: true
+      version:
+        description: Version to deploy
+        required: true
+jobs: {}
+";
+
+    fn run_args(fields: &[&str]) -> RunArgs {
+        RunArgs {
+            workflow: "deploy.yml".into(),
+            repo: Some("owner/repo".into()),
+            r#ref: "main".into(),
+            fields: vec![],
+            raw_fields: fields.iter().map(ToString::to_string).collect(),
+            json_input: false,
+        }
+    }
+
+    async fn mount_workflow(h: &TestHarness) {
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/actions/workflows/deploy.yml",
+            serde_json::json!({ "path": ".github/workflows/deploy.yml" }),
+        )
+        .await;
+        mock_rest_get(
+            &h.server,
+            "/repos/owner/repo/contents/.github/workflows/deploy.yml",
+            serde_json::json!({
+                "content": ghc_core::text::base64_encode(WORKFLOW_YAML.as_bytes())
+            }),
+        )
+        .await;
+    }
+
+    async fn expect_dispatch(h: &TestHarness, inputs: serde_json::Value) {
+        Mock::given(method("POST"))
+            .and(path(
+                "/repos/owner/repo/actions/workflows/deploy.yml/dispatches",
+            ))
+            .and(body_json(
+                serde_json::json!({ "ref": "main", "inputs": inputs }),
+            ))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&h.server)
+            .await;
+    }
+
+    #[test]
+    fn test_should_parse_dispatch_inputs_in_order() {
+        let inputs = parse_dispatch_inputs(WORKFLOW_YAML);
+        let names: Vec<&str> = inputs.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, ["environment", "dry_run", "version"]);
+        assert_eq!(inputs[0].kind, "choice");
+        assert_eq!(inputs[0].options, ["staging", "production"]);
+        assert_eq!(inputs[1].default.as_deref(), Some("true"));
+        assert!(inputs[2].required);
+    }
+
+    #[tokio::test]
+    async fn test_should_prompt_for_declared_inputs() {
+        let mut h = TestHarness::new().await;
+        h.factory.io.set_stdin_tty(true);
+        h.factory.io.set_stdout_tty(true);
+        h.factory.io.set_never_prompt(false);
+        h.prompter.select_answers.lock().unwrap().push(1);
+        h.prompter.confirm_answers.lock().unwrap().push(false);
+        h.prompter
+            .input_answers
+            .lock()
+            .unwrap()
+            .push("1.2.0".into());
+        mount_workflow(&h).await;
+        expect_dispatch(
+            &h,
+            serde_json::json!({
+                "environment": "production",
+                "dry_run": "false",
+                "version": "1.2.0"
+            }),
+        )
+        .await;
+
+        run_args(&[]).run(&h.factory).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_should_use_fields_without_prompting() {
+        let h = TestHarness::new().await;
+        mount_workflow(&h).await;
+        expect_dispatch(&h, serde_json::json!({ "version": "1.2.0" })).await;
+
+        run_args(&["version=1.2.0"]).run(&h.factory).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_should_require_inputs_without_defaults_when_not_interactive() {
+        let h = TestHarness::new().await;
+        mount_workflow(&h).await;
+
+        let err = run_args(&[]).run(&h.factory).await.unwrap_err();
+
+        assert!(
+            err.to_string().contains("missing required inputs: version"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_should_match_gh_field_flags() {
+        #[derive(clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: RunArgs,
+        }
+        use clap::Parser;
+
+        let cli = Cli::try_parse_from([
+            "run",
+            "deploy.yml",
+            "-F",
+            "notes=@notes.md",
+            "--field",
+            "env=prod",
+            "-f",
+            "version=@1.2.0",
+            "--raw-field",
+            "dry=true",
+        ])
+        .unwrap();
+        assert_eq!(cli.args.fields, ["notes=@notes.md", "env=prod"]);
+        assert_eq!(cli.args.raw_fields, ["version=@1.2.0", "dry=true"]);
+    }
 }
@@ -5,7 +5,6 @@ use clap::Args;
 use serde_json::Value;
 
 use ghc_core::ios_println;
-use ghc_core::repo::Repo;
 use ghc_core::table::TablePrinter;
 use ghc_core::text;
 
@@ -49,11 +48,7 @@ impl ViewArgs {
     /// Returns an error if the workflow cannot be viewed.
     #[allow(clippy::too_many_lines)]
     pub async fn run(&self, factory: &crate::factory::Factory) -> Result<()> {
-        let repo = self
-            .repo
-            .as_deref()
-            .ok_or_else(|| anyhow::anyhow!("repository argument required (use -R OWNER/REPO)"))?;
-        let repo = Repo::from_full_name(repo).context("invalid repository format")?;
+        let repo = factory.resolve_repo(self.repo.as_deref()).await?;
 
         if self.web {
             let url = format!(
@@ -85,14 +80,14 @@ impl ViewArgs {
 
         // JSON output
         if !self.json.is_empty() || self.jq.is_some() || self.template.is_some() {
-            let output = ghc_core::json::format_json_output(
+            ghc_core::json::output(
                 &wf,
                 &self.json,
                 self.jq.as_deref(),
                 self.template.as_deref(),
-            )
-            .context("failed to format JSON output")?;
-            ios_println!(ios, "{output}");
+                false,
+                ios,
+            )?;
             return Ok(());
         }
 
@@ -0,0 +1,96 @@
+//! Clipboard integration for copying text.
+//!
+//! Shells out to the platform clipboard tool (`pbcopy`, `xclip`/`xsel`,
+//! or `clip`), mirroring how the browser integration launches commands.
+
+/// Trait for copying text to a clipboard.
+pub trait Clipboard: Send + Sync + std::fmt::Debug {
+    /// Replace the clipboard contents with `text`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no clipboard tool is available or it fails.
+    fn copy(&self, text: &str) -> anyhow::Result<()>;
+}
+
+/// System clipboard implementation using platform tools.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClipboard;
+
+impl Clipboard for SystemClipboard {
+    fn copy(&self, text: &str) -> anyhow::Result<()> {
+        #[cfg(target_os = "macos")]
+        {
+            return pipe_to("pbcopy", &[], text);
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            return pipe_to("xclip", &["-selection", "clipboard"], text)
+                .or_else(|_| pipe_to("xsel", &["--clipboard", "--input"], text));
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            return pipe_to("clip", &[], text);
+        }
+
+        #[allow(unreachable_code)]
+        {
+            let _ = text;
+            anyhow::bail!("clipboard not supported on this platform")
+        }
+    }
+}
+
+/// Run `program` with `text` written to its stdin.
+#[cfg(any(target_os = "macos", target_os = "linux", target_os = "windows"))]
+fn pipe_to(program: &str, args: &[&str], text: &str) -> anyhow::Result<()> {
+    use std::io::Write;
+
+    let mut child = std::process::Command::new(program)
+        .args(args)
+        .stdin(std::process::Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(text.as_bytes())?;
+    }
+    let status = child.wait()?;
+    if !status.success() {
+        anyhow::bail!("{program} exited with {status}");
+    }
+    Ok(())
+}
+
+/// Stub clipboard for testing that records copied text.
+#[derive(Debug, Default)]
+pub struct StubClipboard {
+    /// Text that was "copied", oldest first.
+    pub contents: std::sync::Mutex<Vec<String>>,
+}
+
+impl Clipboard for StubClipboard {
+    fn copy(&self, text: &str) -> anyhow::Result<()> {
+        let mut contents = self
+            .contents
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        contents.push(text.to_string());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_record_copies_in_stub() {
+        let clipboard = StubClipboard::default();
+        clipboard.copy("git status").unwrap();
+        clipboard.copy("ls -la").unwrap();
+
+        let contents = clipboard.contents.lock().unwrap();
+        assert_eq!(*contents, ["git status", "ls -la"]);
+    }
+}
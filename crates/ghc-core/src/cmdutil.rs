@@ -2,6 +2,13 @@
 //!
 //! Maps from Go's `pkg/cmdutil` package.
 
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::ios_eprintln;
+
 /// Error indicating user cancelled an operation.
 #[derive(Debug, thiserror::Error)]
 #[error("user cancelled")]
@@ -82,6 +89,178 @@ pub fn check_auth<C: crate::config::Config + ?Sized>(config: &C) -> bool {
     !config.hosts().is_empty()
 }
 
+/// Resolve body text from the `--body` and `--body-file` flags.
+///
+/// Returns `None` when neither flag was given. A `body_file` of `-` reads
+/// from standard input.
+///
+/// # Errors
+///
+/// Returns a [`FlagError`] if both flags are given, or an error if the file
+/// or standard input cannot be read.
+pub fn read_body(body: Option<String>, body_file: Option<&Path>) -> anyhow::Result<Option<String>> {
+    match (body, body_file) {
+        (Some(_), Some(_)) => {
+            Err(FlagError("specify only one of `--body` or `--body-file`".to_string()).into())
+        }
+        (Some(body), None) => Ok(Some(body)),
+        (None, Some(path)) => read_body_file(path).map(Some),
+        (None, None) => Ok(None),
+    }
+}
+
+/// Read body text from a file path or standard input (`-`).
+///
+/// # Errors
+///
+/// Returns an error if the file or standard input cannot be read.
+pub fn read_body_file(path: &Path) -> anyhow::Result<String> {
+    if path == Path::new("-") {
+        use std::io::Read;
+        let mut buf = String::new();
+        std::io::stdin()
+            .read_to_string(&mut buf)
+            .context("failed to read from stdin")?;
+        Ok(buf)
+    } else {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read file: {}", path.display()))
+    }
+}
+
+/// Simple glob matching supporting `*` wildcards.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let parts: Vec<&str> = pattern.split('*').collect();
+
+    if parts.len() == 1 {
+        return pattern == text;
+    }
+
+    let mut remaining = text;
+
+    for (i, part) in parts.iter().enumerate() {
+        if part.is_empty() {
+            continue;
+        }
+        if i == 0 {
+            if !remaining.starts_with(part) {
+                return false;
+            }
+            remaining = &remaining[part.len()..];
+        } else if i == parts.len() - 1 {
+            if !remaining.ends_with(part) {
+                return false;
+            }
+            return true;
+        } else {
+            match remaining.find(part) {
+                Some(pos) => remaining = &remaining[pos + part.len()..],
+                None => return false,
+            }
+        }
+    }
+
+    true
+}
+
+/// Parse `KEY=value` lines from a .env file, skipping blank lines and
+/// `#` comments.
+///
+/// An optional `export ` prefix is accepted and values may be wrapped in
+/// single or double quotes. Every malformed line is reported with its line
+/// number.
+pub fn parse_env_file(content: &str) -> anyhow::Result<Vec<(String, String)>> {
+    let mut entries = Vec::new();
+    let mut errors = Vec::new();
+    for (idx, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let Some((key, value)) = line.split_once('=') else {
+            errors.push(format!("line {}: expected KEY=VALUE", idx + 1));
+            continue;
+        };
+        let key = key.trim();
+        if !is_valid_env_name(key) {
+            errors.push(format!("line {}: invalid name {key:?}", idx + 1));
+            continue;
+        }
+        entries.push((key.to_string(), unquote(value.trim()).to_string()));
+    }
+    if !errors.is_empty() {
+        anyhow::bail!("{}", errors.join("\n"));
+    }
+    Ok(entries)
+}
+
+/// Strip one pair of matching surrounding quotes.
+fn unquote(value: &str) -> &str {
+    ['"', '\'']
+        .iter()
+        .find_map(|&q| value.strip_prefix(q)?.strip_suffix(q))
+        .unwrap_or(value)
+}
+
+/// Whether `name` is a valid secret or variable name: alphanumerics and underscores, not
+/// starting with a digit.
+fn is_valid_env_name(name: &str) -> bool {
+    name.chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Title and body saved when a create request fails, reloaded by `--recover`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RecoveryInput {
+    /// Title entered for the issue or pull request.
+    pub title: String,
+    /// Body entered for the issue or pull request.
+    pub body: String,
+}
+
+impl RecoveryInput {
+    /// Load input saved by a failed create run.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let data = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read recovery file: {}", path.display()))?;
+        serde_json::from_str(&data)
+            .with_context(|| format!("invalid recovery file: {}", path.display()))
+    }
+
+    /// The recovered body, if any.
+    pub fn body_if_set(&self) -> Option<&str> {
+        Some(self.body.as_str()).filter(|b| !b.is_empty())
+    }
+
+    /// Write the input to a new file under `<state_dir>/recovery`.
+    pub fn save(&self, state_dir: &Path, kind: &str) -> anyhow::Result<PathBuf> {
+        let dir = state_dir.join("recovery");
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("failed to create {}", dir.display()))?;
+        let stamp = chrono::Utc::now().format("%Y%m%d%H%M%S%f");
+        let path = dir.join(format!("{kind}-create-{stamp}.json"));
+        std::fs::write(&path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("failed to write {}", path.display()))?;
+        Ok(path)
+    }
+
+    /// Save the input after a failed create and tell the user how to recover it.
+    pub fn report(&self, ios: &crate::iostreams::IOStreams, state_dir: &Path, kind: &str) {
+        match self.save(state_dir, kind) {
+            Ok(path) => ios_eprintln!(
+                ios,
+                "Your input was saved to {}\nRecover it with: ghc {kind} create --recover {}",
+                path.display(),
+                path.display(),
+            ),
+            Err(err) => ios_eprintln!(ios, "failed to save input for recovery: {err:#}"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -136,6 +315,38 @@ mod tests {
         assert!(!is_user_cancellation(&err));
     }
 
+    #[test]
+    fn test_should_read_inline_body() {
+        let body = read_body(Some("hello".to_string()), None).unwrap();
+        assert_eq!(body.as_deref(), Some("hello"));
+    }
+
+    #[test]
+    fn test_should_read_body_from_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("body.md");
+        std::fs::write(&path, "# Title\n\nfrom file").unwrap();
+        let body = read_body(None, Some(&path)).unwrap();
+        assert_eq!(body.as_deref(), Some("# Title\n\nfrom file"));
+    }
+
+    #[test]
+    fn test_should_return_none_without_body_flags() {
+        assert!(read_body(None, None).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_should_reject_body_and_body_file_together() {
+        let err = read_body(Some("x".to_string()), Some(Path::new("-"))).unwrap_err();
+        assert!(err.downcast_ref::<FlagError>().is_some());
+    }
+
+    #[test]
+    fn test_should_report_missing_body_file() {
+        let err = read_body(None, Some(Path::new("/nonexistent/body.md"))).unwrap_err();
+        assert!(err.to_string().contains("/nonexistent/body.md"));
+    }
+
     #[test]
     fn test_should_determine_editor_from_config() {
         let _guards = [EnvVarGuard::unset("GH_EDITOR")];
@@ -177,4 +388,88 @@ mod tests {
         cfg.set("github.com", "oauth_token", "token").unwrap();
         assert!(check_auth(&cfg));
     }
+
+    #[test]
+    fn test_should_match_exact_pattern() {
+        assert!(glob_match("foo.tar.gz", "foo.tar.gz"));
+        assert!(!glob_match("foo.tar.gz", "bar.tar.gz"));
+    }
+
+    #[test]
+    fn test_should_match_wildcard_prefix() {
+        assert!(glob_match("*.tar.gz", "foo.tar.gz"));
+        assert!(glob_match("*.tar.gz", "bar.tar.gz"));
+        assert!(!glob_match("*.tar.gz", "foo.zip"));
+    }
+
+    #[test]
+    fn test_should_match_wildcard_suffix() {
+        assert!(glob_match("foo*", "foo.tar.gz"));
+        assert!(glob_match("foo*", "foobar"));
+        assert!(!glob_match("foo*", "barfoo"));
+    }
+
+    #[test]
+    fn test_should_match_wildcard_middle() {
+        assert!(glob_match("foo*bar", "foobar"));
+        assert!(glob_match("foo*bar", "foo-something-bar"));
+        assert!(!glob_match("foo*bar", "foo-baz"));
+    }
+
+    #[test]
+    fn test_should_match_star_only() {
+        assert!(glob_match("*", "anything"));
+        assert!(glob_match("*", ""));
+    }
+
+    #[test]
+    fn test_should_match_surrounding_wildcards() {
+        assert!(glob_match("*-linux-*", "build-linux-amd64"));
+        assert!(!glob_match("*-linux-*", "build-macos-arm64"));
+    }
+
+    #[test]
+    fn test_should_match_multiple_wildcards() {
+        assert!(glob_match("*linux*amd64*", "myapp-linux-amd64.tar.gz"));
+        assert!(!glob_match("*linux*amd64*", "myapp-darwin-arm64.tar.gz"));
+    }
+
+    #[test]
+    fn test_should_parse_env_file() {
+        let content = "# comment\n\nexport API_KEY=abc\nTOKEN=\"quoted value\"\nEMPTY=\n";
+        let entries = parse_env_file(content).unwrap();
+        assert_eq!(
+            entries,
+            [
+                ("API_KEY".to_string(), "abc".to_string()),
+                ("TOKEN".to_string(), "quoted value".to_string()),
+                ("EMPTY".to_string(), String::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_should_report_malformed_env_lines() {
+        let err = parse_env_file("OK=1\nnot a pair\n1BAD=x\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2: expected KEY=VALUE\nline 3: invalid name \"1BAD\""
+        );
+    }
+
+    #[test]
+    fn test_should_round_trip_recovery_input() {
+        let state = tempfile::tempdir().unwrap();
+        let path = RecoveryInput {
+            title: "Title".to_string(),
+            body: String::new(),
+        }
+        .save(state.path(), "pr")
+        .unwrap();
+        assert!(path.starts_with(state.path().join("recovery")));
+
+        let loaded = RecoveryInput::load(&path).unwrap();
+        assert_eq!(loaded.title, "Title");
+        assert_eq!(loaded.body_if_set(), None);
+    }
 }
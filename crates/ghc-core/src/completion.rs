@@ -0,0 +1,196 @@
+//! Dynamic shell completion hooks.
+//!
+//! Maps from the `ValidArgsFunction`/`RegisterFlagCompletionFunc` hooks the
+//! Go CLI wires into cobra's hidden `__complete` command. Commands register
+//! [`Completer`]s for the flags whose values come from the API (labels,
+//! branches, repositories); the shell script calls back into the binary
+//! whenever one of those flags is being completed.
+
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::repo::Repo;
+
+/// Future returned by [`Completer::complete`].
+pub type CompletionFuture<'a> =
+    Pin<Box<dyn Future<Output = anyhow::Result<Vec<String>>> + Send + 'a>>;
+
+/// The value being completed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletionRequest {
+    /// The flag whose value is being completed, e.g. `--add-label`.
+    pub flag: String,
+    /// The partial value typed so far.
+    pub prefix: String,
+    /// The repository the command line resolves to, if any.
+    pub repo: Option<Repo>,
+}
+
+/// Source of completion candidates for one or more flags.
+///
+/// `C` is the context the candidates are fetched with, typically an API
+/// client.
+pub trait Completer<C>: Send + Sync {
+    /// The flags (e.g. `--label`) whose values this completer provides.
+    fn flags(&self) -> &[&'static str];
+
+    /// Fetch the candidates for a request.
+    ///
+    /// Candidates need not be filtered by the prefix; see [`filter_candidates`].
+    fn complete<'a>(&'a self, ctx: &'a C, request: &'a CompletionRequest) -> CompletionFuture<'a>;
+}
+
+/// Registry of completers, looked up by flag.
+pub struct Completers<C> {
+    completers: Vec<Box<dyn Completer<C>>>,
+}
+
+impl<C> Default for Completers<C> {
+    fn default() -> Self {
+        Self {
+            completers: Vec::new(),
+        }
+    }
+}
+
+impl<C> std::fmt::Debug for Completers<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.flags()).finish()
+    }
+}
+
+impl<C> Completers<C> {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a completer for the flags it declares.
+    pub fn register(&mut self, completer: impl Completer<C> + 'static) {
+        self.completers.push(Box::new(completer));
+    }
+
+    /// Find the completer for a flag.
+    pub fn find(&self, flag: &str) -> Option<&dyn Completer<C>> {
+        self.completers
+            .iter()
+            .find(|c| c.flags().contains(&flag))
+            .map(AsRef::as_ref)
+    }
+
+    /// Every flag with a registered completer, in registration order.
+    pub fn flags(&self) -> Vec<&'static str> {
+        self.completers
+            .iter()
+            .flat_map(|c| c.flags().iter().copied())
+            .collect()
+    }
+}
+
+/// Split the words of a command line into the flag being completed and the
+/// partial value.
+///
+/// The last word is the one under the cursor. Handles both `--flag value`
+/// and `--flag=value`; returns `None` when the cursor is not on a flag value.
+pub fn pending_flag(words: &[String]) -> Option<(&str, &str)> {
+    let (current, before) = words.split_last()?;
+    if let Some(rest) = current.strip_prefix("--")
+        && let Some((name, value)) = rest.split_once('=')
+    {
+        return Some((&current[..name.len() + 2], value));
+    }
+    let previous = before.last()?;
+    (previous.starts_with('-') && !previous.contains('=')).then_some((previous, current))
+}
+
+/// The value of `-R`/`--repo` on a command line, if given.
+pub fn repo_flag(words: &[String]) -> Option<&str> {
+    words.iter().enumerate().find_map(|(i, word)| {
+        if let Some(value) = word.strip_prefix("--repo=") {
+            return Some(value);
+        }
+        if word == "-R" || word == "--repo" {
+            return words.get(i + 1).map(String::as_str);
+        }
+        None
+    })
+}
+
+/// Keep the candidates starting with `prefix`, sorted and deduplicated.
+pub fn filter_candidates(candidates: Vec<String>, prefix: &str) -> Vec<String> {
+    let mut matched: Vec<String> = candidates
+        .into_iter()
+        .filter(|c| c.starts_with(prefix))
+        .collect();
+    matched.sort();
+    matched.dedup();
+    matched
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    struct Labels;
+
+    impl Completer<Vec<String>> for Labels {
+        fn flags(&self) -> &[&'static str] {
+            &["--label", "--add-label"]
+        }
+
+        fn complete<'a>(
+            &'a self,
+            ctx: &'a Vec<String>,
+            _request: &'a CompletionRequest,
+        ) -> CompletionFuture<'a> {
+            Box::pin(async move { Ok(ctx.clone()) })
+        }
+    }
+
+    fn words(line: &[&str]) -> Vec<String> {
+        line.iter().map(ToString::to_string).collect()
+    }
+
+    #[rstest]
+    #[case(&["issue", "edit", "1", "--add-label", "bu"], Some(("--add-label", "bu")))]
+    #[case(&["issue", "edit", "1", "--add-label", ""], Some(("--add-label", "")))]
+    #[case(&["issue", "edit", "--add-label=bu"], Some(("--add-label", "bu")))]
+    #[case(&["issue", "list", "-R", "own"], Some(("-R", "own")))]
+    #[case(&["issue", "edit", "1", ""], None)]
+    #[case(&["issue", "edit", "--web=true", "x"], None)]
+    #[case(&[], None)]
+    fn test_should_find_pending_flag(
+        #[case] line: &[&str],
+        #[case] expected: Option<(&str, &str)>,
+    ) {
+        assert_eq!(pending_flag(&words(line)), expected);
+    }
+
+    #[rstest]
+    #[case(&["issue", "edit", "-R", "owner/repo", "--label", ""], Some("owner/repo"))]
+    #[case(&["issue", "edit", "--repo=owner/repo", "--label", ""], Some("owner/repo"))]
+    #[case(&["issue", "edit", "--label", ""], None)]
+    fn test_should_find_repo_flag(#[case] line: &[&str], #[case] expected: Option<&str>) {
+        assert_eq!(repo_flag(&words(line)), expected);
+    }
+
+    #[test]
+    fn test_should_filter_candidates_by_prefix() {
+        let candidates = words(&["bug", "docs", "bug", "build"]);
+        assert_eq!(
+            filter_candidates(candidates, "bu"),
+            words(&["bug", "build"])
+        );
+    }
+
+    #[test]
+    fn test_should_find_completer_by_flag() {
+        let mut completers = Completers::new();
+        completers.register(Labels);
+
+        assert!(completers.find("--add-label").is_some());
+        assert!(completers.find("--repo").is_none());
+        assert_eq!(completers.flags(), vec!["--label", "--add-label"]);
+    }
+}
@@ -8,7 +8,7 @@ use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
 
-use super::{AuthConfig, Config, config_dir, default_for_key};
+use super::{AuthConfig, CONFIG_OPTIONS, Config, config_dir, default_for_key};
 use crate::errors::ConfigError;
 
 /// File-based configuration backed by YAML files.
@@ -37,6 +37,9 @@ struct ConfigData {
     http_unix_socket: Option<String>,
     #[serde(default)]
     aliases: HashMap<String, String>,
+    /// Known keys without a dedicated field, such as `color_labels`.
+    #[serde(flatten)]
+    extra: HashMap<String, serde_yaml::Value>,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -63,6 +66,11 @@ struct HostsFile {
     hosts: HashMap<String, HostConfig>,
 }
 
+/// Whether `key` is a known global key stored in [`ConfigData::extra`].
+fn is_extra_key(key: &str) -> bool {
+    key == "accessibility.screen_reader" || CONFIG_OPTIONS.iter().any(|o| o.key == key)
+}
+
 impl FileConfig {
     /// Load configuration from disk.
     ///
@@ -144,7 +152,12 @@ impl FileConfig {
             "pager" => self.global.pager.clone(),
             "browser" => self.global.browser.clone(),
             "http_unix_socket" => self.global.http_unix_socket.clone(),
-            _ => None,
+            _ => match self.global.extra.get(key)? {
+                serde_yaml::Value::String(s) => Some(s.clone()),
+                serde_yaml::Value::Bool(b) => Some(b.to_string()),
+                serde_yaml::Value::Number(n) => Some(n.to_string()),
+                _ => None,
+            },
         }
     }
 }
@@ -182,6 +195,12 @@ impl Config for FileConfig {
                 "pager" => self.global.pager = Some(value.to_string()),
                 "browser" => self.global.browser = Some(value.to_string()),
                 "http_unix_socket" => self.global.http_unix_socket = Some(value.to_string()),
+                _ if is_extra_key(key) => {
+                    self.global.extra.insert(
+                        key.to_string(),
+                        serde_yaml::Value::String(value.to_string()),
+                    );
+                }
                 _ => {}
             }
         } else {
@@ -196,6 +215,30 @@ impl Config for FileConfig {
         Ok(())
     }
 
+    fn unset(&mut self, hostname: &str, key: &str) -> anyhow::Result<()> {
+        if hostname.is_empty() {
+            match key {
+                "git_protocol" => self.global.git_protocol = None,
+                "editor" => self.global.editor = None,
+                "prompt" => self.global.prompt = None,
+                "pager" => self.global.pager = None,
+                "browser" => self.global.browser = None,
+                "http_unix_socket" => self.global.http_unix_socket = None,
+                _ => {
+                    self.global.extra.remove(key);
+                }
+            }
+        } else if let Some(host) = self.hosts.get_mut(hostname) {
+            match key {
+                "oauth_token" => host.oauth_token = None,
+                "user" => host.user = None,
+                "git_protocol" => host.git_protocol = None,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
     fn aliases(&self) -> &HashMap<String, String> {
         &self.aliases
     }
@@ -297,39 +340,75 @@ impl AuthConfig for FileConfig {
         token: &str,
         git_protocol: &str,
         secure_storage: bool,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<bool> {
         let host = self.hosts.entry(hostname.to_string()).or_default();
+
+        // Keep the previously active account around so it can be switched back to
+        if let Some(previous) = host.user.clone()
+            && previous != username
+            && let Some(previous_token) = host.oauth_token.clone()
+        {
+            host.users.entry(previous).or_default().oauth_token = Some(previous_token);
+        }
+
         host.user = Some(username.to_string());
         if !git_protocol.is_empty() {
             host.git_protocol = Some(git_protocol.to_string());
         }
 
-        if secure_storage {
-            // Try keyring first; on success, omit token from config file
-            let keyring_ok = crate::keyring_store::store_token_for_user(hostname, username, token)
-                .is_ok()
-                && crate::keyring_store::store_token(hostname, token).is_ok();
+        // Try keyring first; on success, omit token from config file
+        let in_keyring = secure_storage
+            && crate::keyring_store::store_token_for_user(hostname, username, token).is_ok()
+            && crate::keyring_store::store_token(hostname, token).is_ok();
 
-            if keyring_ok {
-                // Token is in keyring — do not persist to config file
-                host.oauth_token = None;
-            } else {
-                // Keyring unavailable — fall back to config file
-                host.oauth_token = Some(token.to_string());
-            }
+        if in_keyring {
+            // Token is in keyring — do not persist to config file
+            host.oauth_token = None;
         } else {
             host.oauth_token = Some(token.to_string());
         }
+        host.users
+            .entry(username.to_string())
+            .or_default()
+            .oauth_token = host.oauth_token.clone();
 
-        self.write()
+        self.write()?;
+        Ok(in_keyring)
     }
 
     fn logout(&mut self, hostname: &str, username: &str) -> anyhow::Result<()> {
         // Clean up keyring entries (ignore errors — keyring may not be available)
         let _ = crate::keyring_store::delete_token_for_user(hostname, username);
-        let _ = crate::keyring_store::delete_token(hostname);
 
-        self.hosts.remove(hostname);
+        let Some(host) = self.hosts.get_mut(hostname) else {
+            return self.write();
+        };
+        host.users.remove(username);
+
+        if host.user.as_deref() == Some(username) {
+            host.user = None;
+            host.oauth_token = None;
+
+            // Fall back to the first remaining account in name order
+            if let Some(next) = host.users.keys().min().cloned() {
+                host.oauth_token = host.users.get(&next).and_then(|e| e.oauth_token.clone());
+                if host.oauth_token.is_none() {
+                    match crate::keyring_store::get_token_for_user(hostname, &next) {
+                        Ok(Some(token)) => {
+                            let _ = crate::keyring_store::store_token(hostname, &token);
+                        }
+                        _ => {
+                            let _ = crate::keyring_store::delete_token(hostname);
+                        }
+                    }
+                }
+                host.user = Some(next);
+            } else {
+                let _ = crate::keyring_store::delete_token(hostname);
+                self.hosts.remove(hostname);
+            }
+        }
+
         self.write()
     }
 
@@ -485,6 +564,50 @@ mod tests {
         assert!(cfg.get("", "unknown_key").is_none());
     }
 
+    #[test]
+    fn test_should_set_and_unset_known_option_without_field() {
+        let mut cfg = FileConfig::empty();
+        cfg.set("", "color_labels", "enabled").unwrap();
+        cfg.set("", "accessibility.screen_reader", "true").unwrap();
+        assert_eq!(cfg.get("", "color_labels"), Some("enabled".to_string()));
+        assert_eq!(
+            cfg.get("", "accessibility.screen_reader"),
+            Some("true".to_string())
+        );
+
+        cfg.unset("", "color_labels").unwrap();
+        assert!(cfg.get("", "color_labels").is_none());
+    }
+
+    #[test]
+    fn test_should_unset_global_key() {
+        let mut cfg = FileConfig::empty();
+        cfg.set("", "editor", "vim").unwrap();
+        cfg.unset("", "editor").unwrap();
+        assert!(cfg.get("", "editor").is_none());
+    }
+
+    #[test]
+    fn test_should_unset_host_key_and_fall_back_to_global() {
+        let mut cfg = FileConfig::empty();
+        cfg.set("", "git_protocol", "https").unwrap();
+        cfg.set("github.com", "git_protocol", "ssh").unwrap();
+        cfg.unset("github.com", "git_protocol").unwrap();
+        assert_eq!(
+            cfg.get("github.com", "git_protocol"),
+            Some("https".to_string()),
+        );
+    }
+
+    #[test]
+    fn test_should_ignore_unsetting_missing_key() {
+        let mut cfg = FileConfig::empty();
+        cfg.unset("", "editor").unwrap();
+        cfg.unset("example.com", "git_protocol").unwrap();
+        assert!(cfg.get("", "editor").is_none());
+        assert!(Config::hosts(&cfg).is_empty());
+    }
+
     // --- Host-specific config ---
 
     #[test]
@@ -593,6 +716,38 @@ mod tests {
         assert!(AuthConfig::hosts(&cfg).is_empty());
     }
 
+    #[test]
+    fn test_should_keep_other_accounts_when_logging_out_inactive_user() {
+        let mut cfg = FileConfig::empty();
+        cfg.login("github.com", "user1", "token1", "https", false)
+            .unwrap();
+        cfg.login("github.com", "user2", "token2", "https", false)
+            .unwrap();
+
+        cfg.logout("github.com", "user1").unwrap();
+        let auth = cfg.authentication();
+        assert_eq!(auth.active_user("github.com"), Some("user2".to_string()));
+        assert_eq!(auth.users_for_host("github.com"), vec!["user2".to_string()]);
+    }
+
+    #[test]
+    fn test_should_activate_remaining_user_when_logging_out_active_user() {
+        let mut cfg = FileConfig::empty();
+        cfg.login("github.com", "user1", "token1", "https", false)
+            .unwrap();
+        cfg.login("github.com", "user2", "token2", "https", false)
+            .unwrap();
+
+        cfg.logout("github.com", "user2").unwrap();
+        let auth = cfg.authentication();
+        assert_eq!(auth.active_user("github.com"), Some("user1".to_string()));
+        assert_eq!(
+            auth.token_for_user("github.com", "user1"),
+            Some(("token1".to_string(), "config".to_string())),
+        );
+        assert_eq!(auth.users_for_host("github.com"), vec!["user1".to_string()]);
+    }
+
     #[test]
     fn test_should_login_sets_git_protocol() {
         let mut cfg = FileConfig::empty();
@@ -86,6 +86,15 @@ impl Config for MemoryConfig {
         Ok(())
     }
 
+    fn unset(&mut self, hostname: &str, key: &str) -> anyhow::Result<()> {
+        if hostname.is_empty() {
+            self.global.remove(key);
+        } else if let Some(settings) = self.host_settings.get_mut(hostname) {
+            settings.remove(key);
+        }
+        Ok(())
+    }
+
     fn aliases(&self) -> &HashMap<String, String> {
         &self.aliases
     }
@@ -138,8 +147,8 @@ impl AuthConfig for MemoryConfig {
         username: &str,
         token: &str,
         git_protocol: &str,
-        _secure_storage: bool,
-    ) -> anyhow::Result<()> {
+        secure_storage: bool,
+    ) -> anyhow::Result<bool> {
         let entry = self
             .auth
             .entry(hostname.to_string())
@@ -154,7 +163,8 @@ impl AuthConfig for MemoryConfig {
                 .insert("git_protocol".to_string(), git_protocol.to_string());
         }
 
-        Ok(())
+        // The in-memory store stands in for the keyring whenever it is requested
+        Ok(secure_storage)
     }
 
     fn logout(&mut self, hostname: &str, username: &str) -> anyhow::Result<()> {
@@ -163,8 +173,8 @@ impl AuthConfig for MemoryConfig {
             if users.is_empty() {
                 self.auth.remove(hostname);
             } else if active_user == username {
-                // Switch to the first remaining user
-                if let Some(next_user) = users.keys().next().cloned() {
+                // Switch to the first remaining user in name order
+                if let Some(next_user) = users.keys().min().cloned() {
                     *active_user = next_user;
                 }
             }
@@ -258,6 +268,18 @@ mod tests {
         assert!(cfg.get("", "git_protocol").is_none());
     }
 
+    #[test]
+    fn test_should_unset_value() {
+        let mut cfg = MemoryConfig::new();
+        cfg.set("", "editor", "vim").unwrap();
+        cfg.set("github.com", "editor", "nano").unwrap();
+        cfg.unset("github.com", "editor").unwrap();
+        assert_eq!(cfg.get("github.com", "editor"), Some("vim".to_string()));
+        cfg.unset("", "editor").unwrap();
+        assert!(cfg.get("", "editor").is_none());
+        cfg.unset("", "editor").unwrap();
+    }
+
     #[test]
     fn test_should_prefer_host_specific_over_global() {
         let mut cfg = MemoryConfig::new();
@@ -28,19 +28,71 @@ pub fn config_dir() -> std::path::PathBuf {
 }
 
 /// State directory path (usually same as config dir).
+///
+/// Lives in the config dir when running isolated (see [`is_isolated`]).
 pub fn state_dir() -> std::path::PathBuf {
-    if let Ok(dir) = std::env::var("GH_STATE_DIR") {
-        return std::path::PathBuf::from(dir);
-    }
-    dirs::state_dir().map_or_else(config_dir, |d| d.join("gh"))
+    resolve_dir(
+        env_lookup,
+        "GH_STATE_DIR",
+        dirs::state_dir,
+        std::path::PathBuf::new(),
+    )
 }
 
 /// Data directory path.
+///
+/// Lives in the config dir when running isolated (see [`is_isolated`]).
 pub fn data_dir() -> std::path::PathBuf {
-    if let Ok(dir) = std::env::var("GH_DATA_DIR") {
+    resolve_dir(
+        env_lookup,
+        "GH_DATA_DIR",
+        dirs::data_dir,
+        std::path::PathBuf::new(),
+    )
+}
+
+/// Directory where extensions are installed.
+pub fn extensions_dir() -> std::path::PathBuf {
+    config_dir().join("extensions")
+}
+
+/// Whether all ghc directories should live under `GH_CONFIG_DIR`.
+///
+/// Set `GH_ISOLATED` (to anything but `0` or `false`) together with
+/// `GH_CONFIG_DIR` to keep state, data, and cache out of the platform
+/// directories, e.g. for tests and sandboxes.
+pub fn is_isolated() -> bool {
+    isolated(env_lookup)
+}
+
+fn env_lookup(key: &str) -> Option<String> {
+    std::env::var(key).ok()
+}
+
+fn isolated(env: impl Fn(&str) -> Option<String>) -> bool {
+    env("GH_CONFIG_DIR").is_some()
+        && env("GH_ISOLATED").is_some_and(|v| !matches!(v.as_str(), "" | "0" | "false"))
+}
+
+/// Resolve a ghc directory from its env var, the isolated config dir, or the
+/// platform directory, falling back to the config dir.
+///
+/// `isolated_subdir` is joined onto the config dir in isolated mode.
+fn resolve_dir(
+    env: impl Fn(&str) -> Option<String>,
+    var: &str,
+    platform_dir: impl FnOnce() -> Option<std::path::PathBuf>,
+    isolated_subdir: std::path::PathBuf,
+) -> std::path::PathBuf {
+    if let Some(dir) = env(var) {
         return std::path::PathBuf::from(dir);
     }
-    dirs::data_dir().map_or_else(config_dir, |d| d.join("gh"))
+    if isolated(&env)
+        && let Some(config) = env("GH_CONFIG_DIR")
+    {
+        return std::path::PathBuf::from(config).join(isolated_subdir);
+    }
+    platform_dir().map_or_else(config_dir, |d| d.join("gh"))
 }
 
 /// Configuration trait for accessing settings.
@@ -58,6 +110,14 @@ pub trait Config: Send + Sync + std::fmt::Debug {
     /// Returns an error if the configuration cannot be updated.
     fn set(&mut self, hostname: &str, key: &str, value: &str) -> anyhow::Result<()>;
 
+    /// Remove a config value so the default applies again. Empty hostname
+    /// means global. Removing a key that is not set is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration cannot be updated.
+    fn unset(&mut self, hostname: &str, key: &str) -> anyhow::Result<()>;
+
     /// Get the git protocol preference for a host.
     fn git_protocol(&self, hostname: &str) -> String {
         self.get_or_default(hostname, "git_protocol")
@@ -127,6 +187,9 @@ pub trait AuthConfig: Send + Sync + std::fmt::Debug {
     /// file if the keyring is unavailable. When `false`, the token is stored
     /// only in the config file.
     ///
+    /// Returns whether the token ended up in the keyring, so callers can tell
+    /// the user when it was written to the config file instead.
+    ///
     /// # Errors
     ///
     /// Returns an error if credentials cannot be stored.
@@ -137,7 +200,7 @@ pub trait AuthConfig: Send + Sync + std::fmt::Debug {
         token: &str,
         git_protocol: &str,
         secure_storage: bool,
-    ) -> anyhow::Result<()>;
+    ) -> anyhow::Result<bool>;
 
     /// Remove authentication credentials.
     ///
@@ -167,11 +230,16 @@ pub trait AuthConfig: Send + Sync + std::fmt::Debug {
 }
 
 /// Cache directory path.
+///
+/// Lives in a `cache` subdirectory of the config dir when running isolated
+/// (see [`is_isolated`]), so clearing it never touches config files.
 pub fn cache_dir() -> std::path::PathBuf {
-    if let Ok(dir) = std::env::var("GH_CACHE_DIR") {
-        return std::path::PathBuf::from(dir);
-    }
-    dirs::cache_dir().map_or_else(config_dir, |d| d.join("gh"))
+    resolve_dir(
+        env_lookup,
+        "GH_CACHE_DIR",
+        dirs::cache_dir,
+        std::path::PathBuf::from("cache"),
+    )
 }
 
 /// Known configuration keys with descriptions and allowed values.
@@ -226,6 +294,12 @@ pub static CONFIG_OPTIONS: &[ConfigOption] = &[
         allowed_values: &["enabled", "disabled"],
         default_value: "disabled",
     },
+    ConfigOption {
+        key: "accent_color",
+        description: "a hex color (#rrggbb) used for table headers and other emphasis",
+        allowed_values: &[],
+        default_value: "",
+    },
     ConfigOption {
         key: "accessible_colors",
         description: "whether customizable, 4-bit accessible colors should be used",
@@ -342,4 +416,71 @@ mod tests {
         let dir = data_dir();
         assert_eq!(dir, std::path::PathBuf::from("/tmp/test-gh-data"));
     }
+
+    fn fake_env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect();
+        move |key| vars.get(key).cloned()
+    }
+
+    #[test]
+    fn test_should_place_dirs_under_config_dir_when_isolated() {
+        let env = fake_env(&[("GH_CONFIG_DIR", "/tmp/gh"), ("GH_ISOLATED", "1")]);
+        assert_eq!(
+            resolve_dir(
+                &env,
+                "GH_STATE_DIR",
+                || Some("/platform".into()),
+                std::path::PathBuf::new()
+            ),
+            std::path::PathBuf::from("/tmp/gh")
+        );
+        assert_eq!(
+            resolve_dir(
+                &env,
+                "GH_CACHE_DIR",
+                || Some("/platform".into()),
+                "cache".into()
+            ),
+            std::path::PathBuf::from("/tmp/gh/cache")
+        );
+    }
+
+    #[test]
+    fn test_should_prefer_specific_dir_var_when_isolated() {
+        let env = fake_env(&[
+            ("GH_CONFIG_DIR", "/tmp/gh"),
+            ("GH_ISOLATED", "true"),
+            ("GH_DATA_DIR", "/tmp/data"),
+        ]);
+        assert_eq!(
+            resolve_dir(
+                &env,
+                "GH_DATA_DIR",
+                || Some("/platform".into()),
+                std::path::PathBuf::new()
+            ),
+            std::path::PathBuf::from("/tmp/data")
+        );
+    }
+
+    #[rstest]
+    #[case(&[("GH_CONFIG_DIR", "/tmp/gh")])]
+    #[case(&[("GH_CONFIG_DIR", "/tmp/gh"), ("GH_ISOLATED", "0")])]
+    #[case(&[("GH_CONFIG_DIR", "/tmp/gh"), ("GH_ISOLATED", "false")])]
+    #[case(&[("GH_ISOLATED", "1")])]
+    fn test_should_use_platform_dir_when_not_isolated(#[case] vars: &[(&str, &str)]) {
+        let env = fake_env(vars);
+        assert_eq!(
+            resolve_dir(
+                &env,
+                "GH_STATE_DIR",
+                || Some("/platform".into()),
+                std::path::PathBuf::new()
+            ),
+            std::path::PathBuf::from("/platform/gh")
+        );
+    }
 }
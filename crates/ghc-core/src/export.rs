@@ -1,8 +1,8 @@
-//! Export utilities for `--jq` and `--template` flags.
+//! Export utilities for `--jq`, `--template`, and `--yaml` flags.
 //!
-//! Provides real jq filtering via the `jaq` crate and basic Go-template-style
+//! Provides real jq filtering via the `jaq` crate, basic Go-template-style
 //! formatting for JSON output, matching the Go CLI's `--jq` and `--template`
-//! behavior.
+//! behavior, and YAML serialization.
 
 use anyhow::{Context, Result};
 use serde_json::Value;
@@ -26,13 +26,24 @@ pub fn apply_jq_filter(value: &Value, expression: &str) -> Result<String> {
 
     // Parse the filter expression
     let (filter, errs) = jaq_parse::parse(expression, jaq_parse::main());
-    if !errs.is_empty() {
-        let err_msgs: Vec<String> = errs.iter().map(|e| format!("{e}")).collect();
-        anyhow::bail!("failed to parse jq expression: {}", err_msgs.join(", "));
+    if let Some(err) = errs.first() {
+        anyhow::bail!(
+            "failed to parse jq expression: {}",
+            point_at(expression, &err.span(), &err.to_string())
+        );
     }
 
     let filter = filter.context("failed to parse jq expression")?;
     let filter = defs.compile(filter);
+    if let Some((err, span)) = defs.errs.first() {
+        let name = expression
+            .get(byte_range(expression, span))
+            .unwrap_or_default();
+        anyhow::bail!(
+            "failed to compile jq expression: {}",
+            point_at(expression, span, &format!("{err} `{name}`"))
+        );
+    }
 
     let inputs = RcIter::new(core::iter::empty());
     let out = filter.run((Ctx::new([], &inputs), Val::from(value.clone())));
@@ -61,6 +72,29 @@ pub fn apply_jq_filter(value: &Value, expression: &str) -> Result<String> {
     Ok(results.join("\n"))
 }
 
+/// Describe an error in a jq expression with a caret under the offending token.
+///
+/// Spans from the jq parser count characters, not bytes.
+fn point_at(expression: &str, span: &std::ops::Range<usize>, message: &str) -> String {
+    let column = span.start.min(expression.chars().count());
+    format!(
+        "{message} at position {}\n  {expression}\n  {}^",
+        column + 1,
+        " ".repeat(column),
+    )
+}
+
+/// Convert a character span from the jq parser into a byte range of `expression`.
+fn byte_range(expression: &str, span: &std::ops::Range<usize>) -> std::ops::Range<usize> {
+    let byte = |chars: usize| {
+        expression
+            .char_indices()
+            .nth(chars)
+            .map_or(expression.len(), |(i, _)| i)
+    };
+    byte(span.start)..byte(span.end)
+}
+
 /// Apply a Go-template-style expression to a JSON value.
 ///
 /// Supports a subset of Go template syntax:
@@ -69,6 +103,7 @@ pub fn apply_jq_filter(value: &Value, expression: &str) -> Result<String> {
 /// - `{{range .array}}...{{end}}` - iterate arrays
 /// - `{{.}}` - current value
 /// - `{{tablerow .field1 .field2}}` - tab-separated fields (per gh CLI)
+/// - `{{timeago .createdAt}}` - RFC 3339 timestamp as relative time
 /// - Plain text is passed through as-is
 ///
 /// # Errors
@@ -124,6 +159,9 @@ pub fn apply_template(value: &Value, template: &str) -> Result<String> {
                     parts.push(value_to_string(&val));
                 }
                 output.push_str(&parts.join("\t"));
+            } else if let Some(field) = expr.strip_prefix("timeago ") {
+                let val = resolve_path(value, field.trim())?;
+                output.push_str(&timeago(&value_to_string(&val)));
             } else if expr == "." {
                 output.push_str(&value_to_string(value));
             } else if expr.starts_with('.') {
@@ -150,6 +188,31 @@ pub fn apply_template(value: &Value, template: &str) -> Result<String> {
     Ok(output)
 }
 
+/// Render an RFC 3339 timestamp as a relative time such as "3 days ago".
+///
+/// Values that do not parse as a timestamp are returned unchanged.
+fn timeago(timestamp: &str) -> String {
+    chrono::DateTime::parse_from_rfc3339(timestamp).map_or_else(
+        |_| timestamp.to_string(),
+        |t| {
+            let t = t.with_timezone(&chrono::Utc);
+            crate::text::fuzzy_ago(chrono::Utc::now().signed_duration_since(t))
+        },
+    )
+}
+
+/// Serialize a JSON value as a YAML document.
+///
+/// The trailing newline is trimmed so callers can print with `ios_println!`.
+///
+/// # Errors
+///
+/// Returns an error if YAML serialization fails.
+pub fn to_yaml(value: &Value) -> Result<String> {
+    let yaml = serde_yaml::to_string(value).context("failed to serialize YAML")?;
+    Ok(yaml.trim_end().to_string())
+}
+
 /// Unescape a Go string literal, handling common escape sequences.
 fn unescape_go_string(s: &str) -> String {
     let mut result = String::with_capacity(s.len());
@@ -193,6 +256,37 @@ fn resolve_path(value: &Value, path: &str) -> Result<Value> {
     Ok(current.clone())
 }
 
+/// Look up a dotted field path like `author.login` on a JSON value.
+///
+/// Used by `--json` field selection. Unlike template path resolution, a
+/// missing key at any level yields `null` instead of an error, and numeric
+/// segments index into arrays.
+///
+/// # Examples
+///
+/// ```
+/// use serde_json::json;
+/// use ghc_core::export::lookup_dotted_path;
+///
+/// let issue = json!({"author": {"login": "octocat"}});
+/// assert_eq!(lookup_dotted_path(&issue, "author.login"), json!("octocat"));
+/// assert_eq!(lookup_dotted_path(&issue, "milestone.title"), json!(null));
+/// ```
+pub fn lookup_dotted_path(value: &Value, path: &str) -> Value {
+    let mut current = value;
+    for part in path.split('.').filter(|p| !p.is_empty()) {
+        let next = match current {
+            Value::Array(arr) => part.parse::<usize>().ok().and_then(|i| arr.get(i)),
+            other => other.get(part),
+        };
+        match next {
+            Some(v) => current = v,
+            None => return Value::Null,
+        }
+    }
+    current.clone()
+}
+
 /// Convert a JSON value to a display string (without quotes for strings).
 fn value_to_string(value: &Value) -> String {
     match value {
@@ -209,6 +303,53 @@ mod tests {
     use super::*;
     use serde_json::json;
 
+    // --- YAML tests ---
+
+    #[test]
+    fn test_should_serialize_object_to_yaml() {
+        let val = json!({"number": 1, "title": "Bug", "labels": ["a", "b"]});
+        let yaml = to_yaml(&val).unwrap();
+        assert!(yaml.contains("number: 1"));
+        assert!(yaml.contains("title: Bug"));
+        assert!(yaml.contains("- a"));
+        assert!(!yaml.ends_with('\n'));
+    }
+
+    #[test]
+    fn test_should_serialize_array_to_yaml() {
+        let val = json!([{"name": "bug"}, {"name": "docs"}]);
+        let yaml = to_yaml(&val).unwrap();
+        assert_eq!(yaml, "- name: bug\n- name: docs");
+    }
+
+    // --- dotted path lookup tests ---
+
+    #[test]
+    fn test_should_lookup_dotted_path() {
+        let val = json!({"author": {"login": "octocat", "profile": {"name": "Octo"}}});
+        assert_eq!(lookup_dotted_path(&val, "author.login"), json!("octocat"));
+        assert_eq!(
+            lookup_dotted_path(&val, "author.profile.name"),
+            json!("Octo")
+        );
+        assert_eq!(lookup_dotted_path(&val, "author"), val["author"]);
+    }
+
+    #[test]
+    fn test_should_lookup_dotted_path_missing_intermediate_as_null() {
+        let val = json!({"author": null, "milestone": {"title": "v1"}});
+        assert_eq!(lookup_dotted_path(&val, "author.login"), Value::Null);
+        assert_eq!(lookup_dotted_path(&val, "assignee.login"), Value::Null);
+        assert_eq!(lookup_dotted_path(&val, "milestone.due.on"), Value::Null);
+    }
+
+    #[test]
+    fn test_should_lookup_dotted_path_array_index() {
+        let val = json!({"labels": [{"name": "bug"}, {"name": "docs"}]});
+        assert_eq!(lookup_dotted_path(&val, "labels.1.name"), json!("docs"));
+        assert_eq!(lookup_dotted_path(&val, "labels.5.name"), Value::Null);
+    }
+
     // --- jq filter tests ---
 
     #[test]
@@ -285,6 +426,55 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_should_apply_jq_pipeline_with_select() {
+        let val = json!([
+            {"state": "open", "title": "first"},
+            {"state": "closed", "title": "second"},
+            {"state": "open", "title": "third"}
+        ]);
+        let result = apply_jq_filter(&val, r#".[] | select(.state=="open") | .title"#).unwrap();
+        assert_eq!(result, "first\nthird");
+    }
+
+    #[test]
+    fn test_should_apply_jq_map_and_interpolation() {
+        let val = json!([{"number": 1, "title": "a"}, {"number": 2, "title": "b"}]);
+        assert_eq!(apply_jq_filter(&val, "map(.number) | add").unwrap(), "3");
+        assert_eq!(
+            apply_jq_filter(&val, r##".[] | "#\(.number) \(.title)""##).unwrap(),
+            "#1 a\n#2 b"
+        );
+    }
+
+    #[test]
+    fn test_should_point_at_offending_jq_token() {
+        let err = apply_jq_filter(&json!({}), ".a | )")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("at position 6"), "{err}");
+        assert!(err.ends_with("  .a | )\n       ^"), "{err}");
+    }
+
+    #[test]
+    fn test_should_report_undefined_jq_filter() {
+        let err = apply_jq_filter(&json!({}), ".a | nosuchfilter")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("undefined filter `nosuchfilter`"), "{err}");
+        assert!(err.contains("at position 6"), "{err}");
+    }
+
+    #[test]
+    fn test_should_report_undefined_jq_filter_after_multibyte_text() {
+        let err = apply_jq_filter(&json!({}), r#""é" | nosuchfilter"#)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("undefined filter `nosuchfilter`"), "{err}");
+        assert!(err.contains("at position 7"), "{err}");
+        assert!(err.ends_with("  \"é\" | nosuchfilter\n        ^"), "{err}");
+    }
+
     // --- template tests ---
 
     #[test]
@@ -315,6 +505,23 @@ mod tests {
         assert_eq!(result, "test\t42");
     }
 
+    #[test]
+    fn test_should_apply_template_timeago() {
+        let val = json!({"createdAt": "2001-02-03T04:05:06Z", "name": "x"});
+        let result = apply_template(&val, "{{timeago .createdAt}}").unwrap();
+        assert!(result.ends_with("years ago"), "got: {result}");
+
+        let result = apply_template(&val, "{{timeago .name}}").unwrap();
+        assert_eq!(result, "x");
+    }
+
+    #[test]
+    fn test_should_apply_template_range_over_root_array() {
+        let val = json!([{"tagName": "v1.0.0"}, {"tagName": "v0.9.0"}]);
+        let result = apply_template(&val, r#"{{range .}}{{.tagName}}{{"\n"}}{{end}}"#).unwrap();
+        assert_eq!(result, "v1.0.0\nv0.9.0\n");
+    }
+
     #[test]
     fn test_should_apply_template_escape_sequences() {
         let val = json!({"a": 1});
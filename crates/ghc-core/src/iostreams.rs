@@ -79,6 +79,120 @@ impl TestOutput {
     }
 }
 
+/// Cloneable handle to the stderr writer of an [`IOStreams`].
+///
+/// Lets components that outlive a borrow of `IOStreams`, such as API
+/// clients, report progress to the same (possibly captured) stderr.
+#[derive(Debug, Clone)]
+pub struct StderrHandle(Arc<Mutex<OutputWriter>>);
+
+impl StderrHandle {
+    /// Write a string to stderr followed by a newline.
+    pub fn println(&self, s: &str) {
+        let mut w = self
+            .0
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let _ = writeln!(w, "{s}");
+    }
+}
+
+/// Braille frames for the progress spinner; the last entry is the final frame.
+const SPINNER_FRAMES: &[&str] = &["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷", ""];
+
+/// Interval between spinner frames.
+const SPINNER_TICK: std::time::Duration = std::time::Duration::from_millis(120);
+
+/// Handle to a running progress indicator.
+///
+/// The spinner is cleared from the terminal when the handle is dropped.
+/// Handles for non-interactive streams are inert.
+#[derive(Debug)]
+pub struct ProgressHandle(Option<indicatif::ProgressBar>);
+
+impl ProgressHandle {
+    /// Replace the label shown next to the spinner.
+    pub fn set_label(&self, label: &str) {
+        if let Some(ref bar) = self.0 {
+            bar.set_message(label.to_string());
+        }
+    }
+
+    /// Whether a spinner is actually being rendered.
+    pub fn is_active(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// Hide the spinner while `f` runs, so it can print to stderr without
+    /// the output being interleaved with spinner frames.
+    pub fn suspend<R>(&self, f: impl FnOnce() -> R) -> R {
+        if let Some(ref bar) = self.0 {
+            bar.suspend(f)
+        } else {
+            f()
+        }
+    }
+
+    /// Stop and clear the spinner.
+    pub fn stop(self) {}
+}
+
+impl Drop for ProgressHandle {
+    fn drop(&mut self) {
+        if let Some(bar) = self.0.take() {
+            bar.finish_and_clear();
+        }
+    }
+}
+
+/// A set of progress spinners rendered together on stderr, one per item.
+///
+/// Groups for non-interactive streams are inert, as are the spinners added
+/// to them.
+#[derive(Debug)]
+pub struct ProgressGroup(Option<indicatif::MultiProgress>);
+
+impl ProgressGroup {
+    /// Add a spinner with `label` beside it below the existing ones.
+    ///
+    /// The spinner is removed from the group when its handle is dropped.
+    pub fn add(&self, label: &str) -> ProgressHandle {
+        ProgressHandle(self.0.as_ref().map(|multi| {
+            let bar = multi.add(spinner(label));
+            bar.enable_steady_tick(SPINNER_TICK);
+            bar
+        }))
+    }
+
+    /// Whether the spinners are actually being rendered.
+    pub fn is_active(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// Hide every spinner while `f` runs, so it can print to stderr without
+    /// the output being interleaved with spinner frames.
+    pub fn suspend<R>(&self, f: impl FnOnce() -> R) -> R {
+        if let Some(ref multi) = self.0 {
+            multi.suspend(f)
+        } else {
+            f()
+        }
+    }
+}
+
+/// An undrawn spinner with `label` beside it.
+fn spinner(label: &str) -> indicatif::ProgressBar {
+    let bar =
+        indicatif::ProgressBar::with_draw_target(None, indicatif::ProgressDrawTarget::hidden());
+    bar.set_style(
+        indicatif::ProgressStyle::with_template("{spinner} {msg}")
+            .unwrap_or_else(|_| indicatif::ProgressStyle::default_spinner())
+            .tick_strings(SPINNER_FRAMES),
+    );
+    bar.set_message(label.to_string());
+    bar
+}
+
 /// I/O streams for terminal interaction.
 ///
 /// Wraps stdin, stdout, and stderr with TTY detection, color support,
@@ -95,10 +209,11 @@ pub struct IOStreams {
 
     // Color
     color_forced: Option<bool>,
-    color_256: bool,
-    true_color: bool,
+    color_support: ColorSupport,
     color_labels: bool,
     accessible_colors: bool,
+    accent_color: Option<String>,
+    hyperlinks: bool,
 
     // Pager
     pager_cmd: Option<String>,
@@ -111,6 +226,9 @@ pub struct IOStreams {
     never_prompt: bool,
     accessible_prompter: bool,
 
+    // Screen-reader mode
+    accessible: bool,
+
     // Output writers (capturable in test mode)
     out: Arc<Mutex<OutputWriter>>,
     err: Arc<Mutex<OutputWriter>>,
@@ -134,24 +252,24 @@ impl IOStreams {
         let stdin_is_tty = io::stdin().is_terminal();
         let stdout_is_tty = io::stdout().is_terminal();
         let stderr_is_tty = io::stderr().is_terminal();
-        let term = Term::stdout();
-        let color_256 = term.features().colors_supported();
-        let true_color = term.features().colors_supported();
+        let color_support = ColorSupport::detect(|key| std::env::var(key).ok());
 
         Self {
             stdin_is_tty,
             stdout_is_tty,
             stderr_is_tty,
             color_forced: std::env::var("NO_COLOR").ok().map(|_| false),
-            color_256,
-            true_color,
+            color_support,
             color_labels: false,
             accessible_colors: false,
+            accent_color: None,
+            hyperlinks: stdout_is_tty && hyperlinks_supported(|key| std::env::var(key).ok()),
             pager_cmd: None,
             pager_process: Mutex::new(None),
             spinner_disabled: false,
             never_prompt: false,
             accessible_prompter: false,
+            accessible: false,
             out: Arc::new(Mutex::new(OutputWriter(Box::new(io::stdout())))),
             err: Arc::new(Mutex::new(OutputWriter(Box::new(io::stderr())))),
         }
@@ -167,15 +285,17 @@ impl IOStreams {
             stdout_is_tty: false,
             stderr_is_tty: false,
             color_forced: Some(false),
-            color_256: false,
-            true_color: false,
+            color_support: ColorSupport::None,
             color_labels: false,
             accessible_colors: false,
+            accent_color: None,
+            hyperlinks: false,
             pager_cmd: None,
             pager_process: Mutex::new(None),
             spinner_disabled: true,
             never_prompt: true,
             accessible_prompter: false,
+            accessible: false,
             out: Arc::new(Mutex::new(OutputWriter(Box::new(io::stdout())))),
             err: Arc::new(Mutex::new(OutputWriter(Box::new(io::stderr())))),
         }
@@ -202,15 +322,17 @@ impl IOStreams {
             stdout_is_tty: false,
             stderr_is_tty: false,
             color_forced: Some(false),
-            color_256: false,
-            true_color: false,
+            color_support: ColorSupport::None,
             color_labels: false,
             accessible_colors: false,
+            accent_color: None,
+            hyperlinks: false,
             pager_cmd: None,
             pager_process: Mutex::new(None),
             spinner_disabled: true,
             never_prompt: true,
             accessible_prompter: false,
+            accessible: false,
             out: Arc::new(Mutex::new(OutputWriter(Box::new(SharedWriter(
                 out_buf.clone(),
             ))))),
@@ -259,6 +381,15 @@ impl IOStreams {
         let _ = w.write_all(s.as_bytes());
     }
 
+    /// Flush stdout so partial lines (e.g. streamed output) become visible.
+    pub fn flush_out(&self) {
+        let mut w = self
+            .out
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let _ = w.flush();
+    }
+
     /// Write a string to stderr followed by a newline.
     pub fn println_err(&self, s: &str) {
         let mut w = self
@@ -315,6 +446,11 @@ impl IOStreams {
         let _ = w.write_all(b"\n");
     }
 
+    /// Get a cloneable handle to stderr.
+    pub fn stderr_handle(&self) -> StderrHandle {
+        StderrHandle(Arc::clone(&self.err))
+    }
+
     // --- Query methods ---
 
     /// Whether stdin is connected to a terminal.
@@ -334,20 +470,54 @@ impl IOStreams {
 
     /// Whether color output is enabled.
     pub fn color_enabled(&self) -> bool {
+        if self.accessible {
+            return false;
+        }
         if let Some(forced) = self.color_forced {
             return forced;
         }
         self.stdout_is_tty
     }
 
+    /// Force color output on or off, overriding TTY detection.
+    pub fn set_color_enabled(&mut self, enabled: bool) {
+        self.color_forced = Some(enabled);
+    }
+
+    /// The color capability in effect for stdout.
+    ///
+    /// `None` when color is disabled; at least `Basic` when it is forced on.
+    pub fn color_support(&self) -> ColorSupport {
+        if self.color_enabled() {
+            self.color_support.max(ColorSupport::Basic)
+        } else {
+            ColorSupport::None
+        }
+    }
+
+    /// Override the detected terminal color capability.
+    pub fn set_color_support(&mut self, support: ColorSupport) {
+        self.color_support = support;
+    }
+
     /// Whether 256-color mode is supported.
     pub fn color_support_256(&self) -> bool {
-        self.color_enabled() && self.color_256
+        self.color_support() >= ColorSupport::Ansi256
     }
 
     /// Whether true color (24-bit) is supported.
     pub fn true_color_support(&self) -> bool {
-        self.color_enabled() && self.true_color
+        self.color_support() == ColorSupport::TrueColor
+    }
+
+    /// The configured `#rrggbb` accent color, if any.
+    pub fn accent_color(&self) -> Option<&str> {
+        self.accent_color.as_deref()
+    }
+
+    /// Set the `#rrggbb` accent color used for emphasis.
+    pub fn set_accent_color(&mut self, hex: Option<String>) {
+        self.accent_color = hex;
     }
 
     /// Whether label coloring is enabled.
@@ -370,6 +540,16 @@ impl IOStreams {
         self.accessible_colors = enabled;
     }
 
+    /// Whether OSC 8 terminal hyperlinks may be emitted on stdout.
+    pub fn hyperlinks_enabled(&self) -> bool {
+        self.hyperlinks && !self.accessible
+    }
+
+    /// Set hyperlink support.
+    pub fn set_hyperlinks_enabled(&mut self, enabled: bool) {
+        self.hyperlinks = enabled;
+    }
+
     /// Set the pager command.
     pub fn set_pager(&mut self, cmd: impl Into<String>) {
         let cmd = cmd.into();
@@ -441,6 +621,39 @@ impl IOStreams {
         self.spinner_disabled = disabled;
     }
 
+    /// Start an unlabeled progress spinner on stderr.
+    pub fn start_progress_indicator(&self) -> ProgressHandle {
+        self.start_progress_indicator_with_label("")
+    }
+
+    /// Start a progress spinner on stderr with `label` beside it.
+    ///
+    /// Renders only when stderr is a TTY and the spinner is not disabled.
+    pub fn start_progress_indicator_with_label(&self, label: &str) -> ProgressHandle {
+        if !self.renders_progress() {
+            return ProgressHandle(None);
+        }
+        let bar = spinner(label);
+        bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
+        bar.enable_steady_tick(SPINNER_TICK);
+        ProgressHandle(Some(bar))
+    }
+
+    /// Start an empty group of progress spinners on stderr, for reporting
+    /// several concurrent tasks one line each.
+    ///
+    /// Renders only when stderr is a TTY and the spinner is not disabled.
+    pub fn start_progress_group(&self) -> ProgressGroup {
+        ProgressGroup(self.renders_progress().then(|| {
+            indicatif::MultiProgress::with_draw_target(indicatif::ProgressDrawTarget::stderr())
+        }))
+    }
+
+    /// Whether progress spinners should be drawn on stderr.
+    fn renders_progress(&self) -> bool {
+        self.stderr_is_tty && !self.spinner_disabled && !self.accessible
+    }
+
     /// Whether prompts should never be shown.
     pub fn never_prompt(&self) -> bool {
         self.never_prompt
@@ -451,6 +664,39 @@ impl IOStreams {
         self.never_prompt = never;
     }
 
+    /// Apply the user's prompt preference.
+    ///
+    /// In order of precedence: an explicit `--prompt`/`--no-prompt` flag,
+    /// `GH_PROMPT_DISABLED` (any value other than empty, `0`, or `false`),
+    /// and the `prompt` config value (`disabled` turns prompts off).
+    pub fn apply_prompt_preference(
+        &mut self,
+        flag: Option<bool>,
+        env_disabled: Option<&str>,
+        config: &str,
+    ) {
+        let env_disabled = env_disabled
+            .map(|v| !matches!(v.trim().to_ascii_lowercase().as_str(), "" | "0" | "false"));
+        self.never_prompt = match (flag, env_disabled) {
+            (Some(enabled), _) => !enabled,
+            (None, Some(disabled)) => disabled,
+            (None, None) => config == "disabled",
+        };
+    }
+
+    /// Whether screen-reader mode is on.
+    ///
+    /// Output then avoids color, spinners, padded tables, and markdown
+    /// styling in favor of plain linear text.
+    pub fn is_accessible(&self) -> bool {
+        self.accessible
+    }
+
+    /// Turn screen-reader mode on or off.
+    pub fn set_accessible(&mut self, enabled: bool) {
+        self.accessible = enabled;
+    }
+
     /// Whether the accessible prompter is enabled.
     pub fn accessible_prompter_enabled(&self) -> bool {
         self.accessible_prompter
@@ -471,6 +717,16 @@ impl IOStreams {
         }
     }
 
+    /// Get the width of the terminal stderr writes to, or the default if not a TTY.
+    pub fn stderr_terminal_width(&self) -> usize {
+        if self.stderr_is_tty {
+            let term = Term::stderr();
+            term.size().1 as usize
+        } else {
+            DEFAULT_WIDTH
+        }
+    }
+
     /// Check if interactive mode is available (stdin and stdout are TTY, prompts not disabled).
     pub fn can_prompt(&self) -> bool {
         self.stdin_is_tty && self.stdout_is_tty && !self.never_prompt
@@ -480,78 +736,210 @@ impl IOStreams {
     pub fn color_scheme(&self) -> ColorScheme {
         ColorScheme {
             enabled: self.color_enabled(),
+            support: self.color_support(),
+            accent: self.accent_color.as_deref().and_then(parse_hex_color),
         }
     }
 }
 
+/// Detect whether the terminal supports OSC 8 hyperlinks from its environment.
+///
+/// `FORCE_HYPERLINK` overrides detection (`0` disables). Otherwise known
+/// terminal emulators are recognized via `TERM_PROGRAM`, `TERM`,
+/// `VTE_VERSION`, `WT_SESSION`, and `KONSOLE_VERSION`.
+fn hyperlinks_supported(env: impl Fn(&str) -> Option<String>) -> bool {
+    if let Some(force) = env("FORCE_HYPERLINK") {
+        return force != "0";
+    }
+    if env("CI").is_some() {
+        return false;
+    }
+    if env("WT_SESSION").is_some() || env("KONSOLE_VERSION").is_some() {
+        return true;
+    }
+    if let Some(vte) = env("VTE_VERSION") {
+        // VTE gained hyperlink support in 0.50.
+        return vte.parse::<u32>().is_ok_and(|v| v >= 5000);
+    }
+    if let Some(program) = env("TERM_PROGRAM")
+        && matches!(
+            program.as_str(),
+            "iTerm.app" | "WezTerm" | "vscode" | "ghostty" | "Hyper"
+        )
+    {
+        return true;
+    }
+    env("TERM").is_some_and(|term| matches!(term.as_str(), "xterm-kitty" | "alacritty"))
+}
+
+/// Color capability of a terminal, from least to most capable.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorSupport {
+    /// No color escapes.
+    #[default]
+    None,
+    /// The eight basic ANSI colors.
+    Basic,
+    /// The xterm 256-color palette.
+    Ansi256,
+    /// 24-bit RGB color.
+    TrueColor,
+}
+
+impl ColorSupport {
+    /// Detect color capability from `NO_COLOR`, `COLORTERM`, and `TERM`.
+    pub fn detect(env: impl Fn(&str) -> Option<String>) -> Self {
+        if env("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+            return Self::None;
+        }
+        if env("COLORTERM").is_some_and(|v| matches!(v.as_str(), "truecolor" | "24bit")) {
+            return Self::TrueColor;
+        }
+        match env("TERM").as_deref() {
+            Some("dumb") => Self::None,
+            Some(term) if term.contains("256color") => Self::Ansi256,
+            _ => Self::Basic,
+        }
+    }
+}
+
+/// Parse a `#rrggbb` (or `rrggbb`) color into its RGB components.
+fn parse_hex_color(hex: &str) -> Option<(u8, u8, u8)> {
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some((channel(0)?, channel(2)?, channel(4)?))
+}
+
+/// Nearest xterm 256-color palette index for an RGB color.
+fn nearest_256(r: u8, g: u8, b: u8) -> u8 {
+    if r == g && g == b {
+        return match r {
+            0..8 => 16,
+            239.. => 231,
+            v => 232 + (v - 8) / 10,
+        };
+    }
+    let level = |v: u8| match v {
+        0..48 => 0,
+        48..115 => 1,
+        v => (v - 35) / 40,
+    };
+    16 + 36 * level(r) + 6 * level(g) + level(b)
+}
+
+/// Nearest of the eight basic ANSI colors for an RGB color.
+fn nearest_basic(r: u8, g: u8, b: u8) -> console::Color {
+    const PALETTE: [(console::Color, (i32, i32, i32)); 8] = [
+        (console::Color::Black, (0, 0, 0)),
+        (console::Color::Red, (205, 0, 0)),
+        (console::Color::Green, (0, 205, 0)),
+        (console::Color::Yellow, (205, 205, 0)),
+        (console::Color::Blue, (0, 0, 238)),
+        (console::Color::Magenta, (205, 0, 205)),
+        (console::Color::Cyan, (0, 205, 205)),
+        (console::Color::White, (229, 229, 229)),
+    ];
+    let (r, g, b) = (i32::from(r), i32::from(g), i32::from(b));
+    PALETTE
+        .iter()
+        .min_by_key(|(_, (pr, pg, pb))| (r - pr).pow(2) + (g - pg).pow(2) + (b - pb).pow(2))
+        .map_or(console::Color::White, |(color, _)| *color)
+}
+
 /// Terminal color scheme for themed output.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct ColorScheme {
     enabled: bool,
+    support: ColorSupport,
+    accent: Option<(u8, u8, u8)>,
 }
 
 impl ColorScheme {
-    /// Apply bold styling.
-    pub fn bold(&self, text: &str) -> String {
+    /// Color `text` with a `#rrggbb` hex color, such as a label's color.
+    ///
+    /// Uses 24-bit color on truecolor terminals and the nearest palette
+    /// color otherwise. Invalid hex values leave `text` unstyled.
+    pub fn hex(&self, hex: &str, text: &str) -> String {
+        match parse_hex_color(hex) {
+            Some(rgb) if self.enabled => self.rgb(rgb, text),
+            _ => text.to_string(),
+        }
+    }
+
+    /// Apply the configured accent color, or bold when none is set.
+    pub fn accent(&self, text: &str) -> String {
+        match self.accent {
+            Some(rgb) if self.enabled => self.rgb(rgb, text),
+            _ => self.bold(text),
+        }
+    }
+
+    fn rgb(&self, (r, g, b): (u8, u8, u8), text: &str) -> String {
+        let style = console::Style::new();
+        let style = match self.support {
+            ColorSupport::TrueColor => style.true_color(r, g, b),
+            ColorSupport::Ansi256 => style.color256(nearest_256(r, g, b)),
+            ColorSupport::Basic | ColorSupport::None => style.fg(nearest_basic(r, g, b)),
+        };
+        self.paint(&style, text)
+    }
+
+    /// Apply `style` when color is enabled, regardless of whether console
+    /// itself detects a terminal, so `--color=always` survives piping.
+    fn paint(&self, style: &console::Style, text: &str) -> String {
         if self.enabled {
-            console::style(text).bold().to_string()
+            style.clone().force_styling(true).apply_to(text).to_string()
         } else {
             text.to_string()
         }
     }
 
+    /// Apply bold styling.
+    pub fn bold(&self, text: &str) -> String {
+        self.paint(&console::Style::new().bold(), text)
+    }
+
     /// Apply success (green) styling.
     pub fn success(&self, text: &str) -> String {
-        if self.enabled {
-            console::style(text).green().to_string()
-        } else {
-            text.to_string()
-        }
+        self.paint(&console::Style::new().green(), text)
     }
 
     /// Apply warning (yellow) styling.
     pub fn warning(&self, text: &str) -> String {
-        if self.enabled {
-            console::style(text).yellow().to_string()
-        } else {
-            text.to_string()
-        }
+        self.paint(&console::Style::new().yellow(), text)
     }
 
     /// Apply error (red) styling.
     pub fn error(&self, text: &str) -> String {
-        if self.enabled {
-            console::style(text).red().to_string()
-        } else {
-            text.to_string()
-        }
+        self.paint(&console::Style::new().red(), text)
     }
 
     /// Apply dimmed/gray styling.
     pub fn gray(&self, text: &str) -> String {
-        if self.enabled {
-            console::style(text).dim().to_string()
+        let style = if self.support >= ColorSupport::Ansi256 {
+            console::Style::new().color256(242)
         } else {
-            text.to_string()
-        }
+            console::Style::new().dim()
+        };
+        self.paint(&style, text)
     }
 
     /// Apply cyan styling (for links, emphasis).
     pub fn cyan(&self, text: &str) -> String {
-        if self.enabled {
-            console::style(text).cyan().to_string()
-        } else {
-            text.to_string()
-        }
+        self.paint(&console::Style::new().cyan(), text)
     }
 
     /// Apply magenta styling.
     pub fn magenta(&self, text: &str) -> String {
-        if self.enabled {
-            console::style(text).magenta().to_string()
-        } else {
-            text.to_string()
-        }
+        self.paint(&console::Style::new().magenta(), text)
+    }
+
+    /// Apply search-match highlighting (bold yellow).
+    pub fn highlight(&self, text: &str) -> String {
+        self.paint(&console::Style::new().bold().yellow(), text)
     }
 
     /// Whether colors are enabled.
@@ -635,6 +1023,87 @@ mod tests {
         assert!(!ios.true_color_support());
     }
 
+    #[test]
+    fn test_should_color_hex_by_terminal_capability() {
+        let mut ios = IOStreams::test();
+        assert_eq!(ios.color_scheme().hex("#d73a4a", "bug"), "bug");
+
+        ios.set_color_enabled(true);
+        assert_eq!(
+            ios.color_scheme().hex("#d73a4a", "bug"),
+            "\x1b[31mbug\x1b[0m"
+        );
+        ios.set_color_support(ColorSupport::Ansi256);
+        assert_eq!(
+            ios.color_scheme().hex("d73a4a", "bug"),
+            "\x1b[38;5;167mbug\x1b[0m"
+        );
+        ios.set_color_support(ColorSupport::TrueColor);
+        assert_eq!(
+            ios.color_scheme().hex("#d73a4a", "bug"),
+            "\x1b[38;2;215;58;74mbug\x1b[0m"
+        );
+        assert_eq!(ios.color_scheme().hex("not-a-color", "bug"), "bug");
+    }
+
+    #[rstest::rstest]
+    #[case(&[], ColorSupport::Basic)]
+    #[case(&[("COLORTERM", "truecolor")], ColorSupport::TrueColor)]
+    #[case(&[("COLORTERM", "24bit"), ("TERM", "xterm")], ColorSupport::TrueColor)]
+    #[case(&[("TERM", "xterm-256color")], ColorSupport::Ansi256)]
+    #[case(&[("TERM", "xterm")], ColorSupport::Basic)]
+    #[case(&[("TERM", "dumb")], ColorSupport::None)]
+    #[case(&[("NO_COLOR", "1"), ("COLORTERM", "truecolor")], ColorSupport::None)]
+    fn test_should_detect_color_support(
+        #[case] vars: &[(&str, &str)],
+        #[case] expected: ColorSupport,
+    ) {
+        let env = |key: &str| {
+            vars.iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| (*v).to_string())
+        };
+        assert_eq!(ColorSupport::detect(env), expected);
+    }
+
+    #[test]
+    fn test_should_report_effective_color_support() {
+        let mut ios = IOStreams::test();
+        ios.set_color_support(ColorSupport::TrueColor);
+        assert_eq!(ios.color_support(), ColorSupport::None);
+
+        ios.set_color_enabled(true);
+        assert_eq!(ios.color_support(), ColorSupport::TrueColor);
+        assert!(ios.color_support_256());
+
+        ios.set_color_support(ColorSupport::None);
+        assert_eq!(ios.color_support(), ColorSupport::Basic);
+        assert_eq!(ios.color_scheme().success("ok"), "\x1b[32mok\x1b[0m");
+    }
+
+    #[test]
+    fn test_should_map_grays_to_256_color_ramp() {
+        assert_eq!(nearest_256(0, 0, 0), 16);
+        assert_eq!(nearest_256(128, 128, 128), 244);
+        assert_eq!(nearest_256(255, 255, 255), 231);
+        assert_eq!(nearest_256(0, 255, 0), 46);
+    }
+
+    #[test]
+    fn test_should_apply_accent_color() {
+        let mut ios = IOStreams::test();
+        ios.set_color_enabled(true);
+        ios.set_color_support(ColorSupport::TrueColor);
+        ios.set_accent_color(Some("#ff8800".into()));
+        assert_eq!(
+            ios.color_scheme().accent("hi"),
+            "\x1b[38;2;255;136;0mhi\x1b[0m"
+        );
+
+        ios.set_color_enabled(false);
+        assert_eq!(ios.color_scheme().accent("hi"), "hi");
+    }
+
     #[test]
     fn test_should_disable_prompts_in_test_mode() {
         let ios = IOStreams::test();
@@ -664,6 +1133,15 @@ mod tests {
         assert_eq!(output.stderr(), "error message\n");
     }
 
+    #[test]
+    fn test_should_share_stderr_through_handle() {
+        let (ios, output) = IOStreams::test_with_output();
+        let handle = ios.stderr_handle();
+        ios.println_err("first");
+        handle.println("second");
+        assert_eq!(output.stderr(), "first\nsecond\n");
+    }
+
     #[test]
     fn test_should_capture_multiple_writes() {
         let (ios, output) = IOStreams::test_with_output();
@@ -691,6 +1169,38 @@ mod tests {
         assert!(ios.is_stderr_tty());
     }
 
+    // --- Hyperlinks ---
+
+    #[test]
+    fn test_should_disable_hyperlinks_in_test_mode() {
+        let mut ios = IOStreams::test();
+        assert!(!ios.hyperlinks_enabled());
+        ios.set_hyperlinks_enabled(true);
+        assert!(ios.hyperlinks_enabled());
+    }
+
+    #[test]
+    fn test_should_detect_hyperlink_support_from_env() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |key: &str| {
+                vars.iter()
+                    .find(|(k, _)| *k == key)
+                    .map(|(_, v)| (*v).to_string())
+            }
+        };
+        assert!(hyperlinks_supported(env(&[("TERM_PROGRAM", "iTerm.app")])));
+        assert!(hyperlinks_supported(env(&[("VTE_VERSION", "6800")])));
+        assert!(hyperlinks_supported(env(&[("TERM", "xterm-kitty")])));
+        assert!(hyperlinks_supported(env(&[("FORCE_HYPERLINK", "1")])));
+        assert!(!hyperlinks_supported(env(&[("VTE_VERSION", "4800")])));
+        assert!(!hyperlinks_supported(env(&[("TERM", "xterm-256color")])));
+        assert!(!hyperlinks_supported(env(&[
+            ("FORCE_HYPERLINK", "0"),
+            ("TERM_PROGRAM", "WezTerm"),
+        ])));
+        assert!(!hyperlinks_supported(env(&[])));
+    }
+
     // --- Pager ---
 
     #[test]
@@ -727,6 +1237,25 @@ mod tests {
 
     // --- Setters ---
 
+    #[test]
+    fn test_should_highlight_only_when_color_enabled() {
+        let mut ios = IOStreams::test();
+        assert_eq!(ios.color_scheme().highlight("main"), "main");
+        ios.set_color_enabled(true);
+        assert_eq!(
+            ios.color_scheme().highlight("main"),
+            "\x1b[33m\x1b[1mmain\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_should_force_color_enabled() {
+        let mut ios = IOStreams::test();
+        ios.set_color_enabled(true);
+        assert!(ios.color_enabled());
+        assert!(ios.color_scheme().is_enabled());
+    }
+
     #[test]
     fn test_should_set_color_labels() {
         let mut ios = IOStreams::test();
@@ -752,6 +1281,53 @@ mod tests {
         assert!(ios.spinner_disabled());
     }
 
+    #[test]
+    fn test_should_apply_prompt_preference_by_precedence() {
+        let mut ios = IOStreams::test();
+        ios.apply_prompt_preference(None, None, "enabled");
+        assert!(!ios.never_prompt());
+        ios.apply_prompt_preference(None, None, "disabled");
+        assert!(ios.never_prompt());
+        ios.apply_prompt_preference(None, Some("0"), "disabled");
+        assert!(!ios.never_prompt());
+        ios.apply_prompt_preference(None, Some("1"), "enabled");
+        assert!(ios.never_prompt());
+        ios.apply_prompt_preference(Some(true), Some("1"), "disabled");
+        assert!(!ios.never_prompt());
+        ios.apply_prompt_preference(Some(false), None, "enabled");
+        assert!(ios.never_prompt());
+    }
+
+    #[test]
+    fn test_should_not_render_progress_without_tty() {
+        let (mut ios, output) = IOStreams::test_with_output();
+        ios.set_spinner_disabled(false);
+        let progress = ios.start_progress_indicator_with_label("Cloning…");
+        assert!(!progress.is_active());
+        progress.set_label("Still cloning…");
+        progress.stop();
+        assert!(output.stderr().is_empty());
+    }
+
+    #[test]
+    fn test_should_not_render_progress_group_without_tty() {
+        let (mut ios, output) = IOStreams::test_with_output();
+        ios.set_spinner_disabled(false);
+        let group = ios.start_progress_group();
+        let item = group.add("Downloading app.zip…");
+        assert!(!group.is_active());
+        assert!(!item.is_active());
+        group.suspend(|| ios_eprintln!(ios, "done"));
+        assert_eq!(output.stderr(), "done\n");
+    }
+
+    #[test]
+    fn test_should_not_render_progress_when_spinner_disabled() {
+        let mut ios = IOStreams::test();
+        ios.set_stderr_tty(true);
+        assert!(!ios.start_progress_indicator().is_active());
+    }
+
     #[test]
     fn test_should_set_never_prompt() {
         let mut ios = IOStreams::test();
@@ -759,6 +1335,22 @@ mod tests {
         assert!(!ios.never_prompt());
     }
 
+    #[test]
+    fn test_should_disable_color_links_and_spinner_when_accessible() {
+        let mut ios = IOStreams::test();
+        ios.set_color_enabled(true);
+        ios.set_hyperlinks_enabled(true);
+        ios.set_stderr_tty(true);
+        ios.set_spinner_disabled(false);
+
+        ios.set_accessible(true);
+        assert!(ios.is_accessible());
+        assert!(!ios.color_enabled());
+        assert!(!ios.hyperlinks_enabled());
+        assert_eq!(ios.color_scheme().bold("x"), "x");
+        assert!(!ios.start_progress_indicator().is_active());
+    }
+
     #[test]
     fn test_should_set_accessible_prompter() {
         let mut ios = IOStreams::test();
@@ -771,7 +1363,10 @@ mod tests {
 
     #[test]
     fn test_should_pass_through_text_when_color_disabled() {
-        let cs = ColorScheme { enabled: false };
+        let cs = ColorScheme {
+            enabled: false,
+            ..ColorScheme::default()
+        };
         assert!(!cs.is_enabled());
         assert_eq!(cs.bold("hello"), "hello");
         assert_eq!(cs.success("ok"), "ok");
@@ -784,7 +1379,10 @@ mod tests {
 
     #[test]
     fn test_should_return_plain_icons_when_color_disabled() {
-        let cs = ColorScheme { enabled: false };
+        let cs = ColorScheme {
+            enabled: false,
+            ..ColorScheme::default()
+        };
         // Icons should still contain the glyph, just not styled
         assert!(cs.success_icon().contains('\u{2713}') || cs.success_icon().contains('✓'));
         assert!(cs.warning_icon().contains('!'));
@@ -793,7 +1391,10 @@ mod tests {
 
     #[test]
     fn test_should_apply_styles_when_color_enabled() {
-        let cs = ColorScheme { enabled: true };
+        let cs = ColorScheme {
+            enabled: true,
+            ..ColorScheme::default()
+        };
         assert!(cs.is_enabled());
         // Styled output should differ from plain text (contains ANSI codes)
         let styled = cs.bold("hello");
@@ -4,14 +4,22 @@
 
 use std::fmt::Write;
 
+use anyhow::Context;
 use serde_json::Value;
 
+use crate::iostreams::IOStreams;
+use crate::{ios_print, ios_println};
+
 /// Filter a JSON value to only include the specified fields.
 ///
 /// For objects, returns only the specified keys. For arrays, filters each
 /// element. Returns the value unchanged if fields is empty or the value is
 /// not an object/array.
 ///
+/// A field may be a dotted path such as `author.login`. The nested value is
+/// selected into a flat object under the full dotted key, and resolves to
+/// `null` when any intermediate key is missing.
+///
 /// # Examples
 ///
 /// ```
@@ -21,6 +29,10 @@ use serde_json::Value;
 /// let data = json!({"name": "test", "description": "desc", "url": "https://example.com"});
 /// let filtered = filter_json_fields(&data, &["name".to_string(), "url".to_string()]);
 /// assert_eq!(filtered, json!({"name": "test", "url": "https://example.com"}));
+///
+/// let issue = json!({"number": 1, "author": {"login": "octocat"}});
+/// let filtered = filter_json_fields(&issue, &["number".to_string(), "author.login".to_string()]);
+/// assert_eq!(filtered, json!({"number": 1, "author.login": "octocat"}));
 /// ```
 pub fn filter_json_fields(value: &Value, fields: &[String]) -> Value {
     if fields.is_empty() {
@@ -31,19 +43,12 @@ pub fn filter_json_fields(value: &Value, fields: &[String]) -> Value {
         Value::Object(map) => {
             let mut filtered = serde_json::Map::new();
             for field in fields {
-                if let Some(v) = map.get(field) {
+                if let Some((head, rest)) = field.split_once('.') {
+                    let nested = get_field(map, head)
+                        .map_or(Value::Null, |v| crate::export::lookup_dotted_path(v, rest));
+                    filtered.insert(field.clone(), nested);
+                } else if let Some(v) = get_field(map, field) {
                     filtered.insert(field.clone(), v.clone());
-                } else {
-                    // Try alternate casing: camelCase <-> snake_case
-                    let snake = to_snake_case(field);
-                    if let Some(v) = map.get(&snake) {
-                        filtered.insert(field.clone(), v.clone());
-                    } else {
-                        let camel = to_camel_case(field);
-                        if let Some(v) = map.get(&camel) {
-                            filtered.insert(field.clone(), v.clone());
-                        }
-                    }
                 }
             }
             Value::Object(filtered)
@@ -57,6 +62,13 @@ pub fn filter_json_fields(value: &Value, fields: &[String]) -> Value {
     }
 }
 
+/// Look up a top-level field, trying alternate casing: camelCase <-> snake_case.
+fn get_field<'a>(map: &'a serde_json::Map<String, Value>, field: &str) -> Option<&'a Value> {
+    map.get(field)
+        .or_else(|| map.get(&to_snake_case(field)))
+        .or_else(|| map.get(&to_camel_case(field)))
+}
+
 /// Convert a `camelCase` string to `snake_case`.
 fn to_snake_case(s: &str) -> String {
     let mut result = String::with_capacity(s.len() + 4);
@@ -94,6 +106,34 @@ fn to_camel_case(s: &str) -> String {
     result
 }
 
+/// Project a JSON value onto the requested `--json` fields.
+///
+/// Like [`filter_json_fields`], but first checks every requested field
+/// against the keys present in the value (the first element, for arrays)
+/// so a typo is reported instead of silently dropped.
+///
+/// # Errors
+///
+/// Returns an error naming the unknown fields and listing the available ones.
+///
+/// # Examples
+///
+/// ```
+/// use serde_json::json;
+/// use ghc_core::json::project;
+///
+/// let tasks = json!([{"id": 1, "status": "queued", "title": "Fix"}]);
+/// let fields = ["id".to_string(), "status".to_string()];
+/// assert_eq!(project(&tasks, &fields).unwrap(), json!([{"id": 1, "status": "queued"}]));
+/// assert!(project(&tasks, &["nope".to_string()]).is_err());
+/// ```
+pub fn project(value: &Value, fields: &[String]) -> anyhow::Result<Value> {
+    if !fields.is_empty() {
+        validate_json_fields(value, fields)?;
+    }
+    Ok(filter_json_fields(value, fields))
+}
+
 /// Format a filtered JSON value as a pretty-printed string.
 ///
 /// Combines field filtering and pretty-print serialization.
@@ -112,26 +152,34 @@ pub fn format_json_with_fields(
 /// Format JSON output applying field selection, jq filtering, or template rendering.
 ///
 /// This is the unified output function for all commands that support `--json`,
-/// `--jq`, and `--template` flags. It applies them in priority order:
+/// `--jq`, `--template`, and `--yaml` flags. It applies them in priority order:
 /// 1. If `jq_expr` is set, apply jq filter on the (field-filtered) value
 /// 2. If `template` is set, apply template on the (field-filtered) value
-/// 3. Otherwise, pretty-print the field-filtered JSON
+/// 3. If `yaml` is set, serialize the field-filtered value as YAML
+/// 4. Otherwise, pretty-print the field-filtered JSON
+///
+/// `yaml` cannot be combined with `jq_expr` or `template`, since both produce
+/// their own text output.
 ///
 /// # Errors
 ///
-/// Returns an error if filtering, template rendering, or serialization fails.
+/// Returns an error if `yaml` is combined with `jq_expr` or `template`, or if
+/// filtering, template rendering, or serialization fails.
 pub fn format_json_output(
     value: &Value,
     fields: &[String],
     jq_expr: Option<&str>,
     template: Option<&str>,
+    yaml: bool,
 ) -> anyhow::Result<String> {
-    // Validate requested field names against available fields
-    if !fields.is_empty() {
-        validate_json_fields(value, fields)?;
+    if yaml && (jq_expr.is_some() || template.is_some()) {
+        return Err(crate::cmdutil::FlagError(
+            "`--yaml` cannot be combined with `--jq` or `--template`".to_string(),
+        )
+        .into());
     }
 
-    let filtered = filter_json_fields(value, fields);
+    let filtered = project(value, fields)?;
 
     if let Some(jq) = jq_expr {
         return crate::export::apply_jq_filter(&filtered, jq);
@@ -141,9 +189,40 @@ pub fn format_json_output(
         return crate::export::apply_template(&filtered, tmpl);
     }
 
+    if yaml {
+        return crate::export::to_yaml(&filtered);
+    }
+
     serde_json::to_string(&filtered).map_err(|e| anyhow::anyhow!("failed to serialize JSON: {e}"))
 }
 
+/// Print a command's `--json`, `--jq`, `--template`, or `--yaml` output.
+///
+/// Formats `value` with [`format_json_output`] and writes it to stdout.
+/// Template output is written verbatim so the template controls its own
+/// line breaks, as in the Go CLI; everything else gets a trailing newline.
+///
+/// # Errors
+///
+/// Returns an error if filtering, template rendering, or serialization fails.
+pub fn output(
+    value: &Value,
+    fields: &[String],
+    jq_expr: Option<&str>,
+    template: Option<&str>,
+    yaml: bool,
+    ios: &IOStreams,
+) -> anyhow::Result<()> {
+    let formatted = format_json_output(value, fields, jq_expr, template, yaml)
+        .context("failed to format JSON output")?;
+    if jq_expr.is_none() && template.is_some() {
+        ios_print!(ios, "{formatted}");
+    } else {
+        ios_println!(ios, "{formatted}");
+    }
+    Ok(())
+}
+
 /// Validate that requested JSON fields exist in the value.
 ///
 /// Checks each field against the available keys (including camelCase/snake_case
@@ -168,11 +247,15 @@ fn validate_json_fields(value: &Value, fields: &[String]) -> anyhow::Result<()>
 
     let mut unknown = Vec::new();
     for field in fields {
-        let found = available_keys.contains(&field.as_str())
+        // Dotted paths are validated by their top-level key.
+        let field = field
+            .split_once('.')
+            .map_or(field.as_str(), |(head, _)| head);
+        let found = available_keys.contains(&field)
             || available_keys.contains(&to_snake_case(field).as_str())
             || available_keys.contains(&to_camel_case(field).as_str());
         if !found {
-            unknown.push(field.as_str());
+            unknown.push(field);
         }
     }
 
@@ -348,6 +431,133 @@ mod tests {
         assert_eq!(filtered, json!({"tag_name": "v1.0", "is_draft": false}));
     }
 
+    #[test]
+    fn test_should_select_dotted_path_into_flat_object() {
+        let data = json!([
+            {"number": 1, "author": {"login": "alice", "id": "U1"}},
+            {"number": 2, "author": {"login": "bob", "id": "U2"}},
+        ]);
+        let filtered =
+            filter_json_fields(&data, &["number".to_string(), "author.login".to_string()]);
+        assert_eq!(
+            filtered,
+            json!([
+                {"number": 1, "author.login": "alice"},
+                {"number": 2, "author.login": "bob"},
+            ])
+        );
+    }
+
+    #[test]
+    fn test_should_select_dotted_path_with_missing_intermediate_as_null() {
+        let data = json!({"number": 1, "milestone": null});
+        let filtered = filter_json_fields(
+            &data,
+            &["milestone.title".to_string(), "mergedBy.login".to_string()],
+        );
+        assert_eq!(
+            filtered,
+            json!({"milestone.title": null, "mergedBy.login": null})
+        );
+    }
+
+    #[test]
+    fn test_should_validate_dotted_path_by_top_level_key() {
+        let data = json!({"number": 1, "author": {"login": "alice"}});
+        let fields = ["author.login".to_string()];
+        assert!(format_json_output(&data, &fields, None, None, false).is_ok());
+
+        let err =
+            format_json_output(&data, &["owner.login".to_string()], None, None, false).unwrap_err();
+        assert!(err.to_string().contains("Unknown JSON field: \"owner\""));
+    }
+
+    #[test]
+    fn test_should_project_array_onto_fields() {
+        let tasks = json!([
+            {"id": 1, "status": "queued", "title": "A", "body": "x"},
+            {"id": 2, "status": "done", "title": "B", "body": "y"}
+        ]);
+        let fields = ["id".to_string(), "status".to_string()];
+        assert_eq!(
+            project(&tasks, &fields).unwrap(),
+            json!([{"id": 1, "status": "queued"}, {"id": 2, "status": "done"}])
+        );
+    }
+
+    #[test]
+    fn test_should_reject_unknown_projected_field() {
+        let task = json!({"id": 1, "status": "queued"});
+        let err = project(&task, &["state".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("Unknown JSON field: \"state\""));
+        assert!(err.to_string().contains("  status"));
+    }
+
+    #[test]
+    fn test_should_format_filtered_issue_as_yaml() {
+        let issue = json!({
+            "number": 42,
+            "title": "Fix bug",
+            "body": "long body",
+            "author": {"login": "octocat"},
+        });
+        let fields = [
+            "number".to_string(),
+            "title".to_string(),
+            "author".to_string(),
+        ];
+        let output = format_json_output(&issue, &fields, None, None, true).unwrap();
+        assert!(output.contains("number: 42"));
+        assert!(output.contains("title: Fix bug"));
+        assert!(output.contains("login: octocat"));
+        assert!(!output.contains("body"));
+    }
+
+    #[test]
+    fn test_should_reject_yaml_with_jq_or_template() {
+        let issue = json!({"number": 42});
+        for (jq, template) in [(Some(".number"), None), (None, Some("{{.number}}"))] {
+            let err = format_json_output(&issue, &[], jq, template, true).unwrap_err();
+            assert!(err.downcast_ref::<crate::cmdutil::FlagError>().is_some());
+            assert_eq!(
+                err.to_string(),
+                "`--yaml` cannot be combined with `--jq` or `--template`"
+            );
+        }
+    }
+
+    #[test]
+    fn test_should_print_template_output_verbatim() {
+        let (ios, out) = IOStreams::test_with_output();
+        let releases =
+            json!([{"tagName": "v1.0.0", "name": "One"}, {"tagName": "v0.9.0", "name": "Zero"}]);
+        output(
+            &releases,
+            &["tagName".to_string()],
+            None,
+            Some(r#"{{range .}}{{.tagName}}{{"\n"}}{{end}}"#),
+            false,
+            &ios,
+        )
+        .unwrap();
+        assert_eq!(out.stdout(), "v1.0.0\nv0.9.0\n");
+    }
+
+    #[test]
+    fn test_should_print_jq_output_with_newline() {
+        let (ios, out) = IOStreams::test_with_output();
+        output(
+            &json!({"number": 42}),
+            &[],
+            Some(".number"),
+            None,
+            false,
+            &ios,
+        )
+        .unwrap();
+        assert_eq!(out.stdout(), "42\n");
+    }
+
     #[test]
     fn test_should_format_with_fields() {
         let data = json!({"name": "test", "extra": 42});
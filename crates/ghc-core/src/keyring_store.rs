@@ -4,6 +4,8 @@
 //! Implements gh's two-slot keyring model:
 //! - **Per-user slot**: `keyring.Entry("gh:{hostname}", username)` — stores during login
 //! - **Active slot**: `keyring.Entry("gh:{hostname}", "")` — moved during `activate_user`
+//!
+//! Setting `GH_USE_KEYRING=0` opts out of the keyring for new logins.
 
 use std::time::Duration;
 
@@ -27,6 +29,34 @@ where
         .map_err(|_| anyhow::anyhow!("keyring operation timed out after 3 seconds"))?
 }
 
+/// Whether `GH_USE_KEYRING` allows the keyring to be used.
+///
+/// Only `0`, `false`, and `no` opt out; unset or any other value allows it.
+pub fn enabled_by_env() -> bool {
+    keyring_enabled(std::env::var("GH_USE_KEYRING").ok().as_deref())
+}
+
+fn keyring_enabled(value: Option<&str>) -> bool {
+    !value.is_some_and(|v| matches!(v.trim().to_ascii_lowercase().as_str(), "0" | "false" | "no"))
+}
+
+/// Probe whether the OS keyring backend can be reached.
+///
+/// Looks up an entry that is never written: a "no entry" answer means the
+/// backend is working. Headless machines without a secret service, or a
+/// backend that hangs past the timeout, report `false`.
+pub fn is_available() -> bool {
+    with_timeout(|| {
+        let entry = keyring::Entry::new("gh:keyring-probe", "")
+            .context("failed to create keyring entry")?;
+        match entry.get_password() {
+            Ok(_) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(e) => Err(anyhow::anyhow!("keyring error: {e}")),
+        }
+    })
+    .is_ok()
+}
+
 /// Build a keyring entry for the active slot (username = "").
 fn active_entry(hostname: &str) -> Result<keyring::Entry> {
     let service = format!("gh:{hostname}");
@@ -141,3 +171,18 @@ pub fn delete_token_for_user(hostname: &str, username: &str) -> Result<()> {
         }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_disable_keyring_only_for_falsy_values() {
+        assert!(keyring_enabled(None));
+        assert!(keyring_enabled(Some("1")));
+        assert!(keyring_enabled(Some("")));
+        assert!(!keyring_enabled(Some("0")));
+        assert!(!keyring_enabled(Some("false")));
+        assert!(!keyring_enabled(Some(" NO ")));
+    }
+}
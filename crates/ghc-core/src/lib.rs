@@ -8,7 +8,9 @@
 //! - Text utilities, table formatting, and color schemes
 
 pub mod browser;
+pub mod clipboard;
 pub mod cmdutil;
+pub mod completion;
 pub mod config;
 pub mod errors;
 pub mod export;
@@ -1,38 +1,316 @@
 //! Markdown rendering for terminal output.
 //!
-//! Maps from Go's usage of glamour for markdown rendering.
+//! Maps from Go's usage of glamour for markdown rendering. GFM tables are
+//! laid out separately with column alignment, since termimad does not honor
+//! GFM alignment markers. Task-list items (`- [ ]` / `- [x]`) are rendered
+//! with check glyphs.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::iostreams::ColorScheme;
+use crate::table::{self, Align};
+
+/// Matches a GFM table delimiter row such as `| :--- | ---: |`.
+static DELIMITER_ROW: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\s*\|?\s*:?-+:?\s*(\|\s*:?-+:?\s*)*\|?\s*$").unwrap_or_else(|_| unreachable!())
+});
+
+/// Matches a task-list item marker, capturing the list prefix and check state.
+static TASK_ITEM: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(\s*(?:[-*+]|\d+[.)])\s+)\[([ xX])\](\s|$)").unwrap_or_else(|_| unreachable!())
+});
+
+/// Separator placed between table columns in rendered output.
+const COLUMN_SEPARATOR: &str = "  ";
 
 /// Render markdown text for terminal display.
-pub fn render(text: &str, width: usize) -> String {
-    // Use termimad for terminal markdown rendering
-    let skin = termimad::MadSkin::default();
-    let area = termimad::Area::new(0, 0, u16::try_from(width).unwrap_or(u16::MAX), u16::MAX);
-    let fmt = termimad::FmtText::from(&skin, text, Some(area.width as usize));
-    fmt.to_string()
+///
+/// Task-list checkboxes become colored check glyphs when `cs` has color
+/// enabled, and stay as ASCII `[x]` / `[ ]` otherwise.
+pub fn render(text: &str, width: usize, cs: &ColorScheme) -> String {
+    let mut out = String::new();
+    for block in parse_blocks(text) {
+        match block {
+            Block::Text(chunk) => out.push_str(&render_text(&render_tasks(&chunk, cs), width)),
+            Block::Table(table) => out.push_str(&table.render_aligned()),
+        }
+    }
+    out
 }
 
 /// Render markdown to plain text (strip formatting).
+///
+/// GFM tables degrade to pipe-delimited rows.
 pub fn render_plain(text: &str) -> String {
+    let mut result = String::new();
+    for block in parse_blocks(text) {
+        match block {
+            Block::Text(chunk) => result.push_str(&chunk),
+            Block::Table(table) => result.push_str(&table.render_pipes()),
+        }
+    }
     // Simple stripping of common markdown syntax
-    let mut result = text.to_string();
     // Remove headers
-    result = regex::Regex::new(r"(?m)^#{1,6}\s+")
+    result = Regex::new(r"(?m)^#{1,6}\s+")
         .unwrap_or_else(|_| unreachable!())
         .replace_all(&result, "")
         .to_string();
     // Remove bold/italic
     result = result.replace("**", "").replace("__", "");
-    result = regex::Regex::new(r"(?m)\*([^*]+)\*")
+    result = Regex::new(r"(?m)\*([^*]+)\*")
         .unwrap_or_else(|_| unreachable!())
         .replace_all(&result, "$1")
         .to_string();
     result
 }
 
+/// Count task-list items in markdown source, returning `(done, total)`.
+///
+/// Items inside fenced code blocks are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use ghc_core::markdown::count_tasks;
+/// assert_eq!(count_tasks("- [x] one\n- [ ] two\n- [X] three"), (2, 3));
+/// ```
+pub fn count_tasks(text: &str) -> (usize, usize) {
+    let mut done = 0;
+    let mut total = 0;
+    for line in outside_fences(text) {
+        if let Some(caps) = TASK_ITEM.captures(line) {
+            total += 1;
+            if &caps[2] != " " {
+                done += 1;
+            }
+        }
+    }
+    (done, total)
+}
+
+/// Replace task-list checkboxes with check glyphs, leaving fenced code untouched.
+fn render_tasks(text: &str, cs: &ColorScheme) -> String {
+    let (checked, unchecked) = if cs.is_enabled() {
+        (cs.success("\u{2713}"), cs.gray("\u{25cb}"))
+    } else {
+        ("[x]".to_string(), "[ ]".to_string())
+    };
+
+    let mut in_fence = false;
+    text.split_inclusive('\n')
+        .map(|line| {
+            if line.trim_start().starts_with("```") {
+                in_fence = !in_fence;
+            }
+            if in_fence {
+                return line.to_string();
+            }
+            TASK_ITEM
+                .replace(line, |caps: &regex::Captures<'_>| {
+                    let glyph = if &caps[2] == " " {
+                        &unchecked
+                    } else {
+                        &checked
+                    };
+                    format!("{}{glyph}{}", &caps[1], &caps[3])
+                })
+                .into_owned()
+        })
+        .collect()
+}
+
+/// Iterate over the lines of `text` that are not inside fenced code blocks.
+fn outside_fences(text: &str) -> impl Iterator<Item = &str> {
+    let mut in_fence = false;
+    text.lines().filter(move |line| {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+            return false;
+        }
+        !in_fence
+    })
+}
+
+fn render_text(text: &str, width: usize) -> String {
+    // Use termimad for terminal markdown rendering
+    let skin = termimad::MadSkin::default();
+    let area = termimad::Area::new(0, 0, u16::try_from(width).unwrap_or(u16::MAX), u16::MAX);
+    let fmt = termimad::FmtText::from(&skin, text, Some(area.width as usize));
+    fmt.to_string()
+}
+
+/// A run of markdown source: either regular text or a GFM table.
+#[derive(Debug)]
+enum Block {
+    Text(String),
+    Table(MdTable),
+}
+
+/// A parsed GFM table.
+#[derive(Debug)]
+struct MdTable {
+    header: Vec<String>,
+    aligns: Vec<Align>,
+    rows: Vec<Vec<String>>,
+}
+
+impl MdTable {
+    /// Lay out the table with padded, aligned columns and a rule under the header.
+    fn render_aligned(&self) -> String {
+        let mut widths: Vec<usize> = vec![0; self.header.len()];
+        for row in std::iter::once(&self.header).chain(&self.rows) {
+            for (i, cell) in row.iter().enumerate() {
+                widths[i] = widths[i].max(crate::text::display_width(cell));
+            }
+        }
+
+        let format_row = |row: &[String]| {
+            let cells: Vec<String> = widths
+                .iter()
+                .enumerate()
+                .map(|(i, &width)| {
+                    let cell = row.get(i).map_or("", String::as_str);
+                    table::pad_cell(cell, width, self.aligns[i])
+                })
+                .collect();
+            cells.join(COLUMN_SEPARATOR).trim_end().to_string()
+        };
+
+        let rule: Vec<String> = widths.iter().map(|&w| "-".repeat(w)).collect();
+        let mut lines = vec![format_row(&self.header), rule.join(COLUMN_SEPARATOR)];
+        lines.extend(self.rows.iter().map(|row| format_row(row)));
+        lines.push(String::new());
+        lines.join("\n")
+    }
+
+    /// Degrade the table to pipe-delimited rows, dropping the delimiter row.
+    fn render_pipes(&self) -> String {
+        let mut lines: Vec<String> = std::iter::once(&self.header)
+            .chain(&self.rows)
+            .map(|row| row.join(" | "))
+            .collect();
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+/// Split markdown into text runs and GFM tables, ignoring fenced code blocks.
+fn parse_blocks(text: &str) -> Vec<Block> {
+    let lines: Vec<&str> = text.split_inclusive('\n').collect();
+    let mut blocks = Vec::new();
+    let mut pending = String::new();
+    let mut in_fence = false;
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+        }
+        if !in_fence && let Some((table, consumed)) = parse_table(&lines[i..]) {
+            if !pending.is_empty() {
+                blocks.push(Block::Text(std::mem::take(&mut pending)));
+            }
+            blocks.push(Block::Table(table));
+            i += consumed;
+            continue;
+        }
+        pending.push_str(line);
+        i += 1;
+    }
+
+    if !pending.is_empty() {
+        blocks.push(Block::Text(pending));
+    }
+    blocks
+}
+
+/// Parse a GFM table starting at `lines[0]`, returning it and the lines consumed.
+fn parse_table(lines: &[&str]) -> Option<(MdTable, usize)> {
+    let header_line = lines.first()?;
+    let delimiter_line = lines.get(1)?;
+    if !header_line.contains('|') || !DELIMITER_ROW.is_match(delimiter_line.trim_end()) {
+        return None;
+    }
+
+    let header = split_row(header_line);
+    let aligns: Vec<Align> = split_row(delimiter_line)
+        .iter()
+        .map(
+            |marker| match (marker.starts_with(':'), marker.ends_with(':')) {
+                (true, true) => Align::Center,
+                (false, true) => Align::Right,
+                _ => Align::Left,
+            },
+        )
+        .collect();
+    if header.len() != aligns.len() {
+        return None;
+    }
+
+    // As in GFM, excess body cells are dropped and missing ones left empty.
+    let rows: Vec<Vec<String>> = lines[2..]
+        .iter()
+        .take_while(|line| line.contains('|') && !line.trim().is_empty())
+        .map(|line| {
+            let mut row = split_row(line);
+            row.resize(header.len(), String::new());
+            row
+        })
+        .collect();
+    let consumed = 2 + rows.len();
+    Some((
+        MdTable {
+            header,
+            aligns,
+            rows,
+        },
+        consumed,
+    ))
+}
+
+/// Split a table row into trimmed cells, honoring `\|` escapes.
+fn split_row(line: &str) -> Vec<String> {
+    let trimmed = line.trim();
+    let trimmed = trimmed.strip_prefix('|').unwrap_or(trimmed);
+    let trimmed = match trimmed.strip_suffix('|') {
+        Some(rest) if !rest.ends_with('\\') => rest,
+        _ => trimmed,
+    };
+
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut chars = trimmed.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'|') => {
+                cell.push('|');
+                chars.next();
+            }
+            '|' => cells.push(std::mem::take(&mut cell).trim().to_string()),
+            _ => cell.push(c),
+        }
+    }
+    cells.push(cell.trim().to_string());
+    cells
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn plain_cs() -> ColorScheme {
+        crate::iostreams::IOStreams::test().color_scheme()
+    }
+
+    fn color_cs() -> ColorScheme {
+        let mut ios = crate::iostreams::IOStreams::test();
+        ios.set_color_enabled(true);
+        ios.color_scheme()
+    }
+
     #[test]
     fn test_should_render_plain_strips_headers() {
         let plain = render_plain("# Hello");
@@ -76,13 +354,114 @@ mod tests {
 
     #[test]
     fn test_should_render_markdown_returns_string() {
-        let output = render("Hello world", 80);
+        let output = render("Hello world", 80, &plain_cs());
         assert!(output.contains("Hello"));
     }
 
+    const MIXED_TABLE: &str = "| Name | Count |\n| :--- | ---: |\n| apples | 3 |\n| kiwi | 120 |\n";
+
+    #[test]
+    fn test_should_render_gfm_table_with_mixed_alignment() {
+        let output = render(MIXED_TABLE, 80, &plain_cs());
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "Name    Count",
+                "------  -----",
+                "apples      3",
+                "kiwi      120",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_should_center_align_gfm_table_column() {
+        let output = render("| a | b |\n|:---:|---|\n| xyz | 1 |\n", 80, &plain_cs());
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0], " a   b");
+        assert_eq!(lines[2], "xyz  1");
+    }
+
+    #[test]
+    fn test_should_fit_body_rows_to_header_width() {
+        let source = "| a | b |\n|---|---|\n| 1 | 2 | extra |\n| 3 |\n";
+        let lines: Vec<String> = render(source, 80, &plain_cs())
+            .lines()
+            .map(String::from)
+            .collect();
+        assert_eq!(lines, vec!["a  b", "-  -", "1  2", "3"]);
+        assert_eq!(render_plain(source), "a | b\n1 | 2\n3 | \n");
+    }
+
+    #[test]
+    fn test_should_render_text_around_gfm_table() {
+        let output = render(&format!("Before\n\n{MIXED_TABLE}\nAfter"), 80, &plain_cs());
+        assert!(output.contains("Before"));
+        assert!(output.contains("apples      3"));
+        assert!(output.contains("After"));
+        assert!(!output.contains('|'));
+    }
+
+    #[test]
+    fn test_should_degrade_gfm_table_to_pipes_in_plain_mode() {
+        let plain = render_plain(MIXED_TABLE);
+        assert_eq!(plain, "Name | Count\napples | 3\nkiwi | 120\n");
+    }
+
+    #[test]
+    fn test_should_ignore_pipes_inside_code_fence() {
+        let source = "```\n| a | b |\n|---|---|\n```\n";
+        assert_eq!(render_plain(source), source);
+    }
+
+    #[test]
+    fn test_should_split_row_honoring_escaped_pipes() {
+        assert_eq!(split_row("| a \\| b | c |"), vec!["a | b", "c"]);
+        assert_eq!(split_row("a|b"), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_should_render_task_checkboxes_as_glyphs_with_color() {
+        let cs = color_cs();
+        let output = render_tasks("- [ ] todo\n- [x] done\n", &cs);
+        assert_eq!(
+            output,
+            format!(
+                "- {} todo\n- {} done\n",
+                cs.gray("\u{25cb}"),
+                cs.success("\u{2713}")
+            )
+        );
+    }
+
+    #[test]
+    fn test_should_render_task_checkboxes_as_ascii_without_color() {
+        let output = render_tasks("* [X] done\n1. [ ] numbered\n", &plain_cs());
+        assert_eq!(output, "* [x] done\n1. [ ] numbered\n");
+    }
+
+    #[test]
+    fn test_should_not_render_task_glyphs_inside_code_fence() {
+        let source = "```\n- [x] literal\n```\n";
+        assert_eq!(render_tasks(source, &color_cs()), source);
+    }
+
+    #[test]
+    fn test_should_count_tasks() {
+        let source = "Plan:\n- [x] one\n- [ ] two\n  - [X] nested\n- [ ]\n- not a task\n";
+        assert_eq!(count_tasks(source), (2, 4));
+    }
+
+    #[test]
+    fn test_should_not_count_tasks_in_code_fence_or_links() {
+        let source = "```\n- [x] code\n```\n[x] link-like\n- [link](url)\n";
+        assert_eq!(count_tasks(source), (0, 0));
+    }
+
     #[test]
     fn test_should_render_markdown_with_small_width() {
-        let output = render("Hello", 10);
+        let output = render("Hello", 10, &plain_cs());
         assert!(output.contains("Hello"));
     }
 }
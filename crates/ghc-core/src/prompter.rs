@@ -107,8 +107,13 @@ impl Prompter for DialoguerPrompter {
 pub struct StubPrompter {
     /// Pre-configured select answers (index).
     pub select_answers: std::sync::Mutex<Vec<usize>>,
+    /// Pre-configured multi-select answers (indices per prompt).
+    pub multi_select_answers: std::sync::Mutex<Vec<Vec<usize>>>,
     /// Pre-configured input answers.
     pub input_answers: std::sync::Mutex<Vec<String>>,
+    /// Pre-configured password answers, kept apart from input answers so
+    /// tests can tell a masked prompt from a visible one.
+    pub password_answers: std::sync::Mutex<Vec<String>>,
     /// Pre-configured confirm answers.
     pub confirm_answers: std::sync::Mutex<Vec<bool>>,
 }
@@ -129,10 +134,22 @@ impl Prompter for StubPrompter {
     fn multi_select(
         &self,
         _prompt: &str,
-        _defaults: &[bool],
+        defaults: &[bool],
         _options: &[String],
     ) -> Result<Vec<usize>> {
-        Ok(vec![])
+        let mut answers = self
+            .multi_select_answers
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if answers.is_empty() {
+            Ok(defaults
+                .iter()
+                .enumerate()
+                .filter_map(|(i, &on)| on.then_some(i))
+                .collect())
+        } else {
+            Ok(answers.remove(0))
+        }
     }
 
     fn input(&self, _prompt: &str, default: &str) -> Result<String> {
@@ -149,7 +166,7 @@ impl Prompter for StubPrompter {
 
     fn password(&self, _prompt: &str) -> Result<String> {
         let mut answers = self
-            .input_answers
+            .password_answers
             .lock()
             .unwrap_or_else(std::sync::PoisonError::into_inner);
         if answers.is_empty() {
@@ -272,13 +289,18 @@ mod tests {
     #[test]
     fn test_should_return_preconfigured_password() {
         let stub = StubPrompter::default();
-        stub.input_answers
+        stub.password_answers
             .lock()
             .unwrap()
             .push("secret123".to_string());
+        stub.input_answers
+            .lock()
+            .unwrap()
+            .push("visible".to_string());
 
         let result = stub.password("token?").unwrap();
         assert_eq!(result, "secret123");
+        assert_eq!(stub.input("name?", "").unwrap(), "visible");
     }
 
     #[test]
@@ -308,4 +330,36 @@ mod tests {
             .unwrap();
         assert!(result.is_empty());
     }
+
+    #[test]
+    fn test_should_return_defaults_from_multi_select_when_empty() {
+        let stub = StubPrompter::default();
+        let result = stub
+            .multi_select(
+                "pick",
+                &[true, false, true],
+                &["a".into(), "b".into(), "c".into()],
+            )
+            .unwrap();
+        assert_eq!(result, vec![0, 2]);
+    }
+
+    #[test]
+    fn test_should_return_preconfigured_multi_select_answers() {
+        let stub = StubPrompter::default();
+        stub.multi_select_answers
+            .lock()
+            .unwrap()
+            .extend([vec![1], vec![0, 1]]);
+        let options = ["a".to_string(), "b".to_string()];
+
+        assert_eq!(
+            stub.multi_select("q1", &[true, false], &options).unwrap(),
+            vec![1]
+        );
+        assert_eq!(
+            stub.multi_select("q2", &[false, false], &options).unwrap(),
+            vec![0, 1]
+        );
+    }
 }
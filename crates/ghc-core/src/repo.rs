@@ -64,12 +64,40 @@ impl Repo {
         }
     }
 
-    /// Parse a repository from a git remote URL.
+    /// Parse a repository from a clone or browser URL.
+    ///
+    /// Accepts `https://HOST/OWNER/REPO`, scp-like `git@HOST:OWNER/REPO`,
+    /// and `ssh://git@HOST/OWNER/REPO`, each with or without a `.git` suffix.
+    /// The scp-like form needs a user or a dotted host before the colon.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not a URL naming a repository.
+    pub fn from_url(raw: &str) -> Result<Self, RepoParseError> {
+        let raw = raw.trim();
+        let raw = raw.strip_prefix("git+").unwrap_or(raw);
+        let normalized = match raw.split_once(':') {
+            // scp-like syntax is SSH with the path after the first colon; the
+            // part before it must look like a host so `C:foo/bar` is not one
+            Some((host, path))
+                if !raw.contains("://")
+                    && !host.contains('/')
+                    && (host.contains('.') || host.contains('@')) =>
+            {
+                format!("ssh://{host}/{path}")
+            }
+            _ => raw.to_string(),
+        };
+        let u = Url::parse(&normalized).map_err(|_| RepoParseError::InvalidUrl(raw.to_string()))?;
+        Self::from_parsed_url(&u)
+    }
+
+    /// Parse a repository from an already-parsed git remote URL.
     ///
     /// # Errors
     ///
     /// Returns an error if the URL cannot be parsed as a repository reference.
-    pub fn from_url(u: &Url) -> Result<Self, RepoParseError> {
+    pub fn from_parsed_url(u: &Url) -> Result<Self, RepoParseError> {
         let host = u
             .host_str()
             .ok_or_else(|| RepoParseError::InvalidUrl(u.to_string()))?;
@@ -77,7 +105,7 @@ impl Repo {
         let path = u.path().trim_start_matches('/').trim_end_matches(".git");
         let parts: Vec<&str> = path.split('/').collect();
 
-        if parts.len() < 2 {
+        if parts.len() < 2 || parts[0].is_empty() || parts[1].is_empty() {
             return Err(RepoParseError::InvalidUrl(u.to_string()));
         }
 
@@ -198,7 +226,7 @@ mod tests {
         #[case] host: &str,
     ) {
         let u = Url::parse(url_str).unwrap();
-        let repo = Repo::from_url(&u).unwrap();
+        let repo = Repo::from_parsed_url(&u).unwrap();
         assert_eq!(repo.owner(), owner);
         assert_eq!(repo.name(), name);
         assert_eq!(repo.host(), host);
@@ -207,13 +235,47 @@ mod tests {
     #[test]
     fn test_should_reject_url_without_enough_path_segments() {
         let u = Url::parse("https://github.com/only-owner").unwrap();
-        assert!(Repo::from_url(&u).is_err());
+        assert!(Repo::from_parsed_url(&u).is_err());
     }
 
     #[test]
     fn test_should_reject_url_without_host() {
         let u = Url::parse("file:///some/path").unwrap();
-        assert!(Repo::from_url(&u).is_err());
+        assert!(Repo::from_parsed_url(&u).is_err());
+    }
+
+    #[rstest]
+    #[case("https://github.com/cli/cli", "cli", "cli", "github.com")]
+    #[case("https://ghe.corp/team/app.git", "team", "app", "ghe.corp")]
+    #[case("git@ghe.corp:team/app.git", "team", "app", "ghe.corp")]
+    #[case("git@github.com:cli/cli", "cli", "cli", "github.com")]
+    #[case("ghe.corp:team/app", "team", "app", "ghe.corp")]
+    #[case("ssh://git@ghe.corp/team/app", "team", "app", "ghe.corp")]
+    #[case("ssh://git@ghe.corp:2222/team/app.git", "team", "app", "ghe.corp")]
+    #[case("git+https://ghe.corp/team/app.git", "team", "app", "ghe.corp")]
+    fn test_should_parse_url_string(
+        #[case] input: &str,
+        #[case] owner: &str,
+        #[case] name: &str,
+        #[case] host: &str,
+    ) {
+        let repo = Repo::from_url(input).unwrap();
+        assert_eq!(repo.owner(), owner);
+        assert_eq!(repo.name(), name);
+        assert_eq!(repo.host(), host);
+    }
+
+    #[rstest]
+    #[case("cli/cli")]
+    #[case("git@ghe.corp:team")]
+    #[case("https://ghe.corp/")]
+    #[case("C:foo/bar")]
+    #[case("local:team/app")]
+    fn test_should_reject_url_string_without_repository(#[case] input: &str) {
+        assert!(matches!(
+            Repo::from_url(input),
+            Err(RepoParseError::InvalidUrl(_))
+        ));
     }
 
     #[test]
@@ -2,16 +2,86 @@
 //!
 //! Maps from Go's `internal/tableprinter` package.
 
-use comfy_table::{Cell, ContentArrangement, Table as ComfyTable};
+use crate::iostreams::{ColorScheme, IOStreams};
+use crate::text;
 
-use crate::iostreams::IOStreams;
+/// Separator placed between columns in TTY mode.
+const COLUMN_SEPARATOR: &str = "  ";
+
+/// Narrowest a truncatable column is shrunk to when fitting the terminal.
+const MIN_FLEXIBLE_WIDTH: usize = 5;
+
+/// Horizontal alignment of a table column in TTY mode.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Align {
+    /// Pad on the right (default).
+    #[default]
+    Left,
+    /// Pad on the left, typically for numeric columns.
+    Right,
+    /// Pad evenly on both sides.
+    Center,
+}
+
+/// Pad `cell` with spaces to `width` display columns according to `align`.
+///
+/// Escape sequences in `cell` do not count towards its width.
+pub fn pad_cell(cell: &str, width: usize, align: Align) -> String {
+    let diff = width.saturating_sub(text::display_width(cell));
+    let (left, right) = match align {
+        Align::Left => (0, diff),
+        Align::Right => (diff, 0),
+        Align::Center => (diff / 2, diff - diff / 2),
+    };
+    format!("{}{cell}{}", " ".repeat(left), " ".repeat(right))
+}
+
+/// Where to cut a cell that exceeds its column's maximum width.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Truncation {
+    /// Keep the start and append an ellipsis (default).
+    #[default]
+    End,
+    /// Keep both ends and elide the middle, for refs and URLs.
+    Middle,
+}
+
+/// Options for a field added with [`TablePrinter::add_field_with_opts`].
+///
+/// Options apply to the field's whole column and only affect TTY output.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FieldOpts {
+    /// Let the column shrink, with an ellipsis, so the table fits the terminal.
+    pub truncate: bool,
+    /// Cap the column at this many display columns.
+    pub max_width: Option<usize>,
+}
+
+/// Per-column rendering options.
+#[derive(Debug, Clone, Copy, Default)]
+struct ColumnConfig {
+    align: Align,
+    max_width: Option<usize>,
+    truncation: Truncation,
+    flexible: bool,
+}
 
 /// Table printer that adapts output based on TTY/non-TTY mode.
 #[derive(Debug)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct TablePrinter {
     is_tty: bool,
+    accessible: bool,
+    hyperlinks: bool,
+    /// Keep color escapes in plain output, as when `--color=always` is piped.
+    /// Hyperlinks are still removed.
+    color: bool,
+    cs: ColorScheme,
     headers: Vec<String>,
+    columns: Vec<ColumnConfig>,
     rows: Vec<Vec<String>>,
+    current_row: Vec<String>,
+    max_width: Option<usize>,
 }
 
 impl TablePrinter {
@@ -19,26 +89,105 @@ impl TablePrinter {
     pub fn new(ios: &IOStreams) -> Self {
         Self {
             is_tty: ios.is_stdout_tty(),
+            accessible: ios.is_accessible(),
+            hyperlinks: ios.is_stdout_tty() && ios.hyperlinks_enabled(),
+            color: ios.color_enabled(),
+            cs: ios.color_scheme(),
             headers: Vec::new(),
+            columns: Vec::new(),
             rows: Vec::new(),
+            current_row: Vec::new(),
+            max_width: ios.is_stdout_tty().then(|| ios.terminal_width()),
         }
     }
 
+    /// Fit TTY output to `cols` display columns instead of the terminal width.
+    #[must_use]
+    pub fn with_max_width(mut self, cols: usize) -> Self {
+        self.max_width = Some(cols);
+        self
+    }
+
     /// Set table headers. Pass empty to disable headers.
     #[must_use]
     pub fn with_headers(mut self, headers: &[&str]) -> Self {
-        self.headers = headers.iter().map(|h| h.to_uppercase()).collect();
+        self.set_header(headers);
         self
     }
 
+    /// Set the header row. Headers are uppercased and only rendered in TTY mode.
+    pub fn set_header(&mut self, cols: &[&str]) {
+        self.headers = cols.iter().map(|h| h.to_uppercase()).collect();
+    }
+
+    /// Set the alignment of the column at `col` (zero-based).
+    pub fn set_column_align(&mut self, col: usize, align: Align) {
+        self.column_mut(col).align = align;
+    }
+
+    /// Limit the column at `col` to `max_width` display columns in TTY mode.
+    ///
+    /// Longer cells are cut according to `truncation`. Plain output is never truncated.
+    pub fn set_column_max_width(&mut self, col: usize, max_width: usize, truncation: Truncation) {
+        let column = self.column_mut(col);
+        column.max_width = Some(max_width);
+        column.truncation = truncation;
+    }
+
+    fn column_mut(&mut self, col: usize) -> &mut ColumnConfig {
+        if self.columns.len() <= col {
+            self.columns.resize(col + 1, ColumnConfig::default());
+        }
+        &mut self.columns[col]
+    }
+
+    fn column(&self, col: usize) -> ColumnConfig {
+        self.columns.get(col).copied().unwrap_or_default()
+    }
+
+    /// Build a cell showing `text` that links to `url`.
+    ///
+    /// Emits an OSC 8 hyperlink when the terminal supports it, otherwise
+    /// returns the plain text. Column widths only count the visible text.
+    pub fn link(&self, text: &str, url: &str) -> String {
+        if self.hyperlinks && !url.is_empty() {
+            text::hyperlink(text, url)
+        } else {
+            text.to_string()
+        }
+    }
+
     /// Add a row of values.
     pub fn add_row(&mut self, fields: Vec<String>) {
         self.rows.push(fields);
     }
 
+    /// Append a field to the current row. Call [`Self::end_row`] to finish it.
+    pub fn add_field(&mut self, text: &str) {
+        self.add_field_with_opts(text, FieldOpts::default());
+    }
+
+    /// Append a field to the current row, applying `opts` to its column.
+    pub fn add_field_with_opts(&mut self, text: &str, opts: FieldOpts) {
+        let column = self.column_mut(self.current_row.len());
+        column.flexible |= opts.truncate;
+        if let Some(max_width) = opts.max_width {
+            column.max_width = Some(max_width);
+        }
+        self.current_row.push(text.to_string());
+    }
+
+    /// Finish the row started with [`Self::add_field`].
+    pub fn end_row(&mut self) {
+        let row = std::mem::take(&mut self.current_row);
+        self.rows.push(row);
+    }
+
     /// Render the table to a string.
     pub fn render(&self) -> String {
-        if self.is_tty {
+        if self.is_tty && self.accessible {
+            self.render_accessible()
+        } else if self.is_tty {
             self.render_tty()
         } else {
             self.render_plain()
@@ -46,27 +195,129 @@ impl TablePrinter {
     }
 
     fn render_tty(&self) -> String {
-        let mut table = ComfyTable::new();
-        table.set_content_arrangement(ContentArrangement::Dynamic);
-        table.load_preset(comfy_table::presets::NOTHING);
+        let headers: Vec<String> = self.headers.iter().map(|h| self.cs.accent(h)).collect();
+        let mut rows: Vec<Vec<String>> =
+            self.rows.iter().map(|row| self.truncate_row(row)).collect();
+        if !headers.is_empty() {
+            rows.insert(0, headers);
+        }
 
-        if !self.headers.is_empty() {
-            let header_cells: Vec<Cell> = self.headers.iter().map(Cell::new).collect();
-            table.set_header(header_cells);
+        let num_cols = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let mut widths = vec![0; num_cols];
+        for row in &rows {
+            for (i, cell) in row.iter().enumerate() {
+                widths[i] = widths[i].max(text::display_width(cell));
+            }
         }
 
-        for row in &self.rows {
-            let cells: Vec<Cell> = row.iter().map(Cell::new).collect();
-            table.add_row(cells);
+        if self.fit_to_width(&mut widths) {
+            for row in &mut rows {
+                for (cell, &width) in row.iter_mut().zip(&widths) {
+                    if text::display_width(cell) > width {
+                        *cell = text::truncate(cell, width);
+                    }
+                }
+            }
         }
 
-        table.to_string()
+        rows.iter()
+            .map(|line| self.render_tty_line(line, &widths))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Shrink truncatable columns, widest first, until the table fits the
+    /// maximum width. Returns whether any column was shrunk.
+    fn fit_to_width(&self, widths: &mut [usize]) -> bool {
+        let Some(max_width) = self.max_width else {
+            return false;
+        };
+        let separators = COLUMN_SEPARATOR.len() * widths.len().saturating_sub(1);
+        let mut shrunk = false;
+        loop {
+            let excess = (widths.iter().sum::<usize>() + separators).saturating_sub(max_width);
+            if excess == 0 {
+                return shrunk;
+            }
+            let widest = widths
+                .iter()
+                .enumerate()
+                .filter(|&(i, &w)| self.column(i).flexible && w > MIN_FLEXIBLE_WIDTH)
+                .max_by_key(|&(_, &w)| w)
+                .map(|(i, _)| i);
+            let Some(col) = widest else {
+                return shrunk;
+            };
+            widths[col] = widths[col].saturating_sub(excess).max(MIN_FLEXIBLE_WIDTH);
+            shrunk = true;
+        }
+    }
+
+    fn truncate_row(&self, row: &[String]) -> Vec<String> {
+        row.iter()
+            .enumerate()
+            .map(|(i, cell)| {
+                let column = self.column(i);
+                match (column.max_width, column.truncation) {
+                    (None, _) => cell.clone(),
+                    (Some(width), Truncation::End) => text::truncate(cell, width),
+                    (Some(width), Truncation::Middle) => text::truncate_middle(cell, width),
+                }
+            })
+            .collect()
+    }
+
+    fn render_tty_line(&self, line: &[String], widths: &[usize]) -> String {
+        let last = line.len().saturating_sub(1);
+        let cells: Vec<String> = line
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| match self.column(i).align {
+                // Avoid trailing whitespace on the final column.
+                Align::Left if i == last => cell.clone(),
+                align => pad_cell(cell, widths[i], align),
+            })
+            .collect();
+        cells.join(COLUMN_SEPARATOR)
+    }
+
+    /// Render each row as one line of `HEADER: value` pairs for screen
+    /// readers, without padding or truncation.
+    fn render_accessible(&self) -> String {
+        self.rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .map(|(i, cell)| {
+                        let cell = text::strip_escapes(cell);
+                        match self.headers.get(i) {
+                            Some(header) => format!("{header}: {cell}"),
+                            None => cell,
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 
     fn render_plain(&self) -> String {
         self.rows
             .iter()
-            .map(|row| row.join("\t"))
+            .map(|row| {
+                row.iter()
+                    .map(|cell| {
+                        if self.color {
+                            text::strip_hyperlinks(cell)
+                        } else {
+                            text::strip_escapes(cell)
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\t")
+            })
             .collect::<Vec<_>>()
             .join("\n")
     }
@@ -86,6 +337,12 @@ impl TablePrinter {
 mod tests {
     use super::*;
 
+    fn tty_streams() -> IOStreams {
+        let mut ios = IOStreams::test();
+        ios.set_stdout_tty(true);
+        ios
+    }
+
     #[test]
     fn test_should_render_plain_tab_separated() {
         let ios = IOStreams::test();
@@ -152,4 +409,251 @@ mod tests {
         let output = tp.render();
         assert_eq!(output, "only-column");
     }
+
+    #[test]
+    fn test_should_render_header_row_in_tty() {
+        let ios = tty_streams();
+        let mut tp = TablePrinter::new(&ios);
+        tp.set_header(&["id", "title"]);
+        tp.add_row(vec!["1".into(), "hello".into()]);
+
+        let output = tp.render();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines, vec!["ID  TITLE", "1   hello"]);
+    }
+
+    #[test]
+    fn test_should_render_header_in_bold_when_color_enabled() {
+        let mut ios = tty_streams();
+        ios.set_color_enabled(true);
+        let mut tp = TablePrinter::new(&ios);
+        tp.set_header(&["id"]);
+        tp.add_row(vec!["1".into()]);
+
+        let output = tp.render();
+        let header = output.lines().next().unwrap();
+        assert_eq!(header, ios.color_scheme().bold("ID"));
+    }
+
+    #[test]
+    fn test_should_suppress_headers_in_plain_mode() {
+        let ios = IOStreams::test();
+        let mut tp = TablePrinter::new(&ios);
+        tp.set_header(&["id", "title"]);
+        tp.add_row(vec!["1".into(), "hello".into()]);
+
+        assert_eq!(tp.render(), "1\thello");
+    }
+
+    #[test]
+    fn test_should_right_align_numeric_column() {
+        let ios = tty_streams();
+        let mut tp = TablePrinter::new(&ios);
+        tp.set_column_align(0, Align::Right);
+        tp.add_row(vec!["7".into(), "seven".into()]);
+        tp.add_row(vec!["1024".into(), "big".into()]);
+
+        let output = tp.render();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines, vec!["   7  seven", "1024  big"]);
+    }
+
+    #[test]
+    fn test_should_pad_cell_by_alignment() {
+        assert_eq!(pad_cell("ab", 6, Align::Left), "ab    ");
+        assert_eq!(pad_cell("ab", 6, Align::Right), "    ab");
+        assert_eq!(pad_cell("ab", 5, Align::Center), " ab  ");
+        assert_eq!(pad_cell("abcdef", 3, Align::Right), "abcdef");
+    }
+
+    #[test]
+    fn test_should_measure_width_ignoring_ansi_codes() {
+        let mut ios = tty_streams();
+        ios.set_color_enabled(true);
+        let mut tp = TablePrinter::new(&ios);
+        let cs = ios.color_scheme();
+        tp.add_row(vec![cs.bold("ab"), "x".into()]);
+        tp.add_row(vec!["abcd".into(), "y".into()]);
+
+        let output = tp.render();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(console::strip_ansi_codes(lines[0]), "ab    x");
+        assert_eq!(lines[1], "abcd  y");
+    }
+
+    #[test]
+    fn test_should_truncate_column_to_max_width_in_tty() {
+        let ios = tty_streams();
+        let mut tp = TablePrinter::new(&ios);
+        tp.set_column_max_width(0, 8, Truncation::End);
+        tp.set_column_max_width(1, 9, Truncation::Middle);
+        tp.add_row(vec!["hello world".into(), "abcdefghij".into()]);
+
+        assert_eq!(tp.render(), "hello...  abc...hij");
+    }
+
+    #[test]
+    fn test_should_not_truncate_plain_output() {
+        let ios = IOStreams::test();
+        let mut tp = TablePrinter::new(&ios);
+        tp.set_column_max_width(0, 8, Truncation::End);
+        tp.add_row(vec!["hello world".into()]);
+
+        assert_eq!(tp.render(), "hello world");
+    }
+
+    #[test]
+    fn test_should_wrap_link_cell_in_hyperlink_escape() {
+        let mut ios = tty_streams();
+        ios.set_hyperlinks_enabled(true);
+        let tp = TablePrinter::new(&ios);
+
+        let cell = tp.link("#1", "https://github.com/o/r/issues/1");
+        assert_eq!(
+            cell,
+            text::hyperlink("#1", "https://github.com/o/r/issues/1")
+        );
+    }
+
+    #[test]
+    fn test_should_render_plain_link_without_hyperlink_support() {
+        let ios = tty_streams();
+        let tp = TablePrinter::new(&ios);
+        assert_eq!(tp.link("#1", "https://github.com/o/r/issues/1"), "#1");
+
+        let mut ios = IOStreams::test();
+        ios.set_hyperlinks_enabled(true);
+        let tp = TablePrinter::new(&ios);
+        assert_eq!(tp.link("#1", "https://github.com/o/r/issues/1"), "#1");
+    }
+
+    #[test]
+    fn test_should_ignore_hyperlink_escape_in_column_width() {
+        let mut ios = tty_streams();
+        ios.set_hyperlinks_enabled(true);
+        let mut tp = TablePrinter::new(&ios);
+        let link = tp.link("#1", "https://github.com/o/r/issues/1");
+        tp.add_row(vec![link.clone(), "first".into()]);
+        tp.add_row(vec!["#100".into(), "second".into()]);
+
+        let output = tp.render();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0], format!("{link}    first"));
+        assert_eq!(lines[1], "#100  second");
+    }
+
+    #[test]
+    fn test_should_build_rows_from_fields() {
+        let ios = IOStreams::test();
+        let mut tp = TablePrinter::new(&ios);
+        tp.add_field("1");
+        tp.add_field_with_opts("hello", FieldOpts::default());
+        tp.end_row();
+        tp.add_field("2");
+        tp.add_field("world");
+        tp.end_row();
+
+        assert_eq!(tp.len(), 2);
+        assert_eq!(tp.render(), "1\thello\n2\tworld");
+    }
+
+    #[test]
+    fn test_should_truncate_flexible_column_to_fit_max_width() {
+        let ios = tty_streams();
+        let mut tp = TablePrinter::new(&ios).with_max_width(20);
+        let flexible = FieldOpts {
+            truncate: true,
+            ..FieldOpts::default()
+        };
+        tp.add_field("#1");
+        tp.add_field_with_opts("a very long issue title", flexible);
+        tp.add_field("open");
+        tp.end_row();
+
+        let output = tp.render();
+        assert_eq!(output, "#1  a very ...  open");
+        assert_eq!(text::display_width(&output), 20);
+    }
+
+    #[test]
+    fn test_should_keep_rows_that_fit_untruncated() {
+        let ios = tty_streams();
+        let mut tp = TablePrinter::new(&ios).with_max_width(80);
+        let flexible = FieldOpts {
+            truncate: true,
+            ..FieldOpts::default()
+        };
+        tp.add_field_with_opts("short title", flexible);
+        tp.add_field("open");
+        tp.end_row();
+
+        assert_eq!(tp.render(), "short title  open");
+    }
+
+    #[test]
+    fn test_should_apply_field_max_width() {
+        let ios = tty_streams();
+        let mut tp = TablePrinter::new(&ios).with_max_width(80);
+        let capped = FieldOpts {
+            max_width: Some(8),
+            ..FieldOpts::default()
+        };
+        tp.add_field_with_opts("hello world", capped);
+        tp.add_field("x");
+        tp.end_row();
+
+        assert_eq!(tp.render(), "hello...  x");
+    }
+
+    #[test]
+    fn test_should_not_fit_plain_output_to_width() {
+        let ios = IOStreams::test();
+        let mut tp = TablePrinter::new(&ios).with_max_width(10);
+        let flexible = FieldOpts {
+            truncate: true,
+            ..FieldOpts::default()
+        };
+        tp.add_field_with_opts("a very long issue title", flexible);
+        tp.add_field("open");
+        tp.end_row();
+
+        assert_eq!(tp.render(), "a very long issue title\topen");
+    }
+
+    #[test]
+    fn test_should_keep_forced_color_but_strip_links_from_plain_output() {
+        let mut ios = IOStreams::test();
+        ios.set_color_enabled(true);
+        ios.set_hyperlinks_enabled(true);
+        let cs = ios.color_scheme();
+        let row = vec![
+            cs.bold("bug"),
+            text::hyperlink("#1", "https://github.com/o/r/issues/1"),
+        ];
+
+        let mut tp = TablePrinter::new(&ios);
+        tp.add_row(row.clone());
+        assert_eq!(tp.render(), format!("{}\t#1", cs.bold("bug")));
+
+        ios.set_color_enabled(false);
+        let mut tp = TablePrinter::new(&ios);
+        tp.add_row(row);
+        assert_eq!(tp.render(), "bug\t#1");
+    }
+
+    #[test]
+    fn test_should_render_linear_rows_when_accessible() {
+        let mut ios = tty_streams();
+        ios.set_accessible(true);
+        let mut tp = TablePrinter::new(&ios).with_max_width(10);
+        tp.set_header(&["id", "title"]);
+        tp.set_column_max_width(1, 5, Truncation::End);
+        tp.add_row(vec!["1".into(), "a long title".into()]);
+        tp.add_row(vec!["22".into(), "short".into()]);
+
+        assert_eq!(
+            tp.render(),
+            "ID: 1, TITLE: a long title\nID: 22, TITLE: short"
+        );
+    }
 }
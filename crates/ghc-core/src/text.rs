@@ -5,51 +5,289 @@
 use base64::Engine;
 use chrono::{DateTime, SecondsFormat, Utc};
 
+/// Ellipsis appended (or inserted) when text is truncated.
+const ELLIPSIS: &str = "...";
+
 /// Truncate a string to a maximum display width, appending "..." if truncated.
+///
+/// Width is measured in terminal columns, so wide characters (e.g. CJK)
+/// count as two. ANSI escape sequences are preserved and do not count
+/// towards the width. Widths below 4 truncate without an ellipsis.
 pub fn truncate(text: &str, max_width: usize) -> String {
-    if max_width < 4 {
-        return text.chars().take(max_width).collect();
+    if display_width(text) <= max_width {
+        return text.to_string();
     }
+    let tail = if max_width < 4 { "" } else { ELLIPSIS };
+    truncate_with_tail(text, max_width, tail)
+}
 
-    let char_count: usize = text.chars().count();
-    if char_count <= max_width {
+/// Truncate a string to a maximum display width by replacing its middle with "...".
+///
+/// Useful for long refs and URLs where both ends carry meaning. ANSI escape
+/// sequences are preserved and wide characters count as two columns.
+///
+/// # Examples
+///
+/// ```
+/// use ghc_core::text::truncate_middle;
+/// assert_eq!(truncate_middle("feature/very-long-branch", 13), "featu...ranch");
+/// ```
+pub fn truncate_middle(text: &str, max_width: usize) -> String {
+    let total = display_width(text);
+    if total <= max_width {
         return text.to_string();
     }
+    if max_width < ELLIPSIS.len() + 2 {
+        return truncate(text, max_width);
+    }
 
-    let truncated: String = text.chars().take(max_width - 3).collect();
-    format!("{truncated}...")
+    let budget = max_width - ELLIPSIS.len();
+    let head_budget = budget - budget / 2;
+    let tail_start = total - budget / 2;
+
+    let mut out = String::with_capacity(text.len());
+    let mut pos = 0;
+    let mut inserted = false;
+    for (segment, is_escape) in EscapeSegments::new(text) {
+        if is_escape {
+            out.push_str(segment);
+            continue;
+        }
+        for c in segment.chars() {
+            let start = pos;
+            pos += char_width(c);
+            if pos <= head_budget || start >= tail_start {
+                out.push(c);
+            } else if !inserted {
+                out.push_str(ELLIPSIS);
+                inserted = true;
+            }
+        }
+    }
+    out
 }
 
-/// Format a duration as a human-readable fuzzy time string.
-pub fn fuzzy_ago(duration: chrono::Duration) -> String {
-    let seconds = duration.num_seconds();
+/// Cut visible text at `max_width` columns (including `tail`), keeping escape sequences.
+fn truncate_with_tail(text: &str, max_width: usize, tail: &str) -> String {
+    let limit = max_width.saturating_sub(tail.len());
+    let mut out = String::with_capacity(text.len());
+    let mut width = 0;
+    let mut cut = false;
+    for (segment, is_escape) in EscapeSegments::new(text) {
+        if is_escape {
+            out.push_str(segment);
+            continue;
+        }
+        if cut {
+            continue;
+        }
+        // Measure whole prefixes: sequences such as `0\u{fe0f}` are wider
+        // than the sum of their characters.
+        let fits = |end: usize| width + console::measure_text_width(&segment[..end]) <= limit;
+        if let Some((i, _)) = segment
+            .char_indices()
+            .find(|&(i, c)| !fits(i + c.len_utf8()))
+        {
+            out.push_str(&segment[..i]);
+            out.push_str(tail);
+            cut = true;
+        } else {
+            width += console::measure_text_width(segment);
+            out.push_str(segment);
+        }
+    }
+    out
+}
 
-    if seconds < 60 {
-        return "less than a minute ago".to_string();
+/// Display width of a single character in terminal columns.
+fn char_width(c: char) -> usize {
+    let mut buf = [0; 4];
+    console::measure_text_width(c.encode_utf8(&mut buf))
+}
+
+/// Wrap `text` in an OSC 8 terminal hyperlink pointing at `url`.
+///
+/// The escape sequences are invisible; only `text` is displayed.
+pub fn hyperlink(text: &str, url: &str) -> String {
+    format!("\x1b]8;;{url}\x1b\\{text}\x1b]8;;\x1b\\")
+}
+
+/// Measure the visible width of a string in terminal columns.
+///
+/// ANSI SGR sequences and OSC 8 hyperlinks are ignored and wide characters
+/// (e.g. CJK) count as two columns.
+pub fn display_width(text: &str) -> usize {
+    EscapeSegments::new(text)
+        .filter(|(_, is_escape)| !is_escape)
+        .map(|(segment, _)| console::measure_text_width(segment))
+        .sum()
+}
+
+/// Remove ANSI SGR sequences and OSC 8 hyperlinks, keeping the visible text.
+///
+/// # Examples
+///
+/// ```
+/// use ghc_core::text::{hyperlink, strip_escapes};
+/// assert_eq!(strip_escapes(&hyperlink("#1", "https://example.com")), "#1");
+/// assert_eq!(strip_escapes("\x1b[1mbold\x1b[0m"), "bold");
+/// ```
+pub fn strip_escapes(text: &str) -> String {
+    EscapeSegments::new(text)
+        .filter(|(_, is_escape)| !is_escape)
+        .map(|(segment, _)| segment)
+        .collect()
+}
+
+/// Remove OSC 8 hyperlinks, keeping the link text and any color escapes.
+///
+/// # Examples
+///
+/// ```
+/// use ghc_core::text::{hyperlink, strip_hyperlinks};
+/// let bold = "\x1b[1m#1\x1b[0m";
+/// assert_eq!(strip_hyperlinks(&hyperlink(bold, "https://example.com")), bold);
+/// ```
+pub fn strip_hyperlinks(text: &str) -> String {
+    EscapeSegments::new(text)
+        .filter(|(segment, is_escape)| !is_escape || !segment.starts_with("\x1b]"))
+        .map(|(segment, _)| segment)
+        .collect()
+}
+
+/// Iterator splitting a string into visible text and escape sequences.
+///
+/// Yields `(segment, is_escape)` pairs. Recognizes CSI sequences
+/// (`ESC [ ... final`) and OSC sequences (`ESC ] ... BEL` or `ESC ] ... ESC \`).
+struct EscapeSegments<'a> {
+    rest: &'a str,
+}
+
+impl<'a> EscapeSegments<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { rest: text }
     }
 
-    let minutes = seconds / 60;
-    if minutes < 60 {
-        return pluralize(minutes, "minute", "minutes") + " ago";
+    /// Byte length of the escape sequence at the start of `s`, which begins with ESC.
+    fn escape_len(s: &str) -> usize {
+        let bytes = s.as_bytes();
+        match bytes.get(1) {
+            Some(b'[') => bytes[2..]
+                .iter()
+                .position(|b| (0x40..=0x7e).contains(b))
+                .map_or(bytes.len(), |i| i + 3),
+            Some(b']') => {
+                let mut i = 2;
+                while i < bytes.len() {
+                    match bytes[i] {
+                        0x07 => return i + 1,
+                        0x1b if bytes.get(i + 1) == Some(&b'\\') => return i + 2,
+                        _ => i += 1,
+                    }
+                }
+                bytes.len()
+            }
+            Some(b) if b.is_ascii() => 2,
+            _ => 1,
+        }
     }
+}
 
-    let hours = minutes / 60;
-    if hours < 24 {
-        return pluralize(hours, "hour", "hours") + " ago";
+impl<'a> Iterator for EscapeSegments<'a> {
+    type Item = (&'a str, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (len, is_escape) = if self.rest.starts_with('\x1b') {
+            (Self::escape_len(self.rest), true)
+        } else {
+            (self.rest.find('\x1b').unwrap_or(self.rest.len()), false)
+        };
+        let (segment, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        Some((segment, is_escape))
     }
+}
 
-    let days = hours / 24;
-    if days < 30 {
-        return pluralize(days, "day", "days") + " ago";
+/// Format a duration as a human-readable fuzzy time string.
+pub fn fuzzy_ago(duration: chrono::Duration) -> String {
+    match fuzzy_span(duration) {
+        Some(span) => span + " ago",
+        None => "less than a minute ago".to_string(),
     }
+}
 
-    let months = days / 30;
-    if months < 12 {
-        return pluralize(months, "month", "months") + " ago";
+/// Format a duration until a future event (e.g., "in 5 minutes").
+pub fn fuzzy_until(duration: chrono::Duration) -> String {
+    match fuzzy_span(duration) {
+        Some(span) => format!("in {span}"),
+        None => "in less than a minute".to_string(),
     }
+}
 
-    let years = months / 12;
-    pluralize(years, "year", "years") + " ago"
+/// Format a duration compactly for tables (e.g., "3d", "2mo").
+///
+/// Anything under a minute is "just now"; negative durations (timestamps in
+/// the future, usually clock skew) are clamped to it as well.
+pub fn fuzzy_ago_abbr(duration: chrono::Duration) -> String {
+    match fuzzy_bucket(duration) {
+        Some((n, FuzzyUnit::Minute)) => format!("{n}m"),
+        Some((n, FuzzyUnit::Hour)) => format!("{n}h"),
+        Some((n, FuzzyUnit::Day)) => format!("{n}d"),
+        Some((n, FuzzyUnit::Month)) => format!("{n}mo"),
+        Some((n, FuzzyUnit::Year)) => format!("{n}y"),
+        None => "just now".to_string(),
+    }
+}
+
+/// Coarse span for a duration, or `None` when under a minute.
+fn fuzzy_span(duration: chrono::Duration) -> Option<String> {
+    let (n, unit) = fuzzy_bucket(duration)?;
+    Some(match unit {
+        FuzzyUnit::Minute => pluralize(n, "minute", "minutes"),
+        FuzzyUnit::Hour => pluralize(n, "hour", "hours"),
+        FuzzyUnit::Day => pluralize(n, "day", "days"),
+        FuzzyUnit::Month => pluralize(n, "month", "months"),
+        FuzzyUnit::Year => pluralize(n, "year", "years"),
+    })
+}
+
+/// Unit a fuzzy duration is expressed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FuzzyUnit {
+    Minute,
+    Hour,
+    Day,
+    Month,
+    Year,
+}
+
+/// Bucket a duration into a count of its largest whole unit, or `None` when
+/// under a minute (including negative durations).
+///
+/// Months are 30 days and years are 365 days.
+fn fuzzy_bucket(duration: chrono::Duration) -> Option<(i64, FuzzyUnit)> {
+    let seconds = duration.num_seconds();
+    if seconds < 60 {
+        return None;
+    }
+
+    let minutes = seconds / 60;
+    let hours = minutes / 60;
+    let days = hours / 24;
+    Some(if minutes < 60 {
+        (minutes, FuzzyUnit::Minute)
+    } else if hours < 24 {
+        (hours, FuzzyUnit::Hour)
+    } else if days < 30 {
+        (days, FuzzyUnit::Day)
+    } else if days < 365 {
+        (days / 30, FuzzyUnit::Month)
+    } else {
+        (days / 365, FuzzyUnit::Year)
+    })
 }
 
 /// Format a timestamp for display based on whether output is a TTY.
@@ -65,6 +303,24 @@ pub fn relative_time_str(t: &DateTime<Utc>, is_tty: bool) -> String {
     }
 }
 
+/// Format a byte size into a human-readable string.
+#[allow(clippy::cast_precision_loss)]
+pub fn format_size(bytes: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = KB * 1024;
+    const GB: u64 = MB * 1024;
+
+    if bytes >= GB {
+        format!("{:.1} GB", bytes as f64 / GB as f64)
+    } else if bytes >= MB {
+        format!("{:.1} MB", bytes as f64 / MB as f64)
+    } else if bytes >= KB {
+        format!("{:.1} KB", bytes as f64 / KB as f64)
+    } else {
+        format!("{bytes} B")
+    }
+}
+
 /// Simple English pluralization.
 pub fn pluralize(count: i64, singular: &str, plural: &str) -> String {
     if count == 1 {
@@ -225,6 +481,58 @@ mod tests {
         assert_eq!(truncate("", 10), "");
     }
 
+    #[rstest]
+    #[case(
+        "\u{4f60}\u{597d}\u{4e16}\u{754c}",
+        8,
+        "\u{4f60}\u{597d}\u{4e16}\u{754c}"
+    )]
+    #[case("\u{4f60}\u{597d}\u{4e16}\u{754c}", 7, "\u{4f60}\u{597d}...")]
+    #[case("\u{4f60}\u{597d}\u{4e16}\u{754c}", 6, "\u{4f60}...")]
+    #[case("\u{4f60}\u{597d}", 3, "\u{4f60}")]
+    fn test_should_truncate_by_cjk_display_width(
+        #[case] input: &str,
+        #[case] width: usize,
+        #[case] expected: &str,
+    ) {
+        let result = truncate(input, width);
+        assert_eq!(result, expected);
+        assert!(display_width(&result) <= width);
+    }
+
+    #[test]
+    fn test_should_keep_ansi_sequences_when_truncating() {
+        let input = "\x1b[32mhello world\x1b[0m";
+        assert_eq!(truncate(input, 8), "\x1b[32mhello...\x1b[0m");
+    }
+
+    #[rstest]
+    #[case("feature/very-long-branch", 13, "featu...ranch")]
+    #[case("abcdefghij", 9, "abc...hij")]
+    #[case("abcdefghij", 8, "abc...ij")]
+    #[case("short", 10, "short")]
+    #[case("abcdefghij", 4, "a...")]
+    fn test_should_truncate_middle(
+        #[case] input: &str,
+        #[case] width: usize,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(truncate_middle(input, width), expected);
+    }
+
+    #[test]
+    fn test_should_truncate_middle_by_cjk_display_width() {
+        let result = truncate_middle("\u{4f60}\u{597d}\u{4e16}\u{754c}\u{4f60}\u{597d}", 9);
+        assert_eq!(result, "\u{4f60}...\u{597d}");
+        assert!(display_width(&result) <= 9);
+    }
+
+    #[test]
+    fn test_should_keep_ansi_sequences_when_truncating_middle() {
+        let input = "\x1b[1mabcdefghij\x1b[0m";
+        assert_eq!(truncate_middle(input, 9), "\x1b[1mabc...hij\x1b[0m");
+    }
+
     #[test]
     fn test_should_handle_unicode_truncation() {
         // Multi-byte characters should not be split
@@ -232,6 +540,32 @@ mod tests {
         assert_eq!(result, "hello");
     }
 
+    // --- hyperlink / display_width tests ---
+
+    #[test]
+    fn test_should_wrap_visible_text_in_hyperlink_escape() {
+        let link = hyperlink("#42", "https://github.com/o/r/issues/42");
+        assert_eq!(
+            link,
+            "\x1b]8;;https://github.com/o/r/issues/42\x1b\\#42\x1b]8;;\x1b\\"
+        );
+    }
+
+    #[test]
+    fn test_should_ignore_hyperlink_escape_in_display_width() {
+        let link = hyperlink("#42", "https://github.com/o/r/issues/42");
+        assert_eq!(display_width(&link), 3);
+    }
+
+    #[rstest]
+    #[case("hello", 5)]
+    #[case("\x1b[1mbold\x1b[0m", 4)]
+    #[case("\u{4f60}\u{597d}", 4)]
+    #[case("", 0)]
+    fn test_should_measure_display_width(#[case] input: &str, #[case] expected: usize) {
+        assert_eq!(display_width(input), expected);
+    }
+
     // --- fuzzy_ago tests ---
 
     #[rstest]
@@ -251,12 +585,46 @@ mod tests {
     #[case(2_592_000, "1 month ago")]
     #[case(5_184_000, "2 months ago")]
     #[case(28_512_000, "11 months ago")]
-    #[case(31_104_000, "1 year ago")]
+    #[case(31_449_600, "12 months ago")]
+    #[case(31_536_000, "1 year ago")]
     #[case(63_072_000, "2 years ago")]
     fn test_should_format_fuzzy_ago(#[case] seconds: i64, #[case] expected: &str) {
         assert_eq!(fuzzy_ago(chrono::Duration::seconds(seconds)), expected);
     }
 
+    #[rstest]
+    #[case(-5, "in less than a minute")]
+    #[case(30, "in less than a minute")]
+    #[case(60, "in 1 minute")]
+    #[case(1800, "in 30 minutes")]
+    #[case(7200, "in 2 hours")]
+    fn test_should_format_fuzzy_until(#[case] seconds: i64, #[case] expected: &str) {
+        assert_eq!(fuzzy_until(chrono::Duration::seconds(seconds)), expected);
+    }
+
+    #[rstest]
+    #[case(0, "just now")]
+    #[case(59, "just now")]
+    #[case(-30, "just now")]
+    #[case(-300, "just now")]
+    #[case(-5_184_000, "just now")]
+    #[case(60, "1m")]
+    #[case(3599, "59m")]
+    #[case(3600, "1h")]
+    #[case(86_399, "23h")]
+    #[case(86_400, "1d")]
+    #[case(259_200, "3d")]
+    #[case(2_591_999, "29d")]
+    #[case(2_592_000, "1mo")]
+    #[case(5_184_000, "2mo")]
+    #[case(31_449_600, "12mo")]
+    #[case(31_535_999, "12mo")]
+    #[case(31_536_000, "1y")]
+    #[case(63_072_000, "2y")]
+    fn test_should_format_fuzzy_ago_abbr(#[case] seconds: i64, #[case] expected: &str) {
+        assert_eq!(fuzzy_ago_abbr(chrono::Duration::seconds(seconds)), expected);
+    }
+
     // --- relative_time_str tests ---
 
     #[test]
@@ -379,6 +747,14 @@ mod tests {
         assert_eq!(decoded, original);
     }
 
+    #[test]
+    fn test_should_format_size_correctly() {
+        assert_eq!(format_size(500), "500 B");
+        assert_eq!(format_size(1024), "1.0 KB");
+        assert_eq!(format_size(1_048_576), "1.0 MB");
+        assert_eq!(format_size(1_073_741_824), "1.0 GB");
+    }
+
     // --- property-based tests ---
 
     mod prop {
@@ -393,7 +769,16 @@ mod tests {
                 width in 0usize..=200,
             ) {
                 let result = truncate(&s, width);
-                prop_assert!(result.chars().count() <= width || width < 4 && result.chars().count() <= s.chars().count());
+                prop_assert!(display_width(&result) <= width);
+            }
+
+            #[test]
+            fn truncate_middle_output_never_exceeds_max_width(
+                s in "\\PC{0,200}",
+                width in 0usize..=200,
+            ) {
+                let result = truncate_middle(&s, width);
+                prop_assert!(display_width(&result) <= width);
             }
 
             #[test]
@@ -133,8 +133,8 @@ mod tests {
             _token: &str,
             _git_protocol: &str,
             _secure_storage: bool,
-        ) -> anyhow::Result<()> {
-            Ok(())
+        ) -> anyhow::Result<bool> {
+            Ok(false)
         }
 
         fn users_for_host(&self, _hostname: &str) -> Vec<String> {
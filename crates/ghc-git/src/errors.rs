@@ -39,6 +39,15 @@ pub enum GitError {
     #[error("empty credential pattern is not allowed unless provided explicitly")]
     InvalidCredentialPattern,
 
+    /// Git command did not finish within the configured timeout.
+    #[error("git {command} timed out after {}s", timeout.as_secs_f64())]
+    Timeout {
+        /// The git subcommand that timed out.
+        command: String,
+        /// The timeout that expired.
+        timeout: std::time::Duration,
+    },
+
     /// I/O error from subprocess.
     #[error("git IO error: {0}")]
     Io(#[from] std::io::Error),
@@ -110,6 +119,15 @@ mod tests {
         assert!(err.to_string().contains("credential pattern"));
     }
 
+    #[test]
+    fn test_should_display_timeout() {
+        let err = GitError::Timeout {
+            command: "fetch".to_string(),
+            timeout: std::time::Duration::from_secs(30),
+        };
+        assert_eq!(err.to_string(), "git fetch timed out after 30s");
+    }
+
     #[test]
     fn test_should_display_io_error() {
         let io_err = std::io::Error::new(std::io::ErrorKind::PermissionDenied, "access denied");
@@ -1,7 +1,5 @@
 //! Git remote parsing and management.
 
-use url::Url;
-
 use ghc_core::repo::Repo;
 
 /// A git remote with its name and URL.
@@ -95,30 +93,7 @@ fn remote_sort_key(name: &str) -> u8 {
 
 /// Parse a git remote URL into a Repo.
 pub fn parse_remote_url(url_str: &str) -> Option<Repo> {
-    // Try SSH format: git@github.com:owner/repo.git
-    if let Some(rest) = url_str.strip_prefix("git@") {
-        let colon_pos = rest.find(':')?;
-        let host = &rest[..colon_pos];
-        let path = rest[colon_pos + 1..].trim_end_matches(".git");
-        let parts: Vec<&str> = path.split('/').collect();
-        if parts.len() >= 2 {
-            return Some(Repo::with_host(parts[0], parts[1], host));
-        }
-    }
-
-    // Try SSH format: ssh://git@github.com/owner/repo.git
-    if url_str.starts_with("ssh://")
-        && let Ok(u) = Url::parse(url_str)
-    {
-        return Repo::from_url(&u).ok();
-    }
-
-    // Try HTTPS format
-    if let Ok(u) = Url::parse(url_str) {
-        return Repo::from_url(&u).ok();
-    }
-
-    None
+    Repo::from_url(url_str).ok()
 }
 
 #[cfg(test)]
@@ -2,9 +2,14 @@
 //!
 //! Feature-parity rewrite of the official GitHub CLI (`gh`).
 
-use clap::{Parser, Subcommand};
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fmt::Write as _;
+
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
 use tracing_subscriber::EnvFilter;
 
+use ghc_cmd::alias::expand::{self, Expansion};
 use ghc_cmd::factory::Factory;
 
 /// Exit codes matching the Go CLI behavior.
@@ -27,6 +32,40 @@ mod exit_codes {
 struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
+
+    /// Allow interactive prompts, overriding `GH_PROMPT_DISABLED` and config.
+    #[arg(long, global = true, overrides_with = "no_prompt")]
+    prompt: bool,
+
+    /// Never show interactive prompts.
+    #[arg(long, global = true, overrides_with = "prompt")]
+    no_prompt: bool,
+
+    /// When to use color in output.
+    #[arg(long, global = true, value_enum, value_name = "WHEN", default_value_t = ColorWhen::Auto)]
+    color: ColorWhen,
+}
+
+/// Values for the top-level `--color` flag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum ColorWhen {
+    /// Color when stdout is a terminal.
+    Auto,
+    /// Always emit color, even when piped.
+    Always,
+    /// Never emit color.
+    Never,
+}
+
+impl Cli {
+    /// The explicit prompt preference, if either flag was given.
+    fn prompt_flag(&self) -> Option<bool> {
+        match (self.prompt, self.no_prompt) {
+            (true, _) => Some(true),
+            (_, true) => Some(false),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Subcommand)]
@@ -60,6 +99,9 @@ enum Commands {
     Codespace(ghc_cmd::codespace::CodespaceCommand),
     /// Generate shell completion scripts.
     Completion(ghc_cmd::completion::CompletionArgs),
+    /// Print completion candidates for a partial command line.
+    #[command(name = "__complete", hide = true)]
+    Complete(ghc_cmd::completion::CompleteArgs),
     /// Manage configuration for ghc.
     #[command(subcommand)]
     Config(ghc_cmd::config::ConfigCommand),
@@ -126,6 +168,205 @@ enum Commands {
     Workflow(ghc_cmd::workflow::WorkflowCommand),
 }
 
+/// Commands listed under "Core Commands" in the top-level help.
+const CORE_COMMANDS: &[&str] = &[
+    "auth",
+    "browse",
+    "codespace",
+    "gist",
+    "issue",
+    "org",
+    "pr",
+    "project",
+    "release",
+    "repo",
+];
+
+/// Commands listed under "GitHub Actions Commands" in the top-level help.
+const ACTIONS_COMMANDS: &[&str] = &["actions", "cache", "run", "workflow"];
+
+/// Build the root command with `gh`-style grouped command sections.
+///
+/// Commands not in [`CORE_COMMANDS`] or [`ACTIONS_COMMANDS`] are listed as
+/// additional commands; configured aliases get a section of their own.
+fn build_cli(aliases: &HashMap<String, String>) -> clap::Command {
+    let cmd = cli_command();
+    let template = format!(
+        "{{before-help}}{{about-with-newline}}\n{{usage-heading}} {{usage}}\n\n{}Options:\n{{options}}{{after-help}}",
+        grouped_commands(&cmd, aliases),
+    );
+    cmd.help_template(template)
+}
+
+/// The full command tree, with `--help` made long-only wherever `-h` is
+/// taken by `--hostname`.
+fn cli_command() -> clap::Command {
+    long_only_help(Cli::command())
+}
+
+fn long_only_help(mut cmd: clap::Command) -> clap::Command {
+    let names: Vec<String> = cmd
+        .get_subcommands()
+        .map(|sub| sub.get_name().to_string())
+        .collect();
+    for name in names {
+        cmd = cmd.mut_subcommand(name, long_only_help);
+    }
+    if cmd.get_arguments().any(|arg| arg.get_short() == Some('h')) {
+        cmd = cmd.disable_help_flag(true).arg(
+            clap::Arg::new("help")
+                .long("help")
+                .help("Print help")
+                .action(clap::ArgAction::Help),
+        );
+    }
+    cmd
+}
+
+/// Render the command sections of the top-level help.
+fn grouped_commands(cmd: &clap::Command, aliases: &HashMap<String, String>) -> String {
+    let mut core = Vec::new();
+    let mut actions = Vec::new();
+    let mut additional = Vec::new();
+    for sub in cmd.get_subcommands().filter(|s| !s.is_hide_set()) {
+        let name = sub.get_name();
+        let about = sub.get_about().map(ToString::to_string).unwrap_or_default();
+        let entry = (format!("{name}:"), about);
+        if CORE_COMMANDS.contains(&name) {
+            core.push(entry);
+        } else if ACTIONS_COMMANDS.contains(&name) {
+            actions.push(entry);
+        } else {
+            additional.push(entry);
+        }
+    }
+
+    let mut alias_entries: Vec<(String, String)> = aliases
+        .iter()
+        .map(|(name, expansion)| {
+            let kind = if expand::is_shell_alias(expansion) {
+                "Shell alias"
+            } else {
+                "Alias"
+            };
+            (format!("{name}:"), format!("{kind} for \"{expansion}\""))
+        })
+        .collect();
+    alias_entries.sort();
+
+    let width = core
+        .iter()
+        .chain(&actions)
+        .chain(&additional)
+        .chain(&alias_entries)
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(0);
+
+    let mut out = String::new();
+    for (heading, entries) in [
+        ("Core Commands", &core),
+        ("GitHub Actions Commands", &actions),
+        ("Additional Commands", &additional),
+        ("Alias Commands", &alias_entries),
+    ] {
+        if entries.is_empty() {
+            continue;
+        }
+        let _ = writeln!(out, "{heading}:");
+        for (name, about) in entries {
+            let _ = writeln!(out, "  {name:<width$}  {}", about.trim_end_matches('.'));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Configured command aliases, or none when the config cannot be read.
+fn configured_aliases(factory: &Factory) -> HashMap<String, String> {
+    factory
+        .config()
+        .ok()
+        .and_then(|cfg| cfg.lock().ok().map(|cfg| cfg.aliases().clone()))
+        .unwrap_or_default()
+}
+
+/// Expand a configured alias named by the first argument.
+///
+/// Built-in commands always win over aliases of the same name. An alias
+/// may expand to another alias; expanding the same alias twice is an error
+/// rather than an endless loop.
+fn expand_alias(
+    aliases: &HashMap<String, String>,
+    args: &[String],
+) -> anyhow::Result<Option<Expansion>> {
+    let root = cli_command();
+    let mut seen: Vec<&str> = Vec::new();
+    let mut current = args.get(1..).unwrap_or_default().to_vec();
+    loop {
+        let Some(name) = current.first() else {
+            break;
+        };
+        if root.find_subcommand(name).is_some() {
+            break;
+        }
+        let Some((name, expansion)) = aliases.get_key_value(name) else {
+            break;
+        };
+        if seen.contains(&name.as_str()) {
+            seen.push(name);
+            anyhow::bail!("alias loop detected: {}", seen.join(" -> "));
+        }
+        seen.push(name);
+        match expand::expand(expansion, &current[1..])? {
+            Expansion::Command(expanded) => current = expanded,
+            shell @ Expansion::Shell(_) => return Ok(Some(shell)),
+        }
+    }
+    Ok((!seen.is_empty()).then_some(Expansion::Command(current)))
+}
+
+/// The process arguments with any alias expanded.
+///
+/// Arguments are passed through untouched unless the first one names an
+/// alias. Shell aliases are run here and the process exits with their status.
+fn resolve_args(aliases: &HashMap<String, String>) -> Vec<OsString> {
+    let mut args: Vec<OsString> = std::env::args_os().collect();
+    if !args
+        .get(1)
+        .and_then(|arg| arg.to_str())
+        .is_some_and(|name| aliases.contains_key(name))
+    {
+        return args;
+    }
+    let utf8: Vec<String> = args
+        .iter()
+        .map(|arg| arg.to_string_lossy().into_owned())
+        .collect();
+    match expand_alias(aliases, &utf8) {
+        Ok(None) => args,
+        Ok(Some(Expansion::Command(expanded))) => {
+            args.truncate(1);
+            args.extend(expanded.into_iter().map(OsString::from));
+            args
+        }
+        Ok(Some(Expansion::Shell(command))) => {
+            let depth = expand::alias_depth();
+            let code = expand::check_alias_depth(depth)
+                .and_then(|()| expand::run_shell(&command, depth))
+                .unwrap_or_else(|e| {
+                    eprintln!("{e}");
+                    exit_codes::ERROR
+                });
+            std::process::exit(code);
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(exit_codes::ERROR);
+        }
+    }
+}
+
 #[tokio::main]
 async fn main() {
     // Initialize tracing: only enable when GH_DEBUG is set (matching gh behavior).
@@ -144,9 +385,20 @@ async fn main() {
             .init();
     }
 
-    let cli = Cli::parse();
+    let mut factory = Factory::new(env!("CARGO_PKG_VERSION").to_string());
+    let aliases = configured_aliases(&factory);
+    let args = resolve_args(&aliases);
 
-    let factory = Factory::new(env!("CARGO_PKG_VERSION").to_string());
+    let cli = Cli::from_arg_matches(&build_cli(&aliases).get_matches_from(args))
+        .unwrap_or_else(|e| e.exit());
+    factory.apply_prompt_preference(cli.prompt_flag());
+    match cli.color {
+        ColorWhen::Auto => {}
+        ColorWhen::Always => factory.io.set_color_enabled(true),
+        ColorWhen::Never => factory.io.set_color_enabled(false),
+    }
+    factory.apply_accessibility_preference();
+    factory.apply_color_preferences();
 
     let exit_code = if let Some(cmd) = cli.command {
         match run_command(cmd, &factory).await {
@@ -164,14 +416,13 @@ async fn main() {
                 {
                     exit_codes::PENDING
                 } else {
-                    eprintln!("{e:#}");
+                    eprintln!("{}", ghc_api::errors::describe(&e));
                     exit_codes::ERROR
                 }
             }
         }
     } else {
-        use clap::CommandFactory;
-        Cli::command().print_help().ok();
+        build_cli(&aliases).print_help().ok();
         println!();
         exit_codes::OK
     };
@@ -191,7 +442,8 @@ async fn run_command(cmd: Commands, factory: &Factory) -> anyhow::Result<()> {
         Commands::Browse(args) => args.run(factory).await,
         Commands::Cache(sub) => sub.run(factory).await,
         Commands::Codespace(sub) => sub.run(factory).await,
-        Commands::Completion(args) => args.run(factory).await,
+        Commands::Complete(args) => args.run(factory).await,
+        Commands::Completion(args) => args.run(factory, cli_command()),
         Commands::Config(sub) => sub.run(factory),
         Commands::Copilot(sub) => sub.run(factory).await,
         Commands::Extension(sub) => sub.run(factory).await,
@@ -223,3 +475,213 @@ async fn run_command(cmd: Commands, factory: &Factory) -> anyhow::Result<()> {
         Commands::Workflow(sub) => sub.run(factory).await,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_group_commands_in_help() {
+        let aliases = HashMap::from([("co".to_string(), "pr checkout".to_string())]);
+        let help = build_cli(&aliases).render_help().to_string();
+
+        let headings = [
+            "Core Commands:",
+            "GitHub Actions Commands:",
+            "Additional Commands:",
+            "Alias Commands:",
+        ];
+        let positions: Vec<usize> = headings
+            .iter()
+            .map(|h| {
+                help.find(h)
+                    .unwrap_or_else(|| panic!("missing {h}: {help}"))
+            })
+            .collect();
+        assert!(positions.is_sorted(), "headings out of order: {help}");
+
+        assert!(help.contains("Alias for \"pr checkout\""), "{help}");
+        assert!(help.contains("Options:"), "{help}");
+    }
+
+    #[test]
+    fn test_should_expand_aliases_but_not_builtin_commands() {
+        let aliases = HashMap::from([
+            ("co".to_string(), "pr checkout".to_string()),
+            ("pr".to_string(), "issue list".to_string()),
+            (
+                "bugs".to_string(),
+                "!ghc issue list --label bug".to_string(),
+            ),
+        ]);
+        let args =
+            |list: &[&str]| -> Vec<String> { list.iter().map(ToString::to_string).collect() };
+
+        assert_eq!(
+            expand_alias(&aliases, &args(&["ghc", "co", "12"])).unwrap(),
+            Some(Expansion::Command(args(&["pr", "checkout", "12"])))
+        );
+        assert_eq!(
+            expand_alias(&aliases, &args(&["ghc", "bugs"])).unwrap(),
+            Some(Expansion::Shell(args(&[
+                "sh",
+                "-c",
+                "ghc issue list --label bug"
+            ])))
+        );
+        assert_eq!(
+            expand_alias(&aliases, &args(&["ghc", "pr", "list"])).unwrap(),
+            None
+        );
+        assert_eq!(expand_alias(&aliases, &args(&["ghc"])).unwrap(), None);
+    }
+
+    #[test]
+    fn test_should_expand_nested_aliases_and_stop_on_loops() {
+        let aliases = HashMap::from([
+            ("co".to_string(), "pr checkout".to_string()),
+            ("c".to_string(), "co $1 --force".to_string()),
+            ("ping".to_string(), "pong".to_string()),
+            ("pong".to_string(), "ping".to_string()),
+        ]);
+        let args =
+            |list: &[&str]| -> Vec<String> { list.iter().map(ToString::to_string).collect() };
+
+        assert_eq!(
+            expand_alias(&aliases, &args(&["ghc", "c", "123"])).unwrap(),
+            Some(Expansion::Command(args(&[
+                "pr", "checkout", "123", "--force"
+            ])))
+        );
+        let err = expand_alias(&aliases, &args(&["ghc", "ping"])).unwrap_err();
+        assert_eq!(err.to_string(), "alias loop detected: ping -> pong -> ping");
+    }
+
+    #[test]
+    fn test_should_parse_expanded_alias_as_builtin_command() {
+        let aliases = HashMap::from([("co".to_string(), "pr checkout".to_string())]);
+        let Some(Expansion::Command(expanded)) = expand_alias(
+            &aliases,
+            &["ghc".to_string(), "co".to_string(), "123".to_string()],
+        )
+        .unwrap() else {
+            panic!("co should expand to a command");
+        };
+        let argv: Vec<String> = std::iter::once("ghc".to_string()).chain(expanded).collect();
+
+        let cli = Cli::try_parse_from(&argv).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Pr(ghc_cmd::pr::PrCommand::Checkout(_)))
+        ));
+        let matches = cli_command().try_get_matches_from(&argv).unwrap();
+        let checkout = matches
+            .subcommand_matches("pr")
+            .and_then(|pr| pr.subcommand_matches("checkout"))
+            .unwrap();
+        assert_eq!(checkout.get_one::<String>("pr").unwrap(), "123");
+    }
+
+    #[test]
+    fn test_should_list_every_command_once() {
+        let cmd = Cli::command();
+        let sections = grouped_commands(&cmd, &HashMap::new());
+        for sub in cmd.get_subcommands().filter(|s| !s.is_hide_set()) {
+            let needle = format!("  {}:", sub.get_name());
+            assert_eq!(
+                sections.matches(&needle).count(),
+                1,
+                "{} should appear once: {sections}",
+                sub.get_name()
+            );
+        }
+        assert!(!sections.contains("Alias Commands:"));
+    }
+
+    #[test]
+    fn test_should_place_commands_in_groups() {
+        let sections = grouped_commands(&Cli::command(), &HashMap::new());
+        let core = sections.find("Core Commands:").unwrap();
+        let actions = sections.find("GitHub Actions Commands:").unwrap();
+        let additional = sections.find("Additional Commands:").unwrap();
+
+        let pos = |name: &str| sections.find(&format!("  {name}:")).unwrap();
+        assert!((core..actions).contains(&pos("pr")));
+        assert!((actions..additional).contains(&pos("workflow")));
+        assert!(pos("api") > additional);
+    }
+
+    #[test]
+    fn test_should_build_valid_command_tree() {
+        cli_command().debug_assert();
+    }
+
+    #[test]
+    fn test_should_keep_hostname_short_flag() {
+        let matches = cli_command()
+            .try_get_matches_from(["ghc", "auth", "status", "-h", "example.com"])
+            .unwrap();
+        let cli = Cli::from_arg_matches(&matches).unwrap();
+        assert!(matches!(cli.command, Some(Commands::Auth(_))));
+    }
+
+    #[test]
+    fn test_should_complete_pr_subcommands() {
+        let script =
+            ghc_cmd::completion::generate_for(cli_command(), clap_complete::Shell::Bash).unwrap();
+        let pr_opts = script
+            .split("ghc__subcmd__pr)")
+            .nth(1)
+            .and_then(|rest| rest.lines().nth(1))
+            .unwrap();
+        assert!(
+            pr_opts.contains("checkout checks close comment create"),
+            "pr completions: {pr_opts}"
+        );
+    }
+
+    #[test]
+    fn test_should_parse_color_flag() {
+        let parse = |args: &[&str]| {
+            let matches = build_cli(&HashMap::new())
+                .try_get_matches_from(args)
+                .unwrap();
+            Cli::from_arg_matches(&matches).unwrap().color
+        };
+        assert_eq!(parse(&["ghc", "status"]), ColorWhen::Auto);
+        assert_eq!(
+            parse(&["ghc", "--color=always", "status"]),
+            ColorWhen::Always
+        );
+        assert_eq!(
+            parse(&["ghc", "--color", "never", "status"]),
+            ColorWhen::Never
+        );
+        assert_eq!(
+            parse(&["ghc", "pr", "list", "--color=always"]),
+            ColorWhen::Always
+        );
+        assert!(
+            build_cli(&HashMap::new())
+                .try_get_matches_from(["ghc", "--color=sometimes", "status"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_should_parse_global_prompt_flags() {
+        let parse = |args: &[&str]| {
+            let matches = build_cli(&HashMap::new())
+                .try_get_matches_from(args)
+                .unwrap();
+            Cli::from_arg_matches(&matches).unwrap().prompt_flag()
+        };
+        assert_eq!(parse(&["ghc", "repo", "list"]), None);
+        assert_eq!(parse(&["ghc", "repo", "list", "--no-prompt"]), Some(false));
+        assert_eq!(parse(&["ghc", "--no-prompt", "status"]), Some(false));
+        assert_eq!(
+            parse(&["ghc", "--no-prompt", "repo", "create", "--prompt"]),
+            Some(true)
+        );
+    }
+}